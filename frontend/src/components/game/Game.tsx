@@ -6,7 +6,7 @@ import { GameOverModal } from "./GameOverModal";
 import { useGameStore } from "../../store/gameStore";
 import { useWebSocket } from "../../hooks/useWebSocket";
 import type { ServerMessage } from "../../types/generated/ServerMessage";
-import type { GuessResult } from "../../types/generated";
+import type { GameOverReason, GuessResult, RoundRecap } from "../../types/generated";
 
 export const Game: React.FC = () => {
   const { gameId } = useParams<{ gameId: string }>();
@@ -38,7 +38,10 @@ export const Game: React.FC = () => {
     isOpen: boolean;
     winner: any;
     finalScores: any[];
-  }>({ isOpen: false, winner: null, finalScores: [] });
+    recap: RoundRecap[];
+    categories: string[];
+    reason: GameOverReason;
+  }>({ isOpen: false, winner: null, finalScores: [], recap: [], categories: [], reason: "PointThreshold" });
   const [isRejoining, setIsRejoining] = useState(false);
 
   // Create message handler with fresh state access
@@ -107,6 +110,9 @@ export const Game: React.FC = () => {
             isOpen: true,
             winner: message.GameOver.winner,
             finalScores: message.GameOver.final_scores,
+            recap: message.GameOver.recap,
+            categories: message.GameOver.categories,
+            reason: message.GameOver.reason,
           });
         } else if ("PlayerDisconnected" in message) {
           console.log(
@@ -256,11 +262,21 @@ export const Game: React.FC = () => {
       <GameOverModal
         isOpen={gameOverModal.isOpen}
         onClose={() => {
-          setGameOverModal({ isOpen: false, winner: null, finalScores: [] });
+          setGameOverModal({
+            isOpen: false,
+            winner: null,
+            finalScores: [],
+            recap: [],
+            categories: [],
+            reason: "PointThreshold",
+          });
           navigate("/");
         }}
         winner={gameOverModal.winner}
         finalScores={gameOverModal.finalScores}
+        recap={gameOverModal.recap}
+        categories={gameOverModal.categories}
+        reason={gameOverModal.reason}
       />
     </>
   );
@@ -1,11 +1,14 @@
 import React, { useEffect, useState } from "react";
-import type { Player } from "../../types/generated";
+import type { GameOverReason, Player, RoundRecap } from "../../types/generated";
 
 interface GameOverModalProps {
   isOpen: boolean;
   onClose: () => void;
   winner: Player;
   finalScores: Player[];
+  recap?: RoundRecap[]; // One entry per word played
+  categories?: string[]; // Theme the game's words came from
+  reason?: GameOverReason;
   autoCloseDelay?: number; // Auto-close after N milliseconds
 }
 
@@ -14,6 +17,9 @@ export const GameOverModal: React.FC<GameOverModalProps> = ({
   onClose,
   winner,
   finalScores,
+  recap = [],
+  categories = [],
+  reason = "PointThreshold",
   autoCloseDelay = 8000, // 8 seconds default for game over
 }) => {
   const [countdown, setCountdown] = useState(Math.floor(autoCloseDelay / 1000));
@@ -38,6 +44,9 @@ export const GameOverModal: React.FC<GameOverModalProps> = ({
 
   if (!isOpen) return null;
 
+  const displayName = (playerId: string) =>
+    finalScores.find((p) => p.user_id === playerId)?.display_name ?? "Unknown";
+
   // Sort players by points for final standings
   const sortedPlayers = [...finalScores].sort((a, b) => b.points - a.points);
 
@@ -91,9 +100,19 @@ export const GameOverModal: React.FC<GameOverModalProps> = ({
           <div className="text-2xl font-bold text-green-600 mb-4">
             {winner.display_name} Wins!
           </div>
+          {reason === "TimeLimit" && (
+            <div className="text-sm text-gray-500 mb-2" data-testid="time-limit-reached">
+              Time limit reached, the leader takes the win
+            </div>
+          )}
           <div className="text-lg text-gray-600">
             Final Score: <span className="font-bold text-green-600">{winner.points} points</span>
           </div>
+          {categories.length > 0 && (
+            <div className="text-sm text-gray-500 mt-2 capitalize" data-testid="game-theme">
+              Theme: {categories.join(", ")}
+            </div>
+          )}
         </div>
 
         {/* Final Standings */}
@@ -125,6 +144,28 @@ export const GameOverModal: React.FC<GameOverModalProps> = ({
           </div>
         </div>
 
+        {/* Round Recap */}
+        {recap.length > 0 && (
+          <div className="mb-6" data-testid="game-recap">
+            <h3 className="text-xl font-semibold text-gray-800 mb-4">Round Recap</h3>
+            <div className="space-y-1 text-sm">
+              {recap.map((round) => (
+                <div key={round.round} className="flex justify-between text-gray-700">
+                  <span>
+                    Round {round.round}:{" "}
+                    <span className="font-mono font-bold uppercase">{round.word}</span>
+                  </span>
+                  <span>
+                    {round.solved_by ? `Solved by ${displayName(round.solved_by)}` : "Unsolved"}
+                    {" · "}
+                    {round.points_awarded.reduce((sum, entry) => sum + entry.points, 0)} pts
+                  </span>
+                </div>
+              ))}
+            </div>
+          </div>
+        )}
+
         {/* Action Buttons */}
         <div className="flex justify-between items-center">
           <button
@@ -106,6 +106,7 @@ describe("Game State Synchronization", () => {
     points_earned: 3,
     was_winning_guess: false,
     timestamp: "2024-01-01T00:01:00Z",
+    assist_points: 0,
   };
 
   beforeEach(() => {
@@ -491,6 +492,9 @@ describe("Game State Synchronization", () => {
       const gameOverMessage = {
         winner: mockPlayer,
         final_scores: [mockPlayer],
+        recap: [],
+        categories: [],
+        reason: "PointThreshold" as const,
       };
 
       simulateServerMessage({
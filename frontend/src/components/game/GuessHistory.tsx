@@ -53,6 +53,13 @@ const GuessItem: React.FC<GuessItemProps> = ({ guess, index }) => {
               Winner
             </span>
           )}
+
+          {/* Assist Badge */}
+          {guess.assist_points > 0 && (
+            <span className="bg-blue-500 text-white text-xs px-2 py-0.5 rounded-full font-semibold">
+              Assist
+            </span>
+          )}
         </div>
 
         {/* Points */}
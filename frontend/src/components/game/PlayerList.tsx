@@ -6,6 +6,7 @@ interface PlayerListProps {
   currentPlayerId?: string;
   currentWinnerId?: string | null;
   pointThreshold: number;
+  scoresHidden?: boolean; // Opponents' points are withheld until the game ends
 }
 
 interface PlayerItemProps {
@@ -14,6 +15,7 @@ interface PlayerItemProps {
   isCurrentWinner: boolean;
   pointThreshold: number;
   rank: number;
+  scoreHidden: boolean;
 }
 
 const PlayerItem: React.FC<PlayerItemProps> = ({
@@ -22,6 +24,7 @@ const PlayerItem: React.FC<PlayerItemProps> = ({
   isCurrentWinner,
   pointThreshold,
   rank,
+  scoreHidden,
 }) => {
   const progressPercentage = Math.min(
     (player.points / pointThreshold) * 100,
@@ -64,7 +67,7 @@ const PlayerItem: React.FC<PlayerItemProps> = ({
 
         {/* Points */}
         <span className="font-bold text-lg">
-          {player.points}
+          {scoreHidden ? "?" : player.points}
           <span className="text-xs text-gray-500 ml-1">pts</span>
         </span>
       </div>
@@ -108,6 +111,7 @@ export const PlayerList: React.FC<PlayerListProps> = ({
   currentPlayerId,
   currentWinnerId,
   pointThreshold,
+  scoresHidden = false,
 }) => {
   // Sort players by points (descending)
   const sortedPlayers = [...players].sort((a, b) => b.points - a.points);
@@ -130,6 +134,7 @@ export const PlayerList: React.FC<PlayerListProps> = ({
             isCurrentWinner={player.user_id === currentWinnerId}
             pointThreshold={pointThreshold}
             rank={index + 1}
+            scoreHidden={scoresHidden && player.user_id !== currentPlayerId}
           />
         ))}
       </div>
@@ -158,6 +163,7 @@ export const PlayerListContainer: React.FC = () => {
       currentPlayerId={user?.id}
       currentWinnerId={gameState.current_winner}
       pointThreshold={gameState.point_threshold}
+      scoresHidden={gameState.hide_scores_until_end}
     />
   );
 };
@@ -27,6 +27,7 @@ const Lobby: React.FC = () => {
     secondsRemaining: number;
     playersReady: number;
     totalPlayers: number;
+    ratingWindow: number | null;
   } | null>(null);
   const [localCountdown, setLocalCountdown] = useState<number>(0);
 
@@ -52,6 +53,9 @@ const Lobby: React.FC = () => {
           console.log(
             "Match found, navigating to game:",
             message.MatchFound.game_id,
+            message.MatchFound.categories.length > 0
+              ? `(theme: ${message.MatchFound.categories.join(", ")})`
+              : "",
           );
         } else if ("MatchmakingCountdown" in message) {
           const countdown = message.MatchmakingCountdown;
@@ -59,6 +63,7 @@ const Lobby: React.FC = () => {
             secondsRemaining: countdown.seconds_remaining,
             playersReady: countdown.players_ready,
             totalPlayers: countdown.total_players,
+            ratingWindow: countdown.rating_window,
           });
           setLocalCountdown(countdown.seconds_remaining);
           console.log("Countdown update:", countdown);
@@ -298,6 +303,11 @@ const Lobby: React.FC = () => {
                           ? `Starting in ${localCountdown} seconds`
                           : "Starting now..."}
                       </p>
+                      {countdownInfo.ratingWindow !== null && (
+                        <p className="text-blue-600 text-xs">
+                          Searching within ±{countdownInfo.ratingWindow} rating
+                        </p>
+                      )}
                     </div>
                     <div className="text-right">
                       <p className="text-blue-800 font-medium">
@@ -189,7 +189,9 @@ describe("WebSocketService", () => {
       expect(result).toBe(true);
       expect(service.authenticated).toBe(true);
       expect(mockWs.send).toHaveBeenCalledWith(
-        JSON.stringify({ Authenticate: { token: "valid-token" } }),
+        JSON.stringify({
+          Authenticate: { token: "valid-token", auto_queue: false },
+        }),
       );
     });
 
@@ -348,7 +350,23 @@ describe("WebSocketService", () => {
 
       const mockWs = (service as any).ws as MockWebSocket;
       expect(mockWs.send).toHaveBeenCalledWith(
-        JSON.stringify({ RejoinGame: { game_id: "game-123" } }),
+        JSON.stringify({ RejoinGame: { game_id: "game-123", token: null } }),
+      );
+    });
+
+    it("should rejoin with the latest token for the game", async () => {
+      const mockWs = (service as any).ws as MockWebSocket;
+      mockWs.simulateMessage(
+        JSON.stringify({ RejoinToken: { game_id: "game-123", token: "first" } }),
+      );
+      mockWs.simulateMessage(
+        JSON.stringify({ RejoinToken: { game_id: "game-123", token: "second" } }),
+      );
+
+      service.rejoinGame("game-123");
+
+      expect(mockWs.send).toHaveBeenCalledWith(
+        JSON.stringify({ RejoinGame: { game_id: "game-123", token: "second" } }),
       );
     });
   });
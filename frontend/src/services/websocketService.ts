@@ -10,6 +10,8 @@ export class WebSocketService {
   private reconnectInterval = 1000;
   private isAuthenticated = false;
   private authToken: string | null = null;
+  private requeueOnReconnect = false;
+  private rejoinTokens: Map<string, string> = new Map();
   private sessionDisconnectedHandler?: () => void;
 
   constructor(private url: string) {}
@@ -94,7 +96,11 @@ export class WebSocketService {
       if (forceAuth) {
         this.sendMessage({ ForceAuthenticate: { token } });
       } else {
-        this.sendMessage({ Authenticate: { token } });
+        // Rejoin the queue we were in before a server restart
+        this.sendMessage({
+          Authenticate: { token, auto_queue: this.requeueOnReconnect },
+        });
+        this.requeueOnReconnect = false;
       }
 
       // Timeout after 10 seconds
@@ -115,7 +121,8 @@ export class WebSocketService {
   }
 
   rejoinGame(gameId: string) {
-    this.sendMessage({ RejoinGame: { game_id: gameId } });
+    const token = this.rejoinTokens.get(gameId) ?? null;
+    this.sendMessage({ RejoinGame: { game_id: gameId, token } });
   }
 
   addMessageHandler(handler: MessageHandler) {
@@ -130,6 +137,17 @@ export class WebSocketService {
     // Log incoming server messages for easier debugging
     console.log("[WebSocket] Received server message:", JSON.stringify(message, null, 2));
     
+    // Server is restarting while we wait in the queue
+    if (message === "RequeueOnReconnect") {
+      this.requeueOnReconnect = true;
+    }
+
+    // Keep the latest token for reclaiming our seat after a drop
+    if (typeof message === "object" && message !== null && "RejoinToken" in message) {
+      const { game_id, token } = message.RejoinToken;
+      this.rejoinTokens.set(game_id, token);
+    }
+
     // Handle SessionDisconnected specially
     if (typeof message === "object" && message !== null && "SessionDisconnected" in message) {
       console.warn("Session disconnected:", message.SessionDisconnected.reason);
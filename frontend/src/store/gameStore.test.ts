@@ -62,6 +62,7 @@ describe("GameStore Logic", () => {
     points_earned: 3,
     was_winning_guess: false,
     timestamp: "2024-01-01T00:00:00Z",
+    assist_points: 0,
   };
 
   beforeEach(() => {
@@ -343,6 +344,7 @@ describe("GameStore Logic", () => {
         points_earned: 1,
         was_winning_guess: false,
         timestamp: "2024-01-01T00:00:00Z",
+        assist_points: 0,
       };
 
       const guess2: PersonalGuess = {
@@ -350,6 +352,7 @@ describe("GameStore Logic", () => {
         points_earned: 2,
         was_winning_guess: true,
         timestamp: "2024-01-01T00:01:00Z",
+        assist_points: 0,
       };
 
       addPersonalGuess(guess1);
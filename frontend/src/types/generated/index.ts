@@ -1,10 +1,16 @@
 // Auto-generated TypeScript types from Rust
 // This file is regenerated when running: npm run types:generate
 
+export type { Avatar } from "./Avatar";
+export type { AvatarColor } from "./AvatarColor";
+export type { CampaignProgress } from "./CampaignProgress";
 export type { ClientMessage } from "./ClientMessage";
 export type { ConnectionError } from "./ConnectionError";
 export type { ConnectionInfo } from "./ConnectionInfo";
+export type { ErrorCode } from "./ErrorCode";
 export type { GameError } from "./GameError";
+export type { GameHistory } from "./GameHistory";
+export type { GameOverReason } from "./GameOverReason";
 export type { GamePhase } from "./GamePhase";
 export type { GameState } from "./GameState";
 export type { GameStatus } from "./GameStatus";
@@ -13,6 +19,14 @@ export type { LetterResult } from "./LetterResult";
 export type { LetterStatus } from "./LetterStatus";
 export type { PersonalGuess } from "./PersonalGuess";
 export type { Player } from "./Player";
+export type { PriorGuess } from "./PriorGuess";
+export type { Role } from "./Role";
+export type { RoundHistory } from "./RoundHistory";
+export type { RoundPoints } from "./RoundPoints";
+export type { RoundRecap } from "./RoundRecap";
+export type { RoundSummary } from "./RoundSummary";
 export type { SafeGameState } from "./SafeGameState";
+export type { ScoreBreakdown } from "./ScoreBreakdown";
 export type { ServerMessage } from "./ServerMessage";
+export type { Team } from "./Team";
 export type { User } from "./User";
@@ -119,9 +119,8 @@ impl GameCleanup {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Game, WordValidator};
-    use game_types::{GameStatus, Player, PlayerId};
-    use std::time::SystemTime;
+    use crate::Game;
+    use game_types::Player;
 
     fn create_test_game() -> Game {
         let player = Player {
@@ -130,6 +129,7 @@ mod tests {
             points: 0,
             guess_history: Vec::new(),
             is_connected: true,
+            avatar: None,
         };
 
         Game::new(
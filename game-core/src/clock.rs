@@ -0,0 +1,98 @@
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+
+/// Source of the current time for timer logic. Production code uses
+/// `SystemClock`; tests inject a `MockClock` and advance it by hand so
+/// countdowns and timeouts can be checked without sleeping.
+pub trait Clock: Debug + Send + Sync {
+    /// Monotonic time, for measuring elapsed durations
+    fn now(&self) -> Instant;
+    /// Wall-clock time, for deadlines and timestamps
+    fn system_time(&self) -> SystemTime;
+}
+
+pub type SharedClock = Arc<dyn Clock>;
+
+/// Clock backed by the operating system
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl SystemClock {
+    pub fn shared() -> SharedClock {
+        Arc::new(SystemClock)
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn system_time(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// Clock that stands still until `advance` is called
+#[derive(Debug)]
+pub struct MockClock {
+    start: Instant,
+    start_system: SystemTime,
+    elapsed: Mutex<Duration>,
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockClock {
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            start_system: SystemTime::now(),
+            elapsed: Mutex::new(Duration::ZERO),
+        }
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        *self.elapsed.lock().unwrap() += duration;
+    }
+
+    fn elapsed(&self) -> Duration {
+        *self.elapsed.lock().unwrap()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.start + self.elapsed()
+    }
+
+    fn system_time(&self) -> SystemTime {
+        self.start_system + self.elapsed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mock_clock_only_moves_when_advanced() {
+        let clock = MockClock::new();
+        let before = clock.now();
+        let before_system = clock.system_time();
+
+        assert_eq!(clock.now(), before);
+
+        clock.advance(Duration::from_secs(90));
+        assert_eq!(clock.now() - before, Duration::from_secs(90));
+        assert_eq!(
+            clock.system_time().duration_since(before_system).unwrap(),
+            Duration::from_secs(90)
+        );
+    }
+}
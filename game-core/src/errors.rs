@@ -0,0 +1,72 @@
+use game_types::{GamePhase, PriorGuess};
+
+/// Errors produced by game logic. Callers should match on the variant rather
+/// than the rendered message.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum GameError {
+    #[error("Game not found")]
+    GameNotFound,
+    #[error("Player not in game")]
+    PlayerNotInGame,
+    #[error("Guess is empty")]
+    EmptyGuess,
+    #[error("Guess has {actual} letters but the word has {expected}")]
+    WrongLength { expected: usize, actual: usize },
+    #[error("Invalid word: {word}")]
+    InvalidWord { word: String },
+    #[error("Word not allowed: {word}")]
+    WordNotAllowed { word: String },
+    #[error("Word already guessed: {word}")]
+    WordAlreadyGuessed {
+        word: String,
+        prior: Option<PriorGuess>, // Who first played it and when, if still tracked
+    },
+    #[error("Cannot submit guess in phase {phase:?}")]
+    WrongPhase { phase: GamePhase },
+    #[error("Only the round winner can make individual guesses")]
+    NotYourTurn,
+    #[error("Guessing too fast, try again in {retry_after_ms}ms")]
+    GuessTooFast { retry_after_ms: u64 },
+    #[error("This turn cannot be skipped")]
+    TurnSkipNotAllowed,
+    #[error("Skipping rounds is not enabled")]
+    RoundSkipNotAllowed,
+    #[error("Hints are not enabled")]
+    HintsNotAllowed,
+    #[error("No more hints this round (limit {limit})")]
+    HintLimitReached { limit: usize },
+    #[error("Every letter is already revealed")]
+    NoHintAvailable,
+    #[error("Spectators cannot guess")]
+    SpectatorCannotGuess,
+    #[error("Invalid number of players: {count}")]
+    InvalidPlayerCount { count: usize },
+    #[error("Failed to select target word: {reason}")]
+    WordSelection { reason: String },
+    #[error("Player is not connected")]
+    PlayerNotConnected,
+    #[error("Player is not authenticated")]
+    PlayerNotAuthenticated,
+    #[error("{display_name} is already in the game")]
+    DuplicatePlayer { display_name: String },
+    #[error("Server is at capacity")]
+    ServerAtCapacity,
+    #[error("Point threshold must be between {min} and {max}, got {threshold}")]
+    PointThresholdOutOfRange { threshold: i32, min: i32, max: i32 },
+    #[error("Practice words must be {min} to {max} letters long")]
+    PracticeWordLength { min: usize, max: usize },
+    #[error("A rejoin token is required to rejoin this game")]
+    RejoinTokenRequired,
+    #[error("Invalid rejoin token")]
+    InvalidRejoinToken,
+    #[error("Rejoin token has already been used")]
+    RejoinTokenUsed,
+    #[error("Rejoin token has expired")]
+    RejoinTokenExpired,
+    #[error("Player is still connected")]
+    PlayerStillConnected,
+    #[error("No disconnected players to rejoin")]
+    NoSeatToRejoin,
+}
+
+pub type GameResult<T> = std::result::Result<T, GameError>;
@@ -123,7 +123,7 @@ mod tests {
     #[test]
     fn test_event_bus() {
         let mut bus = GameEventBus::new();
-        let mut handler = TestHandler::new();
+        let handler = TestHandler::new();
 
         let game_id = "test-game-id".to_string();
         let event = GameEvent::GameCreated {
@@ -1,11 +1,52 @@
-use crate::{GameEvent, GameEventBus, ScoringEngine, WordValidator};
-use anyhow::{Result, anyhow};
+use crate::{
+    GameError, GameEvent, GameEventBus, GameResult, GameRules, ScoringEngine, SharedClock,
+    SystemClock, TieBreak, WordValidator, letter_count, normalize_word,
+};
 use game_types::{
-    GameId, GamePhase, GameState, GameStatus, GuessResult, PersonalGuess, Player, PlayerId,
-    RoundCompletion, RoundResult,
+    CampaignProgress, GameId, GamePhase, GameState, GameStatus, GuessResult, LetterResult,
+    LetterStatus, PersonalGuess, Player, PlayerId, PriorGuess, RoundCompletion, RoundResult,
+    ScoreBreakdown, Team,
 };
-use std::collections::{HashMap, VecDeque};
-use std::time::{Duration, SystemTime};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::{Duration, Instant, SystemTime};
+
+/// Tiebreaker state when several players finish tied at or above the point
+/// threshold. The tied players take individual guesses in turn; after each
+/// full rotation the game ends if a single player leads.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuddenDeath {
+    pub players: Vec<PlayerId>,
+    pub turn: usize,
+}
+
+impl SuddenDeath {
+    fn current_player(&self) -> PlayerId {
+        self.players[self.turn].clone()
+    }
+}
+
+/// Pause after a round result, and the phase play resumes in once it ends
+#[derive(Debug, Clone, PartialEq)]
+pub struct Intermission {
+    pub ends_at: SystemTime,
+    pub resume_phase: GamePhase,
+}
+
+/// A round's guesses copied out of a `Game`, as (word, player id) pairs
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoundGuesses {
+    guesses: Vec<(String, String)>,
+    target_word: String,
+    tie_break: TieBreak,
+}
+
+impl RoundGuesses {
+    /// Index of the winning guess. Evaluates every guess, so callers sharing
+    /// the game should do this without holding its lock.
+    pub fn pick_winner(&self) -> Option<usize> {
+        ScoringEngine::determine_round_winner(&self.guesses, &self.target_word, self.tie_break)
+    }
+}
 
 #[derive(Debug)]
 pub struct Game {
@@ -15,6 +56,16 @@ pub struct Game {
     pub last_activity: SystemTime,
     pub countdown_end: Option<SystemTime>,
     pub current_phase: GamePhase,
+    pub rules: GameRules,
+    pub final_round: bool, // Threshold reached, next collaborative round is the last
+    pub trimmed_words: HashMap<String, PlayerId>, // Words dropped from the board by the size cap
+    pub sudden_death: Option<SuddenDeath>,
+    pub last_reveals: Vec<LetterResult>, // Newly revealed letters not yet taken
+    pub used_words: HashMap<String, PriorGuess>, // Words from earlier rounds, kept when reuse is forbidden
+    pub intermission: Option<Intermission>,
+    last_guess_at: HashMap<PlayerId, Instant>, // For the minimum interval between guesses
+    hinted_positions: HashMap<PlayerId, HashSet<i32>>, // Letters hinted to each player this word
+    clock: SharedClock,
 }
 
 impl Game {
@@ -24,10 +75,28 @@ impl Game {
         target_word: String,
         point_threshold: i32,
     ) -> Self {
+        Self::new_with_rules(
+            id,
+            players,
+            target_word,
+            point_threshold,
+            GameRules::default(),
+        )
+    }
+
+    pub fn new_with_rules(
+        id: GameId,
+        players: Vec<Player>,
+        target_word: String,
+        point_threshold: i32,
+        rules: GameRules,
+    ) -> Self {
+        let point_threshold =
+            rules.point_threshold_for(letter_count(&target_word), point_threshold);
         let state = GameState {
             id,
-            word: "*".repeat(target_word.len()), // Hide the actual word
-            word_length: target_word.len() as i32,
+            word: "*".repeat(letter_count(&target_word)), // Hide the actual word
+            word_length: letter_count(&target_word) as i32,
             current_round: 1,
             status: GameStatus::Starting,
             current_phase: GamePhase::Waiting,
@@ -36,6 +105,9 @@ impl Game {
             current_winner: None,
             created_at: chrono::Utc::now().to_rfc3339(),
             point_threshold,
+            teams: Vec::new(),
+            campaign: rules.carry_progress.then(CampaignProgress::default),
+            hide_scores_until_end: rules.hide_scores_until_end,
         };
 
         Self {
@@ -45,57 +117,155 @@ impl Game {
             last_activity: SystemTime::now(),
             countdown_end: None,
             current_phase: GamePhase::Waiting,
+            rules,
+            final_round: false,
+            trimmed_words: HashMap::new(),
+            sudden_death: None,
+            last_reveals: Vec::new(),
+            used_words: HashMap::new(),
+            intermission: None,
+            last_guess_at: HashMap::new(),
+            hinted_positions: HashMap::new(),
+            clock: SystemClock::shared(),
+        }
+    }
+
+    /// Read time from `clock` instead of the system clock
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.last_activity = clock.system_time();
+        self.clock = clock;
+        self
+    }
+
+    /// Play the game in team mode with the given sides
+    pub fn with_teams(mut self, teams: Vec<Team>) -> Self {
+        self.state.teams = teams;
+        self
+    }
+
+    pub fn clock(&self) -> &SharedClock {
+        &self.clock
+    }
+
+    /// Count a guess submission against the player's minimum guess interval,
+    /// refusing it if they submitted too recently. Refused submissions don't
+    /// restart the wait.
+    pub fn throttle_guess(&mut self, player_id: &PlayerId) -> GameResult<()> {
+        let Some(interval) = self.rules.min_guess_interval else {
+            return Ok(());
+        };
+
+        let now = self.clock.now();
+        if let Some(&last) = self.last_guess_at.get(player_id) {
+            let elapsed = now.duration_since(last);
+            if elapsed < interval {
+                return Err(GameError::GuessTooFast {
+                    retry_after_ms: (interval - elapsed).as_millis().max(1) as u64,
+                });
+            }
         }
+        self.last_guess_at.insert(player_id.clone(), now);
+        Ok(())
     }
 
-    pub fn add_guess(&mut self, player_id: &PlayerId, word: String) -> Result<()> {
+    pub fn add_guess(&mut self, player_id: &PlayerId, word: String) -> GameResult<()> {
         // Validate player is in the game
         if !self.state.players.iter().any(|p| &p.user_id == player_id) {
-            return Err(anyhow!("Player not in game"));
+            return Err(GameError::PlayerNotInGame);
+        }
+
+        if word.trim().is_empty() {
+            return Err(GameError::EmptyGuess);
         }
 
         // Check if word already guessed in this game
-        if self
-            .state
-            .official_board
-            .iter()
-            .any(|g| g.word.to_lowercase() == word.to_lowercase())
-        {
-            return Err(anyhow!("Word already guessed: {}", word));
+        if let Some(prior) = self.prior_guess(&word) {
+            return Err(GameError::WordAlreadyGuessed {
+                word,
+                prior: Some(prior),
+            });
         }
 
         // Store the guess for this round
         self.current_guesses.insert(player_id.clone(), word);
-        self.last_activity = SystemTime::now();
+        self.last_activity = self.clock.system_time();
 
         Ok(())
     }
 
-    pub fn process_round(&mut self) -> Result<Option<RoundResult>> {
+    pub fn process_round(&mut self) -> GameResult<Option<RoundResult>> {
         if self.current_guesses.is_empty() {
             return Ok(None);
         }
 
-        // Convert guesses to format expected by scoring engine
-        let guesses: Vec<(String, String)> = self
-            .current_guesses
-            .iter()
-            .map(|(player_id, word)| (word.clone(), player_id.to_string()))
-            .collect();
+        let round = self.round_guesses();
+        // Team rounds pick a winner per team while resolving
+        let winner = if self.state.teams.is_empty() {
+            round.pick_winner()
+        } else {
+            None
+        };
+        self.resolve_round(&round, winner)
+    }
+
+    /// Copy out the guesses for the current round so the winner can be
+    /// picked without holding on to the game
+    pub fn round_guesses(&self) -> RoundGuesses {
+        RoundGuesses {
+            guesses: self
+                .current_guesses
+                .iter()
+                .map(|(player_id, word)| (word.clone(), player_id.to_string()))
+                .collect(),
+            target_word: self.target_word.clone(),
+            tie_break: self.rules.scoring.tie_break,
+        }
+    }
+
+    /// Finish a collaborative round with the winner picked from `round`. When
+    /// the guesses have changed since `round` was copied out, the winner is
+    /// picked again from the current ones. A round that leaves the word
+    /// unsolved is followed by the rules' intermission, if any.
+    pub fn resolve_round(
+        &mut self,
+        round: &RoundGuesses,
+        winner_index: Option<usize>,
+    ) -> GameResult<Option<RoundResult>> {
+        let result = self.score_round(round, winner_index)?;
+        if matches!(result, Some(RoundResult::Continuing(_))) {
+            self.begin_intermission();
+        }
+        Ok(result)
+    }
+
+    fn score_round(
+        &mut self,
+        round: &RoundGuesses,
+        winner_index: Option<usize>,
+    ) -> GameResult<Option<RoundResult>> {
+        if self.current_guesses.is_empty() {
+            return Ok(None);
+        }
 
-        // Determine the winning guess
-        let winner_index = ScoringEngine::determine_round_winner(&guesses, &self.target_word);
+        if !self.state.teams.is_empty() {
+            return Ok(self.process_team_round());
+        }
+
+        if *round != self.round_guesses() {
+            let current = self.round_guesses();
+            let winner = current.pick_winner();
+            return self.score_round(&current, winner);
+        }
 
         if let Some(index) = winner_index {
-            let (winning_word, winning_player_str) = &guesses[index];
+            let (winning_word, winning_player_str) = &round.guesses[index];
             let winning_player_id = winning_player_str.clone();
 
             // Evaluate the winning guess
-            let (letter_results, points_earned) = ScoringEngine::evaluate_guess(
-                winning_word,
-                &self.target_word,
-                &self.state.official_board,
-            );
+            let (letter_results, base_points) =
+                self.evaluate_guess(winning_word, &self.state.official_board);
+            let breakdown = self.score_breakdown(&winning_player_id, base_points);
+            let points_earned = breakdown.total();
 
             // Create the guess result
             let guess_result = GuessResult {
@@ -104,16 +274,34 @@ impl Game {
                 letters: letter_results,
                 points_earned,
                 timestamp: chrono::Utc::now().to_rfc3339(),
+                breakdown,
             };
 
+            // Guesses that lost the round can still earn an assist for the
+            // letters they revealed against the board before the round
+            let mut assists = HashMap::new();
+            if self.rules.scoring.assist_fraction > 0.0 {
+                for (player_id, word) in &self.current_guesses {
+                    if *player_id != winning_player_id {
+                        let (_, points) = self.evaluate_guess(word, &self.state.official_board);
+                        assists.insert(player_id.clone(), self.rules.scoring.assist_points(points));
+                    }
+                }
+            }
+
             // Update player scores and guess history
             for player in &mut self.state.players {
                 if let Some(word) = self.current_guesses.get(&player.user_id) {
                     let was_winning_guess = player.user_id == winning_player_id;
-                    let points = if was_winning_guess { points_earned } else { 0 };
-
-                    if was_winning_guess {
-                        player.points += points;
+                    let assist_points = assists.get(&player.user_id).copied().unwrap_or(0);
+                    let points = if was_winning_guess {
+                        points_earned
+                    } else {
+                        assist_points
+                    };
+
+                    if points != 0 {
+                        player.points = self.rules.scoring.add_points(player.points, points);
                     }
 
                     player.guess_history.push(PersonalGuess {
@@ -121,12 +309,13 @@ impl Game {
                         points_earned: points,
                         was_winning_guess,
                         timestamp: chrono::Utc::now().to_rfc3339(),
+                        assist_points,
                     });
                 }
             }
 
             // Add to official board
-            self.state.official_board.push(guess_result.clone());
+            self.record_on_board(guess_result.clone());
 
             // Clear current round guesses
             self.current_guesses.clear();
@@ -142,28 +331,44 @@ impl Game {
                     .collect::<Vec<_>>()
             );
 
-            if let Some(winner) = self
-                .state
-                .players
-                .iter()
-                .find(|p| p.points >= self.state.point_threshold)
-            {
-                tracing::info!(
-                    "🏆 GAME OVER! {} reached {} points (threshold: {})",
-                    winner.display_name,
-                    winner.points,
-                    self.state.point_threshold
-                );
+            let word_solved = normalize_word(winning_word) == normalize_word(&self.target_word);
+
+            if self.should_end_game(true) {
+                if self.start_sudden_death() {
+                    return Ok(Some(if word_solved {
+                        RoundResult::WordCompleted(RoundCompletion {
+                            word: winning_word.clone(),
+                            player_id: winning_player_id,
+                            points_earned,
+                        })
+                    } else {
+                        RoundResult::Continuing(guess_result)
+                    }));
+                }
+                if let Some(winner) = self.state.players.iter().max_by_key(|p| p.points) {
+                    tracing::info!(
+                        "🏆 GAME OVER! {} reached {} points (threshold: {})",
+                        winner.display_name,
+                        winner.points,
+                        self.state.point_threshold
+                    );
+                }
                 self.state.status = GameStatus::Completed;
                 self.set_phase(GamePhase::GameOver);
                 Ok(Some(RoundResult::GameOver(guess_result)))
-            } else if winning_word.to_lowercase() == self.target_word.to_lowercase() {
+            } else if word_solved {
                 // Word was solved - start new round with new word
                 Ok(Some(RoundResult::WordCompleted(RoundCompletion {
                     word: winning_word.clone(),
                     player_id: winning_player_id,
                     points_earned,
                 })))
+            } else if self.final_round || !self.rules.individual_guess {
+                // Final round is armed or the rules leave out the individual
+                // guess - everyone goes straight back to collaborative guessing
+                self.state.current_winner = None;
+                self.set_phase(GamePhase::Guessing);
+                Ok(Some(RoundResult::Continuing(guess_result)))
             } else {
                 // Continue guessing - winner gets individual guess
                 self.state.current_winner = Some(winning_player_id);
@@ -175,551 +380,2224 @@ impl Game {
         }
     }
 
-    pub fn get_winner(&self) -> Option<&Player> {
-        self.state
-            .players
-            .iter()
-            .max_by_key(|p| p.points)
-            .filter(|p| p.points >= self.state.point_threshold)
-    }
-
-    pub fn is_expired(&self, timeout_duration: Duration) -> bool {
-        self.last_activity.elapsed().unwrap_or(Duration::ZERO) > timeout_duration
-    }
-
-    pub fn set_countdown(&mut self, duration: Duration) {
-        self.countdown_end = Some(SystemTime::now() + duration);
-        self.set_phase(GamePhase::Countdown);
-    }
-
-    pub fn is_countdown_finished(&self) -> bool {
-        if let Some(end_time) = self.countdown_end {
-            SystemTime::now() >= end_time
-        } else {
-            false
-        }
-    }
-
-    fn set_phase(&mut self, phase: GamePhase) {
-        self.current_phase = phase.clone();
-        self.state.current_phase = phase;
-    }
-
-    pub fn start_guessing_phase(&mut self) {
-        self.set_phase(GamePhase::Guessing);
-    }
+    /// Team mode round: each team's best guess is scored against the board
+    /// as it stood before the round, so several words can land at once.
+    /// There is no individual guess phase; teams keep guessing together.
+    fn process_team_round(&mut self) -> Option<RoundResult> {
+        let board_before = self.state.official_board.clone();
+        let mut team_results: Vec<GuessResult> = Vec::new();
 
-    /// Process an individual guess from the round winner
-    pub fn process_individual_guess(
-        &mut self,
-        player_id: PlayerId,
-        word: String,
-    ) -> Result<Option<RoundResult>> {
-        // Check if we're in the right phase
-        if self.current_phase != GamePhase::IndividualGuess {
-            return Err(anyhow!("Not in individual guess phase"));
-        }
+        for team in &self.state.teams {
+            let guesses: Vec<(String, String)> = team
+                .player_ids
+                .iter()
+                .filter_map(|id| {
+                    self.current_guesses
+                        .get(id)
+                        .map(|word| (word.clone(), id.clone()))
+                })
+                .collect();
+            let Some(index) = ScoringEngine::determine_round_winner(
+                &guesses,
+                &self.target_word,
+                self.rules.scoring.tie_break,
+            ) else {
+                continue;
+            };
 
-        // Check if this player is the current winner
-        if Some(player_id.clone()) != self.state.current_winner {
-            return Err(anyhow!("Only the round winner can make individual guesses"));
+            let (word, player_id) = &guesses[index];
+            let (letters, base_points) = self.evaluate_guess(word, &board_before);
+            let breakdown = self.score_breakdown(player_id, base_points);
+            team_results.push(GuessResult {
+                word: word.clone(),
+                player_id: player_id.clone(),
+                letters,
+                points_earned: breakdown.total(),
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                breakdown,
+            });
         }
 
-        // Check if word was already guessed
-        let word_lower = word.to_lowercase();
-        for guess in &self.state.official_board {
-            if guess.word.to_lowercase() == word_lower {
-                return Err(anyhow!("Word '{}' was already guessed", word));
-            }
+        if team_results.is_empty() {
+            return None;
         }
 
-        // Evaluate the guess
-        let (letter_results, points_earned) =
-            ScoringEngine::evaluate_guess(&word, &self.target_word, &self.state.official_board);
-
-        println!(
-            "Individual guess '{}' evaluated: {} points earned against target '{}' with {} previous guesses",
-            word,
-            points_earned,
-            self.target_word,
-            self.state.official_board.len()
-        );
-
-        // Create the guess result
-        let guess_result = GuessResult {
-            word: word.clone(),
-            player_id: player_id.clone(),
-            letters: letter_results,
-            points_earned,
-            timestamp: chrono::Utc::now().to_rfc3339(),
-        };
-
-        // Update player score and history
         for player in &mut self.state.players {
-            if player.user_id == player_id {
-                player.points += points_earned;
+            if let Some(word) = self.current_guesses.get(&player.user_id) {
+                let team_guess = team_results.iter().find(|g| g.player_id == player.user_id);
+                let points = team_guess.map(|g| g.points_earned).unwrap_or(0);
+                player.points = self.rules.scoring.add_points(player.points, points);
                 player.guess_history.push(PersonalGuess {
                     word: word.clone(),
-                    points_earned,
-                    was_winning_guess: true,
+                    points_earned: points,
+                    was_winning_guess: team_guess.is_some(),
                     timestamp: chrono::Utc::now().to_rfc3339(),
+                    assist_points: 0,
                 });
-                break;
             }
         }
 
-        // Add to official board
-        self.state.official_board.push(guess_result.clone());
-
-        // Check if anyone has won by points (only way to end the match)
-        tracing::info!(
-            "🎯 Checking point threshold after individual guess: {} | Current scores: {:?}",
-            self.state.point_threshold,
-            self.state
-                .players
-                .iter()
-                .map(|p| format!("{}: {}", p.display_name, p.points))
-                .collect::<Vec<_>>()
-        );
+        // Both teams may have landed on the same word
+        for guess_result in &team_results {
+            if !self.is_already_guessed(&guess_result.word) {
+                self.record_on_board(guess_result.clone());
+            }
+        }
+        self.current_guesses.clear();
+        self.refresh_team_points();
 
-        if let Some(winner) = self
-            .state
-            .players
+        let target = normalize_word(&self.target_word);
+        let solved = team_results
             .iter()
-            .find(|p| p.points >= self.state.point_threshold)
-        {
+            .find(|g| normalize_word(&g.word) == target)
+            .cloned();
+        let best = team_results
+            .iter()
+            .max_by_key(|g| g.points_earned)
+            .cloned()
+            .unwrap_or_else(|| team_results[0].clone());
+
+        if let Some(team) = self.winning_team() {
             tracing::info!(
-                "🏆 GAME OVER! {} reached {} points after individual guess (threshold: {})",
-                winner.display_name,
-                winner.points,
+                "🏆 GAME OVER! Team {} reached {} points (threshold: {})",
+                team.name,
+                team.points,
                 self.state.point_threshold
             );
             self.state.status = GameStatus::Completed;
             self.set_phase(GamePhase::GameOver);
-            Ok(Some(RoundResult::GameOver(guess_result)))
-        } else if word.to_lowercase() == self.target_word.to_lowercase() {
-            // Word was solved - start new round with new word
-            Ok(Some(RoundResult::WordCompleted(RoundCompletion {
-                word: word.clone(),
-                player_id,
-                points_earned,
-            })))
-        } else {
-            // Move back to collaborative guessing phase
-            self.state.current_winner = None; // Clear winner for next collaborative round
-            self.set_phase(GamePhase::Guessing);
-            Ok(Some(RoundResult::Continuing(guess_result)))
+            return Some(RoundResult::GameOver(solved.unwrap_or(best)));
         }
-    }
-}
 
-pub struct GameManager {
-    pub active_games: HashMap<GameId, Game>,
-    pub player_to_game: HashMap<PlayerId, GameId>,
-    pub game_queue: VecDeque<PlayerId>,
-    pub word_validator: WordValidator,
-    pub event_bus: GameEventBus,
-    pub default_point_threshold: i32,
-}
+        if let Some(solved) = solved {
+            return Some(RoundResult::WordCompleted(RoundCompletion {
+                word: solved.word,
+                player_id: solved.player_id,
+                points_earned: solved.points_earned,
+            }));
+        }
 
-impl GameManager {
-    pub fn new(word_validator: WordValidator) -> Self {
-        Self {
-            active_games: HashMap::new(),
-            player_to_game: HashMap::new(),
-            game_queue: VecDeque::new(),
-            word_validator,
-            event_bus: GameEventBus::new(),
-            default_point_threshold: 25,
+        self.state.current_winner = None;
+        self.set_phase(GamePhase::Guessing);
+        Some(RoundResult::Continuing(best))
+    }
+
+    /// Recompute each team's points from its members' scores
+    fn refresh_team_points(&mut self) {
+        for team in &mut self.state.teams {
+            team.points = self
+                .state
+                .players
+                .iter()
+                .filter(|p| team.player_ids.contains(&p.user_id))
+                .fold(0i32, |total, p| total.saturating_add(p.points));
         }
     }
 
-    pub fn add_to_queue(&mut self, player_id: &PlayerId) {
-        if !self.game_queue.contains(player_id) {
-            self.game_queue.push_back(player_id.clone());
+    /// The team that has reached the threshold without a tie for first place
+    fn winning_team(&self) -> Option<&Team> {
+        let top = self.state.teams.iter().map(|t| t.points).max()?;
+        if top < self.state.point_threshold {
+            return None;
         }
+        let mut leaders = self.state.teams.iter().filter(|t| t.points == top);
+        let leader = leaders.next()?;
+        leaders.next().is_none().then_some(leader)
     }
 
-    pub fn remove_from_queue(&mut self, player_id: &PlayerId) {
-        self.game_queue.retain(|id| id != player_id);
+    /// The player credited with the win: the top scorer overall, or in team
+    /// mode the top scorer of the leading team
+    pub fn leader(&self) -> Option<&Player> {
+        let team = self.state.teams.iter().max_by_key(|t| t.points);
+        self.state
+            .players
+            .iter()
+            .filter(|p| team.is_none_or(|t| t.player_ids.contains(&p.user_id)))
+            .max_by_key(|p| p.points)
     }
 
-    pub fn create_game(&mut self, players: Vec<Player>) -> Result<GameId> {
-        if players.len() < 2 || players.len() > 16 {
-            return Err(anyhow!("Invalid number of players: {}", players.len()));
+    /// Decide whether the match ends after the latest scored guess. With
+    /// `final_round_on_threshold` set, crossing the threshold only arms the
+    /// final round; the match ends once that collaborative round is processed.
+    fn should_end_game(&mut self, collaborative_round: bool) -> bool {
+        if self.final_round && collaborative_round {
+            return true;
         }
 
-        // Get random word (defaulting to 6 letters)
-        let target_word = self.word_validator.get_random_word(6)?;
-        let game_id = uuid::Uuid::new_v4().to_string();
+        let threshold_reached = self
+            .state
+            .players
+            .iter()
+            .any(|p| p.points >= self.state.point_threshold);
 
-        let game = Game::new(
-            game_id.clone(),
-            players.clone(),
-            target_word.clone(),
-            self.default_point_threshold,
-        );
+        if !threshold_reached {
+            return false;
+        }
 
-        // Update player mappings
-        for player in &players {
-            self.player_to_game
-                .insert(player.user_id.clone(), game_id.clone());
-            self.remove_from_queue(&player.user_id);
+        if self.rules.final_round_on_threshold {
+            if !self.final_round {
+                tracing::info!(
+                    "⏳ Point threshold {} reached, starting final round",
+                    self.state.point_threshold
+                );
+                self.final_round = true;
+            }
+            return false;
         }
 
-        // Emit event
-        let event = GameEvent::GameCreated {
-            game_id: game_id.clone(),
-            players,
-            word: target_word,
-            point_threshold: self.default_point_threshold,
-        };
-        self.event_bus.publish(event);
+        true
+    }
 
-        self.active_games.insert(game_id.clone(), game);
-        Ok(game_id)
+    /// Evaluate a guess against the target and `previous` board, applying
+    /// the configured scoring rules to its base points
+    fn evaluate_guess(&self, word: &str, previous: &[GuessResult]) -> (Vec<LetterResult>, i32) {
+        let (letters, points) = ScoringEngine::evaluate_guess(word, &self.target_word, previous);
+        let points = self
+            .rules
+            .scoring
+            .solve_points(word, &self.target_word, points);
+        (letters, points)
     }
 
-    pub fn handle_guess(
-        &mut self,
-        game_id: &GameId,
-        player_id: &PlayerId,
-        word: String,
-    ) -> Result<Option<GameEvent>> {
-        // Validate word
+    /// Score a guess for `player_id` against the current leader, before the
+    /// points are awarded
+    fn score_breakdown(&self, player_id: &PlayerId, base_points: i32) -> ScoreBreakdown {
+        let player_points = self
+            .state
+            .players
+            .iter()
+            .find(|p| &p.user_id == player_id)
+            .map(|p| p.points)
+            .unwrap_or_default();
+        let leader_points = self
+            .state
+            .players
+            .iter()
+            .map(|p| p.points)
+            .max()
+            .unwrap_or_default();
+
+        self.rules.scoring.score(
+            base_points,
+            player_points,
+            leader_points,
+            self.state.point_threshold,
+        )
+    }
+
+    fn is_already_guessed(&self, word: &str) -> bool {
+        self.prior_guess(word).is_some()
+    }
+
+    /// Where `word` was first played, if it has been played and cannot be
+    /// guessed again. Board and trimmed words belong to the current round.
+    fn prior_guess(&self, word: &str) -> Option<PriorGuess> {
+        let word = normalize_word(word);
+        if let Some(prior) = self.used_words.get(&word) {
+            return Some(prior.clone());
+        }
+
+        let player_id = self.trimmed_words.get(&word).cloned().or_else(|| {
+            self.state
+                .official_board
+                .iter()
+                .find(|g| normalize_word(&g.word) == word)
+                .map(|g| g.player_id.clone())
+        })?;
+        Some(PriorGuess {
+            round: self.state.current_round,
+            player_id,
+        })
+    }
+
+    /// Append a guess to the official board, enforcing `max_board_size`.
+    /// Only guesses that earned no points are trimmed, so every revealed
+    /// letter stays visible; trimmed words still count as already guessed.
+    fn record_on_board(&mut self, guess_result: GuessResult) {
+        let reveals =
+            ScoringEngine::newly_revealed(&guess_result.letters, &self.state.official_board);
+        self.last_reveals.extend(reveals);
+        self.state.official_board.push(guess_result);
+
+        let Some(max_size) = self.rules.max_board_size else {
+            return;
+        };
+
+        while self.state.official_board.len() > max_size {
+            let Some(index) = self
+                .state
+                .official_board
+                .iter()
+                .position(|g| g.points_earned == 0)
+            else {
+                break;
+            };
+            let trimmed = self.state.official_board.remove(index);
+            self.trimmed_words
+                .insert(normalize_word(&trimmed.word), trimmed.player_id);
+        }
+    }
+
+    /// Letters revealed by board entries since the last call
+    pub fn take_reveals(&mut self) -> Vec<LetterResult> {
+        std::mem::take(&mut self.last_reveals)
+    }
+
+    /// Clear the official board for a new round. Call before advancing
+    /// `current_round` so retired words remember the round they were played in.
+    pub fn reset_board(&mut self) {
+        if !self.rules.allow_reuse_across_rounds {
+            let round = self.state.current_round;
+            let played = self.trimmed_words.drain().chain(
+                self.state
+                    .official_board
+                    .iter()
+                    .map(|g| (normalize_word(&g.word), g.player_id.clone())),
+            );
+            for (word, player_id) in played {
+                self.used_words
+                    .entry(word)
+                    .or_insert(PriorGuess { round, player_id });
+            }
+        }
+        self.state.official_board.clear();
+        self.trimmed_words.clear();
+    }
+
+    /// Switch to a new target word, masking it for display. The point
+    /// threshold stays the one the game started with.
+    pub fn set_target_word(&mut self, word: String) {
+        let word_length = letter_count(&word);
+        self.state.word = "*".repeat(word_length);
+        self.state.word_length = word_length as i32;
+        self.target_word = word;
+        self.hinted_positions.clear();
+    }
+
+    /// Reveal one letter of the target word, in its position, to a single
+    /// player for the rules' hint penalty. Letters the board already shows
+    /// and ones hinted to the player before are skipped. The penalty never
+    /// takes a score below zero.
+    pub fn request_hint(&mut self, player_id: &PlayerId) -> GameResult<LetterResult> {
+        let limit = self
+            .rules
+            .max_hints_per_round
+            .ok_or(GameError::HintsNotAllowed)?;
+        if self.current_phase != GamePhase::Guessing {
+            return Err(GameError::WrongPhase {
+                phase: self.current_phase.clone(),
+            });
+        }
+        let player = self
+            .state
+            .players
+            .iter_mut()
+            .find(|p| &p.user_id == player_id)
+            .ok_or(GameError::PlayerNotInGame)?;
+
+        let hinted = self.hinted_positions.entry(player_id.clone()).or_default();
+        if hinted.len() >= limit {
+            return Err(GameError::HintLimitReached { limit });
+        }
+
+        let on_board: HashSet<i32> = self
+            .state
+            .official_board
+            .iter()
+            .flat_map(|guess| &guess.letters)
+            .filter(|letter| matches!(letter.status, LetterStatus::Correct))
+            .map(|letter| letter.position)
+            .collect();
+        let (position, letter) = normalize_word(&self.target_word)
+            .chars()
+            .enumerate()
+            .map(|(position, letter)| (position as i32, letter))
+            .find(|(position, _)| !on_board.contains(position) && !hinted.contains(position))
+            .ok_or(GameError::NoHintAvailable)?;
+
+        hinted.insert(position);
+        player.points = player.points.saturating_sub(self.rules.hint_penalty).max(0);
+        Ok(LetterResult {
+            letter: letter.to_string(),
+            status: LetterStatus::Correct,
+            position,
+        })
+    }
+
+    /// Count a solved word toward the carried-over progress, extending the
+    /// combo when the same player solved the previous word as well
+    pub fn record_solve(&mut self, player_id: &PlayerId) {
+        let Some(campaign) = self.state.campaign.as_mut() else {
+            return;
+        };
+
+        campaign.words_solved += 1;
+        if campaign.combo_player.as_ref() == Some(player_id) {
+            campaign.combo += 1;
+        } else {
+            campaign.combo = 1;
+            campaign.combo_player = Some(player_id.clone());
+        }
+        campaign.best_combo = campaign.best_combo.max(campaign.combo);
+    }
+
+    /// Players sharing the top score, if that score meets the threshold
+    fn tied_leaders(&self) -> Vec<PlayerId> {
+        let Some(top) = self.state.players.iter().map(|p| p.points).max() else {
+            return Vec::new();
+        };
+        if top < self.state.point_threshold {
+            return Vec::new();
+        }
+        self.state
+            .players
+            .iter()
+            .filter(|p| p.points == top)
+            .map(|p| p.user_id.clone())
+            .collect()
+    }
+
+    /// Enter sudden death if the game would end with a tie at the top.
+    /// Returns true when the tiebreaker started and the game continues.
+    fn start_sudden_death(&mut self) -> bool {
+        let tied = self.tied_leaders();
+        if tied.len() < 2 {
+            return false;
+        }
+
+        tracing::info!("⚔️ Sudden death between {} tied players", tied.len());
+        let sudden_death = SuddenDeath {
+            players: tied,
+            turn: 0,
+        };
+        self.current_guesses.clear();
+        self.state.current_winner = Some(sudden_death.current_player());
+        self.sudden_death = Some(sudden_death);
+        self.set_phase(GamePhase::IndividualGuess);
+        true
+    }
+
+    /// Pass the sudden-death turn on after a tied player's guess, ending the
+    /// game once a full rotation leaves a single leader among them.
+    fn advance_sudden_death(
+        &mut self,
+        guess_result: GuessResult,
+        word_solved: bool,
+        points_earned: i32,
+    ) -> RoundResult {
+        if self.sudden_death.is_none() {
+            return RoundResult::Continuing(guess_result);
+        }
+        if self.rotate_sudden_death() {
+            return RoundResult::GameOver(guess_result);
+        }
+
+        let player_id = guess_result.player_id.clone();
+        if word_solved {
+            // The next turn starts once the new word is in place
+            return RoundResult::WordCompleted(RoundCompletion {
+                word: guess_result.word,
+                player_id,
+                points_earned,
+            });
+        }
+
+        self.start_round_phase();
+        RoundResult::Continuing(guess_result)
+    }
+
+    /// Move the sudden-death turn to the next tied player. Returns true when
+    /// a full rotation left a single leader and the game is over.
+    fn rotate_sudden_death(&mut self) -> bool {
+        let Some(sudden_death) = self.sudden_death.as_mut() else {
+            return false;
+        };
+
+        sudden_death.turn += 1;
+        if sudden_death.turn == sudden_death.players.len() {
+            sudden_death.turn = 0;
+
+            let tied = sudden_death.players.clone();
+            let top = self
+                .state
+                .players
+                .iter()
+                .filter(|p| tied.contains(&p.user_id))
+                .map(|p| p.points)
+                .max()
+                .unwrap_or_default();
+            let leaders: Vec<PlayerId> = self
+                .state
+                .players
+                .iter()
+                .filter(|p| tied.contains(&p.user_id) && p.points == top)
+                .map(|p| p.user_id.clone())
+                .collect();
+
+            if leaders.len() == 1 {
+                tracing::info!("🏆 Sudden death won by {}", leaders[0]);
+                self.sudden_death = None;
+                self.state.current_winner = None;
+                self.state.status = GameStatus::Completed;
+                self.set_phase(GamePhase::GameOver);
+                return true;
+            }
+
+            // Only players still tied for the lead play on
+            if let Some(sudden_death) = self.sudden_death.as_mut() {
+                sudden_death.players = leaders;
+            }
+        }
+        false
+    }
+
+    /// Set the phase for the start of a round: collaborative guessing, or the
+    /// next tied player's individual guess during sudden death
+    pub fn start_round_phase(&mut self) {
+        match &self.sudden_death {
+            Some(sudden_death) => {
+                self.state.current_winner = Some(sudden_death.current_player());
+                self.set_phase(GamePhase::IndividualGuess);
+            }
+            None => {
+                self.state.current_winner = None;
+                self.set_phase(GamePhase::Guessing);
+            }
+        }
+    }
+
+    pub fn get_winner(&self) -> Option<&Player> {
+        self.state
+            .players
+            .iter()
+            .max_by_key(|p| p.points)
+            .filter(|p| p.points >= self.state.point_threshold)
+    }
+
+    pub fn is_expired(&self, timeout_duration: Duration) -> bool {
+        self.clock
+            .system_time()
+            .duration_since(self.last_activity)
+            .unwrap_or(Duration::ZERO)
+            > timeout_duration
+    }
+
+    pub fn set_countdown(&mut self, duration: Duration) {
+        self.countdown_end = Some(self.clock.system_time() + duration);
+        self.set_phase(GamePhase::Countdown);
+    }
+
+    pub fn is_countdown_finished(&self) -> bool {
+        if let Some(end_time) = self.countdown_end {
+            self.clock.system_time() >= end_time
+        } else {
+            false
+        }
+    }
+
+    /// End the game now, whatever the scores
+    pub fn finish(&mut self) {
+        self.state.status = GameStatus::Completed;
+        self.set_phase(GamePhase::GameOver);
+    }
+
+    fn set_phase(&mut self, phase: GamePhase) {
+        self.current_phase = phase.clone();
+        self.state.current_phase = phase;
+    }
+
+    pub fn start_guessing_phase(&mut self) {
+        self.set_phase(GamePhase::Guessing);
+    }
+
+    /// Hold play in the `Intermission` phase for the rules' intermission,
+    /// remembering the phase to resume in
+    fn begin_intermission(&mut self) {
+        let Some(duration) = self.rules.intermission.filter(|d| !d.is_zero()) else {
+            return;
+        };
+
+        self.intermission = Some(Intermission {
+            ends_at: self.clock.system_time() + duration,
+            resume_phase: self.current_phase.clone(),
+        });
+        self.set_phase(GamePhase::Intermission);
+    }
+
+    /// Time left in the current intermission, if one is running
+    pub fn intermission_remaining(&self) -> Option<Duration> {
+        let intermission = self.intermission.as_ref()?;
+        Some(
+            intermission
+                .ends_at
+                .duration_since(self.clock.system_time())
+                .unwrap_or(Duration::ZERO),
+        )
+    }
+
+    /// Resume play once the intermission has run its course. Returns true if
+    /// the intermission ended.
+    pub fn end_intermission_if_elapsed(&mut self) -> bool {
+        match &self.intermission {
+            Some(intermission) if self.clock.system_time() >= intermission.ends_at => {
+                let resume_phase = intermission.resume_phase.clone();
+                self.intermission = None;
+                self.set_phase(resume_phase);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Let the round winner pass on their individual guess, returning the
+    /// game to collaborative guessing
+    pub fn skip_turn(&mut self, player_id: &PlayerId) -> GameResult<()> {
+        if self.current_phase != GamePhase::IndividualGuess {
+            return Err(GameError::WrongPhase {
+                phase: self.current_phase.clone(),
+            });
+        }
+
+        if self.state.current_winner.as_ref() != Some(player_id) {
+            return Err(GameError::NotYourTurn);
+        }
+
+        if !self.rules.allow_turn_skip || self.sudden_death.is_some() {
+            return Err(GameError::TurnSkipNotAllowed);
+        }
+
+        tracing::info!("⏭️ {} skipped their individual guess", player_id);
+        self.state.current_winner = None;
+        self.set_phase(GamePhase::Guessing);
+        Ok(())
+    }
+
+    /// Take the individual guess away from a round winner who let their turn
+    /// run out, whether or not the rules allow skipping. During sudden death
+    /// the turn passes to the next tied player, which can end the game.
+    /// Returns true when it did.
+    pub fn forfeit_turn(&mut self) -> GameResult<bool> {
+        if self.current_phase != GamePhase::IndividualGuess {
+            return Err(GameError::WrongPhase {
+                phase: self.current_phase.clone(),
+            });
+        }
+
+        if let Some(player_id) = &self.state.current_winner {
+            tracing::info!("⌛ {} forfeited their individual guess", player_id);
+        }
+        if self.sudden_death.is_some() {
+            if self.rotate_sudden_death() {
+                return Ok(true);
+            }
+            self.start_round_phase();
+            return Ok(false);
+        }
+
+        self.state.current_winner = None;
+        self.set_phase(GamePhase::Guessing);
+        Ok(false)
+    }
+
+    /// Process an individual guess from the round winner
+    pub fn process_individual_guess(
+        &mut self,
+        player_id: PlayerId,
+        word: String,
+    ) -> GameResult<Option<RoundResult>> {
+        // Check if we're in the right phase
+        if self.current_phase != GamePhase::IndividualGuess {
+            return Err(GameError::WrongPhase {
+                phase: self.current_phase.clone(),
+            });
+        }
+
+        // Check if this player is the current winner
+        if Some(player_id.clone()) != self.state.current_winner {
+            return Err(GameError::NotYourTurn);
+        }
+
+        if word.trim().is_empty() {
+            return Err(GameError::EmptyGuess);
+        }
+
+        // Check if word was already guessed
+        if let Some(prior) = self.prior_guess(&word) {
+            return Err(GameError::WordAlreadyGuessed {
+                word,
+                prior: Some(prior),
+            });
+        }
+
+        // Evaluate the guess
+        let (letter_results, base_points) = self.evaluate_guess(&word, &self.state.official_board);
+        let breakdown = self.score_breakdown(&player_id, base_points);
+        let points_earned = breakdown.total();
+
+        println!(
+            "Individual guess '{}' evaluated: {} points earned against target '{}' with {} previous guesses",
+            word,
+            points_earned,
+            self.target_word,
+            self.state.official_board.len()
+        );
+
+        // Create the guess result
+        let guess_result = GuessResult {
+            word: word.clone(),
+            player_id: player_id.clone(),
+            letters: letter_results,
+            points_earned,
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            breakdown,
+        };
+
+        // Update player score and history
+        for player in &mut self.state.players {
+            if player.user_id == player_id {
+                player.points = self.rules.scoring.add_points(player.points, points_earned);
+                player.guess_history.push(PersonalGuess {
+                    word: word.clone(),
+                    points_earned,
+                    was_winning_guess: true,
+                    timestamp: chrono::Utc::now().to_rfc3339(),
+                    assist_points: 0,
+                });
+                break;
+            }
+        }
+
+        // Add to official board
+        self.record_on_board(guess_result.clone());
+
+        let word_solved = normalize_word(&word) == normalize_word(&self.target_word);
+
+        if self.sudden_death.is_some() {
+            return Ok(Some(self.advance_sudden_death(
+                guess_result,
+                word_solved,
+                points_earned,
+            )));
+        }
+
+        // Check if anyone has won by points (only way to end the match)
+        tracing::info!(
+            "🎯 Checking point threshold after individual guess: {} | Current scores: {:?}",
+            self.state.point_threshold,
+            self.state
+                .players
+                .iter()
+                .map(|p| format!("{}: {}", p.display_name, p.points))
+                .collect::<Vec<_>>()
+        );
+
+        if self.should_end_game(false) {
+            if self.start_sudden_death() {
+                return Ok(Some(if word_solved {
+                    RoundResult::WordCompleted(RoundCompletion {
+                        word: word.clone(),
+                        player_id,
+                        points_earned,
+                    })
+                } else {
+                    RoundResult::Continuing(guess_result)
+                }));
+            }
+            if let Some(winner) = self.state.players.iter().max_by_key(|p| p.points) {
+                tracing::info!(
+                    "🏆 GAME OVER! {} reached {} points after individual guess (threshold: {})",
+                    winner.display_name,
+                    winner.points,
+                    self.state.point_threshold
+                );
+            }
+            self.state.status = GameStatus::Completed;
+            self.set_phase(GamePhase::GameOver);
+            Ok(Some(RoundResult::GameOver(guess_result)))
+        } else if word_solved {
+            // Word was solved - start new round with new word
+            Ok(Some(RoundResult::WordCompleted(RoundCompletion {
+                word: word.clone(),
+                player_id,
+                points_earned,
+            })))
+        } else {
+            // Move back to collaborative guessing phase
+            self.state.current_winner = None; // Clear winner for next collaborative round
+            self.set_phase(GamePhase::Guessing);
+            Ok(Some(RoundResult::Continuing(guess_result)))
+        }
+    }
+}
+
+pub struct GameManager {
+    pub active_games: HashMap<GameId, Game>,
+    pub player_to_game: HashMap<PlayerId, GameId>,
+    pub game_queue: VecDeque<PlayerId>,
+    pub word_validator: WordValidator,
+    pub event_bus: GameEventBus,
+    pub default_point_threshold: i32,
+}
+
+impl GameManager {
+    pub fn new(word_validator: WordValidator) -> Self {
+        Self {
+            active_games: HashMap::new(),
+            player_to_game: HashMap::new(),
+            game_queue: VecDeque::new(),
+            word_validator,
+            event_bus: GameEventBus::new(),
+            default_point_threshold: 25,
+        }
+    }
+
+    pub fn add_to_queue(&mut self, player_id: &PlayerId) {
+        if !self.game_queue.contains(player_id) {
+            self.game_queue.push_back(player_id.clone());
+        }
+    }
+
+    pub fn remove_from_queue(&mut self, player_id: &PlayerId) {
+        self.game_queue.retain(|id| id != player_id);
+    }
+
+    pub fn create_game(&mut self, players: Vec<Player>) -> GameResult<GameId> {
+        if players.len() < 2 || players.len() > 16 {
+            return Err(GameError::InvalidPlayerCount {
+                count: players.len(),
+            });
+        }
+
+        // Get random word (defaulting to 6 letters)
+        let target_word =
+            self.word_validator
+                .get_random_word(6)
+                .map_err(|e| GameError::WordSelection {
+                    reason: e.to_string(),
+                })?;
+        let game_id = uuid::Uuid::new_v4().to_string();
+
+        let game = Game::new(
+            game_id.clone(),
+            players.clone(),
+            target_word.clone(),
+            self.default_point_threshold,
+        );
+
+        // Update player mappings
+        for player in &players {
+            self.player_to_game
+                .insert(player.user_id.clone(), game_id.clone());
+            self.remove_from_queue(&player.user_id);
+        }
+
+        // Emit event
+        let event = GameEvent::GameCreated {
+            game_id: game_id.clone(),
+            players,
+            word: target_word,
+            point_threshold: self.default_point_threshold,
+        };
+        self.event_bus.publish(event);
+
+        self.active_games.insert(game_id.clone(), game);
+        Ok(game_id)
+    }
+
+    pub fn handle_guess(
+        &mut self,
+        game_id: &GameId,
+        player_id: &PlayerId,
+        word: String,
+    ) -> GameResult<Option<GameEvent>> {
+        // Validate word
         if !self.word_validator.is_valid_word(&word) || !self.word_validator.is_alphabetic(&word) {
-            return Err(anyhow!("Invalid word: {}", word));
+            return Err(GameError::InvalidWord { word });
+        }
+
+        let game = self
+            .active_games
+            .get_mut(game_id)
+            .ok_or(GameError::GameNotFound)?;
+
+        game.add_guess(player_id, word.clone())?;
+
+        // Emit guess event
+        let event = GameEvent::GuessSubmitted {
+            game_id: game_id.clone(),
+            player_id: player_id.clone(),
+            word,
+        };
+        self.event_bus.publish(event.clone());
+
+        Ok(Some(event))
+    }
+
+    pub fn cleanup_expired_games(&mut self) {
+        let timeout_duration = Duration::from_secs(600); // 10 minutes
+        let expired_games: Vec<GameId> = self
+            .active_games
+            .iter()
+            .filter(|(_, game)| game.is_expired(timeout_duration))
+            .map(|(id, _)| id.clone())
+            .collect();
+
+        for game_id in expired_games {
+            if let Some(game) = self.active_games.remove(&game_id) {
+                // Remove player mappings
+                for player in &game.state.players {
+                    self.player_to_game.remove(&player.user_id);
+                }
+
+                // Emit abandonment event
+                let event = GameEvent::GameAbandoned {
+                    game_id,
+                    reason: "Inactivity timeout".to_string(),
+                };
+                self.event_bus.publish(event);
+            }
+        }
+    }
+
+    pub fn get_queue_position(&self, player_id: &PlayerId) -> Option<usize> {
+        self.game_queue.iter().position(|id| id == player_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MockClock, SOLVE_BONUS, ScoringConfig};
+    use game_types::LetterStatus;
+    use game_types::Player;
+
+    fn create_test_validator() -> WordValidator {
+        let word_list = "apple\nbanana\ncherry\ntests\nvalid\nhello\nworld";
+        WordValidator::from_word_list(word_list)
+    }
+
+    fn create_test_player(name: &str) -> Player {
+        Player {
+            user_id: format!("test-player-{}", name.to_lowercase()),
+            display_name: name.to_string(),
+            points: 0,
+            guess_history: Vec::new(),
+            is_connected: true,
+            avatar: None,
+        }
+    }
+
+    #[test]
+    fn test_game_creation() {
+        let validator = create_test_validator();
+        let mut manager = GameManager::new(validator);
+
+        let players = vec![create_test_player("Alice"), create_test_player("Bob")];
+
+        let result = manager.create_game(players);
+        assert!(result.is_ok());
+
+        let game_id = result.unwrap();
+        assert!(manager.active_games.contains_key(&game_id));
+    }
+
+    #[test]
+    fn test_queue_management() {
+        let validator = create_test_validator();
+        let mut manager = GameManager::new(validator);
+
+        let player_id = "test-player-id".to_string();
+
+        manager.add_to_queue(&player_id);
+        assert_eq!(manager.get_queue_position(&player_id), Some(0));
+
+        manager.remove_from_queue(&player_id);
+        assert_eq!(manager.get_queue_position(&player_id), None);
+    }
+
+    #[test]
+    fn test_game_creation_edge_cases() {
+        let validator = create_test_validator();
+        let mut manager = GameManager::new(validator);
+
+        // Test invalid player count - too few
+        let result = manager.create_game(vec![create_test_player("Alice")]);
+        assert!(result.is_err());
+        assert!(matches!(
+            result.unwrap_err(),
+            GameError::InvalidPlayerCount { .. }
+        ));
+
+        // Test invalid player count - too many
+        let many_players: Vec<Player> = (0..17)
+            .map(|i| create_test_player(&format!("Player{}", i)))
+            .collect();
+        let result = manager.create_game(many_players);
+        assert!(result.is_err());
+        assert!(matches!(
+            result.unwrap_err(),
+            GameError::InvalidPlayerCount { .. }
+        ));
+
+        // Test boundary cases - exactly 2 and 16 players
+        let min_players = vec![create_test_player("Alice"), create_test_player("Bob")];
+        let result = manager.create_game(min_players);
+        assert!(result.is_ok());
+
+        let max_players: Vec<Player> = (0..16)
+            .map(|i| create_test_player(&format!("Player{}", i)))
+            .collect();
+        let result = manager.create_game(max_players);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_duplicate_queue_entries() {
+        let validator = create_test_validator();
+        let mut manager = GameManager::new(validator);
+
+        let player_id = "test-player-duplicate".to_string();
+
+        // Add same player multiple times
+        manager.add_to_queue(&player_id);
+        manager.add_to_queue(&player_id);
+        manager.add_to_queue(&player_id);
+
+        // Should only appear once
+        assert_eq!(manager.get_queue_position(&player_id), Some(0));
+        assert_eq!(manager.game_queue.len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_word_handling() {
+        let validator = create_test_validator();
+        let mut manager = GameManager::new(validator);
+
+        let players = vec![create_test_player("Alice"), create_test_player("Bob")];
+        let game_id = manager.create_game(players.clone()).unwrap();
+        let player_id = players[0].user_id.clone();
+
+        // Test invalid word
+        let result = manager.handle_guess(&game_id, &player_id, "invalidword".to_string());
+        assert!(result.is_err());
+        assert!(matches!(result.unwrap_err(), GameError::InvalidWord { .. }));
+
+        // Test non-alphabetic word
+        let result = manager.handle_guess(&game_id, &player_id, "test123".to_string());
+        assert!(result.is_err());
+        assert!(matches!(result.unwrap_err(), GameError::InvalidWord { .. }));
+
+        // Test empty word
+        let result = manager.handle_guess(&game_id, &player_id, "".to_string());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_guess_submission_edge_cases() {
+        let validator = create_test_validator();
+        let mut manager = GameManager::new(validator);
+
+        let players = vec![create_test_player("Alice"), create_test_player("Bob")];
+        let game_id = manager.create_game(players.clone()).unwrap();
+        let alice_id = players[0].user_id.clone();
+        let bob_id = players[1].user_id.clone();
+
+        let game = manager.active_games.get_mut(&game_id).unwrap();
+
+        // Test player not in game
+        let fake_player = "fake-player".to_string();
+        let result = game.add_guess(&fake_player, "hello".to_string());
+        assert!(result.is_err());
+        assert!(matches!(result.unwrap_err(), GameError::PlayerNotInGame));
+
+        // Test duplicate word guessing
+        game.add_guess(&alice_id, "hello".to_string()).unwrap();
+        let guess_result = game.process_round().unwrap();
+        assert!(guess_result.is_some());
+
+        // Try to guess the same word again
+        let result = game.add_guess(&bob_id, "hello".to_string());
+        assert!(result.is_err());
+        assert!(matches!(
+            result.unwrap_err(),
+            GameError::WordAlreadyGuessed { .. }
+        ));
+    }
+
+    #[test]
+    fn test_game_state_transitions() {
+        let validator = create_test_validator();
+        let mut manager = GameManager::new(validator);
+
+        let players = vec![create_test_player("Alice"), create_test_player("Bob")];
+        let game_id = manager.create_game(players.clone()).unwrap();
+
+        let game = manager.active_games.get_mut(&game_id).unwrap();
+        assert_eq!(game.state.status, GameStatus::Starting);
+        assert_eq!(game.current_phase, GamePhase::Waiting);
+
+        // Test countdown functionality against a clock we control
+        let clock = std::sync::Arc::new(MockClock::new());
+        let mut game = Game::new(
+            "countdown-game".to_string(),
+            players,
+            "hello".to_string(),
+            25,
+        )
+        .with_clock(clock.clone());
+        game.set_countdown(Duration::from_secs(10));
+        assert_eq!(game.current_phase, GamePhase::Countdown);
+        assert!(!game.is_countdown_finished());
+
+        clock.advance(Duration::from_secs(9));
+        assert!(!game.is_countdown_finished());
+        clock.advance(Duration::from_secs(1));
+        assert!(game.is_countdown_finished());
+    }
+
+    #[test]
+    fn test_game_completion_conditions() {
+        let validator = create_test_validator();
+        let mut manager = GameManager::new(validator);
+
+        let mut players = vec![create_test_player("Alice"), create_test_player("Bob")];
+        let game_id = manager.create_game(players.clone()).unwrap();
+
+        let game = manager.active_games.get_mut(&game_id).unwrap();
+        let target_word = game.target_word.clone();
+        let alice_id = players[0].user_id.clone();
+
+        // Test word completion - should return WordCompleted result when target word is guessed
+        game.add_guess(&alice_id, target_word.clone()).unwrap();
+        let result = game.process_round();
+
+        // Should return Ok with WordCompleted variant
+        assert!(result.is_ok());
+        let round_result = result.unwrap();
+        assert!(round_result.is_some());
+
+        // Check that it's a WordCompleted variant
+        if let Some(RoundResult::WordCompleted(completion)) = round_result {
+            assert_eq!(completion.word, target_word);
+            assert_eq!(completion.player_id, alice_id);
+            // 6 letters × 2 points each + 5 completion bonus = 17 points
+            assert_eq!(
+                completion.points_earned, 17,
+                "Expected 17 points for 6-letter word completion"
+            );
+        } else {
+            panic!("Expected WordCompleted result");
         }
 
-        let game = self
-            .active_games
-            .get_mut(game_id)
-            .ok_or_else(|| anyhow!("Game not found"))?;
+        // Game state should not be Completed (round completion handled externally)
+        // It might be Starting or Active depending on game initialization
+        assert_ne!(game.state.status, GameStatus::Completed);
 
-        game.add_guess(player_id, word.clone())?;
+        // Test point threshold completion
+        let mut game2 = Game::new(
+            "test-game-id-2".to_string(),
+            players.clone(),
+            "tests".to_string(),
+            10, // Low threshold
+        );
 
-        // Emit guess event
-        let event = GameEvent::GuessSubmitted {
-            game_id: game_id.clone(),
-            player_id: player_id.clone(),
-            word,
-        };
-        self.event_bus.publish(event.clone());
+        // Manually set a player's points above threshold
+        players[0].points = 15;
+        game2.state.players = players;
 
-        Ok(Some(event))
+        game2.add_guess(&alice_id, "tests".to_string()).unwrap();
+        let result = game2.process_round().unwrap();
+        assert!(result.is_some());
+        assert_eq!(game2.state.status, GameStatus::Completed);
     }
 
-    pub fn cleanup_expired_games(&mut self) {
-        let timeout_duration = Duration::from_secs(600); // 10 minutes
-        let expired_games: Vec<GameId> = self
-            .active_games
-            .iter()
-            .filter(|(_, game)| game.is_expired(timeout_duration))
-            .map(|(id, _)| id.clone())
-            .collect();
+    #[test]
+    fn test_game_expiration() {
+        let validator = create_test_validator();
+        let mut manager = GameManager::new(validator);
 
-        for game_id in expired_games {
-            if let Some(game) = self.active_games.remove(&game_id) {
-                // Remove player mappings
-                for player in &game.state.players {
-                    self.player_to_game.remove(&player.user_id);
-                }
+        let players = vec![create_test_player("Alice"), create_test_player("Bob")];
+        let game_id = manager.create_game(players.clone()).unwrap();
 
-                // Emit abandonment event
-                let event = GameEvent::GameAbandoned {
-                    game_id,
-                    reason: "Inactivity timeout".to_string(),
-                };
-                self.event_bus.publish(event);
-            }
-        }
+        // Game should not be expired initially
+        let game = manager.active_games.get(&game_id).unwrap();
+        assert!(!game.is_expired(std::time::Duration::from_secs(1)));
+
+        // Test with zero duration - should be expired
+        assert!(game.is_expired(std::time::Duration::from_millis(0)));
+
+        // Test cleanup
+        manager.cleanup_expired_games();
+        // Game should still exist (not expired with reasonable timeout)
+        assert!(manager.active_games.contains_key(&game_id));
     }
 
-    pub fn get_queue_position(&self, player_id: &PlayerId) -> Option<usize> {
-        self.game_queue.iter().position(|id| id == player_id)
+    #[test]
+    fn test_empty_round_processing() {
+        let players = vec![create_test_player("Alice"), create_test_player("Bob")];
+        let mut game = Game::new("test-game-id".to_string(), players, "hello".to_string(), 25);
+
+        // Process round with no guesses
+        let result = game.process_round().unwrap();
+        assert!(result.is_none());
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use game_types::Player;
+    #[test]
+    fn test_final_round_gives_everyone_last_guess() {
+        let players = vec![create_test_player("Alice"), create_test_player("Bob")];
+        let alice_id = players[0].user_id.clone();
+        let bob_id = players[1].user_id.clone();
+        let rules = GameRules {
+            final_round_on_threshold: true,
+            ..Default::default()
+        };
+        let mut game = Game::new_with_rules(
+            "test-game-id".to_string(),
+            players,
+            "hello".to_string(),
+            10,
+            rules,
+        );
+        game.start_guessing_phase();
 
-    fn create_test_validator() -> WordValidator {
-        let word_list = "apple\nbanana\ncherry\ntests\nvalid\nhello\nworld";
-        WordValidator::from_word_list(word_list)
+        // Alice solves the word and crosses the threshold - game continues
+        game.add_guess(&alice_id, "hello".to_string()).unwrap();
+        let result = game.process_round().unwrap();
+        assert!(matches!(result, Some(RoundResult::WordCompleted(_))));
+        assert!(game.final_round);
+        assert_ne!(game.state.status, GameStatus::Completed);
+
+        // Everyone gets one last collaborative guess
+        game.add_guess(&alice_id, "world".to_string()).unwrap();
+        game.add_guess(&bob_id, "valid".to_string()).unwrap();
+        let result = game.process_round().unwrap();
+        assert!(matches!(result, Some(RoundResult::GameOver(_))));
+        assert_eq!(game.state.status, GameStatus::Completed);
+        assert_eq!(game.current_phase, GamePhase::GameOver);
+
+        let bob = game.state.players.iter().find(|p| p.user_id == bob_id);
+        assert_eq!(bob.unwrap().guess_history.len(), 1);
+        assert_eq!(game.get_winner().unwrap().user_id, alice_id);
     }
 
-    fn create_test_player(name: &str) -> Player {
-        Player {
-            user_id: format!("test-player-{}", name.to_lowercase()),
-            display_name: name.to_string(),
-            points: 0,
-            guess_history: Vec::new(),
-            is_connected: true,
+    #[test]
+    fn test_round_winner_can_skip_individual_guess() {
+        let players = vec![create_test_player("Alice"), create_test_player("Bob")];
+        let alice_id = players[0].user_id.clone();
+        let bob_id = players[1].user_id.clone();
+        let mut game = Game::new(
+            "test-game-id".to_string(),
+            players.clone(),
+            "hello".to_string(),
+            25,
+        );
+        game.start_guessing_phase();
+
+        game.add_guess(&alice_id, "world".to_string()).unwrap();
+        game.process_round().unwrap();
+        assert_eq!(game.current_phase, GamePhase::IndividualGuess);
+        assert_eq!(game.state.current_winner, Some(alice_id.clone()));
+
+        assert_eq!(game.skip_turn(&bob_id), Err(GameError::NotYourTurn));
+
+        game.skip_turn(&alice_id).unwrap();
+        assert_eq!(game.current_phase, GamePhase::Guessing);
+        assert_eq!(game.state.current_phase, GamePhase::Guessing);
+        assert_eq!(game.state.current_winner, None);
+        assert!(matches!(
+            game.skip_turn(&alice_id),
+            Err(GameError::WrongPhase { .. })
+        ));
+
+        // Games can require the winner to take their guess
+        let rules = GameRules {
+            allow_turn_skip: false,
+            ..Default::default()
+        };
+        let mut game = Game::new_with_rules(
+            "test-game-id-2".to_string(),
+            players,
+            "hello".to_string(),
+            25,
+            rules,
+        );
+        game.start_guessing_phase();
+        game.add_guess(&alice_id, "world".to_string()).unwrap();
+        game.process_round().unwrap();
+        assert_eq!(
+            game.skip_turn(&alice_id),
+            Err(GameError::TurnSkipNotAllowed)
+        );
+        assert_eq!(game.current_phase, GamePhase::IndividualGuess);
+    }
+
+    #[test]
+    fn test_rounds_stay_collaborative_without_individual_guess() {
+        let players = vec![create_test_player("Alice"), create_test_player("Bob")];
+        let alice_id = players[0].user_id.clone();
+        let bob_id = players[1].user_id.clone();
+        let rules = GameRules {
+            individual_guess: false,
+            ..Default::default()
+        };
+        let mut game = Game::new_with_rules(
+            "test-game-id".to_string(),
+            players,
+            "hello".to_string(),
+            25,
+            rules,
+        );
+        game.start_guessing_phase();
+
+        for (alice_word, bob_word) in [("world", "crane"), ("shell", "night"), ("jelly", "pilot")] {
+            game.add_guess(&alice_id, alice_word.to_string()).unwrap();
+            game.add_guess(&bob_id, bob_word.to_string()).unwrap();
+            let result = game.process_round().unwrap();
+            assert!(matches!(result, Some(RoundResult::Continuing(_))));
+            assert_eq!(game.current_phase, GamePhase::Guessing);
+            assert_eq!(game.state.current_phase, GamePhase::Guessing);
+            assert_eq!(game.state.current_winner, None);
         }
+
+        // Round winners are still scored
+        assert_eq!(game.state.official_board.len(), 3);
+        assert!(game.state.players.iter().any(|p| p.points > 0));
     }
 
     #[test]
-    fn test_game_creation() {
-        let validator = create_test_validator();
-        let mut manager = GameManager::new(validator);
+    fn test_rapid_guesses_are_throttled() {
+        let players = vec![create_test_player("Alice"), create_test_player("Bob")];
+        let alice_id = players[0].user_id.clone();
+        let bob_id = players[1].user_id.clone();
+        let rules = GameRules {
+            min_guess_interval: Some(Duration::from_millis(500)),
+            ..Default::default()
+        };
+        let clock = std::sync::Arc::new(MockClock::new());
+        let mut game = Game::new_with_rules(
+            "test-game-id".to_string(),
+            players,
+            "hello".to_string(),
+            25,
+            rules,
+        )
+        .with_clock(clock.clone());
+
+        game.throttle_guess(&alice_id).unwrap();
+        clock.advance(Duration::from_millis(100));
+        assert_eq!(
+            game.throttle_guess(&alice_id),
+            Err(GameError::GuessTooFast {
+                retry_after_ms: 400
+            })
+        );
+        // Other players keep their own pace
+        game.throttle_guess(&bob_id).unwrap();
+
+        // The refused attempt did not restart Alice's wait
+        clock.advance(Duration::from_millis(400));
+        game.throttle_guess(&alice_id).unwrap();
+        clock.advance(Duration::from_secs(2));
+        game.throttle_guess(&alice_id).unwrap();
+    }
 
+    #[test]
+    fn test_stale_round_guesses_are_picked_again() {
         let players = vec![create_test_player("Alice"), create_test_player("Bob")];
+        let alice_id = players[0].user_id.clone();
+        let bob_id = players[1].user_id.clone();
+        let mut game = Game::new("test-game-id".to_string(), players, "hello".to_string(), 25);
+        game.start_guessing_phase();
 
-        let result = manager.create_game(players);
-        assert!(result.is_ok());
+        game.add_guess(&alice_id, "world".to_string()).unwrap();
+        game.add_guess(&bob_id, "about".to_string()).unwrap();
+        let round = game.round_guesses();
+        let winner = round.pick_winner();
 
-        let game_id = result.unwrap();
-        assert!(manager.active_games.contains_key(&game_id));
+        // Bob replaces his guess before the round is resolved
+        game.add_guess(&bob_id, "hells".to_string()).unwrap();
+
+        let result = game.resolve_round(&round, winner).unwrap();
+        match result {
+            Some(RoundResult::Continuing(guess_result)) => {
+                assert_eq!(guess_result.word, "hells");
+                assert_eq!(guess_result.player_id, bob_id);
+            }
+            other => panic!("Expected Bob's replacement to win, got {:?}", other),
+        }
     }
 
     #[test]
-    fn test_queue_management() {
-        let validator = create_test_validator();
-        let mut manager = GameManager::new(validator);
+    fn test_board_size_cap_keeps_revealing_guesses() {
+        let players = vec![create_test_player("Alice"), create_test_player("Bob")];
+        let alice_id = players[0].user_id.clone();
+        let bob_id = players[1].user_id.clone();
+        let rules = GameRules {
+            max_board_size: Some(2),
+            ..Default::default()
+        };
+        let mut game = Game::new_with_rules(
+            "test-game-id".to_string(),
+            players,
+            "hello".to_string(),
+            100,
+            rules,
+        );
+        game.start_guessing_phase();
 
-        let player_id = "test-player-id".to_string();
+        // "hotel" reveals letters and must survive trimming
+        game.add_guess(&alice_id, "hotel".to_string()).unwrap();
+        game.process_round().unwrap();
+        game.process_individual_guess(alice_id.clone(), "zzzzz".to_string())
+            .unwrap();
 
-        manager.add_to_queue(&player_id);
-        assert_eq!(manager.get_queue_position(&player_id), Some(0));
+        game.add_guess(&bob_id, "zzzyy".to_string()).unwrap();
+        game.process_round().unwrap();
+        game.process_individual_guess(bob_id.clone(), "yyyyy".to_string())
+            .unwrap();
 
-        manager.remove_from_queue(&player_id);
-        assert_eq!(manager.get_queue_position(&player_id), None);
+        let board: Vec<&str> = game
+            .state
+            .official_board
+            .iter()
+            .map(|g| g.word.as_str())
+            .collect();
+        assert_eq!(board, vec!["hotel", "yyyyy"]);
+
+        // Trimmed words still count as already guessed
+        let result = game.add_guess(&alice_id, "zzzzz".to_string());
+        assert!(matches!(result, Err(GameError::WordAlreadyGuessed { .. })));
+
+        game.reset_board();
+        assert!(game.add_guess(&alice_id, "zzzzz".to_string()).is_ok());
     }
 
     #[test]
-    fn test_game_creation_edge_cases() {
-        let validator = create_test_validator();
-        let mut manager = GameManager::new(validator);
+    fn test_sudden_death_resolves_tie_at_threshold() {
+        let mut players = vec![create_test_player("Alice"), create_test_player("Bob")];
+        players[0].points = 10;
+        players[1].points = 10;
+        let alice_id = players[0].user_id.clone();
+        let bob_id = players[1].user_id.clone();
+        let mut game = Game::new("test-game-id".to_string(), players, "hello".to_string(), 10);
+        game.start_guessing_phase();
 
-        // Test invalid player count - too few
-        let result = manager.create_game(vec![create_test_player("Alice")]);
-        assert!(result.is_err());
-        assert!(
-            result
-                .unwrap_err()
-                .to_string()
-                .contains("Invalid number of players")
+        // A scoreless round at the threshold leaves the players tied
+        game.add_guess(&alice_id, "zzzzz".to_string()).unwrap();
+        game.add_guess(&bob_id, "yyyyy".to_string()).unwrap();
+        let result = game.process_round().unwrap();
+        assert!(matches!(result, Some(RoundResult::Continuing(_))));
+        assert!(game.sudden_death.is_some());
+        assert_eq!(game.current_phase, GamePhase::IndividualGuess);
+        assert_eq!(game.state.current_winner, Some(alice_id.clone()));
+
+        // Tied players alternate; a scoreless rotation keeps sudden death going
+        let result = game
+            .process_individual_guess(bob_id.clone(), "xxxxx".to_string())
+            .unwrap_err();
+        assert_eq!(result, GameError::NotYourTurn);
+        game.process_individual_guess(alice_id.clone(), "xxxxx".to_string())
+            .unwrap();
+        assert_eq!(game.state.current_winner, Some(bob_id.clone()));
+        game.process_individual_guess(bob_id.clone(), "wwwww".to_string())
+            .unwrap();
+        assert_ne!(game.state.status, GameStatus::Completed);
+        assert_eq!(game.state.current_winner, Some(alice_id.clone()));
+
+        // Bob pulls ahead at the end of the next rotation
+        game.process_individual_guess(alice_id.clone(), "vvvvv".to_string())
+            .unwrap();
+        let result = game
+            .process_individual_guess(bob_id.clone(), "hotel".to_string())
+            .unwrap();
+        assert!(matches!(result, Some(RoundResult::GameOver(_))));
+        assert_eq!(game.state.status, GameStatus::Completed);
+        assert!(game.sudden_death.is_none());
+        assert_eq!(game.get_winner().unwrap().user_id, bob_id);
+    }
+
+    #[test]
+    fn test_forfeit_turn_passes_on_individual_guess() {
+        let mut players = vec![create_test_player("Alice"), create_test_player("Bob")];
+        players[0].points = 10;
+        players[1].points = 10;
+        let alice_id = players[0].user_id.clone();
+        let bob_id = players[1].user_id.clone();
+        let mut game = Game::new("test-game-id".to_string(), players, "hello".to_string(), 10);
+        game.start_guessing_phase();
+        assert!(matches!(
+            game.forfeit_turn(),
+            Err(GameError::WrongPhase { .. })
+        ));
+
+        // In sudden death the turn rotates; Bob scores on his and wins
+        game.add_guess(&alice_id, "zzzzz".to_string()).unwrap();
+        game.add_guess(&bob_id, "yyyyy".to_string()).unwrap();
+        game.process_round().unwrap();
+        assert_eq!(game.state.current_winner, Some(alice_id.clone()));
+        assert!(!game.forfeit_turn().unwrap());
+        assert_eq!(game.current_phase, GamePhase::IndividualGuess);
+        assert_eq!(game.state.current_winner, Some(bob_id.clone()));
+        game.process_individual_guess(bob_id.clone(), "hotel".to_string())
+            .unwrap();
+        assert_eq!(game.state.status, GameStatus::Completed);
+
+        // Outside sudden death the forfeit returns to collaborative guessing,
+        // even when the rules don't let players skip
+        let players = vec![create_test_player("Alice"), create_test_player("Bob")];
+        let rules = GameRules {
+            allow_turn_skip: false,
+            ..Default::default()
+        };
+        let mut game = Game::new_with_rules(
+            "test-game-id".to_string(),
+            players,
+            "hello".to_string(),
+            25,
+            rules,
         );
+        game.start_guessing_phase();
+        game.add_guess(&alice_id, "hotel".to_string()).unwrap();
+        game.add_guess(&bob_id, "zzzzz".to_string()).unwrap();
+        game.process_round().unwrap();
+        assert_eq!(game.current_phase, GamePhase::IndividualGuess);
+        assert!(!game.forfeit_turn().unwrap());
+        assert_eq!(game.current_phase, GamePhase::Guessing);
+        assert_eq!(game.state.current_winner, None);
+    }
 
-        // Test invalid player count - too many
-        let many_players: Vec<Player> = (0..17)
-            .map(|i| create_test_player(&format!("Player{}", i)))
-            .collect();
-        let result = manager.create_game(many_players);
-        assert!(result.is_err());
-        assert!(
-            result
-                .unwrap_err()
-                .to_string()
-                .contains("Invalid number of players")
+    #[test]
+    fn test_catch_up_bonus_for_trailing_round_winner() {
+        let mut players = vec![create_test_player("Alice"), create_test_player("Bob")];
+        players[0].points = 15;
+        let alice_id = players[0].user_id.clone();
+        let bob_id = players[1].user_id.clone();
+        let rules = GameRules {
+            scoring: ScoringConfig {
+                catch_up_multiplier: 0.5,
+                catch_up_gap: 0.4,
+                uninformed_solve_bonus: None,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut game = Game::new_with_rules(
+            "test-game-id".to_string(),
+            players,
+            "hello".to_string(),
+            25,
+            rules,
         );
+        game.start_guessing_phase();
 
-        // Test boundary cases - exactly 2 and 16 players
-        let min_players = vec![create_test_player("Alice"), create_test_player("Bob")];
-        let result = manager.create_game(min_players);
-        assert!(result.is_ok());
+        // Bob trails by 15 and wins the round with "hotel": 2 + 1 + 1 + 1 base points
+        game.add_guess(&alice_id, "zzzzz".to_string()).unwrap();
+        game.add_guess(&bob_id, "hotel".to_string()).unwrap();
+        let Some(RoundResult::Continuing(guess)) = game.process_round().unwrap() else {
+            panic!("Expected the round to continue");
+        };
+        assert_eq!(guess.breakdown.base_points, 5);
+        assert_eq!(guess.breakdown.catch_up_bonus, 3);
+        assert_eq!(guess.points_earned, 8);
+        let bob = game.state.players.iter().find(|p| p.user_id == bob_id);
+        assert_eq!(bob.unwrap().points, 8);
+
+        // Alice leads, so her individual guess earns no bonus
+        game.state.current_winner = Some(alice_id.clone());
+        let Some(RoundResult::Continuing(guess)) = game
+            .process_individual_guess(alice_id.clone(), "hxllx".to_string())
+            .unwrap()
+        else {
+            panic!("Expected the round to continue");
+        };
+        assert_eq!(guess.breakdown.catch_up_bonus, 0);
+        assert_eq!(guess.points_earned, guess.breakdown.base_points);
+    }
 
-        let max_players: Vec<Player> = (0..16)
-            .map(|i| create_test_player(&format!("Player{}", i)))
-            .collect();
-        let result = manager.create_game(max_players);
-        assert!(result.is_ok());
+    #[test]
+    fn test_informative_losing_guess_earns_assist() {
+        let players = vec![
+            create_test_player("Alice"),
+            create_test_player("Bob"),
+            create_test_player("Carol"),
+        ];
+        let ids: Vec<PlayerId> = players.iter().map(|p| p.user_id.clone()).collect();
+        let play_round = |assist_fraction: f64| {
+            let rules = GameRules {
+                scoring: ScoringConfig {
+                    assist_fraction,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            let mut game = Game::new_with_rules(
+                "test-game-id".to_string(),
+                players.clone(),
+                "hello".to_string(),
+                25,
+                rules,
+            );
+            game.start_guessing_phase();
+
+            // Alice wins with "hotel" (5 points); Bob's "world" reveals the
+            // o and an l (3 points); Carol's "zzzzz" reveals nothing
+            for (player_id, word) in ids.iter().zip(["hotel", "world", "zzzzz"]) {
+                game.add_guess(player_id, word.to_string()).unwrap();
+            }
+            game.process_round().unwrap();
+            game.state.players
+        };
+
+        let players = play_round(0.5);
+        let alice = &players[0].guess_history[0];
+        assert!(alice.was_winning_guess);
+        assert_eq!((alice.points_earned, alice.assist_points), (5, 0));
+        let bob = &players[1].guess_history[0];
+        assert!(!bob.was_winning_guess);
+        assert_eq!((bob.points_earned, bob.assist_points), (2, 2));
+        assert_eq!(players[1].points, 2);
+        let carol = &players[2].guess_history[0];
+        assert_eq!((carol.points_earned, carol.assist_points), (0, 0));
+        assert_eq!(players[2].points, 0);
+
+        // Off by default: the winner takes the round
+        let players = play_round(0.0);
+        assert_eq!(players[0].points, 5);
+        assert_eq!(players[1].points, 0);
+        assert_eq!(players[1].guess_history[0].assist_points, 0);
+    }
+
+    #[test]
+    fn test_uninformed_solve_earns_reduced_bonus() {
+        let rules = GameRules {
+            scoring: ScoringConfig {
+                uninformed_solve_bonus: Some(1),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let players = vec![create_test_player("Alice"), create_test_player("Bob")];
+        let alice_id = players[0].user_id.clone();
+
+        // "hells" then "jello" leave every letter of "hello" on the board
+        let mut game = Game::new_with_rules(
+            "test-game-id".to_string(),
+            players.clone(),
+            "hello".to_string(),
+            100,
+            rules.clone(),
+        );
+        game.start_guessing_phase();
+        game.add_guess(&alice_id, "hells".to_string()).unwrap();
+        game.process_round().unwrap();
+        game.process_individual_guess(alice_id.clone(), "jello".to_string())
+            .unwrap();
+        game.add_guess(&alice_id, "hello".to_string()).unwrap();
+        let Some(RoundResult::WordCompleted(free_solve)) = game.process_round().unwrap() else {
+            panic!("Expected the word to be completed");
+        };
+        assert_eq!(free_solve.points_earned, 1);
+
+        // Solving straight after "hells" reveals the o and earns the full bonus
+        let mut game = Game::new_with_rules(
+            "test-game-id".to_string(),
+            players,
+            "hello".to_string(),
+            100,
+            rules,
+        );
+        game.start_guessing_phase();
+        game.add_guess(&alice_id, "hells".to_string()).unwrap();
+        game.process_round().unwrap();
+        let Some(RoundResult::WordCompleted(informed_solve)) = game
+            .process_individual_guess(alice_id.clone(), "hello".to_string())
+            .unwrap()
+        else {
+            panic!("Expected the word to be completed");
+        };
+        assert_eq!(informed_solve.points_earned, 2 + SOLVE_BONUS);
     }
 
     #[test]
-    fn test_duplicate_queue_entries() {
-        let validator = create_test_validator();
-        let mut manager = GameManager::new(validator);
+    fn test_empty_guess_is_refused() {
+        let players = vec![create_test_player("Alice"), create_test_player("Bob")];
+        let alice_id = players[0].user_id.clone();
+        let mut game = Game::new("test-game-id".to_string(), players, "hello".to_string(), 25);
+        game.start_guessing_phase();
 
-        let player_id = "test-player-duplicate".to_string();
+        for word in ["", "   "] {
+            assert!(matches!(
+                game.add_guess(&alice_id, word.to_string()),
+                Err(GameError::EmptyGuess)
+            ));
+        }
+        assert!(game.current_guesses.is_empty());
+
+        game.state.current_winner = Some(alice_id.clone());
+        game.set_phase(GamePhase::IndividualGuess);
+        assert!(matches!(
+            game.process_individual_guess(alice_id, " \t".to_string()),
+            Err(GameError::EmptyGuess)
+        ));
+        assert!(game.state.official_board.is_empty());
+    }
 
-        // Add same player multiple times
-        manager.add_to_queue(&player_id);
-        manager.add_to_queue(&player_id);
-        manager.add_to_queue(&player_id);
+    #[test]
+    fn test_solve_penalty_stops_at_points_floor() {
+        let rules = GameRules {
+            scoring: ScoringConfig {
+                uninformed_solve_bonus: Some(-15),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let players = vec![create_test_player("Alice"), create_test_player("Bob")];
+        let alice_id = players[0].user_id.clone();
+        let mut game = Game::new_with_rules(
+            "test-game-id".to_string(),
+            players,
+            "hello".to_string(),
+            100,
+            rules,
+        );
 
-        // Should only appear once
-        assert_eq!(manager.get_queue_position(&player_id), Some(0));
-        assert_eq!(manager.game_queue.len(), 1);
+        // Alice earns 10 points uncovering the word, then solves it for free
+        game.start_guessing_phase();
+        game.add_guess(&alice_id, "hells".to_string()).unwrap();
+        game.process_round().unwrap();
+        game.process_individual_guess(alice_id.clone(), "jello".to_string())
+            .unwrap();
+        let alice = game.state.players.iter().find(|p| p.user_id == alice_id);
+        assert_eq!(alice.unwrap().points, 10);
+
+        game.add_guess(&alice_id, "hello".to_string()).unwrap();
+        let Some(RoundResult::WordCompleted(solve)) = game.process_round().unwrap() else {
+            panic!("Expected the word to be completed");
+        };
+        assert_eq!(solve.points_earned, -15);
+        let alice = game.state.players.iter().find(|p| p.user_id == alice_id);
+        assert_eq!(alice.unwrap().points, 0);
     }
 
     #[test]
-    fn test_invalid_word_handling() {
-        let validator = create_test_validator();
-        let mut manager = GameManager::new(validator);
+    fn test_large_totals_saturate() {
+        let mut game = create_team_game(i32::MAX);
+        for player in game.state.players.iter_mut().take(2) {
+            player.points = i32::MAX - 1;
+        }
+        let alice_id = game.state.players[0].user_id.clone();
+
+        game.add_guess(&alice_id, "hello".to_string()).unwrap();
+        game.process_round().unwrap();
+        assert_eq!(game.state.players[0].points, i32::MAX);
+        assert_eq!(game.state.teams[0].points, i32::MAX);
+    }
 
+    #[test]
+    fn test_personalized_mask_shows_only_solved_positions() {
         let players = vec![create_test_player("Alice"), create_test_player("Bob")];
-        let game_id = manager.create_game(players.clone()).unwrap();
-        let player_id = players[0].user_id.clone();
+        let alice_id = players[0].user_id.clone();
+        let mut game = Game::new(
+            "test-game-id".to_string(),
+            players,
+            "hello".to_string(),
+            100,
+        );
+        game.start_guessing_phase();
+        assert_eq!(
+            game.state.personalized_for_player(alice_id.clone()).word,
+            "*****"
+        );
 
-        // Test invalid word
-        let result = manager.handle_guess(&game_id, &player_id, "invalidword".to_string());
-        assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("Invalid word"));
+        // "hotel": only the h is in the right place; o, e and l are merely present
+        game.add_guess(&alice_id, "hotel".to_string()).unwrap();
+        game.process_round().unwrap();
+        assert_eq!(
+            game.state.personalized_for_player(alice_id.clone()).word,
+            "h****"
+        );
 
-        // Test non-alphabetic word
-        let result = manager.handle_guess(&game_id, &player_id, "test123".to_string());
-        assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("Invalid word"));
+        // "helps" solves e and l at positions 1 and 2; position 3's l and the o
+        // have never been shown as correct
+        game.process_individual_guess(alice_id.clone(), "helps".to_string())
+            .unwrap();
+        let mask = game.state.personalized_for_player(alice_id.clone()).word;
+        assert_eq!(mask, "hel**");
 
-        // Test empty word
-        let result = manager.handle_guess(&game_id, &player_id, "".to_string());
-        assert!(result.is_err());
+        // The shared state itself stays fully masked
+        assert_eq!(game.state.word, "*****");
     }
 
     #[test]
-    fn test_guess_submission_edge_cases() {
-        let validator = create_test_validator();
-        let mut manager = GameManager::new(validator);
-
+    fn test_hidden_scores_revealed_at_game_end() {
         let players = vec![create_test_player("Alice"), create_test_player("Bob")];
-        let game_id = manager.create_game(players.clone()).unwrap();
         let alice_id = players[0].user_id.clone();
         let bob_id = players[1].user_id.clone();
-
-        let game = manager.active_games.get_mut(&game_id).unwrap();
-
-        // Test player not in game
-        let fake_player = "fake-player".to_string();
-        let result = game.add_guess(&fake_player, "hello".to_string());
-        assert!(result.is_err());
-        assert!(
-            result
-                .unwrap_err()
-                .to_string()
-                .contains("Player not in game")
+        let rules = GameRules {
+            hide_scores_until_end: true,
+            ..Default::default()
+        };
+        let mut game = Game::new_with_rules(
+            "test-game-id".to_string(),
+            players,
+            "hello".to_string(),
+            100,
+            rules,
         );
+        game.start_guessing_phase();
+        game.add_guess(&alice_id, "hotel".to_string()).unwrap();
+        game.add_guess(&bob_id, "world".to_string()).unwrap();
+        game.process_round().unwrap();
+
+        let points_seen_by = |game: &Game, viewer: &PlayerId| {
+            let state = game.state.personalized_for_player(viewer.clone());
+            let alice = state
+                .players
+                .iter()
+                .find(|p| p.user_id == alice_id)
+                .unwrap();
+            let alice_board: i32 = state
+                .official_board
+                .iter()
+                .filter(|guess| guess.player_id == alice_id)
+                .map(|guess| guess.points_earned)
+                .sum();
+            (alice.points, alice_board)
+        };
 
-        // Test duplicate word guessing
-        game.add_guess(&alice_id, "hello".to_string()).unwrap();
-        let guess_result = game.process_round().unwrap();
-        assert!(guess_result.is_some());
+        // Alice sees her own score; Bob doesn't until the game is over
+        assert_eq!(points_seen_by(&game, &alice_id), (5, 5));
+        assert_eq!(points_seen_by(&game, &bob_id), (0, 0));
 
-        // Try to guess the same word again
-        let result = game.add_guess(&bob_id, "hello".to_string());
-        assert!(result.is_err());
+        game.state.status = GameStatus::Completed;
+        assert_eq!(points_seen_by(&game, &bob_id), (5, 5));
+    }
+
+    #[test]
+    fn test_only_new_reveals_reported() {
+        let players = vec![create_test_player("Alice"), create_test_player("Bob")];
+        let alice_id = players[0].user_id.clone();
+        let mut game = Game::new(
+            "test-game-id".to_string(),
+            players,
+            "hello".to_string(),
+            100,
+        );
+        game.start_guessing_phase();
+
+        // "hotel": h correct, o/e/l present
+        game.add_guess(&alice_id, "hotel".to_string()).unwrap();
+        game.process_round().unwrap();
+        let reveals: Vec<(String, i32)> = game
+            .take_reveals()
+            .into_iter()
+            .map(|l| (l.letter, l.position))
+            .collect();
+        assert_eq!(
+            reveals,
+            vec![
+                ("h".to_string(), 0),
+                ("o".to_string(), 1),
+                ("e".to_string(), 3),
+                ("l".to_string(), 4),
+            ]
+        );
+        assert!(game.take_reveals().is_empty());
+
+        // "helps": h is already known, e and l move to correct, p/s are absent
+        game.process_individual_guess(alice_id.clone(), "helps".to_string())
+            .unwrap();
+        let reveals: Vec<(String, LetterStatus)> = game
+            .take_reveals()
+            .into_iter()
+            .map(|l| (l.letter, l.status))
+            .collect();
+        assert_eq!(reveals.len(), 2);
         assert!(
-            result
-                .unwrap_err()
-                .to_string()
-                .contains("Word already guessed")
+            reveals
+                .iter()
+                .all(|(letter, status)| matches!(status, LetterStatus::Correct)
+                    && (letter == "e" || letter == "l"))
         );
     }
 
     #[test]
-    fn test_game_state_transitions() {
-        let validator = create_test_validator();
-        let mut manager = GameManager::new(validator);
+    fn test_hint_reveals_hidden_letter_for_penalty() {
+        let players = vec![
+            create_test_player("Alice"),
+            create_test_player("Bob"),
+            create_test_player("Carol"),
+        ];
+        let (alice_id, bob_id, carol_id) = (
+            players[0].user_id.clone(),
+            players[1].user_id.clone(),
+            players[2].user_id.clone(),
+        );
+        let rules = GameRules {
+            max_hints_per_round: Some(2),
+            hint_penalty: 3,
+            ..GameRules::default()
+        };
+        let mut game = Game::new_with_rules(
+            "test-game-id".to_string(),
+            players,
+            "hello".to_string(),
+            100,
+            rules,
+        );
+        game.state.players[0].points = 4;
+        game.start_guessing_phase();
+
+        // Each player gets the next letter they haven't been shown
+        let hint = game.request_hint(&alice_id).unwrap();
+        assert_eq!((hint.letter.as_str(), hint.position), ("h", 0));
+        assert!(matches!(hint.status, LetterStatus::Correct));
+        assert_eq!(game.state.players[0].points, 1);
+        let hint = game.request_hint(&alice_id).unwrap();
+        assert_eq!((hint.letter.as_str(), hint.position), ("e", 1));
+        assert_eq!(game.state.players[0].points, 0);
+        assert_eq!(
+            game.request_hint(&alice_id).unwrap_err(),
+            GameError::HintLimitReached { limit: 2 }
+        );
+        let hint = game.request_hint(&bob_id).unwrap();
+        assert_eq!(hint.position, 0);
+
+        // Letters the board already shows are never hinted
+        game.add_guess(&carol_id, "hotel".to_string()).unwrap();
+        game.process_round().unwrap();
+        game.skip_turn(&carol_id).unwrap();
+        let hint = game.request_hint(&carol_id).unwrap();
+        assert_eq!((hint.letter.as_str(), hint.position), ("e", 1));
+
+        // A new word brings a fresh allowance
+        game.reset_board();
+        game.set_target_word("world".to_string());
+        let hint = game.request_hint(&alice_id).unwrap();
+        assert_eq!((hint.letter.as_str(), hint.position), ("w", 0));
+    }
 
+    #[test]
+    fn test_hints_refused_unless_enabled() {
         let players = vec![create_test_player("Alice"), create_test_player("Bob")];
-        let game_id = manager.create_game(players.clone()).unwrap();
-
-        let game = manager.active_games.get_mut(&game_id).unwrap();
-        assert_eq!(game.state.status, GameStatus::Starting);
-        assert_eq!(game.current_phase, GamePhase::Waiting);
+        let alice_id = players[0].user_id.clone();
+        let mut game = Game::new("test-game-id".to_string(), players, "hello".to_string(), 25);
+        game.start_guessing_phase();
 
-        // Test countdown functionality
-        game.set_countdown(std::time::Duration::from_millis(1));
-        assert_eq!(game.current_phase, GamePhase::Countdown);
+        assert_eq!(
+            game.request_hint(&alice_id).unwrap_err(),
+            GameError::HintsNotAllowed
+        );
+    }
 
-        // Wait for countdown to finish
-        std::thread::sleep(std::time::Duration::from_millis(2));
-        assert!(game.is_countdown_finished());
+    fn play_round_with_guess(game: &mut Game, word: &str) {
+        let player_id = game.state.players[0].user_id.clone();
+        game.start_guessing_phase();
+        game.add_guess(&player_id, word.to_string()).unwrap();
+        game.process_round().unwrap();
+        game.reset_board();
+        game.state.current_round += 1;
+        game.start_round_phase();
     }
 
     #[test]
-    fn test_game_completion_conditions() {
-        let validator = create_test_validator();
-        let mut manager = GameManager::new(validator);
+    fn test_word_reuse_allowed_across_rounds() {
+        let players = vec![create_test_player("Alice"), create_test_player("Bob")];
+        let alice_id = players[0].user_id.clone();
+        let mut game = Game::new(
+            "test-game-id".to_string(),
+            players,
+            "hello".to_string(),
+            100,
+        );
 
-        let mut players = vec![create_test_player("Alice"), create_test_player("Bob")];
-        let game_id = manager.create_game(players.clone()).unwrap();
+        play_round_with_guess(&mut game, "about");
+        game.start_guessing_phase();
+        assert!(game.add_guess(&alice_id, "about".to_string()).is_ok());
+    }
 
-        let game = manager.active_games.get_mut(&game_id).unwrap();
-        let target_word = game.target_word.clone();
+    #[test]
+    fn test_word_reuse_forbidden_across_rounds() {
+        let players = vec![create_test_player("Alice"), create_test_player("Bob")];
         let alice_id = players[0].user_id.clone();
+        let rules = GameRules {
+            allow_reuse_across_rounds: false,
+            ..Default::default()
+        };
+        let mut game = Game::new_with_rules(
+            "test-game-id".to_string(),
+            players,
+            "hello".to_string(),
+            100,
+            rules,
+        );
 
-        // Test word completion - should return WordCompleted result when target word is guessed
-        game.add_guess(&alice_id, target_word.clone()).unwrap();
-        let result = game.process_round();
+        play_round_with_guess(&mut game, "about");
+        game.start_guessing_phase();
+        assert_eq!(
+            game.add_guess(&alice_id, "ABOUT".to_string()),
+            Err(GameError::WordAlreadyGuessed {
+                word: "ABOUT".to_string(),
+                prior: Some(PriorGuess {
+                    round: 1,
+                    player_id: alice_id.clone(),
+                }),
+            })
+        );
+        assert!(game.add_guess(&alice_id, "beach".to_string()).is_ok());
+    }
 
-        // Should return Ok with WordCompleted variant
-        assert!(result.is_ok());
-        let round_result = result.unwrap();
-        assert!(round_result.is_some());
+    #[test]
+    fn test_campaign_combo_carries_over_while_board_resets() {
+        let players = vec![create_test_player("Alice"), create_test_player("Bob")];
+        let alice_id = players[0].user_id.clone();
+        let bob_id = players[1].user_id.clone();
+        let rules = GameRules {
+            carry_progress: true,
+            ..Default::default()
+        };
+        let mut game = Game::new_with_rules(
+            "test-game-id".to_string(),
+            players,
+            "hello".to_string(),
+            100,
+            rules,
+        );
 
-        // Check that it's a WordCompleted variant
-        if let Some(RoundResult::WordCompleted(completion)) = round_result {
-            assert_eq!(completion.word, target_word);
-            assert_eq!(completion.player_id, alice_id);
-            // 6 letters × 2 points each + 5 completion bonus = 17 points
-            assert_eq!(
-                completion.points_earned, 17,
-                "Expected 17 points for 6-letter word completion"
-            );
-        } else {
-            panic!("Expected WordCompleted result");
+        // Alice solves two words in a row, then Bob takes the third
+        for (solver, next_word) in [
+            (&alice_id, "world"),
+            (&alice_id, "tests"),
+            (&bob_id, "valid"),
+        ] {
+            game.start_guessing_phase();
+            let target = game.target_word.clone();
+            game.add_guess(solver, target).unwrap();
+            let result = game.process_round().unwrap();
+            assert!(matches!(result, Some(RoundResult::WordCompleted(_))));
+            assert!(!game.state.official_board.is_empty());
+
+            game.reset_board();
+            game.record_solve(solver);
+            game.state.current_round += 1;
+            game.target_word = next_word.to_string();
+            assert!(game.state.official_board.is_empty());
+
+            if game.state.current_round == 3 {
+                assert_eq!(game.state.campaign.as_ref().unwrap().combo, 2);
+            }
         }
 
-        // Game state should not be Completed (round completion handled externally)
-        // It might be Starting or Active depending on game initialization
-        assert_ne!(game.state.status, GameStatus::Completed);
+        let campaign = game.state.campaign.unwrap();
+        assert_eq!(campaign.words_solved, 3);
+        assert_eq!(campaign.combo, 1);
+        assert_eq!(campaign.combo_player, Some(bob_id));
+        assert_eq!(campaign.best_combo, 2);
+    }
 
-        // Test point threshold completion
-        let mut game2 = Game::new(
-            "test-game-id-2".to_string(),
-            players.clone(),
-            "tests".to_string(),
-            10, // Low threshold
+    /// Play a game where Alice solves every word on her first guess, cycling
+    /// through `words`, and return the round the game ended in
+    fn rounds_until_game_over(words: &[&str], point_threshold: i32, rules: GameRules) -> i32 {
+        let players = vec![create_test_player("Alice"), create_test_player("Bob")];
+        let alice_id = players[0].user_id.clone();
+        let mut game = Game::new_with_rules(
+            "test-game-id".to_string(),
+            players,
+            words[0].to_string(),
+            point_threshold,
+            rules,
         );
 
-        // Manually set a player's points above threshold
-        players[0].points = 15;
-        game2.state.players = players;
+        for next_word in words.iter().cycle().skip(1) {
+            game.start_guessing_phase();
+            let target = game.target_word.clone();
+            game.add_guess(&alice_id, target).unwrap();
+            match game.process_round().unwrap() {
+                Some(RoundResult::GameOver(_)) => return game.state.current_round,
+                Some(RoundResult::WordCompleted(_)) => {}
+                other => panic!("Expected the word to be solved, got {:?}", other),
+            }
 
-        game2.add_guess(&alice_id, "tests".to_string()).unwrap();
-        let result = game2.process_round().unwrap();
-        assert!(result.is_some());
-        assert_eq!(game2.state.status, GameStatus::Completed);
+            game.reset_board();
+            game.state.current_round += 1;
+            game.set_target_word(next_word.to_string());
+        }
+        unreachable!()
     }
 
     #[test]
-    fn test_game_expiration() {
-        let validator = create_test_validator();
-        let mut manager = GameManager::new(validator);
+    fn test_length_scaled_threshold_evens_out_game_length() {
+        let short_words = ["hello", "world", "tests", "valid", "words"];
+        let long_words = ["absolute", "backyard", "campaign", "daughter", "elephant"];
+
+        // With a flat threshold long words end the game noticeably sooner
+        let flat_short = rounds_until_game_over(&short_words, 100, GameRules::default());
+        let flat_long = rounds_until_game_over(&long_words, 100, GameRules::default());
+        assert!(flat_short - flat_long >= 2);
+
+        let scaled = GameRules {
+            points_per_letter: Some(20),
+            ..Default::default()
+        };
+        let scaled_short = rounds_until_game_over(&short_words, 100, scaled.clone());
+        let scaled_long = rounds_until_game_over(&long_words, 100, scaled);
+        assert!((scaled_short - scaled_long).abs() <= 1);
+    }
 
+    #[test]
+    fn test_length_scaled_threshold_is_fixed_at_creation() {
         let players = vec![create_test_player("Alice"), create_test_player("Bob")];
-        let game_id = manager.create_game(players.clone()).unwrap();
-
-        // Game should not be expired initially
-        let game = manager.active_games.get(&game_id).unwrap();
-        assert!(!game.is_expired(std::time::Duration::from_secs(1)));
+        let rules = GameRules {
+            points_per_letter: Some(5),
+            ..Default::default()
+        };
+        let mut game = Game::new_with_rules(
+            "test-game-id".to_string(),
+            players,
+            "elephant".to_string(),
+            25,
+            rules,
+        );
+        assert_eq!(game.state.point_threshold, 40);
 
-        // Test with zero duration - should be expired
-        assert!(game.is_expired(std::time::Duration::from_millis(0)));
+        game.set_target_word("hello".to_string());
+        assert_eq!(game.state.point_threshold, 40);
+        assert_eq!(game.state.word_length, 5);
+        assert_eq!(game.state.word, "*****");
+    }
 
-        // Test cleanup
-        manager.cleanup_expired_games();
-        // Game should still exist (not expired with reasonable timeout)
-        assert!(manager.active_games.contains_key(&game_id));
+    #[test]
+    fn test_length_scaled_threshold_stays_in_range() {
+        let scaled = |per_letter| GameRules {
+            points_per_letter: Some(per_letter),
+            ..Default::default()
+        };
+        assert_eq!(scaled(1).point_threshold_for(4, 25), 5);
+        assert_eq!(scaled(1000).point_threshold_for(8, 25), 200);
+        assert_eq!(scaled(i32::MAX).point_threshold_for(8, 25), 200);
     }
 
     #[test]
-    fn test_empty_round_processing() {
-        let validator = create_test_validator();
+    fn test_flat_threshold_ignores_word_length() {
         let players = vec![create_test_player("Alice"), create_test_player("Bob")];
         let mut game = Game::new("test-game-id".to_string(), players, "hello".to_string(), 25);
+        game.set_target_word("elephant".to_string());
+        assert_eq!(game.state.point_threshold, 25);
+    }
+
+    #[test]
+    fn test_campaign_absent_by_default() {
+        let players = vec![create_test_player("Alice"), create_test_player("Bob")];
+        let player_id = players[0].user_id.clone();
+        let mut game = Game::new(
+            "test-game-id".to_string(),
+            players,
+            "hello".to_string(),
+            100,
+        );
+        game.record_solve(&player_id);
+        assert!(game.state.campaign.is_none());
+    }
+
+    fn create_team_game(point_threshold: i32) -> Game {
+        let players: Vec<Player> = ["Alice", "Bob", "Carol", "Dave"]
+            .iter()
+            .map(|name| create_test_player(name))
+            .collect();
+        let team = |id: u32, name: &str, members: [&Player; 2]| Team {
+            id,
+            name: name.to_string(),
+            player_ids: members.iter().map(|p| p.user_id.clone()).collect(),
+            points: 0,
+        };
+        let teams = vec![
+            team(0, "Red", [&players[0], &players[1]]),
+            team(1, "Blue", [&players[2], &players[3]]),
+        ];
+
+        let mut game = Game::new(
+            "team-game".to_string(),
+            players,
+            "hello".to_string(),
+            point_threshold,
+        )
+        .with_teams(teams);
+        game.start_guessing_phase();
+        game
+    }
+
+    #[test]
+    fn test_team_round_scores_each_team() {
+        let mut game = create_team_game(100);
+        let id = |name: &str| format!("test-player-{}", name);
+
+        game.add_guess(&id("alice"), "hotel".to_string()).unwrap();
+        game.add_guess(&id("bob"), "about".to_string()).unwrap();
+        game.add_guess(&id("carol"), "world".to_string()).unwrap();
 
-        // Process round with no guesses
         let result = game.process_round().unwrap();
-        assert!(result.is_none());
+        assert!(matches!(result, Some(RoundResult::Continuing(_))));
+
+        // Both teams are scored against the board from before the round
+        let (_, hotel_points) = ScoringEngine::evaluate_guess("hotel", "hello", &[]);
+        let (_, world_points) = ScoringEngine::evaluate_guess("world", "hello", &[]);
+        let points = |name: &str| {
+            game.state
+                .players
+                .iter()
+                .find(|p| p.user_id == id(name))
+                .unwrap()
+                .points
+        };
+        assert_eq!(points("alice"), hotel_points);
+        assert_eq!(points("bob"), 0);
+        assert_eq!(points("carol"), world_points);
+        assert_eq!(game.state.teams[0].points, hotel_points);
+        assert_eq!(game.state.teams[1].points, world_points);
+
+        // Each team's word lands on the shared board and play continues together
+        let words: Vec<&str> = game
+            .state
+            .official_board
+            .iter()
+            .map(|g| g.word.as_str())
+            .collect();
+        assert_eq!(words, vec!["hotel", "world"]);
+        assert_eq!(game.current_phase, GamePhase::Guessing);
+        assert_eq!(game.state.current_winner, None);
+    }
+
+    #[test]
+    fn test_team_game_ends_when_team_reaches_threshold() {
+        let mut game = create_team_game(10);
+        let id = |name: &str| format!("test-player-{}", name);
+
+        // Both teams solving at once ties them, so the game goes on
+        game.add_guess(&id("alice"), "hello".to_string()).unwrap();
+        game.add_guess(&id("carol"), "hello".to_string()).unwrap();
+        let result = game.process_round().unwrap();
+        assert!(matches!(result, Some(RoundResult::WordCompleted(_))));
+        assert_eq!(game.state.teams[0].points, game.state.teams[1].points);
+        assert_eq!(game.state.status, GameStatus::Starting);
+
+        game.target_word = "world".to_string();
+        game.reset_board();
+        game.start_round_phase();
+
+        game.add_guess(&id("bob"), "world".to_string()).unwrap();
+        game.add_guess(&id("dave"), "hotel".to_string()).unwrap();
+        let result = game.process_round().unwrap();
+        assert!(matches!(result, Some(RoundResult::GameOver(_))));
+        assert_eq!(game.state.status, GameStatus::Completed);
+        assert!(game.state.teams[0].points > game.state.teams[1].points);
+
+        // The win goes to the top scorer on the leading team
+        let leader = game.leader().unwrap();
+        assert!(game.state.teams[0].player_ids.contains(&leader.user_id));
     }
 
     #[test]
     fn test_winner_determination() {
-        let validator = create_test_validator();
         let mut players = vec![create_test_player("Alice"), create_test_player("Bob")];
 
         // Set different point values
@@ -757,7 +2635,7 @@ mod tests {
         // Try to handle guess for non-existent game
         let result = manager.handle_guess(&fake_game_id, &fake_player_id, "hello".to_string());
         assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("Game not found"));
+        assert!(matches!(result.unwrap_err(), GameError::GameNotFound));
     }
 
     #[test]
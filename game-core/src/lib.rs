@@ -1,12 +1,20 @@
 pub mod cleanup;
+pub mod clock;
+pub mod errors;
 pub mod game_events;
 pub mod game_state;
+pub mod rules;
 pub mod scoring;
+pub mod teams;
 pub mod word_validation;
 
 // Re-export main components
 pub use cleanup::*;
+pub use clock::*;
+pub use errors::*;
 pub use game_events::*;
 pub use game_state::*;
+pub use rules::*;
 pub use scoring::*;
+pub use teams::*;
 pub use word_validation::*;
@@ -0,0 +1,132 @@
+use crate::ScoringConfig;
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+/// Point thresholds a game may be played to, whether requested for a match
+/// or scaled from the word length
+pub const POINT_THRESHOLD_RANGE: RangeInclusive<i32> = 5..=200;
+
+/// Optional game rules that change how a match plays out. The defaults
+/// reproduce the classic ruleset.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameRules {
+    /// When a player reaches the point threshold, play one more collaborative
+    /// round before ending instead of ending immediately. The highest scorer
+    /// after that round wins.
+    pub final_round_on_threshold: bool,
+    /// Maximum number of entries kept on the official board per round. Once
+    /// the cap is exceeded the oldest guess that earned no points is dropped;
+    /// guesses that revealed new letters are always kept, so the board may
+    /// still exceed the cap when every entry carries information. `None`
+    /// keeps every guess.
+    pub max_board_size: Option<usize>,
+    /// Scoring options such as the catch-up bonus for trailing players
+    pub scoring: ScoringConfig,
+    /// Whether a word guessed in an earlier round may be guessed again once
+    /// the board is cleared for a new word. When false every word can only be
+    /// played once per game.
+    pub allow_reuse_across_rounds: bool,
+    /// Split players into two teams that guess the same word side by side.
+    /// Each team's best guess is scored every round and the first team to
+    /// reach the point threshold with a clear lead wins.
+    pub team_mode: bool,
+    /// Let the round winner pass on their individual guess and send the game
+    /// straight back to collaborative guessing. Sudden-death turns can never
+    /// be skipped.
+    pub allow_turn_skip: bool,
+    /// Resolve a collaborative round once this many connected players have
+    /// guessed instead of waiting for all of them. Rounds with fewer connected
+    /// players still wait for everyone. `None` always waits for every
+    /// connected player.
+    pub round_quorum: Option<usize>,
+    /// Pause in the `Intermission` phase for this long after a round that
+    /// leaves the word unsolved, so players can take in the result before
+    /// play resumes. `None` moves on immediately.
+    pub intermission: Option<Duration>,
+    /// Refuse blocklisted words as guesses, not just as targets, to keep the
+    /// board family-friendly
+    pub strict_guesses: bool,
+    /// Keep a running tally of solved words and the current solve combo in
+    /// `GameState::campaign` across words. The board still resets for every
+    /// new word.
+    pub carry_progress: bool,
+    /// Draw target words only from these themed categories (see
+    /// `WordValidator::with_category`). Empty uses the whole dictionary.
+    pub word_categories: Vec<String>,
+    /// With `word_categories` set, only accept guesses from those categories
+    /// instead of the full dictionary
+    pub category_guesses_only: bool,
+    /// Scale the point threshold with word length: the threshold becomes
+    /// this many points per letter of the game's first word, kept within
+    /// `POINT_THRESHOLD_RANGE`, so long words don't end games in fewer rounds
+    /// than short ones. It is resolved once when the game is created and
+    /// doesn't change mid-game. `None` keeps the flat threshold.
+    pub points_per_letter: Option<i32>,
+    /// Accept guesses whose length differs from the target word's. They are
+    /// scored by `evaluate_guess` as usual, comparing position by position.
+    /// Live games refuse them by default.
+    pub allow_mismatched_length: bool,
+    /// Give the round winner an individual guess after each collaborative
+    /// round. When false the winner is still scored but play goes straight
+    /// back to collaborative guessing. Sudden death is unaffected.
+    pub individual_guess: bool,
+    /// Shortest time a player must wait between guess submissions, so a
+    /// scripted client can't brute-force the word by guessing every few
+    /// milliseconds. `None` accepts guesses as fast as they arrive.
+    pub min_guess_interval: Option<Duration>,
+    /// Withhold opponents' running scores, and what their board guesses
+    /// earned, from each player until the game ends. Everyone still sees
+    /// their own score and the final standings.
+    pub hide_scores_until_end: bool,
+    /// Share of connected players who must vote to skip a collaborative
+    /// round before its word is revealed and a new one drawn, with no solve
+    /// credited. `None` turns skip votes off.
+    pub skip_round_vote_fraction: Option<f64>,
+    /// Letters each player may have revealed to them per word by asking for
+    /// a hint. `None` turns hints off.
+    pub max_hints_per_round: Option<usize>,
+    /// Points taken from a player for each hint. Scores never drop below
+    /// zero to pay for one.
+    pub hint_penalty: i32,
+}
+
+impl Default for GameRules {
+    fn default() -> Self {
+        Self {
+            final_round_on_threshold: false,
+            max_board_size: None,
+            scoring: ScoringConfig::default(),
+            allow_reuse_across_rounds: true,
+            team_mode: false,
+            allow_turn_skip: true,
+            round_quorum: None,
+            intermission: None,
+            strict_guesses: false,
+            carry_progress: false,
+            word_categories: Vec::new(),
+            category_guesses_only: false,
+            points_per_letter: None,
+            allow_mismatched_length: false,
+            individual_guess: true,
+            min_guess_interval: None,
+            hide_scores_until_end: false,
+            skip_round_vote_fraction: None,
+            max_hints_per_round: None,
+            hint_penalty: 3,
+        }
+    }
+}
+
+impl GameRules {
+    /// The point threshold for a game starting on a word of `word_length`
+    /// letters, falling back to `flat` when the threshold isn't scaled by
+    /// length. A scaled threshold is clamped to `POINT_THRESHOLD_RANGE`.
+    pub fn point_threshold_for(&self, word_length: usize, flat: i32) -> i32 {
+        match self.points_per_letter {
+            Some(per_letter) => per_letter
+                .saturating_mul(word_length as i32)
+                .clamp(*POINT_THRESHOLD_RANGE.start(), *POINT_THRESHOLD_RANGE.end()),
+            None => flat,
+        }
+    }
+}
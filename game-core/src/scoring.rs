@@ -1,5 +1,157 @@
-use game_types::{GuessResult, LetterResult, LetterStatus};
-use std::collections::HashMap;
+use crate::{letter_count, normalize_word};
+use game_types::{GuessResult, LetterResult, LetterStatus, ScoreBreakdown};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+/// Points for guessing the target word, on top of any letters it reveals
+pub const SOLVE_BONUS: i32 = 5;
+
+/// Tunable scoring options. The defaults are standard competitive scoring.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoringConfig {
+    /// Bonus awarded to a trailing round winner, as a fraction of the points
+    /// they earned. `0.0` disables the catch-up mechanic.
+    pub catch_up_multiplier: f64,
+    /// How far behind the leader a player must be to earn the bonus, as a
+    /// fraction of the point threshold
+    pub catch_up_gap: f64,
+    /// Solve bonus for a solving guess that revealed no new letter, to
+    /// discourage waiting for others to uncover the word. `None` always
+    /// awards the full `SOLVE_BONUS`. A negative value is a penalty.
+    pub uninformed_solve_bonus: Option<i32>,
+    /// Lowest in-game total a penalty can bring a player down to. Totals
+    /// already below the floor are not pushed further down.
+    pub points_floor: i32,
+    /// How the round winner is picked among guesses with equal correct and
+    /// present letter counts
+    pub tie_break: TieBreak,
+    /// Share of its own points a guess that revealed new letters earns when
+    /// someone else wins the round. `0.0` leaves non-winners with nothing.
+    pub assist_fraction: f64,
+}
+
+impl Default for ScoringConfig {
+    fn default() -> Self {
+        Self {
+            catch_up_multiplier: 0.0,
+            catch_up_gap: 0.4,
+            uninformed_solve_bonus: None,
+            points_floor: 0,
+            tie_break: TieBreak::default(),
+            assist_fraction: 0.0,
+        }
+    }
+}
+
+/// Tie-break between round guesses that reveal the same number of correct
+/// and present letters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TieBreak {
+    /// The tied guess that comes first in the round's guesses
+    #[default]
+    First,
+    /// The guess that repeats the fewest letters, having tested the most
+    /// distinct ones
+    FewestRepeats,
+    /// The guess whose length is closest to the target's
+    ClosestLength,
+}
+
+impl TieBreak {
+    /// Whether `candidate` beats `current` under this tie-break. Guesses
+    /// that are still tied keep the earlier one.
+    fn prefers(self, candidate: &str, current: &str, target: &str) -> bool {
+        let key = |word: &str| -> usize {
+            match self {
+                TieBreak::First => 0,
+                TieBreak::FewestRepeats => {
+                    let word = normalize_word(word);
+                    word.chars().count() - word.chars().collect::<HashSet<_>>().len()
+                }
+                TieBreak::ClosestLength => letter_count(word).abs_diff(letter_count(target)),
+            }
+        };
+        key(candidate) < key(current)
+    }
+}
+
+impl std::str::FromStr for TieBreak {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_lowercase().as_str() {
+            "first" => Ok(TieBreak::First),
+            "fewest-repeats" => Ok(TieBreak::FewestRepeats),
+            "closest-length" => Ok(TieBreak::ClosestLength),
+            other => Err(format!(
+                "expected 'first', 'fewest-repeats' or 'closest-length', got '{}'",
+                other
+            )),
+        }
+    }
+}
+
+impl ScoringConfig {
+    /// Apply the uninformed-solve rule to the base points from
+    /// `ScoringEngine::evaluate_guess`. A solve worth only the bonus revealed
+    /// nothing the board didn't already show.
+    pub fn solve_points(&self, word: &str, target: &str, base_points: i32) -> i32 {
+        match self.uninformed_solve_bonus {
+            Some(reduced)
+                if base_points == SOLVE_BONUS && normalize_word(word) == normalize_word(target) =>
+            {
+                reduced
+            }
+            _ => base_points,
+        }
+    }
+
+    /// Assist points for a guess that lost the round but was worth
+    /// `base_points` against the board as it stood
+    pub fn assist_points(&self, base_points: i32) -> i32 {
+        if self.assist_fraction > 0.0 && base_points > 0 {
+            (base_points as f64 * self.assist_fraction).round() as i32
+        } else {
+            0
+        }
+    }
+
+    /// Add `points` to a player's in-game `total`, saturating instead of
+    /// overflowing and stopping penalties at `points_floor`
+    pub fn add_points(&self, total: i32, points: i32) -> i32 {
+        let sum = total.saturating_add(points);
+        if points < 0 {
+            sum.max(self.points_floor.min(total))
+        } else {
+            sum
+        }
+    }
+
+    /// Break down the points for a scoring guess, adding the catch-up bonus
+    /// when the player trails the leader by at least the configured gap
+    pub fn score(
+        &self,
+        base_points: i32,
+        player_points: i32,
+        leader_points: i32,
+        point_threshold: i32,
+    ) -> ScoreBreakdown {
+        let gap = leader_points.saturating_sub(player_points);
+        let min_gap = (self.catch_up_gap * point_threshold as f64).ceil() as i32;
+
+        let catch_up_bonus = if self.catch_up_multiplier > 0.0 && base_points > 0 && gap >= min_gap
+        {
+            (base_points as f64 * self.catch_up_multiplier).round() as i32
+        } else {
+            0
+        };
+
+        ScoreBreakdown {
+            base_points,
+            catch_up_bonus,
+        }
+    }
+}
 
 pub struct ScoringEngine;
 
@@ -10,8 +162,8 @@ impl ScoringEngine {
         target: &str,
         previous_guesses: &[GuessResult],
     ) -> (Vec<LetterResult>, i32) {
-        let word = word.to_lowercase();
-        let target = target.to_lowercase();
+        let word = normalize_word(word);
+        let target = normalize_word(target);
 
         // Track letters we've already revealed
         let mut previously_revealed = HashMap::new();
@@ -102,14 +254,48 @@ impl ScoringEngine {
 
         // Award bonus for solving the word
         if word == target {
-            points += 5; // Guessing the word: 5 points
+            points += SOLVE_BONUS;
         }
 
         (letters, points)
     }
 
-    /// Determine which guess should win the round based on accuracy
-    pub fn determine_round_winner(guesses: &[(String, String)], target: &str) -> Option<usize> {
+    /// Letters in an evaluated guess that reveal something new compared to the
+    /// previous guesses: a correct letter in a position not solved before, or a
+    /// present letter not yet known to be in the word. Follows the same rules
+    /// as the points awarded by `evaluate_guess`.
+    pub fn newly_revealed(
+        letters: &[LetterResult],
+        previous_guesses: &[GuessResult],
+    ) -> Vec<LetterResult> {
+        let previous: Vec<&LetterResult> = previous_guesses
+            .iter()
+            .flat_map(|guess| guess.letters.iter())
+            .collect();
+
+        letters
+            .iter()
+            .filter(|letter| match letter.status {
+                LetterStatus::Correct => !previous
+                    .iter()
+                    .any(|p| p.letter == letter.letter && p.position == letter.position),
+                LetterStatus::Present => !previous.iter().any(|p| {
+                    p.letter == letter.letter
+                        && matches!(p.status, LetterStatus::Correct | LetterStatus::Present)
+                }),
+                LetterStatus::Absent => false,
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Determine which guess should win the round based on accuracy, settling
+    /// equal scores with `tie_break`
+    pub fn determine_round_winner(
+        guesses: &[(String, String)],
+        target: &str,
+        tie_break: TieBreak,
+    ) -> Option<usize> {
         if guesses.is_empty() {
             return None;
         }
@@ -133,7 +319,12 @@ impl ScoringEngine {
             let score = (correct_count, present_count);
 
             // Prioritize correct positions, then present letters
-            if score.0 > best_score.0 || (score.0 == best_score.0 && score.1 > best_score.1) {
+            let better = match score.cmp(&best_score) {
+                Ordering::Greater => true,
+                Ordering::Equal => tie_break.prefers(word, &guesses[best_index].0, target),
+                Ordering::Less => false,
+            };
+            if better {
                 best_score = score;
                 best_index = i;
             }
@@ -146,7 +337,6 @@ impl ScoringEngine {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use game_types::PlayerId;
 
     #[test]
     fn test_evaluate_guess_correct_word() {
@@ -189,7 +379,7 @@ mod tests {
             ("hells".to_string(), "player3".to_string()),
         ];
 
-        let winner = ScoringEngine::determine_round_winner(&guesses, "hello");
+        let winner = ScoringEngine::determine_round_winner(&guesses, "hello", TieBreak::First);
         assert_eq!(winner, Some(0)); // "hello" should win (exact match)
     }
 
@@ -371,6 +561,7 @@ mod tests {
                 },
             ],
             points_earned: 3,
+            breakdown: ScoreBreakdown::default(),
             timestamp: chrono::Utc::now().to_rfc3339(),
         };
 
@@ -390,7 +581,7 @@ mod tests {
 
     #[test]
     fn test_empty_guesses() {
-        let winner = ScoringEngine::determine_round_winner(&[], "hello");
+        let winner = ScoringEngine::determine_round_winner(&[], "hello", TieBreak::First);
         assert_eq!(winner, None);
     }
 
@@ -402,7 +593,7 @@ mod tests {
             ("helle".to_string(), "player3".to_string()), // 4 blue, 0 orange
         ];
 
-        let winner = ScoringEngine::determine_round_winner(&guesses, "hello");
+        let winner = ScoringEngine::determine_round_winner(&guesses, "hello", TieBreak::First);
         // Should return the first one in case of tie (per game rules)
         assert_eq!(winner, Some(0));
 
@@ -413,10 +604,133 @@ mod tests {
             ("hilly".to_string(), "player3".to_string()), // 1 blue (h), 1 orange (l)
         ];
 
-        let winner2 = ScoringEngine::determine_round_winner(&guesses2, "hello");
+        let winner2 = ScoringEngine::determine_round_winner(&guesses2, "hello", TieBreak::First);
         assert_eq!(winner2, Some(1)); // "helms" has most blue letters (prioritized)
     }
 
+    #[test]
+    fn test_tie_break_strategies() {
+        // Each guess has one correct letter ("h") and nothing else in "hippo"
+        let guesses = vec![
+            ("hatch".to_string(), "player1".to_string()), // one repeat, 5 letters
+            ("hunted".to_string(), "player2".to_string()), // no repeats, 6 letters
+            ("hwack".to_string(), "player3".to_string()), // no repeats, 5 letters
+        ];
+        let winner =
+            |tie_break| ScoringEngine::determine_round_winner(&guesses, "hippo", tie_break);
+
+        assert_eq!(winner(TieBreak::First), Some(0));
+        // Both unrepeated guesses tie again, so the earlier one wins
+        assert_eq!(winner(TieBreak::FewestRepeats), Some(1));
+        assert_eq!(winner(TieBreak::ClosestLength), Some(0));
+
+        // A higher score always beats the tie-break
+        let guesses = vec![
+            ("hunted".to_string(), "player1".to_string()),
+            ("hippie".to_string(), "player2".to_string()),
+        ];
+        assert_eq!(
+            ScoringEngine::determine_round_winner(&guesses, "hippo", TieBreak::FewestRepeats),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_tie_break_closest_length_overrides_order() {
+        let guesses = vec![
+            ("holder".to_string(), "player1".to_string()), // h, o correct/present; 6 letters
+            ("hobby".to_string(), "player2".to_string()),  // h correct, o present; 5 letters
+        ];
+        assert_eq!(
+            ScoringEngine::determine_round_winner(&guesses, "hippo", TieBreak::First),
+            Some(0)
+        );
+        assert_eq!(
+            ScoringEngine::determine_round_winner(&guesses, "hippo", TieBreak::ClosestLength),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_tie_break_from_str() {
+        assert_eq!("first".parse(), Ok(TieBreak::First));
+        assert_eq!("Fewest-Repeats".parse(), Ok(TieBreak::FewestRepeats));
+        assert_eq!("closest-length".parse(), Ok(TieBreak::ClosestLength));
+        assert!("random".parse::<TieBreak>().is_err());
+    }
+
+    #[test]
+    fn test_accented_letters_score_consistently() {
+        // Decomposed target, precomposed uppercase guess
+        let (letters, points) = ScoringEngine::evaluate_guess("ÉCOLE", "e\u{301}cole", &[]);
+        assert_eq!(letters.len(), 5);
+        assert!(
+            letters
+                .iter()
+                .all(|l| matches!(l.status, LetterStatus::Correct))
+        );
+        assert_eq!(letters[0].letter, "é");
+        assert_eq!(points, 15);
+
+        // The accented letter is distinct from its base letter
+        let (letters, _) = ScoringEngine::evaluate_guess("ecole", "école", &[]);
+        assert!(matches!(letters[0].status, LetterStatus::Absent));
+    }
+
+    #[test]
+    fn test_catch_up_bonus() {
+        let config = ScoringConfig {
+            catch_up_multiplier: 0.5,
+            catch_up_gap: 0.4,
+            uninformed_solve_bonus: None,
+            ..Default::default()
+        };
+
+        // 10 behind with a threshold of 25 clears the 40% gap
+        let breakdown = config.score(4, 5, 15, 25);
+        assert_eq!(breakdown.base_points, 4);
+        assert_eq!(breakdown.catch_up_bonus, 2);
+
+        // Close behind, the leader, and scoreless guesses get nothing extra
+        assert_eq!(config.score(4, 10, 15, 25).catch_up_bonus, 0);
+        assert_eq!(config.score(4, 15, 15, 25).catch_up_bonus, 0);
+        assert_eq!(config.score(0, 0, 15, 25).catch_up_bonus, 0);
+
+        // Off by default
+        assert_eq!(
+            ScoringConfig::default().score(4, 0, 20, 25).catch_up_bonus,
+            0
+        );
+    }
+
+    #[test]
+    fn test_add_points_saturates_and_respects_floor() {
+        let config = ScoringConfig::default();
+        assert_eq!(config.add_points(i32::MAX - 3, 15), i32::MAX);
+        assert_eq!(config.add_points(10, 5), 15);
+
+        // Penalties stop at the floor, and never push a total already below it
+        assert_eq!(config.add_points(4, -10), 0);
+        assert_eq!(config.add_points(i32::MIN + 1, -10), i32::MIN + 1);
+        let config = ScoringConfig {
+            points_floor: -5,
+            ..Default::default()
+        };
+        assert_eq!(config.add_points(4, -20), -5);
+        assert_eq!(config.add_points(4, -3), 1);
+    }
+
+    #[test]
+    fn test_catch_up_bonus_with_extreme_totals() {
+        let config = ScoringConfig {
+            catch_up_multiplier: 2.0,
+            ..Default::default()
+        };
+        let breakdown = config.score(i32::MAX, i32::MIN, i32::MAX, 25);
+        assert_eq!(breakdown.catch_up_bonus, i32::MAX);
+        assert_eq!(breakdown.total(), i32::MAX);
+    }
+
     #[test]
     fn test_game_rules_compliance() {
         // Test the exact scoring from user story
@@ -478,6 +792,7 @@ mod tests {
                 },
             ],
             points_earned: 3, // 2 for blue h + 1 for orange o
+            breakdown: ScoreBreakdown::default(),
             timestamp: chrono::Utc::now().to_rfc3339(),
         };
 
@@ -504,14 +819,14 @@ mod tests {
     #[test]
     fn test_mismatched_word_lengths() {
         // Test shorter guess
-        let (letters, points) = ScoringEngine::evaluate_guess("hi", "hello", &[]);
+        let (letters, _) = ScoringEngine::evaluate_guess("hi", "hello", &[]);
         assert_eq!(letters.len(), 5); // Should pad to target length
         assert!(matches!(letters[0].status, LetterStatus::Correct)); // 'h' correct
         assert!(matches!(letters[1].status, LetterStatus::Absent)); // 'i' not in target
 
         // Remaining positions should have default values
-        for i in 2..5 {
-            assert!(matches!(letters[i].status, LetterStatus::Absent));
+        for letter in &letters[2..5] {
+            assert!(matches!(letter.status, LetterStatus::Absent));
         }
 
         // Test longer guess
@@ -519,13 +834,13 @@ mod tests {
         assert_eq!(letters.len(), 10); // Should expand to guess length
 
         // First 5 should be correct
-        for i in 0..5 {
-            assert!(matches!(letters[i].status, LetterStatus::Correct));
+        for letter in &letters[0..5] {
+            assert!(matches!(letter.status, LetterStatus::Correct));
         }
 
         // Remaining should be absent (no target positions to match)
-        for i in 5..10 {
-            assert!(matches!(letters[i].status, LetterStatus::Absent));
+        for letter in &letters[5..10] {
+            assert!(matches!(letter.status, LetterStatus::Absent));
         }
     }
 
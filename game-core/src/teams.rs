@@ -0,0 +1,57 @@
+use game_types::{PlayerId, Team};
+use rand::SeedableRng;
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha8Rng;
+
+/// Number of teams in team mode
+pub const TEAM_COUNT: usize = 2;
+
+const TEAM_NAMES: [&str; TEAM_COUNT] = ["Red", "Blue"];
+
+/// Shuffle players into `TEAM_COUNT` teams whose sizes differ by at most one.
+/// The same seed always produces the same split.
+pub fn assign_teams(player_ids: &[PlayerId], seed: u64) -> Vec<Team> {
+    let mut shuffled = player_ids.to_vec();
+    shuffled.shuffle(&mut ChaCha8Rng::seed_from_u64(seed));
+
+    let mut teams: Vec<Team> = TEAM_NAMES
+        .iter()
+        .enumerate()
+        .map(|(id, name)| Team {
+            id: id as u32,
+            name: name.to_string(),
+            player_ids: Vec::new(),
+            points: 0,
+        })
+        .collect();
+
+    for (index, player_id) in shuffled.into_iter().enumerate() {
+        teams[index % TEAM_COUNT].player_ids.push(player_id);
+    }
+
+    teams
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_teams_are_balanced_and_deterministic() {
+        let players: Vec<PlayerId> = (0..5).map(|i| format!("player-{}", i)).collect();
+
+        let teams = assign_teams(&players, 42);
+        assert_eq!(teams.len(), TEAM_COUNT);
+        assert_eq!(teams[0].player_ids.len(), 3);
+        assert_eq!(teams[1].player_ids.len(), 2);
+
+        let mut assigned: Vec<PlayerId> = teams
+            .iter()
+            .flat_map(|t| t.player_ids.iter().cloned())
+            .collect();
+        assigned.sort();
+        assert_eq!(assigned, players);
+
+        assert_eq!(assign_teams(&players, 42), teams);
+    }
+}
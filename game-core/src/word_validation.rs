@@ -1,61 +1,213 @@
+use crate::{GameError, GameResult};
 use anyhow::{Result, anyhow};
-use std::collections::HashSet;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::{BTreeMap, HashSet, VecDeque};
 use std::fs;
 use std::path::Path;
+use std::time::Instant;
+use unicode_normalization::UnicodeNormalization;
 
-#[derive(Debug)]
-pub struct WordValidator {
-    valid_words: HashSet<String>,
+/// Normalize a word for comparison: trim, lowercase and compose to NFC so that
+/// precomposed and combining-mark spellings ("é" vs "e\u{301}") compare equal.
+pub fn normalize_word(word: &str) -> String {
+    word.trim().to_lowercase().nfc().collect()
 }
 
-impl WordValidator {
-    /// Create a new word validator by loading all .txt files from a directory
-    pub fn new<P: AsRef<Path>>(dir_path: P) -> Result<Self> {
-        let dir = dir_path.as_ref();
-        if !dir.exists() {
-            return Err(anyhow!("Directory does not exist: {}", dir.display()));
-        }
-        if !dir.is_dir() {
-            return Err(anyhow!("Path is not a directory: {}", dir.display()));
+/// How submitted guesses treat hyphens and apostrophes. Pick the variant that
+/// matches how the dictionary spells such words.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GuessPunctuation {
+    /// Remove hyphens and apostrophes, for letters-only dictionaries such as
+    /// the bundled lists: "co-opted" is checked as "coopted"
+    #[default]
+    Strip,
+    /// Leave them in place, for dictionaries that store words like "co-opted"
+    Keep,
+}
+
+impl std::str::FromStr for GuessPunctuation {
+    type Err = String;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        match value.trim().to_lowercase().as_str() {
+            "strip" => Ok(GuessPunctuation::Strip),
+            "keep" => Ok(GuessPunctuation::Keep),
+            other => Err(format!("expected 'strip' or 'keep', got '{}'", other)),
         }
+    }
+}
 
-        let mut all_words = HashSet::new();
+/// Hyphens and apostrophes removed by `GuessPunctuation::Strip`, including the
+/// typographic forms mobile keyboards substitute
+const GUESS_PUNCTUATION: [char; 4] = ['-', '\u{2010}', '\'', '\u{2019}'];
 
-        // Read all .txt files in the directory
-        let entries = fs::read_dir(dir)
-            .map_err(|e| anyhow!("Failed to read directory {}: {}", dir.display(), e))?;
+/// Number of letters in a word, counted after NFC normalization rather than in
+/// bytes, so accented letters count once
+pub fn letter_count(word: &str) -> usize {
+    normalize_word(word).chars().count()
+}
 
-        for entry in entries {
-            let entry = entry.map_err(|e| anyhow!("Failed to read directory entry: {}", e))?;
-            let path = entry.path();
+/// Parse a word list file: one word per line, blank lines and `#` comments
+/// skipped, words normalized and limited to 5-8 letters. Duplicates are left
+/// for the caller to collapse.
+fn parse_word_list(content: &str) -> impl Iterator<Item = String> + '_ {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(normalize_word)
+        .filter(|word| (5..=8).contains(&word.chars().count()))
+}
+
+/// Read and merge every .txt word list in `dir`
+fn load_word_dir(dir: &Path) -> Result<HashSet<String>> {
+    if !dir.exists() {
+        return Err(anyhow!("Directory does not exist: {}", dir.display()));
+    }
+    if !dir.is_dir() {
+        return Err(anyhow!("Path is not a directory: {}", dir.display()));
+    }
+
+    let mut all_words = HashSet::new();
+    let mut duplicates = 0;
 
-            // Only process .txt files
-            if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some("txt") {
-                let content = fs::read_to_string(&path)
-                    .map_err(|e| anyhow!("Failed to read file {}: {}", path.display(), e))?;
+    // Read all .txt files in the directory
+    let entries = fs::read_dir(dir)
+        .map_err(|e| anyhow!("Failed to read directory {}: {}", dir.display(), e))?;
 
-                // Parse words from this file using the same logic as the original constructor
-                let words_from_file: HashSet<String> = content
-                    .lines()
-                    .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
-                    .map(|word| word.trim().to_lowercase())
-                    .filter(|word| word.len() >= 5 && word.len() <= 8)
-                    .collect();
+    for entry in entries {
+        let entry = entry.map_err(|e| anyhow!("Failed to read directory entry: {}", e))?;
+        let path = entry.path();
 
-                all_words.extend(words_from_file);
+        // Only process .txt files
+        if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some("txt") {
+            let content = fs::read_to_string(&path)
+                .map_err(|e| anyhow!("Failed to read file {}: {}", path.display(), e))?;
+
+            // Words are normalized before insertion, so case variants within
+            // and across files collapse to a single entry
+            for word in parse_word_list(&content) {
+                if !all_words.insert(word) {
+                    duplicates += 1;
+                }
             }
         }
+    }
 
-        if all_words.is_empty() {
-            return Err(anyhow!(
-                "No valid words found in .txt files in directory: {}",
-                dir.display()
-            ));
+    if duplicates > 0 {
+        tracing::info!(
+            "Removed {} duplicate words while loading {}",
+            duplicates,
+            dir.display()
+        );
+    }
+
+    if all_words.is_empty() {
+        return Err(anyhow!(
+            "No valid words found in .txt files in directory: {}",
+            dir.display()
+        ));
+    }
+
+    Ok(all_words)
+}
+
+/// Lengths random target words are drawn from
+pub const TARGET_WORD_LENGTHS: std::ops::RangeInclusive<usize> = 5..=7;
+
+/// Small word list compiled into the binary so the server stays playable
+/// when no word list directory has been set up
+const EMBEDDED_WORD_LIST: &str = include_str!("../words/default_words.txt");
+
+/// Ring buffer of the most recently chosen target words, so selection can
+/// avoid handing out the same word again right away
+#[derive(Debug, Clone, Default)]
+pub struct RecentWords {
+    capacity: usize,
+    words: VecDeque<String>,
+}
+
+impl RecentWords {
+    /// Remember the last `capacity` words; 0 remembers nothing
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            words: VecDeque::with_capacity(capacity),
         }
+    }
 
-        Ok(Self {
-            valid_words: all_words,
-        })
+    pub fn push(&mut self, word: String) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.words.len() == self.capacity {
+            self.words.pop_front();
+        }
+        self.words.push_back(word);
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        self.words.iter().any(|w| w == word)
+    }
+}
+
+/// Dictionary of valid guesses and target words. Every word is stored
+/// normalized, so its letters can be counted with `chars` directly.
+#[derive(Debug)]
+pub struct WordValidator {
+    valid_words: HashSet<String>,
+    blocked_words: HashSet<String>, // Never chosen as targets; optionally refused as guesses
+    punctuation: GuessPunctuation,
+    categories: BTreeMap<String, HashSet<String>>, // Themed word lists keyed by name
+    answer_words: Option<HashSet<String>>, // Curated targets; None draws from every valid word
+}
+
+impl WordValidator {
+    /// Create a new word validator by loading all .txt files from a directory
+    pub fn new<P: AsRef<Path>>(dir_path: P) -> Result<Self> {
+        let dir = dir_path.as_ref();
+        let started = Instant::now();
+        let validator = Self {
+            valid_words: load_word_dir(dir)?,
+            blocked_words: HashSet::new(),
+            punctuation: GuessPunctuation::default(),
+            categories: BTreeMap::new(),
+            answer_words: None,
+        };
+        tracing::info!(
+            "Loaded {} words from {} in {:?} (words per length: {:?})",
+            validator.len(),
+            dir.display(),
+            started.elapsed(),
+            validator.word_counts_by_length()
+        );
+
+        Ok(validator)
+    }
+
+    /// Load words from `dir_path`, falling back to the built-in list when the
+    /// directory is missing or holds no usable words. External directories are
+    /// always preferred; the fallback only keeps the server playable.
+    pub fn new_or_embedded<P: AsRef<Path>>(dir_path: P) -> Self {
+        match Self::new(&dir_path) {
+            Ok(validator) => validator,
+            Err(e) => {
+                tracing::warn!(
+                    "Could not load word lists from {}: {}",
+                    dir_path.as_ref().display(),
+                    e
+                );
+                tracing::warn!(
+                    "USING THE MINIMAL BUILT-IN WORD LIST. Run './scripts/download_and_split_words.sh' or set WORDS_DIRECTORY for the full dictionary."
+                );
+                Self::embedded()
+            }
+        }
+    }
+
+    /// Create a word validator from the word list compiled into the binary
+    pub fn embedded() -> Self {
+        Self::from_word_list(EMBEDDED_WORD_LIST)
     }
 
     /// Create a new word validator with test words (for testing)
@@ -79,28 +231,302 @@ impl WordValidator {
 
     /// Create a new word validator from a word list string (for testing)
     pub fn from_word_list(word_list: &str) -> Self {
-        let valid_words = word_list
-            .lines()
-            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
-            .map(|word| word.trim().to_lowercase())
-            .filter(|word| word.len() >= 5 && word.len() <= 8)
+        let mut valid_words = HashSet::new();
+        let mut duplicates = 0;
+        for word in parse_word_list(word_list) {
+            if !valid_words.insert(word) {
+                duplicates += 1;
+            }
+        }
+
+        if duplicates > 0 {
+            tracing::info!("Removed {} duplicate words from word list", duplicates);
+        }
+
+        Self {
+            valid_words,
+            blocked_words: HashSet::new(),
+            punctuation: GuessPunctuation::default(),
+            categories: BTreeMap::new(),
+            answer_words: None,
+        }
+    }
+
+    /// Block the words in `word_list` (same format as the dictionary files).
+    /// Blocked words are never picked as targets but stay valid guesses
+    /// unless a game checks guesses strictly.
+    pub fn with_blocklist(mut self, word_list: &str) -> Self {
+        self.blocked_words.extend(parse_word_list(word_list));
+        self
+    }
+
+    /// Draw targets only from the words in `word_list` (same format as the
+    /// dictionary files), like Wordle's curated answers. Guesses are still
+    /// checked against the whole dictionary, which gains any answers it
+    /// lacks.
+    pub fn with_answers(mut self, word_list: &str) -> Self {
+        let words: HashSet<String> = parse_word_list(word_list).collect();
+        self.valid_words.extend(words.iter().cloned());
+        self.answer_words.get_or_insert_default().extend(words);
+        self
+    }
+
+    /// Load every .txt file in `dir_path` as the answers list
+    pub fn with_answers_dir<P: AsRef<Path>>(mut self, dir_path: P) -> Result<Self> {
+        let dir = dir_path.as_ref();
+        let words = load_word_dir(dir)?;
+        tracing::info!("Loaded {} answer words from {}", words.len(), dir.display());
+        self.valid_words.extend(words.iter().cloned());
+        self.answer_words.get_or_insert_default().extend(words);
+        Ok(self)
+    }
+
+    /// Add a themed category named `name` from `word_list` (same format as
+    /// the dictionary files). Category words are also valid guesses, even if
+    /// the main dictionary lacks them.
+    pub fn with_category(mut self, name: &str, word_list: &str) -> Self {
+        let words: HashSet<String> = parse_word_list(word_list).collect();
+        self.valid_words.extend(words.iter().cloned());
+        self.categories
+            .entry(name.trim().to_lowercase())
+            .or_default()
+            .extend(words);
+        self
+    }
+
+    /// Load every .txt file in `dir_path` as a category named after the file,
+    /// so `animals.txt` becomes the `animals` category
+    pub fn with_categories_dir<P: AsRef<Path>>(mut self, dir_path: P) -> Result<Self> {
+        let dir = dir_path.as_ref();
+        let entries = fs::read_dir(dir)
+            .map_err(|e| anyhow!("Failed to read directory {}: {}", dir.display(), e))?;
+
+        for entry in entries {
+            let entry = entry.map_err(|e| anyhow!("Failed to read directory entry: {}", e))?;
+            let path = entry.path();
+            if !path.is_file() || path.extension().and_then(|s| s.to_str()) != Some("txt") {
+                continue;
+            }
+            let Some(name) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            let content = fs::read_to_string(&path)
+                .map_err(|e| anyhow!("Failed to read file {}: {}", path.display(), e))?;
+            self = self.with_category(name, &content);
+        }
+
+        tracing::info!(
+            "Loaded word categories from {}: {:?}",
+            dir.display(),
+            self.category_names()
+        );
+        Ok(self)
+    }
+
+    /// Names of the loaded categories, alphabetically
+    pub fn category_names(&self) -> Vec<&str> {
+        self.categories.keys().map(String::as_str).collect()
+    }
+
+    /// Fail with the first name in `categories` that was never loaded
+    pub fn check_categories(&self, categories: &[String]) -> Result<()> {
+        match categories
+            .iter()
+            .find(|name| !self.categories.contains_key(name.as_str()))
+        {
+            Some(name) => Err(anyhow!(
+                "Unknown word category '{}' (available: {})",
+                name,
+                self.category_names().join(", ")
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Fail if any target word length has fewer than `min_words` words to
+    /// draw from, reporting the count for every length
+    pub fn check_coverage(&self, min_words: usize) -> Result<()> {
+        let mut counts = BTreeMap::new();
+        for word in self.target_words() {
+            *counts.entry(word.chars().count()).or_insert(0) += 1;
+        }
+
+        let coverage: Vec<(usize, usize)> = TARGET_WORD_LENGTHS
+            .map(|length| (length, counts.get(&length).copied().unwrap_or(0)))
             .collect();
+        if coverage.iter().all(|(_, count)| *count >= min_words) {
+            return Ok(());
+        }
+
+        Err(anyhow!(
+            "Dictionary too small: need at least {} words per length, found {}",
+            min_words,
+            coverage
+                .iter()
+                .map(|(length, count)| format!("{} with {} letters", count, length))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))
+    }
+
+    /// Whether `word` belongs to any of `categories`
+    pub fn is_in_categories(&self, word: &str, categories: &[String]) -> bool {
+        let word = normalize_word(word);
+        categories.iter().any(|name| {
+            self.categories
+                .get(name)
+                .is_some_and(|words| words.contains(&word))
+        })
+    }
 
-        Self { valid_words }
+    /// Treat hyphens and apostrophes in guesses as `punctuation` says
+    pub fn with_punctuation(mut self, punctuation: GuessPunctuation) -> Self {
+        self.punctuation = punctuation;
+        self
+    }
+
+    /// Bring a submitted guess into the form the dictionary stores words in:
+    /// surrounding whitespace is trimmed, letters are lowercased and composed
+    /// to NFC, and with `GuessPunctuation::Strip` every hyphen and apostrophe
+    /// is removed. Whitespace inside the word is kept, so "he llo" stays
+    /// invalid. The result is what gets validated and shown on the board.
+    pub fn normalize_guess(&self, word: &str) -> String {
+        let word = normalize_word(word);
+        match self.punctuation {
+            GuessPunctuation::Strip => word
+                .chars()
+                .filter(|c| !GUESS_PUNCTUATION.contains(c))
+                .collect(),
+            GuessPunctuation::Keep => word,
+        }
+    }
+
+    /// Block the words listed in the file at `path`
+    pub fn with_blocklist_file<P: AsRef<Path>>(self, path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read blocklist {}: {}", path.display(), e))?;
+        let validator = self.with_blocklist(&content);
+        tracing::info!(
+            "Blocked {} words from {}",
+            validator.blocked_words.len(),
+            path.display()
+        );
+        Ok(validator)
     }
 
     /// Check if a word is valid for the game
     pub fn is_valid_word(&self, word: &str) -> bool {
-        let word = word.trim().to_lowercase();
-        self.valid_words.contains(&word)
+        self.valid_words.contains(&normalize_word(word))
+    }
+
+    /// Whether the word is on the blocklist
+    pub fn is_blocked(&self, word: &str) -> bool {
+        self.blocked_words.contains(&normalize_word(word))
+    }
+
+    /// Check a word submitted as a guess. Any real word is accepted unless
+    /// `strict`, which also refuses blocked words.
+    pub fn check_guess(&self, word: &str, strict: bool) -> GameResult<()> {
+        if !self.is_valid_word(word) {
+            return Err(GameError::InvalidWord {
+                word: word.to_string(),
+            });
+        }
+        if strict && self.is_blocked(word) {
+            return Err(GameError::WordNotAllowed {
+                word: word.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Dictionary words that may be chosen as targets: the answers list when
+    /// one was loaded, otherwise every valid word
+    fn target_words(&self) -> impl Iterator<Item = &String> {
+        self.answer_words
+            .as_ref()
+            .unwrap_or(&self.valid_words)
+            .iter()
+            .filter(|word| !self.blocked_words.contains(*word))
+    }
+
+    /// Target words in any of `categories`, which must all exist
+    fn category_target_words(&self, categories: &[String]) -> Result<HashSet<&String>> {
+        self.check_categories(categories)?;
+        Ok(categories
+            .iter()
+            .flat_map(|name| &self.categories[name])
+            .filter(|word| !self.blocked_words.contains(*word))
+            .collect())
+    }
+
+    /// Get a random word of the specified length from one category
+    pub fn get_random_word_in_category(&self, category: &str, length: usize) -> Result<String> {
+        let words_of_length: Vec<&String> = self
+            .category_target_words(&[category.to_string()])?
+            .into_iter()
+            .filter(|word| word.chars().count() == length)
+            .collect();
+
+        if words_of_length.is_empty() {
+            return Err(anyhow!(
+                "No words available of length {} in category '{}'",
+                length,
+                category
+            ));
+        }
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        use std::hash::{Hash, Hasher};
+        std::time::SystemTime::now().hash(&mut hasher);
+        let random_index = (hasher.finish() as usize) % words_of_length.len();
+
+        Ok(words_of_length[random_index].clone())
+    }
+
+    /// Seeded selection of a 5-7 letter target from `categories` that avoids
+    /// recently used targets where the categories allow it. Themed lists
+    /// rarely cover every length evenly, so the word is drawn from all
+    /// eligible lengths at once. An empty `categories` uses the whole
+    /// dictionary, exactly like `get_random_word_random_length_avoiding`.
+    pub fn get_random_word_in_categories_avoiding(
+        &self,
+        categories: &[String],
+        seed: u64,
+        recent: &RecentWords,
+    ) -> Result<String> {
+        if categories.is_empty() {
+            return self.get_random_word_random_length_avoiding(seed, recent);
+        }
+
+        let mut candidates: Vec<&String> = self
+            .category_target_words(categories)?
+            .into_iter()
+            .filter(|word| (5..=7).contains(&word.chars().count()))
+            .collect();
+        if candidates.is_empty() {
+            return Err(anyhow!(
+                "No 5-7 letter words available in categories: {}",
+                categories.join(", ")
+            ));
+        }
+        if candidates.iter().any(|word| !recent.contains(word)) {
+            candidates.retain(|word| !recent.contains(word));
+        }
+
+        candidates.sort();
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let random_index = rng.gen_range(0..candidates.len());
+
+        Ok(candidates[random_index].clone())
     }
 
     /// Get a random word of the specified length
     pub fn get_random_word(&self, length: usize) -> Result<String> {
         let words_of_length: Vec<&String> = self
-            .valid_words
-            .iter()
-            .filter(|word| word.len() == length)
+            .target_words()
+            .filter(|word| word.chars().count() == length)
             .collect();
 
         if words_of_length.is_empty() {
@@ -117,17 +543,80 @@ impl WordValidator {
         Ok(words_of_length[random_index].clone())
     }
 
+    /// Get a word of the specified length chosen deterministically from `seed`.
+    /// The same seed and word list always produce the same word, regardless of
+    /// the order the words were loaded in.
+    pub fn get_random_word_seeded(&self, length: usize, seed: u64) -> Result<String> {
+        let mut words_of_length: Vec<&String> = self
+            .target_words()
+            .filter(|word| word.chars().count() == length)
+            .collect();
+
+        if words_of_length.is_empty() {
+            return Err(anyhow!("No words available of length {}", length));
+        }
+
+        words_of_length.sort();
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let random_index = rng.gen_range(0..words_of_length.len());
+
+        Ok(words_of_length[random_index].clone())
+    }
+
+    /// Seeded selection that skips words in `recent`. Repeats are only allowed
+    /// when every word of the length is recent. With nothing to skip this
+    /// picks the same word as `get_random_word_seeded`.
+    pub fn get_random_word_seeded_avoiding(
+        &self,
+        length: usize,
+        seed: u64,
+        recent: &RecentWords,
+    ) -> Result<String> {
+        let mut words_of_length: Vec<&String> = self
+            .target_words()
+            .filter(|word| word.chars().count() == length && !recent.contains(word))
+            .collect();
+
+        if words_of_length.is_empty() {
+            return self.get_random_word_seeded(length, seed);
+        }
+
+        words_of_length.sort();
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let random_index = rng.gen_range(0..words_of_length.len());
+
+        Ok(words_of_length[random_index].clone())
+    }
+
     /// Get word count by length
     pub fn word_count_by_length(&self, length: usize) -> usize {
         self.valid_words
             .iter()
-            .filter(|word| word.len() == length)
+            .filter(|word| word.chars().count() == length)
             .count()
     }
 
+    /// Word counts keyed by length, shortest first
+    pub fn word_counts_by_length(&self) -> BTreeMap<usize, usize> {
+        let mut counts = BTreeMap::new();
+        for word in &self.valid_words {
+            *counts.entry(word.chars().count()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Total number of words in the dictionary
+    pub fn len(&self) -> usize {
+        self.valid_words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.valid_words.is_empty()
+    }
+
     /// Check if word contains only alphabetic characters
     pub fn is_alphabetic(&self, word: &str) -> bool {
-        word.chars().all(|c| c.is_alphabetic())
+        word.nfc().all(|c| c.is_alphabetic())
     }
 
     /// Get a random word with random length between 5-7 letters
@@ -140,6 +629,23 @@ impl WordValidator {
 
         self.get_random_word(random_length)
     }
+
+    /// Seeded variant of `get_random_word_random_length`
+    pub fn get_random_word_random_length_seeded(&self, seed: u64) -> Result<String> {
+        self.get_random_word_random_length_avoiding(seed, &RecentWords::default())
+    }
+
+    /// Seeded random-length selection that avoids recently used targets
+    pub fn get_random_word_random_length_avoiding(
+        &self,
+        seed: u64,
+        recent: &RecentWords,
+    ) -> Result<String> {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let random_length = rng.gen_range(TARGET_WORD_LENGTHS);
+
+        self.get_random_word_seeded_avoiding(random_length, rng.r#gen(), recent)
+    }
 }
 
 #[cfg(test)]
@@ -159,6 +665,23 @@ mod tests {
         assert!(!validator.is_valid_word("xyz")); // too short
     }
 
+    #[test]
+    fn test_unicode_normalization() {
+        // "café" spelled with a combining acute accent in the list
+        let validator = WordValidator::from_word_list("cafe\u{301}s\nécole");
+
+        // Precomposed and decomposed spellings are the same word
+        assert!(validator.is_valid_word("cafés"));
+        assert!(validator.is_valid_word("CAFE\u{301}S"));
+        assert!(validator.is_valid_word("ÉCOLE"));
+        assert!(validator.is_alphabetic("cafe\u{301}s"));
+
+        // Length is counted in letters, not bytes or code points
+        assert_eq!(letter_count("cafe\u{301}s"), 5);
+        assert_eq!(validator.word_count_by_length(5), 2);
+        assert!(validator.get_random_word_seeded(5, 1).is_ok());
+    }
+
     #[test]
     fn test_alphabetic_check() {
         let validator = WordValidator::from_word_list("test");
@@ -195,6 +718,23 @@ mod tests {
         assert!(validator.is_valid_word("spaces"));
     }
 
+    #[test]
+    fn test_falls_back_to_embedded_words() {
+        let validator = WordValidator::new_or_embedded("/nonexistent/word/lists");
+        assert!(validator.is_valid_word("about"));
+        for length in 5..=7 {
+            assert!(validator.word_count_by_length(length) > 100);
+            assert!(validator.get_random_word(length).is_ok());
+        }
+
+        // An empty directory falls back too
+        let dir = std::env::temp_dir().join(format!("empty-words-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let validator = WordValidator::new_or_embedded(&dir);
+        assert!(validator.is_valid_word("about"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
     #[test]
     fn test_empty_word_list() {
         let validator = WordValidator::from_word_list("");
@@ -272,6 +812,84 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_seeded_word_selection_is_deterministic() {
+        let validator = WordValidator::from_word_list("apple\ntests\nvalid\nhello\nworld");
+        let reordered = WordValidator::from_word_list("world\nhello\nvalid\ntests\napple");
+
+        for seed in 0..20 {
+            let word = validator.get_random_word_seeded(5, seed).unwrap();
+            assert_eq!(word, validator.get_random_word_seeded(5, seed).unwrap());
+            assert_eq!(word, reordered.get_random_word_seeded(5, seed).unwrap());
+        }
+
+        assert!(validator.get_random_word_seeded(10, 42).is_err());
+    }
+
+    #[test]
+    fn test_recent_targets_are_not_repeated() {
+        let word_list = "apple\ntests\nvalid\nbanana\ncherry\norange\nfreedom\nkitchen\nlibrary";
+        let validator = WordValidator::from_word_list(word_list);
+        let mut recent = RecentWords::new(2);
+
+        // Three words per length and a window of two: any three consecutive
+        // picks are distinct, and repeats only appear once the buffer wraps
+        let mut picks: Vec<String> = Vec::new();
+        for seed in 0..100 {
+            let word = validator
+                .get_random_word_random_length_avoiding(seed, &recent)
+                .unwrap();
+            let window = &picks[picks.len().saturating_sub(2)..];
+            assert!(!window.contains(&word), "{} repeated too soon", word);
+            recent.push(word.clone());
+            picks.push(word);
+        }
+    }
+
+    #[test]
+    fn test_small_dictionary_allows_repeats() {
+        let validator = WordValidator::from_word_list("apple\nbanana\nfreedom");
+        let mut recent = RecentWords::new(10);
+
+        for seed in 0..10 {
+            let word = validator
+                .get_random_word_random_length_avoiding(seed, &recent)
+                .unwrap();
+            recent.push(word);
+        }
+
+        // Without avoidance the seeded pick is unchanged
+        for seed in 0..20 {
+            assert_eq!(
+                validator
+                    .get_random_word_random_length_avoiding(seed, &RecentWords::new(0))
+                    .unwrap(),
+                validator
+                    .get_random_word_random_length_seeded(seed)
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_seeded_random_length_is_deterministic() {
+        let word_list = "apple\nbanana\ncherry\ntests\nvalid\nfreedom";
+        let validator = WordValidator::from_word_list(word_list);
+
+        for seed in 0..20 {
+            let word = validator
+                .get_random_word_random_length_seeded(seed)
+                .unwrap();
+            assert!(word.len() >= 5 && word.len() <= 7);
+            assert_eq!(
+                word,
+                validator
+                    .get_random_word_random_length_seeded(seed)
+                    .unwrap()
+            );
+        }
+    }
+
     #[test]
     fn test_word_count_by_length() {
         let word_list = "apple\nbanana\ncherry\ntests\nvalid\nhello\nworld\nab\nabcd\nabcdefghijk";
@@ -288,6 +906,18 @@ mod tests {
         assert_eq!(validator.word_count_by_length(11), 0); // too long
     }
 
+    #[test]
+    fn test_word_counts_by_length_for_test_words() {
+        let validator = WordValidator::new_with_test_words();
+        let counts = validator.word_counts_by_length();
+
+        assert_eq!(
+            counts.into_iter().collect::<Vec<_>>(),
+            vec![(5, 26), (6, 16), (7, 16)]
+        );
+        assert_eq!(validator.len(), 58);
+    }
+
     #[test]
     fn test_random_word_random_length() {
         let word_list = "apple\nbanana\ncherry\ntests\nvalid\nhello\nworld\nfreedom\nbuilding\nbeautiful\nextralong\nsuperlong";
@@ -353,6 +983,27 @@ mod tests {
         fs::remove_dir_all(&temp_dir).unwrap();
     }
 
+    #[test]
+    fn test_case_variant_duplicates_collapse() {
+        use std::fs;
+
+        let temp_dir = std::env::temp_dir().join("word_arena_duplicates_test");
+        fs::create_dir_all(&temp_dir).unwrap();
+
+        fs::write(temp_dir.join("words1.txt"), "Hello\nhello\nHELLO\nworld").unwrap();
+        fs::write(temp_dir.join("words2.txt"), "hello\nWorld\nbeach").unwrap();
+
+        let validator = WordValidator::new(&temp_dir).unwrap();
+
+        // One canonical lowercase entry per word, so selection weighting is fair
+        assert_eq!(validator.word_count_by_length(5), 3);
+        assert!(validator.valid_words.contains("hello"));
+        assert!(!validator.valid_words.contains("Hello"));
+        assert!(validator.is_valid_word("HeLLo"));
+
+        fs::remove_dir_all(&temp_dir).unwrap();
+    }
+
     #[test]
     fn test_from_directory_nonexistent() {
         let result = WordValidator::new("/nonexistent/path");
@@ -411,4 +1062,173 @@ mod tests {
         // Clean up
         std::fs::remove_dir_all(&temp_dir).unwrap();
     }
+
+    #[test]
+    fn test_blocked_words_only_refused_in_strict_mode() {
+        let validator =
+            WordValidator::from_word_list("apple\ngrape\nlemon").with_blocklist("GRAPE\nmelon");
+
+        assert!(validator.is_blocked("grape"));
+        assert_eq!(validator.check_guess("grape", false), Ok(()));
+        assert_eq!(
+            validator.check_guess("grape", true),
+            Err(GameError::WordNotAllowed {
+                word: "grape".to_string()
+            })
+        );
+        // Words missing from the dictionary are invalid whatever the mode
+        assert_eq!(
+            validator.check_guess("melon", true),
+            Err(GameError::InvalidWord {
+                word: "melon".to_string()
+            })
+        );
+
+        // Blocked words are never chosen as targets
+        for seed in 0..20 {
+            assert_ne!(validator.get_random_word_seeded(5, seed).unwrap(), "grape");
+        }
+    }
+
+    #[test]
+    fn test_guess_normalization() {
+        let validator = WordValidator::from_word_list("hello\ncoopted\nlet's");
+
+        for input in [" Hello ", "HELLO", "hello\n"] {
+            let guess = validator.normalize_guess(input);
+            assert_eq!(guess, "hello");
+            assert_eq!(validator.check_guess(&guess, false), Ok(()));
+        }
+        assert!(!validator.is_valid_word(&validator.normalize_guess("he llo")));
+
+        // Letters-only dictionaries accept hyphenated and apostrophe spellings
+        assert_eq!(validator.normalize_guess("Co-opted"), "coopted");
+        assert_eq!(validator.normalize_guess("co\u{2010}opted"), "coopted");
+        assert_eq!(validator.normalize_guess("let\u{2019}s"), "lets");
+        assert!(validator.is_valid_word(&validator.normalize_guess("Co-opted")));
+
+        // Dictionaries that keep punctuation are matched as spelled
+        let keep = validator.with_punctuation(GuessPunctuation::Keep);
+        assert_eq!(keep.normalize_guess(" Co-opted "), "co-opted");
+        assert!(!keep.is_valid_word(&keep.normalize_guess("co-opted")));
+        assert!(keep.is_valid_word(&keep.normalize_guess("LET'S")));
+    }
+
+    #[test]
+    fn test_guess_punctuation_from_str() {
+        assert_eq!("strip".parse(), Ok(GuessPunctuation::Strip));
+        assert_eq!(" KEEP ".parse(), Ok(GuessPunctuation::Keep));
+        assert!("drop".parse::<GuessPunctuation>().is_err());
+    }
+
+    #[test]
+    fn test_category_words() {
+        let validator = WordValidator::new_with_test_words()
+            .with_category("Animals", "tiger\nzebra\nparrot\nbadger\n")
+            .with_category("places", "house\nschool\nbeach");
+        let animals = vec!["animals".to_string()];
+
+        assert_eq!(validator.category_names(), vec!["animals", "places"]);
+        assert!(validator.is_valid_word("zebra"));
+        assert!(validator.is_in_categories("Tiger", &animals));
+        assert!(!validator.is_in_categories("house", &animals));
+
+        for seed in 0..20 {
+            let word = validator
+                .get_random_word_in_categories_avoiding(&animals, seed, &RecentWords::default())
+                .unwrap();
+            assert!(validator.is_in_categories(&word, &animals));
+        }
+        let six = validator.get_random_word_in_category("animals", 6).unwrap();
+        assert!(six == "parrot" || six == "badger");
+    }
+
+    #[test]
+    fn test_unknown_category_is_rejected() {
+        let validator = WordValidator::new_with_test_words().with_category("animals", "tiger");
+
+        let err = validator
+            .get_random_word_in_category("countries", 5)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown word category 'countries' (available: animals)"
+        );
+        assert!(
+            validator
+                .check_categories(&["animals".to_string(), "tech".to_string()])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_dictionary_coverage() {
+        // The test list has 26, 16 and 16 words of five, six and seven letters
+        let validator = WordValidator::new_with_test_words();
+        assert!(validator.check_coverage(16).is_ok());
+
+        let err = validator.check_coverage(20).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Dictionary too small: need at least 20 words per length, \
+             found 26 with 5 letters, 16 with 6 letters, 16 with 7 letters"
+        );
+
+        // Blocked words can never be targets, so they don't count
+        let validator =
+            WordValidator::from_word_list("hello\nworld\nsecond\nexample").with_blocklist("world");
+        assert!(validator.check_coverage(1).is_ok());
+        assert!(validator.check_coverage(2).is_err());
+    }
+
+    #[test]
+    fn test_categories_from_directory() {
+        use std::fs;
+
+        let temp_dir = std::env::temp_dir().join("word_arena_category_test");
+        fs::create_dir_all(&temp_dir).unwrap();
+        fs::write(temp_dir.join("tech.txt"), "laptop\nserver\n").unwrap();
+        fs::write(temp_dir.join("notes.md"), "ignored").unwrap();
+
+        let validator = WordValidator::new_with_test_words()
+            .with_categories_dir(&temp_dir)
+            .unwrap();
+        assert_eq!(validator.category_names(), vec!["tech"]);
+        assert!(validator.is_in_categories("laptop", &["tech".to_string()]));
+
+        fs::remove_dir_all(&temp_dir).unwrap();
+    }
+
+    #[test]
+    fn test_targets_come_from_answers_only() {
+        let validator = WordValidator::new_with_test_words().with_answers("beach\nchair\nhouse");
+
+        for seed in 0..50 {
+            let word = validator.get_random_word_seeded(5, seed).unwrap();
+            assert!(["beach", "chair", "house"].contains(&word.as_str()));
+        }
+        assert!(validator.get_random_word_seeded(6, 0).is_err());
+
+        // Guesses are still checked against the whole dictionary
+        assert!(validator.is_valid_word("world"));
+        assert_eq!(validator.check_guess("nothing", true), Ok(()));
+    }
+
+    #[test]
+    fn test_answers_join_the_allowed_words() {
+        use std::fs;
+
+        let temp_dir = std::env::temp_dir().join("word_arena_answers_test");
+        fs::create_dir_all(&temp_dir).unwrap();
+        fs::write(temp_dir.join("answers.txt"), "zebra\n").unwrap();
+
+        let validator = WordValidator::from_word_list("apple\ngrape")
+            .with_answers_dir(&temp_dir)
+            .unwrap();
+        assert!(validator.is_valid_word("zebra"));
+        assert!(validator.is_valid_word("apple"));
+        assert_eq!(validator.get_random_word_seeded(5, 7).unwrap(), "zebra");
+
+        fs::remove_dir_all(&temp_dir).unwrap();
+    }
 }
@@ -1,5 +1,7 @@
+#![allow(dead_code)]
+
 use game_core::{Game, GameEvent, GameEventHandler, GameManager, WordValidator};
-use game_types::{GameId, GamePhase, GameStatus, Player, PlayerId};
+use game_types::{GamePhase, GameStatus, Player};
 use std::sync::{Arc, Mutex};
 
 /// Creates a test WordValidator with a known set of words
@@ -21,6 +23,7 @@ pub fn create_test_player_with_points(name: &str, points: i32) -> Player {
         points,
         guess_history: Vec::new(),
         is_connected: true,
+        avatar: None,
     }
 }
 
@@ -47,6 +50,12 @@ pub struct EventCollector {
     events: Arc<Mutex<Vec<GameEvent>>>,
 }
 
+impl Default for EventCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl EventCollector {
     pub fn new() -> Self {
         Self {
@@ -101,6 +110,10 @@ pub fn advance_to_phase(game: &mut Game, target_phase: GamePhase) {
                 game.process_round().ok();
             }
         }
+        GamePhase::Intermission => {
+            game.current_phase = GamePhase::Intermission;
+            game.state.current_phase = GamePhase::Intermission;
+        }
         GamePhase::GameOver => {
             game.state.status = GameStatus::Completed;
             game.current_phase = GamePhase::GameOver;
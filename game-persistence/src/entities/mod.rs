@@ -2,4 +2,8 @@
 
 pub mod prelude;
 
+pub mod abuse_events;
+pub mod game_players;
+pub mod games;
 pub mod users;
+pub mod word_reports;
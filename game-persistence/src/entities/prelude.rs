@@ -1,3 +1,7 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.16
 
+pub use super::abuse_events::Entity as AbuseEvents;
+pub use super::game_players::Entity as GamePlayers;
+pub use super::games::Entity as Games;
 pub use super::users::Entity as Users;
+pub use super::word_reports::Entity as WordReports;
@@ -16,6 +16,10 @@ pub struct Model {
     pub total_games: i32,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
+    pub avatar_color: Option<String>,
+    pub avatar_emoji: Option<String>,
+    pub last_played_at: Option<DateTimeWithTimeZone>,
+    pub rating: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -1,6 +1,7 @@
 pub mod connection;
 pub mod entities;
 pub mod repositories;
+pub mod snapshots;
 
 use sea_orm::{Database, DatabaseConnection, DbErr};
 
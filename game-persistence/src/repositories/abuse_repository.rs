@@ -0,0 +1,101 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder};
+
+use crate::entities::{abuse_events, prelude::*};
+use game_types::PlayerId;
+
+pub struct AbuseRepository {
+    db: DatabaseConnection,
+}
+
+/// Behaviour that counts against a user across connections and restarts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AbuseKind {
+    RateLimited,
+    Abandoned,
+    AuthFailure,
+}
+
+impl AbuseKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AbuseKind::RateLimited => "rate_limited",
+            AbuseKind::Abandoned => "abandoned",
+            AbuseKind::AuthFailure => "auth_failure",
+        }
+    }
+}
+
+impl AbuseRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    pub async fn record(&self, user_id: &PlayerId, kind: AbuseKind) -> Result<()> {
+        let event = abuse_events::ActiveModel {
+            id: sea_orm::ActiveValue::NotSet,
+            user_id: sea_orm::ActiveValue::Set(user_id.clone()),
+            kind: sea_orm::ActiveValue::Set(kind.as_str().to_string()),
+            created_at: sea_orm::ActiveValue::Set(Utc::now().into()),
+        };
+
+        AbuseEvents::insert(event).exec(&self.db).await?;
+        Ok(())
+    }
+
+    /// When each of a user's events since `since` happened, oldest first
+    pub async fn event_times_since(
+        &self,
+        user_id: &PlayerId,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<DateTime<Utc>>> {
+        let events = AbuseEvents::find()
+            .filter(abuse_events::Column::UserId.eq(user_id))
+            .filter(abuse_events::Column::CreatedAt.gte(since))
+            .order_by_asc(abuse_events::Column::CreatedAt)
+            .order_by_asc(abuse_events::Column::Id)
+            .all(&self.db)
+            .await?;
+
+        Ok(events
+            .into_iter()
+            .map(|event| event.created_at.with_timezone(&Utc))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::connect_to_memory_database;
+    use migration::{Migrator, MigratorTrait};
+
+    #[tokio::test]
+    async fn test_event_times_since() {
+        let db = connect_to_memory_database().await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        let repo = AbuseRepository::new(db);
+        let user = "user-1".to_string();
+        let before = Utc::now() - chrono::Duration::minutes(1);
+
+        repo.record(&user, AbuseKind::RateLimited).await.unwrap();
+        repo.record(&user, AbuseKind::Abandoned).await.unwrap();
+        repo.record(&"user-2".to_string(), AbuseKind::AuthFailure)
+            .await
+            .unwrap();
+
+        let times = repo.event_times_since(&user, before).await.unwrap();
+        assert_eq!(times.len(), 2);
+        assert!(times[0] <= times[1]);
+        assert!(times[0] >= before);
+
+        let later = Utc::now() + chrono::Duration::minutes(1);
+        assert!(repo
+            .event_times_since(&user, later)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
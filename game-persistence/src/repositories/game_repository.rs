@@ -0,0 +1,489 @@
+use anyhow::Result;
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, TransactionTrait,
+};
+
+use crate::entities::{game_players, games, prelude::*};
+use game_types::PlayerId;
+
+pub struct GameRepository {
+    db: DatabaseConnection,
+}
+
+/// Where a recorded game stands. Games are recorded in progress when they
+/// are created and later marked completed or abandoned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GameRecordStatus {
+    InProgress,
+    Completed,
+    Abandoned,
+}
+
+impl GameRecordStatus {
+    pub const ALL: [GameRecordStatus; 3] = [
+        GameRecordStatus::InProgress,
+        GameRecordStatus::Completed,
+        GameRecordStatus::Abandoned,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GameRecordStatus::InProgress => "in_progress",
+            GameRecordStatus::Completed => "completed",
+            GameRecordStatus::Abandoned => "abandoned",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|status| status.as_str() == value)
+    }
+}
+
+/// A game as recorded for analytics
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GameRecord {
+    pub id: String,
+    pub player_ids: Vec<PlayerId>,
+    pub word_length: i32, // Length of the first target word
+    pub mode: String,
+    pub status: GameRecordStatus,
+    pub winner_id: Option<PlayerId>,
+    pub created_at: String,
+    pub ended_at: Option<String>,
+    pub words: Vec<String>, // Each round's target word, once the game is completed
+    pub final_scores: Vec<GameRecordScore>, // Empty until the game is completed
+    #[serde(skip)]
+    pub seed: Option<u64>, // Reveals every target word, so it never leaves the server
+}
+
+/// A player's points when a recorded game completed
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GameRecordScore {
+    pub player_id: PlayerId,
+    pub points: i32,
+}
+
+/// One page of a player's finished games, newest first
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GameRecordPage {
+    pub games: Vec<GameRecord>,
+    pub page: u64, // Zero-based
+    pub per_page: u64,
+    pub total: u64,
+}
+
+impl GameRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    fn model_to_record(model: games::Model, players: Vec<game_players::Model>) -> GameRecord {
+        let final_scores = players
+            .iter()
+            .filter_map(|player| {
+                Some(GameRecordScore {
+                    player_id: player.player_id.clone(),
+                    points: player.points?,
+                })
+            })
+            .collect();
+        GameRecord {
+            id: model.id,
+            player_ids: players.into_iter().map(|player| player.player_id).collect(),
+            word_length: model.word_length,
+            mode: model.mode,
+            // Rows are only ever written through this repository
+            status: GameRecordStatus::parse(&model.status).unwrap_or(GameRecordStatus::InProgress),
+            winner_id: model.winner_id,
+            created_at: model.created_at.to_rfc3339(),
+            ended_at: model.ended_at.map(|ended_at| ended_at.to_rfc3339()),
+            words: model
+                .words
+                .and_then(|words| serde_json::from_str(&words).ok())
+                .unwrap_or_default(),
+            final_scores,
+            // Stored with its bits unchanged, as SQLite has no unsigned integers
+            seed: model.seed.map(|seed| seed as u64),
+        }
+    }
+
+    /// Record a game that has just started, marked in progress until it is
+    /// completed or abandoned
+    pub async fn record_game_created(
+        &self,
+        game_id: &str,
+        players: &[PlayerId],
+        word_length: i32,
+        created_at: chrono::DateTime<chrono::Utc>,
+        mode: &str,
+        seed: u64,
+    ) -> Result<()> {
+        let game_model = games::ActiveModel {
+            id: sea_orm::ActiveValue::Set(game_id.to_string()),
+            player_count: sea_orm::ActiveValue::Set(players.len() as i32),
+            word_length: sea_orm::ActiveValue::Set(word_length),
+            mode: sea_orm::ActiveValue::Set(mode.to_string()),
+            status: sea_orm::ActiveValue::Set(GameRecordStatus::InProgress.as_str().to_string()),
+            winner_id: sea_orm::ActiveValue::Set(None),
+            created_at: sea_orm::ActiveValue::Set(created_at.into()),
+            ended_at: sea_orm::ActiveValue::Set(None),
+            words: sea_orm::ActiveValue::Set(None),
+            seed: sea_orm::ActiveValue::Set(Some(seed as i64)),
+        };
+        let player_models = players.iter().map(|player_id| game_players::ActiveModel {
+            id: sea_orm::ActiveValue::NotSet,
+            game_id: sea_orm::ActiveValue::Set(game_id.to_string()),
+            player_id: sea_orm::ActiveValue::Set(player_id.clone()),
+            points: sea_orm::ActiveValue::Set(None),
+        });
+
+        let txn = self.db.begin().await?;
+        Games::insert(game_model).exec(&txn).await?;
+        if !players.is_empty() {
+            GamePlayers::insert_many(player_models).exec(&txn).await?;
+        }
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    /// Mark an in-progress game as won by `winner_id`, storing the word of
+    /// each round and every player's final points. Returns false when the
+    /// game was never recorded or has already ended.
+    pub async fn record_game_completed(
+        &self,
+        game_id: &str,
+        winner_id: &PlayerId,
+        words: &[String],
+        final_scores: &[GameRecordScore],
+    ) -> Result<bool> {
+        let words = serde_json::to_string(words)?;
+
+        let txn = self.db.begin().await?;
+        let finished = Self::finish_game(
+            &txn,
+            game_id,
+            GameRecordStatus::Completed,
+            Some(winner_id),
+            Some(words),
+        )
+        .await?;
+        if !finished {
+            return Ok(false);
+        }
+        for score in final_scores {
+            GamePlayers::update_many()
+                .col_expr(
+                    game_players::Column::Points,
+                    Expr::value(Some(score.points)),
+                )
+                .filter(game_players::Column::GameId.eq(game_id))
+                .filter(game_players::Column::PlayerId.eq(&score.player_id))
+                .exec(&txn)
+                .await?;
+        }
+        txn.commit().await?;
+
+        Ok(true)
+    }
+
+    /// Mark an in-progress game as abandoned. Returns false when the game was
+    /// never recorded or has already ended.
+    pub async fn mark_abandoned(&self, game_id: &str) -> Result<bool> {
+        Self::finish_game(&self.db, game_id, GameRecordStatus::Abandoned, None, None).await
+    }
+
+    async fn finish_game<C: ConnectionTrait>(
+        db: &C,
+        game_id: &str,
+        status: GameRecordStatus,
+        winner_id: Option<&PlayerId>,
+        words: Option<String>,
+    ) -> Result<bool> {
+        let ended_at: sea_orm::prelude::DateTimeWithTimeZone = chrono::Utc::now().into();
+        let result = Games::update_many()
+            .col_expr(games::Column::Status, Expr::value(status.as_str()))
+            .col_expr(games::Column::WinnerId, Expr::value(winner_id.cloned()))
+            .col_expr(games::Column::EndedAt, Expr::value(Some(ended_at)))
+            .col_expr(games::Column::Words, Expr::value(words))
+            .filter(games::Column::Id.eq(game_id))
+            .filter(games::Column::Status.eq(GameRecordStatus::InProgress.as_str()))
+            .exec(db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    pub async fn find_by_id(&self, game_id: &str) -> Result<Option<GameRecord>> {
+        let Some(game) = Games::find_by_id(game_id).one(&self.db).await? else {
+            return Ok(None);
+        };
+
+        let players = GamePlayers::find()
+            .filter(game_players::Column::GameId.eq(game_id))
+            .order_by_asc(game_players::Column::Id)
+            .all(&self.db)
+            .await?;
+
+        Ok(Some(Self::model_to_record(game, players)))
+    }
+
+    /// Every recorded game `player_id` took part in, newest first
+    pub async fn find_by_player(&self, player_id: &PlayerId) -> Result<Vec<GameRecord>> {
+        let game_ids = self.game_ids_for_player(player_id).await?;
+        let games = Games::find()
+            .filter(games::Column::Id.is_in(game_ids))
+            .order_by_desc(games::Column::CreatedAt)
+            .all(&self.db)
+            .await?;
+
+        self.with_players(games).await
+    }
+
+    /// One page of the completed and abandoned games `player_id` took part
+    /// in, newest first. Games still in progress are left out.
+    pub async fn find_finished_by_player(
+        &self,
+        player_id: &PlayerId,
+        page: u64,
+        per_page: u64,
+    ) -> Result<GameRecordPage> {
+        let game_ids = self.game_ids_for_player(player_id).await?;
+        let paginator = Games::find()
+            .filter(games::Column::Id.is_in(game_ids))
+            .filter(games::Column::Status.ne(GameRecordStatus::InProgress.as_str()))
+            .order_by_desc(games::Column::CreatedAt)
+            .order_by_asc(games::Column::Id)
+            .paginate(&self.db, per_page.max(1));
+        let total = paginator.num_items().await?;
+        let games = paginator.fetch_page(page).await?;
+
+        Ok(GameRecordPage {
+            games: self.with_players(games).await?,
+            page,
+            per_page,
+            total,
+        })
+    }
+
+    async fn game_ids_for_player(&self, player_id: &PlayerId) -> Result<Vec<String>> {
+        Ok(GamePlayers::find()
+            .filter(game_players::Column::PlayerId.eq(player_id))
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(|player| player.game_id)
+            .collect())
+    }
+
+    /// Records for `games`, in the same order, with their players loaded
+    async fn with_players(&self, games: Vec<games::Model>) -> Result<Vec<GameRecord>> {
+        let game_ids: Vec<&str> = games.iter().map(|game| game.id.as_str()).collect();
+        let mut players = GamePlayers::find()
+            .filter(game_players::Column::GameId.is_in(game_ids))
+            .order_by_asc(game_players::Column::Id)
+            .all(&self.db)
+            .await?;
+
+        Ok(games
+            .into_iter()
+            .map(|game| {
+                let game_players = players
+                    .extract_if(.., |player| player.game_id == game.id)
+                    .collect();
+                Self::model_to_record(game, game_players)
+            })
+            .collect())
+    }
+
+    /// Number of recorded games in `status`, for created/completed/abandoned funnels
+    pub async fn count_by_status(&self, status: GameRecordStatus) -> Result<u64> {
+        let count = Games::find()
+            .filter(games::Column::Status.eq(status.as_str()))
+            .count(&self.db)
+            .await?;
+
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::connect_to_memory_database;
+    use migration::{Migrator, MigratorTrait};
+
+    async fn setup_test_db() -> GameRepository {
+        let db = connect_to_memory_database().await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        GameRepository::new(db)
+    }
+
+    fn players() -> Vec<PlayerId> {
+        vec!["alice".to_string(), "bob".to_string()]
+    }
+
+    #[tokio::test]
+    async fn test_created_game_completes_once() {
+        let repo = setup_test_db().await;
+        repo.record_game_created(
+            "game-1",
+            &players(),
+            5,
+            chrono::Utc::now(),
+            "classic",
+            u64::MAX,
+        )
+        .await
+        .unwrap();
+
+        let record = repo.find_by_id("game-1").await.unwrap().unwrap();
+        assert_eq!(record.status, GameRecordStatus::InProgress);
+        assert_eq!(record.player_ids, players());
+        assert_eq!(record.word_length, 5);
+        assert_eq!(record.mode, "classic");
+        assert_eq!(record.seed, Some(u64::MAX));
+        assert!(record.ended_at.is_none());
+
+        assert!(repo
+            .record_game_completed(
+                "game-1",
+                &"alice".to_string(),
+                &[
+                    "crane".to_string(),
+                    "crane".to_string(),
+                    "plumb".to_string()
+                ],
+                &[
+                    GameRecordScore {
+                        player_id: "alice".to_string(),
+                        points: 25,
+                    },
+                    GameRecordScore {
+                        player_id: "bob".to_string(),
+                        points: 12,
+                    },
+                ],
+            )
+            .await
+            .unwrap());
+        let record = repo.find_by_id("game-1").await.unwrap().unwrap();
+        assert_eq!(record.status, GameRecordStatus::Completed);
+        assert_eq!(record.winner_id, Some("alice".to_string()));
+        assert!(record.ended_at.is_some());
+        assert_eq!(record.words, vec!["crane", "crane", "plumb"]);
+        assert_eq!(
+            record.final_scores,
+            vec![
+                GameRecordScore {
+                    player_id: "alice".to_string(),
+                    points: 25,
+                },
+                GameRecordScore {
+                    player_id: "bob".to_string(),
+                    points: 12,
+                },
+            ]
+        );
+        assert!(!repo
+            .record_game_completed("game-1", &"bob".to_string(), &[], &[])
+            .await
+            .unwrap());
+
+        // A finished game is not later counted as abandoned
+        assert!(!repo.mark_abandoned("game-1").await.unwrap());
+        assert!(!repo.mark_abandoned("missing").await.unwrap());
+        assert_eq!(
+            repo.count_by_status(GameRecordStatus::Completed)
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            repo.count_by_status(GameRecordStatus::Abandoned)
+                .await
+                .unwrap(),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_find_by_player() {
+        let repo = setup_test_db().await;
+        let started = chrono::Utc::now();
+        repo.record_game_created("game-1", &players(), 5, started, "classic", 1)
+            .await
+            .unwrap();
+        let carol = vec!["carol".to_string(), "bob".to_string()];
+        repo.record_game_created(
+            "game-2",
+            &carol,
+            6,
+            started + chrono::Duration::seconds(1),
+            "team",
+            2,
+        )
+        .await
+        .unwrap();
+
+        let bob_games = repo.find_by_player(&"bob".to_string()).await.unwrap();
+        let ids: Vec<&str> = bob_games.iter().map(|game| game.id.as_str()).collect();
+        assert_eq!(ids, vec!["game-2", "game-1"]);
+        assert_eq!(bob_games[0].player_ids, carol);
+
+        let alice_games = repo.find_by_player(&"alice".to_string()).await.unwrap();
+        assert_eq!(alice_games.len(), 1);
+        assert_eq!(alice_games[0].player_ids, players());
+        assert!(repo
+            .find_by_player(&"dave".to_string())
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_find_finished_by_player_pages_newest_first() {
+        let repo = setup_test_db().await;
+        let started = chrono::Utc::now();
+        for i in 0..5 {
+            let game_id = format!("game-{}", i);
+            repo.record_game_created(
+                &game_id,
+                &players(),
+                5,
+                started + chrono::Duration::seconds(i),
+                "classic",
+                i as u64,
+            )
+            .await
+            .unwrap();
+            if i < 4 {
+                repo.record_game_completed(&game_id, &"alice".to_string(), &[], &[])
+                    .await
+                    .unwrap();
+            }
+        }
+
+        // The game still in progress is not history yet
+        let first = repo
+            .find_finished_by_player(&"bob".to_string(), 0, 3)
+            .await
+            .unwrap();
+        assert_eq!(first.total, 4);
+        let ids: Vec<&str> = first.games.iter().map(|game| game.id.as_str()).collect();
+        assert_eq!(ids, vec!["game-3", "game-2", "game-1"]);
+
+        let second = repo
+            .find_finished_by_player(&"bob".to_string(), 1, 3)
+            .await
+            .unwrap();
+        assert_eq!(second.games.len(), 1);
+        assert_eq!(second.games[0].id, "game-0");
+        assert_eq!(second.games[0].player_ids, players());
+    }
+}
@@ -1,3 +1,9 @@
+pub mod abuse_repository;
+pub mod game_repository;
 pub mod user_repository;
+pub mod word_report_repository;
 
+pub use abuse_repository::AbuseRepository;
+pub use game_repository::GameRepository;
 pub use user_repository::UserRepository;
+pub use word_report_repository::WordReportRepository;
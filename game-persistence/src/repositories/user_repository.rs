@@ -1,14 +1,43 @@
 use anyhow::Result;
 use sea_orm::{
-    ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
-    QuerySelect,
+    ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
 };
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
 
 use crate::entities::{prelude::*, users};
-use game_types::{PlayerId, User};
+use game_types::{Avatar, AvatarColor, PlayerId, User};
+
+/// Leaderboard entries cached by `UserRepository`; requests for up to this
+/// many entries are served from one shared batch
+pub const LEADERBOARD_CACHE_SIZE: u64 = 100;
+
+/// Display name left on a user whose personal data was erased
+pub const DELETED_DISPLAY_NAME: &str = "Deleted player";
+
+/// Skill rating a player starts from before finishing any games
+pub const DEFAULT_RATING: i32 = 1000;
+
+/// Most rating one game can move a player, shared across their opponents
+const RATING_K_FACTOR: f64 = 32.0;
 
 pub struct UserRepository {
     db: DatabaseConnection,
+    leaderboard_cache: Option<LeaderboardCache>,
+}
+
+struct LeaderboardCache {
+    ttl: Duration,
+    state: RwLock<LeaderboardCacheState>,
+}
+
+#[derive(Default)]
+struct LeaderboardCacheState {
+    /// Bumped on every invalidation, so a query that raced a stats write
+    /// doesn't store its stale result
+    generation: u64,
+    entries: Option<(Instant, Vec<LeaderboardEntry>)>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -19,7 +48,29 @@ pub struct LeaderboardEntry {
 
 impl UserRepository {
     pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+        Self {
+            db,
+            leaderboard_cache: None,
+        }
+    }
+
+    /// Serve the leaderboard from memory for up to `ttl` between recomputes.
+    /// Writes through this repository invalidate the cache; `None` disables it.
+    pub fn with_leaderboard_cache(mut self, ttl: Option<Duration>) -> Self {
+        self.leaderboard_cache = ttl.map(|ttl| LeaderboardCache {
+            ttl,
+            state: RwLock::new(LeaderboardCacheState::default()),
+        });
+        self
+    }
+
+    /// Drop the cached leaderboard so the next request recomputes it
+    pub async fn invalidate_leaderboard(&self) {
+        if let Some(cache) = &self.leaderboard_cache {
+            let mut state = cache.state.write().await;
+            state.generation += 1;
+            state.entries = None;
+        }
     }
 
     fn model_to_user(model: users::Model) -> User {
@@ -31,9 +82,23 @@ impl UserRepository {
             total_wins: model.total_wins,
             total_games: model.total_games,
             created_at: model.created_at.to_rfc3339(),
+            avatar: Self::model_avatar(model.avatar_color, model.avatar_emoji),
+            roles: Vec::new(),
         }
     }
 
+    /// Stored avatar columns that no longer match the allowed sets are dropped
+    fn model_avatar(color: Option<String>, emoji: Option<String>) -> Option<Avatar> {
+        let color = AvatarColor::parse(&color?)?;
+        Avatar::new(color, &emoji?)
+    }
+
+    /// Cheap round trip confirming the database is reachable
+    pub async fn ping(&self) -> Result<()> {
+        self.db.execute_unprepared("SELECT 1").await?;
+        Ok(())
+    }
+
     pub async fn find_by_id(&self, id: &PlayerId) -> Result<Option<User>> {
         let user_model = Users::find_by_id(id).one(&self.db).await?;
         Ok(user_model.map(Self::model_to_user))
@@ -62,9 +127,16 @@ impl UserRepository {
             total_games: sea_orm::ActiveValue::Set(user.total_games),
             created_at: sea_orm::ActiveValue::Set(created_at),
             updated_at: sea_orm::ActiveValue::Set(now),
+            avatar_color: sea_orm::ActiveValue::Set(
+                user.avatar.as_ref().map(|a| a.color.as_str().to_string()),
+            ),
+            avatar_emoji: sea_orm::ActiveValue::Set(user.avatar.map(|a| a.emoji)),
+            last_played_at: sea_orm::ActiveValue::Set(None),
+            rating: sea_orm::ActiveValue::Set(DEFAULT_RATING),
         };
 
         let saved_model = Users::insert(user_model).exec(&self.db).await?;
+        self.invalidate_leaderboard().await;
 
         // Fetch the created user
         let created_user = Users::find_by_id(saved_model.last_insert_id)
@@ -86,24 +158,162 @@ impl UserRepository {
             .await?
             .ok_or_else(|| anyhow::anyhow!("User not found"))?;
 
+        let now = chrono::Utc::now();
         let updated_user = users::ActiveModel {
             id: sea_orm::ActiveValue::Unchanged(user.id),
             email: sea_orm::ActiveValue::Unchanged(user.email),
             display_name: sea_orm::ActiveValue::Unchanged(user.display_name),
-            total_points: sea_orm::ActiveValue::Set(user.total_points + points_gained),
-            total_wins: sea_orm::ActiveValue::Set(user.total_wins + if won { 1 } else { 0 }),
-            total_games: sea_orm::ActiveValue::Set(user.total_games + 1),
+            total_points: sea_orm::ActiveValue::Set(
+                user.total_points.saturating_add(points_gained),
+            ),
+            total_wins: sea_orm::ActiveValue::Set(user.total_wins.saturating_add(i32::from(won))),
+            total_games: sea_orm::ActiveValue::Set(user.total_games.saturating_add(1)),
             created_at: sea_orm::ActiveValue::Unchanged(user.created_at),
-            updated_at: sea_orm::ActiveValue::Set(chrono::Utc::now().into()),
+            updated_at: sea_orm::ActiveValue::Set(now.into()),
+            avatar_color: sea_orm::ActiveValue::Unchanged(user.avatar_color),
+            avatar_emoji: sea_orm::ActiveValue::Unchanged(user.avatar_emoji),
+            last_played_at: sea_orm::ActiveValue::Set(Some(now.into())),
+            rating: sea_orm::ActiveValue::Unchanged(user.rating),
         };
 
         Users::update(updated_user).exec(&self.db).await?;
+        self.invalidate_leaderboard().await;
         Ok(())
     }
 
-    pub async fn get_leaderboard(&self, limit: u64) -> Result<Vec<LeaderboardEntry>> {
-        let users = Users::find()
+    /// Skill rating used for matchmaking, if the user has a stored profile
+    pub async fn get_rating(&self, user_id: &PlayerId) -> Result<Option<i32>> {
+        let user_model = Users::find_by_id(user_id).one(&self.db).await?;
+        Ok(user_model.map(|model| model.rating))
+    }
+
+    /// Elo update for a finished game, treated as the winner beating each
+    /// other player. The K factor is split across opponents so large games
+    /// move ratings no further than one-on-one ones, and the winner gains
+    /// exactly what the others lose. Players without a stored profile are
+    /// left out. Ratings are read and written in one transaction, so games
+    /// finishing at the same time can't overwrite each other's changes.
+    pub async fn update_ratings(&self, winner_id: &PlayerId, losers: &[PlayerId]) -> Result<()> {
+        let txn = self.db.begin().await?;
+        let Some(winner) = Users::find_by_id(winner_id).one(&txn).await? else {
+            return Ok(());
+        };
+        let losers = Users::find()
+            .filter(users::Column::Id.is_in(losers.iter().filter(|id| *id != winner_id)))
+            .all(&txn)
+            .await?;
+        if losers.is_empty() {
+            return Ok(());
+        }
+
+        let k = RATING_K_FACTOR / losers.len() as f64;
+        let mut winner_gain = 0;
+        for loser in losers {
+            let expected =
+                1.0 / (1.0 + 10f64.powf(f64::from(loser.rating - winner.rating) / 400.0));
+            let change = (k * (1.0 - expected)).round() as i32;
+            winner_gain += change;
+
+            let rating = loser.rating.saturating_sub(change);
+            let mut updated_loser: users::ActiveModel = loser.into();
+            updated_loser.rating = sea_orm::ActiveValue::Set(rating);
+            Users::update(updated_loser).exec(&txn).await?;
+        }
+
+        let rating = winner.rating.saturating_add(winner_gain);
+        let mut updated_winner: users::ActiveModel = winner.into();
+        updated_winner.rating = sea_orm::ActiveValue::Set(rating);
+        Users::update(updated_winner).exec(&txn).await?;
+        txn.commit().await?;
+        Ok(())
+    }
+
+    pub async fn update_avatar(&self, user_id: &PlayerId, avatar: &Avatar) -> Result<()> {
+        let user = Users::find_by_id(user_id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("User not found"))?;
+
+        let mut updated_user: users::ActiveModel = user.into();
+        updated_user.avatar_color =
+            sea_orm::ActiveValue::Set(Some(avatar.color.as_str().to_string()));
+        updated_user.avatar_emoji = sea_orm::ActiveValue::Set(Some(avatar.emoji.clone()));
+        updated_user.updated_at = sea_orm::ActiveValue::Set(chrono::Utc::now().into());
+
+        Users::update(updated_user).exec(&self.db).await?;
+        self.invalidate_leaderboard().await;
+        Ok(())
+    }
+
+    /// Erase a user's personal data while keeping the row, so their id stays
+    /// valid in recorded games and their aggregate stats stay on the
+    /// leaderboard. Returns false when the user does not exist.
+    pub async fn anonymize_user(&self, user_id: &PlayerId) -> Result<bool> {
+        let Some(user) = Users::find_by_id(user_id).one(&self.db).await? else {
+            return Ok(false);
+        };
+
+        let mut updated_user: users::ActiveModel = user.into();
+        // Emails are unique, so each anonymized user gets a distinct placeholder
+        updated_user.email = sea_orm::ActiveValue::Set(format!("deleted-{}@invalid", user_id));
+        updated_user.display_name = sea_orm::ActiveValue::Set(DELETED_DISPLAY_NAME.to_string());
+        updated_user.avatar_color = sea_orm::ActiveValue::Set(None);
+        updated_user.avatar_emoji = sea_orm::ActiveValue::Set(None);
+        updated_user.updated_at = sea_orm::ActiveValue::Set(chrono::Utc::now().into());
+
+        Users::update(updated_user).exec(&self.db).await?;
+        self.invalidate_leaderboard().await;
+        Ok(true)
+    }
+
+    /// Top players by total points. With `active_within`, only players who
+    /// finished a game within that window are ranked; such boards bypass
+    /// the cache.
+    pub async fn get_leaderboard(
+        &self,
+        limit: u64,
+        active_within: Option<chrono::Duration>,
+    ) -> Result<Vec<LeaderboardEntry>> {
+        let cache = match &self.leaderboard_cache {
+            Some(cache) if limit <= LEADERBOARD_CACHE_SIZE && active_within.is_none() => cache,
+            _ => return self.query_leaderboard(limit, active_within).await,
+        };
+
+        let generation = {
+            let state = cache.state.read().await;
+            if let Some((fetched_at, entries)) = &state.entries {
+                if fetched_at.elapsed() < cache.ttl {
+                    return Ok(entries.iter().take(limit as usize).cloned().collect());
+                }
+            }
+            state.generation
+        };
+
+        let entries = self.query_leaderboard(LEADERBOARD_CACHE_SIZE, None).await?;
+        {
+            let mut state = cache.state.write().await;
+            if state.generation == generation {
+                state.entries = Some((Instant::now(), entries.clone()));
+            }
+        }
+
+        Ok(entries.into_iter().take(limit as usize).collect())
+    }
+
+    async fn query_leaderboard(
+        &self,
+        limit: u64,
+        active_within: Option<chrono::Duration>,
+    ) -> Result<Vec<LeaderboardEntry>> {
+        let mut query = Users::find();
+        if let Some(window) = active_within {
+            let since: sea_orm::prelude::DateTimeWithTimeZone =
+                (chrono::Utc::now() - window).into();
+            query = query.filter(users::Column::LastPlayedAt.gte(since));
+        }
+        let users = query
             .order_by_desc(users::Column::TotalPoints)
+            .order_by_asc(users::Column::Id)
             .limit(limit)
             .all(&self.db)
             .await?;
@@ -134,6 +344,56 @@ impl UserRepository {
             Ok(None)
         }
     }
+
+    /// The leaderboard entries up to `window` places either side of the
+    /// user, their own entry included. `None` for unknown users and for
+    /// users who have not finished a game yet, who are unranked.
+    pub async fn get_rank_context(
+        &self,
+        user_id: &PlayerId,
+        window: u32,
+    ) -> Result<Option<Vec<LeaderboardEntry>>> {
+        let Some(user_model) = Users::find_by_id(user_id).one(&self.db).await? else {
+            return Ok(None);
+        };
+        if user_model.total_games == 0 {
+            return Ok(None);
+        }
+
+        // Position on the leaderboard, whose ties are ordered by id
+        let users_ahead = Users::find()
+            .filter(
+                Condition::any()
+                    .add(users::Column::TotalPoints.gt(user_model.total_points))
+                    .add(
+                        Condition::all()
+                            .add(users::Column::TotalPoints.eq(user_model.total_points))
+                            .add(users::Column::Id.lt(user_id.as_str())),
+                    ),
+            )
+            .count(&self.db)
+            .await?;
+        let first = users_ahead.saturating_sub(window.into());
+
+        let users = Users::find()
+            .order_by_desc(users::Column::TotalPoints)
+            .order_by_asc(users::Column::Id)
+            .offset(first)
+            .limit(users_ahead - first + u64::from(window) + 1)
+            .all(&self.db)
+            .await?;
+
+        let context = users
+            .into_iter()
+            .enumerate()
+            .map(|(index, model)| LeaderboardEntry {
+                user: Self::model_to_user(model),
+                rank: (first + index as u64 + 1) as u32,
+            })
+            .collect();
+
+        Ok(Some(context))
+    }
 }
 
 #[cfg(test)]
@@ -161,6 +421,8 @@ mod tests {
             total_wins: 0,
             total_games: 0,
             created_at: chrono::Utc::now().to_rfc3339(),
+            avatar: None,
+            roles: Vec::new(),
         };
 
         // Create user
@@ -194,6 +456,8 @@ mod tests {
             total_wins: 1,
             total_games: 2,
             created_at: chrono::Utc::now().to_rfc3339(),
+            avatar: None,
+            roles: Vec::new(),
         };
 
         repo.create_user(user).await.unwrap();
@@ -213,6 +477,114 @@ mod tests {
         assert_eq!(updated_user.total_games, 3);
     }
 
+    #[tokio::test]
+    async fn test_update_user_stats_saturates() {
+        let repo = setup_test_db().await;
+
+        let user_id = "test-user-saturate".to_string();
+        repo.create_user(User {
+            id: user_id.clone(),
+            email: "saturate@example.com".to_string(),
+            display_name: "Saturated".to_string(),
+            total_points: i32::MAX - 2,
+            total_wins: i32::MAX,
+            total_games: i32::MAX,
+            created_at: chrono::Utc::now().to_rfc3339(),
+            avatar: None,
+            roles: Vec::new(),
+        })
+        .await
+        .unwrap();
+
+        repo.update_user_stats(&user_id, 50, true).await.unwrap();
+
+        let updated_user = repo.find_by_id(&user_id).await.unwrap().unwrap();
+        assert_eq!(updated_user.total_points, i32::MAX);
+        assert_eq!(updated_user.total_wins, i32::MAX);
+        assert_eq!(updated_user.total_games, i32::MAX);
+    }
+
+    #[tokio::test]
+    async fn test_update_ratings_moves_rating_from_losers_to_winner() {
+        let repo = setup_test_db().await;
+        for id in ["winner", "loser1", "loser2"] {
+            repo.create_user(User {
+                id: id.to_string(),
+                email: format!("{}@example.com", id),
+                display_name: id.to_string(),
+                total_points: 0,
+                total_wins: 0,
+                total_games: 0,
+                created_at: chrono::Utc::now().to_rfc3339(),
+                avatar: None,
+                roles: Vec::new(),
+            })
+            .await
+            .unwrap();
+        }
+        assert_eq!(
+            repo.get_rating(&"winner".to_string()).await.unwrap(),
+            Some(DEFAULT_RATING)
+        );
+
+        repo.update_ratings(
+            &"winner".to_string(),
+            &["loser1".to_string(), "loser2".to_string()],
+        )
+        .await
+        .unwrap();
+
+        // Evenly matched, so each loser gives up half of their share of K
+        let rating = |id: &str| {
+            let id = id.to_string();
+            let repo = &repo;
+            async move { repo.get_rating(&id).await.unwrap().unwrap() }
+        };
+        assert_eq!(rating("winner").await, DEFAULT_RATING + 16);
+        assert_eq!(rating("loser1").await, DEFAULT_RATING - 8);
+        assert_eq!(rating("loser2").await, DEFAULT_RATING - 8);
+        assert_eq!(repo.get_rating(&"nobody".to_string()).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_rating_updates_keep_every_change() {
+        let repo = setup_test_db().await;
+        let ids: Vec<String> = ["alice", "bob", "carol"]
+            .iter()
+            .map(|id| id.to_string())
+            .collect();
+        for id in &ids {
+            repo.create_user(User {
+                id: id.clone(),
+                email: format!("{}@example.com", id),
+                display_name: id.clone(),
+                total_points: 0,
+                total_wins: 0,
+                total_games: 0,
+                created_at: chrono::Utc::now().to_rfc3339(),
+                avatar: None,
+                roles: Vec::new(),
+            })
+            .await
+            .unwrap();
+        }
+
+        // Two games sharing a player finish at once
+        let (first, second) = tokio::join!(
+            repo.update_ratings(&ids[0], &ids[1..2]),
+            repo.update_ratings(&ids[2], &ids[1..2]),
+        );
+        first.unwrap();
+        second.unwrap();
+
+        let mut total = 0;
+        for id in &ids {
+            total += repo.get_rating(id).await.unwrap().unwrap();
+        }
+        assert_eq!(total, 3 * DEFAULT_RATING);
+        assert!(repo.get_rating(&ids[1]).await.unwrap().unwrap() < DEFAULT_RATING - 16);
+    }
+
     #[tokio::test]
     async fn test_leaderboard() {
         let repo = setup_test_db().await;
@@ -227,6 +599,8 @@ mod tests {
                 total_wins: 5,
                 total_games: 10,
                 created_at: chrono::Utc::now().to_rfc3339(),
+                avatar: None,
+                roles: Vec::new(),
             },
             User {
                 id: "test-user-2".to_string(),
@@ -236,6 +610,8 @@ mod tests {
                 total_wins: 8,
                 total_games: 12,
                 created_at: chrono::Utc::now().to_rfc3339(),
+                avatar: None,
+                roles: Vec::new(),
             },
             User {
                 id: "test-user-3".to_string(),
@@ -245,6 +621,8 @@ mod tests {
                 total_wins: 2,
                 total_games: 8,
                 created_at: chrono::Utc::now().to_rfc3339(),
+                avatar: None,
+                roles: Vec::new(),
             },
         ];
 
@@ -254,7 +632,7 @@ mod tests {
         }
 
         // Get leaderboard
-        let leaderboard = repo.get_leaderboard(10).await.unwrap();
+        let leaderboard = repo.get_leaderboard(10, None).await.unwrap();
 
         assert_eq!(leaderboard.len(), 3);
 
@@ -283,6 +661,8 @@ mod tests {
                 total_wins: 5,
                 total_games: 10,
                 created_at: chrono::Utc::now().to_rfc3339(),
+                avatar: None,
+                roles: Vec::new(),
             },
             User {
                 id: "test-rank-user-2".to_string(),
@@ -292,6 +672,8 @@ mod tests {
                 total_wins: 8,
                 total_games: 12,
                 created_at: chrono::Utc::now().to_rfc3339(),
+                avatar: None,
+                roles: Vec::new(),
             },
         ];
 
@@ -329,12 +711,14 @@ mod tests {
                 total_wins: i,
                 total_games: i * 2,
                 created_at: chrono::Utc::now().to_rfc3339(),
+                avatar: None,
+                roles: Vec::new(),
             };
             repo.create_user(user).await.unwrap();
         }
 
         // Get top 3
-        let leaderboard = repo.get_leaderboard(3).await.unwrap();
+        let leaderboard = repo.get_leaderboard(3, None).await.unwrap();
         assert_eq!(leaderboard.len(), 3);
 
         // Should be in descending order by points
@@ -342,4 +726,243 @@ mod tests {
         assert_eq!(leaderboard[1].user.total_points, 40);
         assert_eq!(leaderboard[2].user.total_points, 30);
     }
+
+    #[tokio::test]
+    async fn test_update_avatar() {
+        let repo = setup_test_db().await;
+
+        let user_id = "test-avatar-user".to_string();
+        let user = User {
+            id: user_id.clone(),
+            email: "avatar@example.com".to_string(),
+            display_name: "Avatar User".to_string(),
+            total_points: 0,
+            total_wins: 0,
+            total_games: 0,
+            created_at: chrono::Utc::now().to_rfc3339(),
+            avatar: None,
+            roles: Vec::new(),
+        };
+        repo.create_user(user).await.unwrap();
+
+        let found_user = repo.find_by_id(&user_id).await.unwrap().unwrap();
+        assert!(found_user.avatar.is_none());
+
+        let avatar = Avatar::new(AvatarColor::Green, "🐸").unwrap();
+        repo.update_avatar(&user_id, &avatar).await.unwrap();
+
+        let found_user = repo.find_by_id(&user_id).await.unwrap().unwrap();
+        assert_eq!(found_user.avatar, Some(avatar.clone()));
+
+        // Stats updates leave the avatar alone
+        repo.update_user_stats(&user_id, 5, true).await.unwrap();
+        let found_user = repo.find_by_id(&user_id).await.unwrap().unwrap();
+        assert_eq!(found_user.avatar, Some(avatar));
+
+        let result = repo
+            .update_avatar(
+                &"missing-user".to_string(),
+                &Avatar::new(AvatarColor::Red, "🔥").unwrap(),
+            )
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_anonymize_user_keeps_stats() {
+        let repo = setup_test_db().await;
+
+        let user_id = "test-erased-user".to_string();
+        repo.create_user(User {
+            id: user_id.clone(),
+            email: "erase-me@example.com".to_string(),
+            display_name: "Erase Me".to_string(),
+            total_points: 40,
+            total_wins: 2,
+            total_games: 5,
+            created_at: chrono::Utc::now().to_rfc3339(),
+            avatar: Some(Avatar::new(AvatarColor::Blue, "🐳").unwrap()),
+            roles: Vec::new(),
+        })
+        .await
+        .unwrap();
+
+        assert!(repo.anonymize_user(&user_id).await.unwrap());
+        let user = repo.find_by_id(&user_id).await.unwrap().unwrap();
+        assert_eq!(user.display_name, DELETED_DISPLAY_NAME);
+        assert!(!user.email.contains("erase-me"));
+        assert!(user.avatar.is_none());
+        assert_eq!(
+            (user.total_points, user.total_wins, user.total_games),
+            (40, 2, 5)
+        );
+        assert!(repo
+            .find_by_email("erase-me@example.com")
+            .await
+            .unwrap()
+            .is_none());
+
+        assert!(!repo.anonymize_user(&"missing".to_string()).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_leaderboard_cache_serves_repeats_until_stats_change() {
+        let db = connect_to_memory_database().await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        let repo =
+            UserRepository::new(db.clone()).with_leaderboard_cache(Some(Duration::from_secs(60)));
+        // Writes through a second repository don't touch the first one's cache
+        let other_writer = UserRepository::new(db);
+
+        let user = |id: &str, total_points: i32| User {
+            id: id.to_string(),
+            email: format!("{}@example.com", id),
+            display_name: id.to_string(),
+            total_points,
+            total_wins: 0,
+            total_games: 0,
+            created_at: chrono::Utc::now().to_rfc3339(),
+            avatar: None,
+            roles: Vec::new(),
+        };
+        repo.create_user(user("alice", 100)).await.unwrap();
+
+        assert_eq!(repo.get_leaderboard(10, None).await.unwrap().len(), 1);
+        other_writer.create_user(user("bob", 200)).await.unwrap();
+
+        // The second request is served from the cache, without Bob
+        let leaderboard = repo.get_leaderboard(10, None).await.unwrap();
+        assert_eq!(leaderboard.len(), 1);
+        assert_eq!(leaderboard[0].user.id, "alice");
+
+        // A completed game's stats update busts the cache
+        repo.update_user_stats(&"alice".to_string(), 150, true)
+            .await
+            .unwrap();
+        let leaderboard = repo.get_leaderboard(10, None).await.unwrap();
+        assert_eq!(leaderboard.len(), 2);
+        assert_eq!(leaderboard[0].user.id, "alice");
+        assert_eq!(leaderboard[0].user.total_points, 250);
+        assert_eq!(leaderboard[1].user.id, "bob");
+
+        // Smaller limits are cut from the same batch
+        assert_eq!(repo.get_leaderboard(1, None).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_leaderboard_excludes_inactive_users() {
+        let repo = setup_test_db().await;
+
+        for (id, total_points) in [("veteran", 500), ("regular", 100), ("newcomer", 50)] {
+            repo.create_user(User {
+                id: id.to_string(),
+                email: format!("{}@example.com", id),
+                display_name: id.to_string(),
+                total_points,
+                total_wins: 0,
+                total_games: 0,
+                created_at: chrono::Utc::now().to_rfc3339(),
+                avatar: None,
+                roles: Vec::new(),
+            })
+            .await
+            .unwrap();
+        }
+        repo.update_user_stats(&"veteran".to_string(), 10, false)
+            .await
+            .unwrap();
+        repo.update_user_stats(&"regular".to_string(), 10, true)
+            .await
+            .unwrap();
+
+        // The veteran's last game was two months ago; the newcomer never finished one
+        let veteran = Users::find_by_id("veteran").one(&repo.db).await.unwrap();
+        let mut veteran: users::ActiveModel = veteran.unwrap().into();
+        veteran.last_played_at = sea_orm::ActiveValue::Set(Some(
+            (chrono::Utc::now() - chrono::Duration::days(60)).into(),
+        ));
+        Users::update(veteran).exec(&repo.db).await.unwrap();
+
+        let leaderboard = repo
+            .get_leaderboard(10, Some(chrono::Duration::days(30)))
+            .await
+            .unwrap();
+        assert_eq!(leaderboard.len(), 1);
+        assert_eq!(leaderboard[0].user.id, "regular");
+        assert_eq!(leaderboard[0].rank, 1);
+
+        // Everyone is ranked without the filter
+        let leaderboard = repo.get_leaderboard(10, None).await.unwrap();
+        assert_eq!(leaderboard.len(), 3);
+        assert_eq!(leaderboard[0].user.id, "veteran");
+    }
+
+    #[tokio::test]
+    async fn test_rank_context_surrounds_user() {
+        let repo = setup_test_db().await;
+
+        // Ranked by points: e, d, c, b, a; "idle" has never finished a game
+        for (id, total_points, total_games) in [
+            ("a", 10, 1),
+            ("b", 20, 1),
+            ("c", 30, 1),
+            ("d", 40, 1),
+            ("e", 50, 1),
+            ("idle", 0, 0),
+        ] {
+            repo.create_user(User {
+                id: id.to_string(),
+                email: format!("{}@example.com", id),
+                display_name: id.to_string(),
+                total_points,
+                total_wins: 0,
+                total_games,
+                created_at: chrono::Utc::now().to_rfc3339(),
+                avatar: None,
+                roles: Vec::new(),
+            })
+            .await
+            .unwrap();
+        }
+
+        let ids_and_ranks = |context: Vec<LeaderboardEntry>| -> Vec<(String, u32)> {
+            context
+                .into_iter()
+                .map(|entry| (entry.user.id, entry.rank))
+                .collect()
+        };
+
+        let context = repo.get_rank_context(&"c".to_string(), 1).await.unwrap();
+        assert_eq!(
+            ids_and_ranks(context.unwrap()),
+            vec![
+                ("d".to_string(), 2),
+                ("c".to_string(), 3),
+                ("b".to_string(), 4)
+            ]
+        );
+
+        // Nobody is above the leader
+        let context = repo.get_rank_context(&"e".to_string(), 2).await.unwrap();
+        assert_eq!(
+            ids_and_ranks(context.unwrap()),
+            vec![
+                ("e".to_string(), 1),
+                ("d".to_string(), 2),
+                ("c".to_string(), 3)
+            ]
+        );
+
+        // Players without a finished game, and unknown ids, are unranked
+        assert!(repo
+            .get_rank_context(&"idle".to_string(), 2)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(repo
+            .get_rank_context(&"nobody".to_string(), 2)
+            .await
+            .unwrap()
+            .is_none());
+    }
 }
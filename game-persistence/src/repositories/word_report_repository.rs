@@ -0,0 +1,166 @@
+use anyhow::Result;
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect,
+};
+
+use crate::entities::{prelude::*, word_reports};
+use game_types::PlayerId;
+
+pub struct WordReportRepository {
+    db: DatabaseConnection,
+}
+
+/// A player's report that a target word is offensive, misspelled or not a real word
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct WordReport {
+    pub id: i32,
+    pub game_id: String,
+    pub word: String,
+    pub reporter_id: PlayerId,
+    pub reason: String,
+    pub created_at: String,
+}
+
+impl WordReportRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    fn model_to_report(model: word_reports::Model) -> WordReport {
+        WordReport {
+            id: model.id,
+            game_id: model.game_id,
+            word: model.word,
+            reporter_id: model.reporter_id,
+            reason: model.reason,
+            created_at: model.created_at.to_rfc3339(),
+        }
+    }
+
+    pub async fn create_report(
+        &self,
+        game_id: &str,
+        word: &str,
+        reporter_id: &PlayerId,
+        reason: &str,
+    ) -> Result<WordReport> {
+        let report_model = word_reports::ActiveModel {
+            id: sea_orm::ActiveValue::NotSet,
+            game_id: sea_orm::ActiveValue::Set(game_id.to_string()),
+            word: sea_orm::ActiveValue::Set(word.to_string()),
+            reporter_id: sea_orm::ActiveValue::Set(reporter_id.clone()),
+            reason: sea_orm::ActiveValue::Set(reason.to_string()),
+            created_at: sea_orm::ActiveValue::Set(chrono::Utc::now().into()),
+        };
+
+        let saved = WordReports::insert(report_model).exec(&self.db).await?;
+
+        let report = WordReports::find_by_id(saved.last_insert_id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Failed to fetch created word report"))?;
+
+        Ok(Self::model_to_report(report))
+    }
+
+    /// Number of reports a player has filed since `since`, for rate limiting
+    pub async fn count_reports_since(
+        &self,
+        reporter_id: &PlayerId,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<u64> {
+        let count = WordReports::find()
+            .filter(word_reports::Column::ReporterId.eq(reporter_id))
+            .filter(word_reports::Column::CreatedAt.gte(since))
+            .count(&self.db)
+            .await?;
+
+        Ok(count)
+    }
+
+    /// Every report `reporter_id` has filed, oldest first
+    pub async fn list_by_reporter(&self, reporter_id: &PlayerId) -> Result<Vec<WordReport>> {
+        let reports = WordReports::find()
+            .filter(word_reports::Column::ReporterId.eq(reporter_id))
+            .order_by_asc(word_reports::Column::Id)
+            .all(&self.db)
+            .await?;
+
+        Ok(reports.into_iter().map(Self::model_to_report).collect())
+    }
+
+    /// Most recent reports first
+    pub async fn list_reports(&self, limit: u64) -> Result<Vec<WordReport>> {
+        let reports = WordReports::find()
+            .order_by_desc(word_reports::Column::CreatedAt)
+            .order_by_desc(word_reports::Column::Id)
+            .limit(limit)
+            .all(&self.db)
+            .await?;
+
+        Ok(reports.into_iter().map(Self::model_to_report).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::connect_to_memory_database;
+    use migration::{Migrator, MigratorTrait};
+
+    async fn setup_test_db() -> WordReportRepository {
+        let db = connect_to_memory_database().await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        WordReportRepository::new(db)
+    }
+
+    #[tokio::test]
+    async fn test_create_and_list_reports() {
+        let repo = setup_test_db().await;
+        let reporter = "reporter-1".to_string();
+
+        let report = repo
+            .create_report("game-1", "hello", &reporter, "Not a real word")
+            .await
+            .unwrap();
+        assert_eq!(report.word, "hello");
+        assert_eq!(report.reporter_id, reporter);
+
+        repo.create_report("game-2", "world", &reporter, "Misspelled")
+            .await
+            .unwrap();
+
+        let reports = repo.list_reports(10).await.unwrap();
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].word, "world"); // Newest first
+        assert_eq!(reports[1], report);
+
+        let reports = repo.list_reports(1).await.unwrap();
+        assert_eq!(reports.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_count_reports_since() {
+        let repo = setup_test_db().await;
+        let reporter = "reporter-1".to_string();
+        let before = chrono::Utc::now() - chrono::Duration::minutes(1);
+
+        for _ in 0..3 {
+            repo.create_report("game-1", "hello", &reporter, "Offensive")
+                .await
+                .unwrap();
+        }
+        repo.create_report("game-1", "hello", &"reporter-2".to_string(), "Offensive")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            repo.count_reports_since(&reporter, before).await.unwrap(),
+            3
+        );
+
+        let later = chrono::Utc::now() + chrono::Duration::minutes(1);
+        assert_eq!(repo.count_reports_since(&reporter, later).await.unwrap(), 0);
+    }
+}
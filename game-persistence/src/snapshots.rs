@@ -0,0 +1,461 @@
+//! Versioned storage format for game state.
+//!
+//! Checkpoints and replays are stored in a serialized form that is kept
+//! separate from the in-memory `GameState`, so refactoring the game types
+//! doesn't make stored games unreadable. Every snapshot is wrapped in an
+//! envelope carrying its format version, and `decode` dispatches on that
+//! version. When the stored form has to change, add a new version module,
+//! bump `SNAPSHOT_VERSION` and teach `decode` to upgrade the older versions.
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+use game_types::{
+    CampaignProgress, GamePhase, GameState, GameStatus, GuessResult, LetterResult, LetterStatus,
+    PersonalGuess, Player, ScoreBreakdown, Team,
+};
+
+/// Format version written by `encode`
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// A game as stored: its public state plus the hidden target word
+#[derive(Debug, Clone)]
+pub struct GameSnapshot {
+    pub state: GameState,
+    pub target_word: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Envelope {
+    version: u32,
+    data: serde_json::Value,
+}
+
+/// Serialize `snapshot` in the current storage format
+pub fn encode(snapshot: &GameSnapshot) -> Result<String> {
+    let data = serde_json::to_value(v1::StoredGame::from(snapshot))?;
+    Ok(serde_json::to_string(&Envelope {
+        version: SNAPSHOT_VERSION,
+        data,
+    })?)
+}
+
+/// Read a snapshot written by this or any earlier storage format
+pub fn decode(stored: &str) -> Result<GameSnapshot> {
+    let envelope: Envelope = serde_json::from_str(stored)?;
+    match envelope.version {
+        1 => Ok(serde_json::from_value::<v1::StoredGame>(envelope.data)?.into()),
+        version => Err(anyhow!(
+            "Snapshot version {} is newer than this server supports ({})",
+            version,
+            SNAPSHOT_VERSION
+        )),
+    }
+}
+
+/// The first storage format. Frozen: never change these types, add a new
+/// version instead.
+mod v1 {
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    pub struct StoredGame {
+        pub id: String,
+        pub target_word: String,
+        pub masked_word: String,
+        pub word_length: i32,
+        pub round: i32,
+        pub status: StoredStatus,
+        pub phase: StoredPhase,
+        pub players: Vec<StoredPlayer>,
+        pub board: Vec<StoredGuess>,
+        pub round_winner: Option<String>,
+        pub created_at: String,
+        pub point_threshold: i32,
+        pub teams: Vec<StoredTeam>,
+        pub campaign: Option<StoredCampaign>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    pub enum StoredStatus {
+        Queuing,
+        Starting,
+        Active,
+        Paused,
+        Completed,
+        Abandoned,
+        TimedOut,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    pub enum StoredPhase {
+        Waiting,
+        Countdown,
+        Guessing,
+        IndividualGuess,
+        Intermission,
+        GameOver,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub struct StoredPlayer {
+        pub user_id: String,
+        pub display_name: String,
+        pub points: i32,
+        pub connected: bool,
+        pub guesses: Vec<StoredPersonalGuess>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub struct StoredPersonalGuess {
+        pub word: String,
+        pub points: i32,
+        pub assist_points: i32,
+        pub won_round: bool,
+        pub timestamp: String,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub struct StoredGuess {
+        pub word: String,
+        pub player_id: String,
+        pub letters: Vec<StoredLetter>,
+        pub points: i32,
+        pub base_points: i32,
+        pub catch_up_bonus: i32,
+        pub timestamp: String,
+    }
+
+    /// One letter of a guess; `status` is `c`orrect, `p`resent or `a`bsent
+    #[derive(Serialize, Deserialize)]
+    pub struct StoredLetter {
+        pub letter: String,
+        pub status: char,
+        pub position: i32,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub struct StoredTeam {
+        pub id: u32,
+        pub name: String,
+        pub player_ids: Vec<String>,
+        pub points: i32,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub struct StoredCampaign {
+        pub words_solved: u32,
+        pub combo: u32,
+        pub combo_player: Option<String>,
+        pub best_combo: u32,
+    }
+
+    impl From<&GameSnapshot> for StoredGame {
+        fn from(snapshot: &GameSnapshot) -> Self {
+            let state = &snapshot.state;
+            Self {
+                id: state.id.clone(),
+                target_word: snapshot.target_word.clone(),
+                masked_word: state.word.clone(),
+                word_length: state.word_length,
+                round: state.current_round,
+                status: match state.status {
+                    GameStatus::Queuing => StoredStatus::Queuing,
+                    GameStatus::Starting => StoredStatus::Starting,
+                    GameStatus::Active => StoredStatus::Active,
+                    GameStatus::Paused => StoredStatus::Paused,
+                    GameStatus::Completed => StoredStatus::Completed,
+                    GameStatus::Abandoned => StoredStatus::Abandoned,
+                    GameStatus::TimedOut => StoredStatus::TimedOut,
+                },
+                phase: match state.current_phase {
+                    GamePhase::Waiting => StoredPhase::Waiting,
+                    GamePhase::Countdown => StoredPhase::Countdown,
+                    GamePhase::Guessing => StoredPhase::Guessing,
+                    GamePhase::IndividualGuess => StoredPhase::IndividualGuess,
+                    GamePhase::Intermission => StoredPhase::Intermission,
+                    GamePhase::GameOver => StoredPhase::GameOver,
+                },
+                players: state
+                    .players
+                    .iter()
+                    .map(|player| StoredPlayer {
+                        user_id: player.user_id.clone(),
+                        display_name: player.display_name.clone(),
+                        points: player.points,
+                        connected: player.is_connected,
+                        guesses: player
+                            .guess_history
+                            .iter()
+                            .map(|guess| StoredPersonalGuess {
+                                word: guess.word.clone(),
+                                points: guess.points_earned,
+                                assist_points: guess.assist_points,
+                                won_round: guess.was_winning_guess,
+                                timestamp: guess.timestamp.clone(),
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+                board: state
+                    .official_board
+                    .iter()
+                    .map(|guess| StoredGuess {
+                        word: guess.word.clone(),
+                        player_id: guess.player_id.clone(),
+                        letters: guess
+                            .letters
+                            .iter()
+                            .map(|letter| StoredLetter {
+                                letter: letter.letter.clone(),
+                                status: match letter.status {
+                                    LetterStatus::Correct => 'c',
+                                    LetterStatus::Present => 'p',
+                                    LetterStatus::Absent => 'a',
+                                },
+                                position: letter.position,
+                            })
+                            .collect(),
+                        points: guess.points_earned,
+                        base_points: guess.breakdown.base_points,
+                        catch_up_bonus: guess.breakdown.catch_up_bonus,
+                        timestamp: guess.timestamp.clone(),
+                    })
+                    .collect(),
+                round_winner: state.current_winner.clone(),
+                created_at: state.created_at.clone(),
+                point_threshold: state.point_threshold,
+                teams: state
+                    .teams
+                    .iter()
+                    .map(|team| StoredTeam {
+                        id: team.id,
+                        name: team.name.clone(),
+                        player_ids: team.player_ids.clone(),
+                        points: team.points,
+                    })
+                    .collect(),
+                campaign: state.campaign.as_ref().map(|campaign| StoredCampaign {
+                    words_solved: campaign.words_solved,
+                    combo: campaign.combo,
+                    combo_player: campaign.combo_player.clone(),
+                    best_combo: campaign.best_combo,
+                }),
+            }
+        }
+    }
+
+    impl From<StoredGame> for GameSnapshot {
+        fn from(stored: StoredGame) -> Self {
+            let state = GameState {
+                id: stored.id,
+                word: stored.masked_word,
+                word_length: stored.word_length,
+                current_round: stored.round,
+                status: match stored.status {
+                    StoredStatus::Queuing => GameStatus::Queuing,
+                    StoredStatus::Starting => GameStatus::Starting,
+                    StoredStatus::Active => GameStatus::Active,
+                    StoredStatus::Paused => GameStatus::Paused,
+                    StoredStatus::Completed => GameStatus::Completed,
+                    StoredStatus::Abandoned => GameStatus::Abandoned,
+                    StoredStatus::TimedOut => GameStatus::TimedOut,
+                },
+                current_phase: match stored.phase {
+                    StoredPhase::Waiting => GamePhase::Waiting,
+                    StoredPhase::Countdown => GamePhase::Countdown,
+                    StoredPhase::Guessing => GamePhase::Guessing,
+                    StoredPhase::IndividualGuess => GamePhase::IndividualGuess,
+                    StoredPhase::Intermission => GamePhase::Intermission,
+                    StoredPhase::GameOver => GamePhase::GameOver,
+                },
+                players: stored
+                    .players
+                    .into_iter()
+                    .map(|player| Player {
+                        user_id: player.user_id,
+                        display_name: player.display_name,
+                        points: player.points,
+                        guess_history: player
+                            .guesses
+                            .into_iter()
+                            .map(|guess| PersonalGuess {
+                                word: guess.word,
+                                points_earned: guess.points,
+                                was_winning_guess: guess.won_round,
+                                timestamp: guess.timestamp,
+                                assist_points: guess.assist_points,
+                            })
+                            .collect(),
+                        is_connected: player.connected,
+                        avatar: None, // Profiles are loaded from the users table
+                    })
+                    .collect(),
+                official_board: stored
+                    .board
+                    .into_iter()
+                    .map(|guess| GuessResult {
+                        word: guess.word,
+                        player_id: guess.player_id,
+                        letters: guess
+                            .letters
+                            .into_iter()
+                            .map(|letter| LetterResult {
+                                letter: letter.letter,
+                                status: match letter.status {
+                                    'c' => LetterStatus::Correct,
+                                    'p' => LetterStatus::Present,
+                                    _ => LetterStatus::Absent,
+                                },
+                                position: letter.position,
+                            })
+                            .collect(),
+                        points_earned: guess.points,
+                        timestamp: guess.timestamp,
+                        breakdown: ScoreBreakdown {
+                            base_points: guess.base_points,
+                            catch_up_bonus: guess.catch_up_bonus,
+                        },
+                    })
+                    .collect(),
+                current_winner: stored.round_winner,
+                created_at: stored.created_at,
+                point_threshold: stored.point_threshold,
+                teams: stored
+                    .teams
+                    .into_iter()
+                    .map(|team| Team {
+                        id: team.id,
+                        name: team.name,
+                        player_ids: team.player_ids,
+                        points: team.points,
+                    })
+                    .collect(),
+                campaign: stored.campaign.map(|campaign| CampaignProgress {
+                    words_solved: campaign.words_solved,
+                    combo: campaign.combo,
+                    combo_player: campaign.combo_player,
+                    best_combo: campaign.best_combo,
+                }),
+                // A rule rather than state; restored games apply their rules again
+                hide_scores_until_end: false,
+            };
+
+            GameSnapshot {
+                state,
+                target_word: stored.target_word,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A version 1 snapshot exactly as an older build wrote it. Kept verbatim
+    /// so changes to the in-memory types that would break stored games fail
+    /// here instead.
+    const V1_SNAPSHOT: &str = r#"{
+        "version": 1,
+        "data": {
+            "id": "game-1",
+            "target_word": "hello",
+            "masked_word": "*****",
+            "word_length": 5,
+            "round": 2,
+            "status": "active",
+            "phase": "individual_guess",
+            "players": [
+                {
+                    "user_id": "alice",
+                    "display_name": "Alice",
+                    "points": 5,
+                    "connected": true,
+                    "guesses": [
+                        {
+                            "word": "hotel",
+                            "points": 5,
+                            "assist_points": 0,
+                            "won_round": true,
+                            "timestamp": "2024-01-01T00:00:00Z"
+                        }
+                    ]
+                },
+                {
+                    "user_id": "bob",
+                    "display_name": "Bob",
+                    "points": 0,
+                    "connected": false,
+                    "guesses": []
+                }
+            ],
+            "board": [
+                {
+                    "word": "hotel",
+                    "player_id": "alice",
+                    "letters": [
+                        {"letter": "h", "status": "c", "position": 0},
+                        {"letter": "o", "status": "p", "position": 1},
+                        {"letter": "t", "status": "a", "position": 2},
+                        {"letter": "e", "status": "p", "position": 3},
+                        {"letter": "l", "status": "p", "position": 4}
+                    ],
+                    "points": 5,
+                    "base_points": 5,
+                    "catch_up_bonus": 0,
+                    "timestamp": "2024-01-01T00:00:00Z"
+                }
+            ],
+            "round_winner": "alice",
+            "created_at": "2024-01-01T00:00:00Z",
+            "point_threshold": 25,
+            "teams": [],
+            "campaign": null
+        }
+    }"#;
+
+    #[test]
+    fn test_v1_snapshot_decodes() {
+        let snapshot = decode(V1_SNAPSHOT).unwrap();
+        let state = &snapshot.state;
+
+        assert_eq!(snapshot.target_word, "hello");
+        assert_eq!(state.current_round, 2);
+        assert_eq!(state.status, GameStatus::Active);
+        assert_eq!(state.current_phase, GamePhase::IndividualGuess);
+        assert_eq!(state.current_winner.as_deref(), Some("alice"));
+        assert_eq!(state.players.len(), 2);
+        assert_eq!(state.players[0].guess_history[0].word, "hotel");
+        assert!(!state.players[1].is_connected);
+        assert!(state.players[0].avatar.is_none());
+        assert_eq!(state.official_board[0].letters.len(), 5);
+        assert!(matches!(
+            state.official_board[0].letters[1].status,
+            LetterStatus::Present
+        ));
+        assert_eq!(state.official_board[0].breakdown.total(), 5);
+    }
+
+    #[test]
+    fn test_snapshot_round_trips() {
+        let snapshot = decode(V1_SNAPSHOT).unwrap();
+        let encoded = encode(&snapshot).unwrap();
+        assert!(encoded.contains(&format!("\"version\":{}", SNAPSHOT_VERSION)));
+
+        let decoded = decode(&encoded).unwrap();
+        assert_eq!(decoded.target_word, snapshot.target_word);
+        assert_eq!(
+            serde_json::to_value(&decoded.state).unwrap(),
+            serde_json::to_value(&snapshot.state).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_unknown_version_is_refused() {
+        let error = decode(r#"{"version": 99, "data": {}}"#).unwrap_err();
+        assert!(error.to_string().contains("version 99"), "Got {}", error);
+    }
+}
@@ -0,0 +1,119 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{error, warn};
+
+use game_persistence::repositories::AbuseRepository;
+use game_persistence::repositories::abuse_repository::AbuseKind;
+use game_types::PlayerId;
+
+/// How persisted abuse events turn into penalties. A penalty lasts until
+/// enough of the user's events have aged out of the window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AbusePolicy {
+    /// How long an event counts against the user
+    pub window: Duration,
+    /// Events within the window that keep a user out of the queue
+    pub cooldown_after: usize,
+    /// Events within the window that refuse authentication, if any
+    pub ban_after: Option<usize>,
+}
+
+/// Records abuse events by user so penalties survive reconnects and restarts
+pub struct AbuseTracker {
+    repository: Arc<AbuseRepository>,
+    policy: AbusePolicy,
+}
+
+impl AbuseTracker {
+    pub fn new(repository: Arc<AbuseRepository>, policy: AbusePolicy) -> Self {
+        Self { repository, policy }
+    }
+
+    pub async fn record(&self, user_id: &PlayerId, kind: AbuseKind) {
+        warn!(
+            "Recording {} abuse event for user {}",
+            kind.as_str(),
+            user_id
+        );
+        if let Err(e) = self.repository.record(user_id, kind).await {
+            error!("Failed to record abuse event for {}: {}", user_id, e);
+        }
+    }
+
+    /// Time left before a user may join the queue again
+    pub async fn cooldown_remaining(&self, user_id: &PlayerId) -> Option<Duration> {
+        self.penalty_remaining(user_id, self.policy.cooldown_after)
+            .await
+    }
+
+    /// Time left before a user may authenticate again
+    pub async fn ban_remaining(&self, user_id: &PlayerId) -> Option<Duration> {
+        let ban_after = self.policy.ban_after?;
+        self.penalty_remaining(user_id, ban_after).await
+    }
+
+    /// Time until the user has fewer than `threshold` events in the window
+    async fn penalty_remaining(&self, user_id: &PlayerId, threshold: usize) -> Option<Duration> {
+        let window = chrono::Duration::from_std(self.policy.window).ok()?;
+        let now = chrono::Utc::now();
+        let times = match self
+            .repository
+            .event_times_since(user_id, now - window)
+            .await
+        {
+            Ok(times) => times,
+            Err(e) => {
+                error!("Failed to look up abuse events for {}: {}", user_id, e);
+                return None;
+            }
+        };
+
+        if threshold == 0 || times.len() < threshold {
+            return None;
+        }
+
+        // The penalty lifts once this event leaves the window
+        let lifting = times[times.len() - threshold];
+        (lifting + window - now).to_std().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use migration::MigratorTrait;
+
+    async fn tracker(policy: AbusePolicy) -> AbuseTracker {
+        let db = game_persistence::connection::connect_to_memory_database()
+            .await
+            .unwrap();
+        migration::Migrator::up(&db, None).await.unwrap();
+        AbuseTracker::new(Arc::new(AbuseRepository::new(db)), policy)
+    }
+
+    #[tokio::test]
+    async fn test_penalties_follow_event_count() {
+        let tracker = tracker(AbusePolicy {
+            window: Duration::from_secs(600),
+            cooldown_after: 2,
+            ban_after: Some(3),
+        })
+        .await;
+        let user_id = "user-1".to_string();
+
+        tracker.record(&user_id, AbuseKind::RateLimited).await;
+        assert_eq!(tracker.cooldown_remaining(&user_id).await, None);
+
+        tracker.record(&user_id, AbuseKind::Abandoned).await;
+        let remaining = tracker.cooldown_remaining(&user_id).await.unwrap();
+        assert!(remaining > Duration::from_secs(590) && remaining <= Duration::from_secs(600));
+        assert_eq!(tracker.ban_remaining(&user_id).await, None);
+
+        tracker.record(&user_id, AbuseKind::AuthFailure).await;
+        assert!(tracker.ban_remaining(&user_id).await.is_some());
+        assert_eq!(
+            tracker.cooldown_remaining(&"user-2".to_string()).await,
+            None
+        );
+    }
+}
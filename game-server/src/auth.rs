@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
@@ -9,7 +9,9 @@ use serde::{Deserialize, Serialize};
 use serde_json;
 use tokio::sync::RwLock;
 
-use game_types::User;
+use game_types::{Role, User};
+
+use crate::websocket::connection::ConnectionId;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MicrosoftJwtClaims {
@@ -22,8 +24,49 @@ pub struct MicrosoftJwtClaims {
     pub email: Option<String>,              // User email - optional in some scenarios
     pub name: Option<String>,               // Display name - optional
     pub preferred_username: Option<String>, // Username - optional
+    pub jti: Option<String>,                // Token ID - optional
+    pub uti: Option<String>,                // Azure AD token ID - optional
+    #[serde(default)]
+    pub roles: Vec<String>, // App roles assigned in Azure AD - optional
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoogleJwtClaims {
+    pub aud: String,           // Audience
+    pub iss: String,           // Issuer
+    pub iat: u64,              // Issued at
+    pub exp: u64,              // Expiry
+    pub sub: String,           // Subject (Google account ID)
+    pub email: Option<String>, // User email - needs the email scope
+    pub name: Option<String>,  // Display name - needs the profile scope
+}
+
+/// Issuers Google signs ID tokens with
+const GOOGLE_ISSUERS: [&str; 2] = ["https://accounts.google.com", "accounts.google.com"];
+const GOOGLE_JWKS_URL: &str = "https://www.googleapis.com/oauth2/v3/certs";
+
+/// Who issued a token, and so whose keys and claims it is checked against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IdentityProvider {
+    Microsoft,
+    Google,
 }
 
+impl IdentityProvider {
+    /// The provider behind an `iss` claim. Anything not from Google is
+    /// treated as Microsoft, whose issuers vary by tenant.
+    pub fn from_issuer(issuer: &str) -> Self {
+        if GOOGLE_ISSUERS.contains(&issuer) {
+            IdentityProvider::Google
+        } else {
+            IdentityProvider::Microsoft
+        }
+    }
+}
+
+/// Decoding keys by provider and key ID, with when each was fetched
+type JwksCache = HashMap<(IdentityProvider, String), (DecodingKey, SystemTime)>;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JwksKey {
     pub kty: String,
@@ -42,22 +85,46 @@ pub struct JwksResponse {
     pub keys: Vec<JwksKey>,
 }
 
+/// Optional hardening checks applied on top of signature and expiry
+/// validation. The default policy enables neither check.
+#[derive(Debug, Clone, Default)]
+pub struct TokenPolicy {
+    /// Reject tokens whose `iat` is further in the past than this
+    pub max_token_age: Option<Duration>,
+    /// Reject a token presented by a second connection within this window
+    pub replay_window: Option<Duration>,
+}
+
 pub struct AuthService {
     client: Client,
-    jwks_cache: Arc<RwLock<HashMap<String, (DecodingKey, SystemTime)>>>,
+    jwks_cache: Arc<RwLock<JwksCache>>,
     tenant_id: String,
-    client_id: String,
+    client_ids: Vec<String>,        // Accepted token audiences
+    google_client_ids: Vec<String>, // Empty turns Google sign-in off
     dev_mode: bool,
+    token_policy: TokenPolicy,
+    seen_tokens: Arc<RwLock<HashMap<String, (ConnectionId, SystemTime)>>>,
+    admin_user_ids: HashSet<String>,
 }
 
 impl AuthService {
     pub fn new(tenant_id: String, client_id: String) -> Self {
+        Self::new_with_audiences(tenant_id, vec![client_id])
+    }
+
+    /// Accept tokens issued to any of `client_ids`, e.g. when separate web
+    /// and mobile app registrations front the same server
+    pub fn new_with_audiences(tenant_id: String, client_ids: Vec<String>) -> Self {
         Self {
             client: Client::new(),
             jwks_cache: Arc::new(RwLock::new(HashMap::new())),
             tenant_id,
-            client_id,
+            client_ids,
+            google_client_ids: Vec::new(),
             dev_mode: false,
+            token_policy: TokenPolicy::default(),
+            seen_tokens: Arc::new(RwLock::new(HashMap::new())),
+            admin_user_ids: HashSet::new(),
         }
     }
 
@@ -66,41 +133,186 @@ impl AuthService {
             client: Client::new(),
             jwks_cache: Arc::new(RwLock::new(HashMap::new())),
             tenant_id: "dev".to_string(),
-            client_id: "dev".to_string(),
+            client_ids: vec!["dev".to_string()],
+            google_client_ids: Vec::new(),
             dev_mode: true,
+            token_policy: TokenPolicy::default(),
+            seen_tokens: Arc::new(RwLock::new(HashMap::new())),
+            admin_user_ids: HashSet::new(),
         }
     }
 
-    pub async fn validate_token(&self, token: &str) -> Result<User, AuthError> {
-        if self.dev_mode {
-            return self.validate_dev_token(token).await;
+    pub fn with_token_policy(mut self, token_policy: TokenPolicy) -> Self {
+        self.token_policy = token_policy;
+        self
+    }
+
+    /// Also accept Google ID tokens issued to any of `client_ids`
+    pub fn with_google_client_ids(mut self, client_ids: Vec<String>) -> Self {
+        self.google_client_ids = client_ids;
+        self
+    }
+
+    /// Users allowed to call the admin routes
+    pub fn with_admin_user_ids(mut self, admin_user_ids: HashSet<String>) -> Self {
+        self.admin_user_ids = admin_user_ids;
+        self
+    }
+
+    pub fn is_admin(&self, user_id: &str) -> bool {
+        self.admin_user_ids.contains(user_id)
+    }
+
+    /// Whether tokens are accepted without verification, for local testing
+    pub fn is_dev_mode(&self) -> bool {
+        self.dev_mode
+    }
+
+    /// Roles named by a token's `roles` claim; unknown roles are ignored
+    fn roles_from_claims(claims: &[String]) -> Vec<Role> {
+        let mut roles: Vec<Role> = claims
+            .iter()
+            .filter_map(|claim| Role::from_claim(claim))
+            .collect();
+        roles.dedup();
+        roles
+    }
+
+    /// Validate a token presented to open a WebSocket session. On top of
+    /// `validate_token`, this rejects a token already used by a different
+    /// connection within the configured replay window.
+    pub async fn validate_connection_token(
+        &self,
+        token: &str,
+        connection_id: ConnectionId,
+    ) -> Result<User, AuthError> {
+        let user = self.validate_token(token).await?;
+
+        if let Some(window) = self.token_policy.replay_window {
+            let token_id = Self::token_id(token);
+            let now = SystemTime::now();
+            let mut seen = self.seen_tokens.write().await;
+            seen.retain(|_, (_, seen_at)| {
+                now.duration_since(*seen_at).unwrap_or(Duration::ZERO) < window
+            });
+
+            match seen.get(&token_id) {
+                Some((seen_by, _)) if *seen_by != connection_id => {
+                    tracing::warn!(
+                        "Rejecting replayed token for user {} on connection {}",
+                        user.id,
+                        connection_id
+                    );
+                    return Err(AuthError::TokenReplayed);
+                }
+                _ => {
+                    seen.insert(token_id, (connection_id, now));
+                }
+            }
         }
 
-        // Decode header to get key ID
-        let header = decode_header(token).map_err(|e| {
-            tracing::warn!("Failed to decode JWT header: {:?}", e);
-            AuthError::InvalidToken
-        })?;
-        let kid = header.kid.ok_or_else(|| {
-            tracing::warn!("JWT header missing 'kid' field");
-            AuthError::InvalidToken
-        })?;
+        Ok(user)
+    }
 
-        // Get or fetch the public key
-        tracing::debug!("Fetching decoding key for kid: {}", kid);
-        let decoding_key = self.get_decoding_key(&kid).await?;
+    /// Forget the tokens `connection_id` presented once it closes, so the
+    /// same client can reconnect with its still-valid token
+    pub async fn release_connection(&self, connection_id: ConnectionId) {
+        if self.token_policy.replay_window.is_none() {
+            return;
+        }
+        self.seen_tokens
+            .write()
+            .await
+            .retain(|_, (seen_by, _)| *seen_by != connection_id);
+    }
 
-        // Validate the token
-        let mut validation = Validation::new(Algorithm::RS256);
-        // Use our app's client ID as the audience (not Microsoft Graph)
-        validation.set_audience(&[&self.client_id]);
+    /// Identify a token for replay detection: its `jti`/`uti` claim when
+    /// present, otherwise the raw token
+    fn token_id(token: &str) -> String {
+        Self::peek_claims::<MicrosoftJwtClaims>(token)
+            .and_then(|claims| claims.jti.or(claims.uti))
+            .unwrap_or_else(|| token.to_string())
+    }
+
+    /// Read a token's payload without verifying it, for routing and
+    /// bookkeeping only
+    fn peek_claims<T: serde::de::DeserializeOwned>(token: &str) -> Option<T> {
+        token
+            .split('.')
+            .nth(1)
+            .and_then(|payload| {
+                base64::engine::general_purpose::URL_SAFE_NO_PAD
+                    .decode(payload.trim_end_matches('='))
+                    .ok()
+            })
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+    }
+
+    /// The provider a token should be validated against, going by its
+    /// unverified issuer. Google tokens are only recognised when Google
+    /// sign-in is configured.
+    fn provider_for(&self, token: &str) -> IdentityProvider {
+        #[derive(Deserialize)]
+        struct IssuerClaim {
+            iss: String,
+        }
+
+        match Self::peek_claims::<IssuerClaim>(token) {
+            Some(claim) if !self.google_client_ids.is_empty() => {
+                IdentityProvider::from_issuer(&claim.iss)
+            }
+            _ => IdentityProvider::Microsoft,
+        }
+    }
+
+    /// Reject tokens issued implausibly long ago, if the policy asks for it
+    fn check_token_age(&self, iat: u64) -> Result<(), AuthError> {
+        let Some(max_age) = self.token_policy.max_token_age else {
+            return Ok(());
+        };
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        if now.saturating_sub(iat) > max_age.as_secs() {
+            tracing::warn!("Token too old: iat={}, now={}", iat, now);
+            return Err(AuthError::TokenTooOld);
+        }
+
+        Ok(())
+    }
+
+    /// Validate a token and resolve the user's roles from its claims and the
+    /// configured admin allowlist
+    pub async fn validate_token(&self, token: &str) -> Result<User, AuthError> {
+        let mut user = if self.dev_mode {
+            self.validate_dev_token(token).await?
+        } else {
+            match self.provider_for(token) {
+                IdentityProvider::Microsoft => self.validate_microsoft_token(token).await?,
+                IdentityProvider::Google => self.validate_google_token(token).await?,
+            }
+        };
+
+        if self.is_admin(&user.id) && !user.has_role(Role::Admin) {
+            user.roles.push(Role::Admin);
+        }
+
+        Ok(user)
+    }
+
+    /// Checks applied to a Microsoft token: the audience must be one of our
+    /// client IDs, and the issuer must belong to the configured tenant
+    fn token_validation(&self, algorithm: Algorithm) -> Validation {
+        let mut validation = Validation::new(algorithm);
+        // Use our app's client IDs as the audience (not Microsoft Graph)
+        validation.set_audience(&self.client_ids);
 
         // Handle issuer validation based on tenant type
-        let is_common_tenant = self.tenant_id == "common";
-        if is_common_tenant {
-            // For common tenant, use dangerous validation without issuer check
-            validation = Validation::new(Algorithm::RS256);
-            validation.set_audience(&[&self.client_id]);
+        if self.tenant_id == "common" {
+            // For common tenant, skip the issuer check here
             validation.validate_exp = true;
             validation.validate_nbf = true;
             validation.validate_aud = true;
@@ -113,8 +325,34 @@ impl AuthService {
             validation.set_issuer(&[&v1_issuer, &v2_issuer]);
             tracing::debug!("Accepted issuers: {} and {}", v1_issuer, v2_issuer);
         }
+        validation
+    }
+
+    async fn validate_microsoft_token(&self, token: &str) -> Result<User, AuthError> {
+        // Decode header to get key ID
+        let header = decode_header(token).map_err(|e| {
+            tracing::warn!("Failed to decode JWT header: {:?}", e);
+            AuthError::InvalidToken
+        })?;
+        let kid = header.kid.ok_or_else(|| {
+            tracing::warn!("JWT header missing 'kid' field");
+            AuthError::InvalidToken
+        })?;
 
-        tracing::debug!("Validating token with audience: {}", self.client_id);
+        // Get or fetch the public key
+        tracing::debug!("Fetching decoding key for kid: {}", kid);
+        let decoding_key = self
+            .get_decoding_key(IdentityProvider::Microsoft, &kid)
+            .await?;
+
+        // Validate the token
+        let is_common_tenant = self.tenant_id == "common";
+        let validation = self.token_validation(Algorithm::RS256);
+
+        tracing::debug!(
+            "Validating token with audiences: {}",
+            self.client_ids.join(", ")
+        );
 
         let token_data =
             decode::<MicrosoftJwtClaims>(token, &decoding_key, &validation).map_err(|e| {
@@ -163,6 +401,7 @@ impl AuthService {
             tracing::warn!("Token expired: exp={}, now={}", claims.exp, now);
             return Err(AuthError::TokenExpired);
         }
+        self.check_token_age(claims.iat)?;
 
         // Create user from claims
         // Extract tenant ID from issuer to create compound ID like MSAL homeAccountId
@@ -174,7 +413,8 @@ impl AuthService {
         // Extract tenant ID from issuer URL to match MSAL homeAccountId format
         let tenant_id = if let Some(captures) = regex::Regex::new(r"https://[^/]+/([^/]+)/?")
             .unwrap()
-            .captures(&claims.iss) {
+            .captures(&claims.iss)
+        {
             captures.get(1).map(|m| m.as_str().to_string())
         } else {
             None
@@ -188,8 +428,12 @@ impl AuthService {
             base_user_id.clone()
         };
 
-        tracing::debug!("Using compound user ID: {} (base: {}, tenant: {:?})", 
-                       user_id, base_user_id, tenant_id);
+        tracing::debug!(
+            "Using compound user ID: {} (base: {}, tenant: {:?})",
+            user_id,
+            base_user_id,
+            tenant_id
+        );
 
         Ok(User {
             id: user_id,
@@ -201,14 +445,85 @@ impl AuthService {
             total_wins: 0,
             total_games: 0,
             created_at: chrono::Utc::now().to_string(),
+            avatar: None,
+            roles: Self::roles_from_claims(&claims.roles),
         })
     }
 
-    async fn get_decoding_key(&self, kid: &str) -> Result<DecodingKey, AuthError> {
+    /// Checks applied to a Google ID token: the audience must be one of our
+    /// Google client IDs, and Google must have issued it
+    fn google_token_validation(&self, algorithm: Algorithm) -> Validation {
+        let mut validation = Validation::new(algorithm);
+        validation.set_audience(&self.google_client_ids);
+        validation.set_issuer(&GOOGLE_ISSUERS);
+        validation
+    }
+
+    async fn validate_google_token(&self, token: &str) -> Result<User, AuthError> {
+        let header = decode_header(token).map_err(|e| {
+            tracing::warn!("Failed to decode JWT header: {:?}", e);
+            AuthError::InvalidToken
+        })?;
+        let kid = header.kid.ok_or_else(|| {
+            tracing::warn!("JWT header missing 'kid' field");
+            AuthError::InvalidToken
+        })?;
+
+        let decoding_key = self
+            .get_decoding_key(IdentityProvider::Google, &kid)
+            .await?;
+        let validation = self.google_token_validation(Algorithm::RS256);
+        let claims = decode::<GoogleJwtClaims>(token, &decoding_key, &validation)
+            .map_err(|e| {
+                tracing::warn!("Google token validation failed: {:?}", e);
+                AuthError::InvalidToken
+            })?
+            .claims;
+        self.check_token_age(claims.iat)?;
+
+        Ok(Self::google_user(claims))
+    }
+
+    /// The user a verified Google ID token describes. Google grants no app
+    /// roles; admins come from the allowlist alone.
+    fn google_user(claims: GoogleJwtClaims) -> User {
+        User {
+            id: claims.sub,
+            email: claims
+                .email
+                .unwrap_or_else(|| "unknown@example.com".to_string()),
+            display_name: claims.name.unwrap_or_else(|| "Unknown User".to_string()),
+            total_points: 0,
+            total_wins: 0,
+            total_games: 0,
+            created_at: chrono::Utc::now().to_string(),
+            avatar: None,
+            roles: Vec::new(),
+        }
+    }
+
+    /// Where a provider publishes the keys it signs tokens with
+    fn jwks_url(&self, provider: IdentityProvider) -> String {
+        match provider {
+            IdentityProvider::Microsoft => format!(
+                "https://login.microsoftonline.com/{}/discovery/v2.0/keys",
+                self.tenant_id
+            ),
+            IdentityProvider::Google => GOOGLE_JWKS_URL.to_string(),
+        }
+    }
+
+    async fn get_decoding_key(
+        &self,
+        provider: IdentityProvider,
+        kid: &str,
+    ) -> Result<DecodingKey, AuthError> {
+        let cache_key = (provider, kid.to_string());
+
         // Check cache first
         {
             let cache = self.jwks_cache.read().await;
-            if let Some((key, cached_time)) = cache.get(kid) {
+            if let Some((key, cached_time)) = cache.get(&cache_key) {
                 // Cache for 1 hour
                 let elapsed = cached_time.elapsed().unwrap_or(Duration::from_secs(3600));
                 if elapsed < Duration::from_secs(3600) {
@@ -230,16 +545,9 @@ impl AuthService {
             }
         }
 
-        // Fetch from Microsoft
-        let jwks_url = format!(
-            "https://login.microsoftonline.com/{}/discovery/v2.0/keys",
-            self.tenant_id
-        );
-        tracing::debug!(
-            "Fetching JWKS from tenant {} at: {}",
-            self.tenant_id,
-            jwks_url
-        );
+        // Fetch from the provider
+        let jwks_url = self.jwks_url(provider);
+        tracing::debug!("Fetching {:?} JWKS at: {}", provider, jwks_url);
 
         let response = self.client.get(&jwks_url).send().await.map_err(|e| {
             tracing::warn!("Failed to fetch JWKS: {:?}", e);
@@ -266,11 +574,7 @@ impl AuthService {
         );
 
         let jwks_key = jwks.keys.iter().find(|key| key.kid == kid).ok_or_else(|| {
-            tracing::warn!(
-                "Key with kid '{}' not found in JWKS from tenant {}",
-                kid,
-                self.tenant_id
-            );
+            tracing::warn!("Key with kid '{}' not found in JWKS at {}", kid, jwks_url);
             tracing::warn!(
                 "This means the token was signed by a different tenant or the key has rotated"
             );
@@ -278,9 +582,9 @@ impl AuthService {
         })?;
 
         tracing::debug!(
-            "Found matching key with kid '{}' in JWKS from tenant {}",
+            "Found matching key with kid '{}' in JWKS at {}",
             kid,
-            self.tenant_id
+            jwks_url
         );
 
         // Convert to DecodingKey
@@ -320,7 +624,7 @@ impl AuthService {
         // Cache the key
         {
             let mut cache = self.jwks_cache.write().await;
-            cache.insert(kid.to_string(), (decoding_key.clone(), SystemTime::now()));
+            cache.insert(cache_key, (decoding_key.clone(), SystemTime::now()));
         }
 
         Ok(decoding_key)
@@ -363,8 +667,9 @@ impl AuthService {
                     tracing::warn!("Failed to parse JWT claims in dev mode: {:?}", e);
                     AuthError::InvalidToken
                 })?;
+            self.check_token_age(claims.iat)?;
 
-            // Create user from claims (no validation in dev mode)
+            // Create user from claims (no signature validation in dev mode)
             // Use oid (object ID) if available, fallback to sub, then generate new UUID
             let base_user_id = claims
                 .oid
@@ -384,6 +689,8 @@ impl AuthService {
                 total_wins: 0,
                 total_games: 0,
                 created_at: chrono::Utc::now().to_string(),
+                avatar: None,
+                roles: Self::roles_from_claims(&claims.roles),
             })
         } else {
             // Fallback for non-JWT format (for backwards compatibility)
@@ -407,6 +714,8 @@ impl AuthService {
                     total_wins: 0,
                     total_games: 0,
                     created_at: chrono::Utc::now().to_string(),
+                    avatar: None,
+                    roles: Vec::new(),
                 })
             } else {
                 // Simple string format: "user_id:email:name"
@@ -420,6 +729,8 @@ impl AuthService {
                         total_wins: 0,
                         total_games: 0,
                         created_at: chrono::Utc::now().to_string(),
+                        avatar: None,
+                        roles: Vec::new(),
                     })
                 } else {
                     Err(AuthError::InvalidToken)
@@ -435,6 +746,10 @@ pub enum AuthError {
     InvalidToken,
     #[error("Token expired")]
     TokenExpired,
+    #[error("Token too old")]
+    TokenTooOld,
+    #[error("Token already in use by another connection")]
+    TokenReplayed,
     #[error("Failed to fetch JWKS")]
     JwksFetchError,
     #[error("Key not found")]
@@ -462,7 +777,7 @@ mod tests {
         let auth_service = AuthService::new("test-tenant".to_string(), "test-client".to_string());
 
         assert_eq!(auth_service.tenant_id, "test-tenant");
-        assert_eq!(auth_service.client_id, "test-client");
+        assert_eq!(auth_service.client_ids, vec!["test-client".to_string()]);
     }
 
     #[tokio::test]
@@ -473,4 +788,272 @@ mod tests {
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), AuthError::InvalidToken));
     }
+
+    fn dev_jwt(iat: u64, jti: &str) -> String {
+        let claims = serde_json::json!({
+            "aud": "dev",
+            "iss": "dev",
+            "iat": iat,
+            "exp": iat + 7200,
+            "oid": "user-1",
+            "name": "Alice",
+            "jti": jti,
+        });
+        let payload = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(claims.to_string());
+        format!("header.{}.signature", payload)
+    }
+
+    fn now_secs() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+
+    #[tokio::test]
+    async fn test_old_token_rejected_when_max_age_set() {
+        let old_token = dev_jwt(now_secs() - 3600, "old-token");
+
+        // Lenient by default
+        let auth_service = AuthService::new_dev_mode();
+        assert!(auth_service.validate_token(&old_token).await.is_ok());
+
+        let auth_service = AuthService::new_dev_mode().with_token_policy(TokenPolicy {
+            max_token_age: Some(Duration::from_secs(600)),
+            ..Default::default()
+        });
+        let result = auth_service.validate_token(&old_token).await;
+        assert!(matches!(result, Err(AuthError::TokenTooOld)));
+
+        let fresh_token = dev_jwt(now_secs(), "fresh-token");
+        assert!(auth_service.validate_token(&fresh_token).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_replayed_token_rejected_when_window_set() {
+        let token = dev_jwt(now_secs(), "shared-token");
+        let first = ConnectionId::new();
+        let second = ConnectionId::new();
+
+        // Lenient by default
+        let auth_service = AuthService::new_dev_mode();
+        assert!(
+            auth_service
+                .validate_connection_token(&token, first)
+                .await
+                .is_ok()
+        );
+        assert!(
+            auth_service
+                .validate_connection_token(&token, second)
+                .await
+                .is_ok()
+        );
+
+        let auth_service = AuthService::new_dev_mode().with_token_policy(TokenPolicy {
+            replay_window: Some(Duration::from_secs(60)),
+            ..Default::default()
+        });
+        assert!(
+            auth_service
+                .validate_connection_token(&token, first)
+                .await
+                .is_ok()
+        );
+
+        // The same connection may re-authenticate, e.g. to force a session
+        assert!(
+            auth_service
+                .validate_connection_token(&token, first)
+                .await
+                .is_ok()
+        );
+
+        let result = auth_service.validate_connection_token(&token, second).await;
+        assert!(matches!(result, Err(AuthError::TokenReplayed)));
+    }
+
+    #[tokio::test]
+    async fn test_token_reusable_after_its_connection_closes() {
+        let token = dev_jwt(now_secs(), "reconnect-token");
+        let auth_service = AuthService::new_dev_mode().with_token_policy(TokenPolicy {
+            replay_window: Some(Duration::from_secs(60)),
+            ..Default::default()
+        });
+
+        let dropped = ConnectionId::new();
+        assert!(
+            auth_service
+                .validate_connection_token(&token, dropped)
+                .await
+                .is_ok()
+        );
+
+        // The client's socket drops and it reconnects with the same token
+        auth_service.release_connection(dropped).await;
+        let reconnected = ConnectionId::new();
+        assert!(
+            auth_service
+                .validate_connection_token(&token, reconnected)
+                .await
+                .is_ok()
+        );
+
+        // The new connection holds the token again
+        let result = auth_service
+            .validate_connection_token(&token, ConnectionId::new())
+            .await;
+        assert!(matches!(result, Err(AuthError::TokenReplayed)));
+    }
+
+    #[tokio::test]
+    async fn test_roles_from_claims_and_admin_allowlist() {
+        let auth_service = AuthService::new_dev_mode()
+            .with_admin_user_ids(["listed-admin".to_string()].into_iter().collect());
+
+        let claims = serde_json::json!({
+            "aud": "dev",
+            "iss": "dev",
+            "iat": now_secs(),
+            "exp": now_secs() + 7200,
+            "oid": "claimed-admin",
+            "roles": ["Admin", "Reader"],
+        });
+        let payload = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(claims.to_string());
+        let user = auth_service
+            .validate_token(&format!("header.{}.signature", payload))
+            .await
+            .unwrap();
+        assert_eq!(user.roles, vec![Role::Admin]);
+
+        let user = auth_service
+            .validate_token("listed-admin:admin@example.com:Admin")
+            .await
+            .unwrap();
+        assert!(user.has_role(Role::Admin));
+
+        let user = auth_service
+            .validate_token(&dev_jwt(now_secs(), "plain-token"))
+            .await
+            .unwrap();
+        assert!(user.roles.is_empty());
+    }
+
+    #[test]
+    fn test_any_configured_audience_is_accepted() {
+        let auth_service = AuthService::new_with_audiences(
+            "test-tenant".to_string(),
+            vec!["web-client".to_string(), "mobile-client".to_string()],
+        );
+        let validation = auth_service.token_validation(Algorithm::HS256);
+        let secret = b"test-secret";
+
+        let token_for = |aud: &str| {
+            let claims = serde_json::json!({
+                "aud": aud,
+                "iss": "https://login.microsoftonline.com/test-tenant/v2.0",
+                "exp": now_secs() + 3600,
+            });
+            jsonwebtoken::encode(
+                &jsonwebtoken::Header::new(Algorithm::HS256),
+                &claims,
+                &jsonwebtoken::EncodingKey::from_secret(secret),
+            )
+            .unwrap()
+        };
+        let accepts = |aud: &str| {
+            decode::<serde_json::Value>(
+                &token_for(aud),
+                &DecodingKey::from_secret(secret),
+                &validation,
+            )
+            .is_ok()
+        };
+
+        assert!(accepts("web-client"));
+        assert!(accepts("mobile-client"));
+        assert!(!accepts("other-client"));
+    }
+
+    #[test]
+    fn test_google_tokens_routed_only_when_configured() {
+        let google_token = dev_jwt_with_issuer("https://accounts.google.com");
+        let microsoft_token =
+            dev_jwt_with_issuer("https://login.microsoftonline.com/test-tenant/v2.0");
+
+        let auth_service = AuthService::new("test-tenant".to_string(), "test-client".to_string());
+        assert_eq!(
+            auth_service.provider_for(&google_token),
+            IdentityProvider::Microsoft
+        );
+
+        let auth_service = auth_service.with_google_client_ids(vec!["google-client".to_string()]);
+        assert_eq!(
+            auth_service.provider_for(&google_token),
+            IdentityProvider::Google
+        );
+        assert_eq!(
+            auth_service.provider_for(&microsoft_token),
+            IdentityProvider::Microsoft
+        );
+        assert_eq!(
+            auth_service.provider_for("not-a-jwt"),
+            IdentityProvider::Microsoft
+        );
+    }
+
+    fn dev_jwt_with_issuer(iss: &str) -> String {
+        let claims = serde_json::json!({ "iss": iss });
+        let payload = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(claims.to_string());
+        format!("header.{}.signature", payload)
+    }
+
+    #[test]
+    fn test_google_token_checks_audience_and_issuer() {
+        let auth_service = AuthService::new("test-tenant".to_string(), "test-client".to_string())
+            .with_google_client_ids(vec!["google-client".to_string()]);
+        let validation = auth_service.google_token_validation(Algorithm::HS256);
+        let secret = b"test-secret";
+
+        let accepts = |aud: &str, iss: &str| {
+            let claims = serde_json::json!({
+                "aud": aud,
+                "iss": iss,
+                "iat": now_secs(),
+                "exp": now_secs() + 3600,
+                "sub": "1234567890",
+            });
+            let token = jsonwebtoken::encode(
+                &jsonwebtoken::Header::new(Algorithm::HS256),
+                &claims,
+                &jsonwebtoken::EncodingKey::from_secret(secret),
+            )
+            .unwrap();
+            decode::<GoogleJwtClaims>(&token, &DecodingKey::from_secret(secret), &validation)
+                .is_ok()
+        };
+
+        assert!(accepts("google-client", "https://accounts.google.com"));
+        assert!(accepts("google-client", "accounts.google.com"));
+        assert!(!accepts("test-client", "https://accounts.google.com"));
+        assert!(!accepts("google-client", "https://example.com"));
+    }
+
+    #[test]
+    fn test_google_user_from_claims() {
+        let user = AuthService::google_user(GoogleJwtClaims {
+            aud: "google-client".to_string(),
+            iss: "https://accounts.google.com".to_string(),
+            iat: now_secs(),
+            exp: now_secs() + 3600,
+            sub: "1234567890".to_string(),
+            email: Some("alice@gmail.com".to_string()),
+            name: Some("Alice".to_string()),
+        });
+
+        assert_eq!(user.id, "1234567890");
+        assert_eq!(user.email, "alice@gmail.com");
+        assert_eq!(user.display_name, "Alice");
+        assert!(user.roles.is_empty());
+    }
 }
@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use game_types::{Role, User};
+use warp::Filter;
+use warp::http::StatusCode;
+
+use crate::auth::AuthService;
+
+/// Why an HTTP request was refused. Raised by the filters below and by
+/// handlers, and turned into a JSON error reply by `handle_rejection`.
+#[derive(Debug)]
+pub enum AuthRejection {
+    MissingToken,
+    InvalidToken,
+    Forbidden(&'static str),
+}
+
+impl warp::reject::Reject for AuthRejection {}
+
+impl AuthRejection {
+    fn status(&self) -> StatusCode {
+        match self {
+            AuthRejection::MissingToken | AuthRejection::InvalidToken => StatusCode::UNAUTHORIZED,
+            AuthRejection::Forbidden(_) => StatusCode::FORBIDDEN,
+        }
+    }
+
+    fn message(&self) -> &'static str {
+        match self {
+            AuthRejection::MissingToken => "Authentication required",
+            AuthRejection::InvalidToken => "Invalid authentication token",
+            AuthRejection::Forbidden(message) => message,
+        }
+    }
+}
+
+/// Extract the user behind the request's `Authorization: Bearer` token
+pub fn authenticated(
+    auth_service: Arc<AuthService>,
+) -> impl Filter<Extract = (User,), Error = warp::Rejection> + Clone {
+    warp::header::optional::<String>("authorization").and_then(move |auth_header| {
+        let auth_service = auth_service.clone();
+        async move { authenticate(auth_header, &auth_service).await }
+    })
+}
+
+/// Like `authenticated`, but also refuse users who lack `role`
+pub fn require_role(
+    auth_service: Arc<AuthService>,
+    role: Role,
+    message: &'static str,
+) -> impl Filter<Extract = (User,), Error = warp::Rejection> + Clone {
+    authenticated(auth_service).and_then(move |user: User| async move {
+        if user.has_role(role) {
+            Ok(user)
+        } else {
+            Err(warp::reject::custom(AuthRejection::Forbidden(message)))
+        }
+    })
+}
+
+/// Allow a user to act on their own resources, or anyone's when they hold `role`
+pub fn authorize_self_or_role(
+    user: &User,
+    owner_id: &str,
+    role: Role,
+    message: &'static str,
+) -> Result<(), warp::Rejection> {
+    if user.id == owner_id || user.has_role(role) {
+        Ok(())
+    } else {
+        Err(warp::reject::custom(AuthRejection::Forbidden(message)))
+    }
+}
+
+/// Allow a user to act on their own resources only
+pub fn authorize_self(
+    user: &User,
+    owner_id: &str,
+    message: &'static str,
+) -> Result<(), warp::Rejection> {
+    if user.id == owner_id {
+        Ok(())
+    } else {
+        Err(warp::reject::custom(AuthRejection::Forbidden(message)))
+    }
+}
+
+async fn authenticate(
+    auth_header: Option<String>,
+    auth_service: &AuthService,
+) -> Result<User, warp::Rejection> {
+    let auth_header =
+        auth_header.ok_or_else(|| warp::reject::custom(AuthRejection::MissingToken))?;
+    let token = auth_header.strip_prefix("Bearer ").unwrap_or(&auth_header);
+
+    auth_service
+        .validate_token(token)
+        .await
+        .map_err(|_| warp::reject::custom(AuthRejection::InvalidToken))
+}
+
+/// Reply to an `AuthRejection` with its status and a JSON error body; any
+/// other rejection is passed on untouched
+pub async fn handle_rejection(
+    rejection: warp::Rejection,
+) -> Result<warp::reply::WithStatus<warp::reply::Json>, warp::Rejection> {
+    match rejection.find::<AuthRejection>() {
+        Some(auth_rejection) => Ok(warp::reply::with_status(
+            warp::reply::json(&serde_json::json!({
+                "error": auth_rejection.message()
+            })),
+            auth_rejection.status(),
+        )),
+        None => Err(rejection),
+    }
+}
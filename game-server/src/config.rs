@@ -1,15 +1,82 @@
+use crate::abuse::AbusePolicy;
+use crate::auth::TokenPolicy;
+use crate::game_manager::validate_point_threshold;
+use crate::lobby::HostLeavePolicy;
+use crate::matchmaking::RatingWindow;
+use game_core::{GameRules, GuessPunctuation, ScoringConfig, TieBreak};
+use std::collections::HashSet;
 use std::env;
+use std::time::Duration;
 
 #[derive(Debug, Clone)]
 pub struct Config {
     pub host: String,
     pub port: u16,
-    pub points_to_win: u32,
+    pub points_to_win: Option<i32>, // Points needed to win each matched game, 5 to 200; unset follows the rules
     pub max_players_per_game: usize,
     pub min_players_per_game: usize,
     pub queue_timeout_seconds: u64,
     pub game_timeout_minutes: u64,
     pub connection_timeout_seconds: u64,
+    pub final_round_on_threshold: bool,
+    pub ready_check_timeout_seconds: u64, // 0 disables the ready check
+    pub max_board_size: usize,            // 0 keeps every guess on the board
+    pub max_token_age_seconds: u64,       // 0 accepts tokens of any age
+    pub token_replay_window_seconds: u64, // 0 disables replay detection
+    pub max_active_games: usize,          // 0 allows unlimited games
+    pub catch_up_multiplier: f64,         // 0 disables the catch-up bonus
+    pub catch_up_gap: f64,                // Fraction of the threshold behind the leader
+    pub allow_word_reuse: bool,           // false forbids reusing words from earlier rounds
+    pub admin_user_ids: Vec<String>,      // Users allowed to call the admin routes
+    pub send_timeout_seconds: u64,        // Stalled writes longer than this drop the socket
+    pub team_mode: bool,
+    pub abandon_cooldown_seconds: u64, // 0 disables the re-queue penalty for abandoning
+    pub abandon_grace_seconds: u64,    // How long a disconnected player has to rejoin
+    pub empty_game_grace_seconds: u64, // How long a game everyone left waits; 0 removes it at once
+    pub recent_word_window: usize, // A game's recent targets to avoid repeating; 0 allows repeats
+    pub queue_hold_seconds: u64,   // How long a dropped connection keeps its queue place
+    pub uninformed_solve_bonus: Option<i32>, // Unset awards the full bonus for every solve
+    pub leaderboard_cache_seconds: u64, // 0 queries the database for every leaderboard request
+    pub allow_turn_skip: bool,
+    pub max_concurrent_guesses: usize, // 0 processes every submission as it arrives
+    pub round_quorum: usize,           // 0 waits for every connected player to guess
+    pub intermission_seconds: u64,     // 0 resumes play right after a round result
+    pub strict_guesses: bool,          // true also refuses blocklisted words as guesses
+    pub auth_deadline_seconds: u64,    // 0 lets connections stay unauthenticated
+    pub carry_progress: bool,          // true tracks solved words and combos across words
+    pub guess_punctuation: GuessPunctuation, // "keep" for dictionaries that store hyphens
+    pub tie_break: TieBreak,           // first, fewest-repeats or closest-length
+    pub matchmaking_tick_millis: u64,  // How often the queue is checked for ready matches
+    pub max_matches_per_tick: usize,   // 0 forms every ready match in one tick
+    pub word_categories: Vec<String>,  // Themed categories to draw words from; empty for all words
+    pub category_guesses_only: bool,   // true refuses guesses outside the word categories
+    pub points_per_letter: i32,        // 0 keeps a flat threshold; POINTS_TO_WIN overrides it
+    pub points_floor: i32,             // Lowest total a negative solve bonus can bring a player to
+    pub allow_mismatched_length: bool, // true scores guesses longer or shorter than the word
+    pub individual_guess: bool,        // false keeps every round collaborative
+    pub rejoin_token_ttl_seconds: Option<u64>, // Unset follows the abandon grace; 0 rejoins without tokens
+    pub abuse_window_seconds: u64,             // 0 keeps no abuse record beyond each connection
+    pub abuse_cooldown_after: usize,           // Abuse events in the window that block queueing
+    pub abuse_ban_after: usize, // Abuse events in the window that refuse sign-in; 0 never bans
+    pub min_words_per_length: usize, // 0 skips the dictionary coverage check at startup
+    pub require_dictionary_coverage: bool, // false starts anyway, logging an error
+    pub assist_fraction: f64,   // 0 gives non-winning guesses no points
+    pub max_queue_size: usize,  // 0 lets any number of players queue
+    pub min_guess_interval_millis: u64, // 0 accepts guesses as fast as they arrive
+    pub themed_games: bool,     // true draws each game's words from one random category
+    pub hide_scores_until_end: bool, // true shows players only their own score until game over
+    pub skip_round_vote_fraction: f64, // 0 disables voting to skip a round
+    pub slow_consumer_queue_depth: usize, // 0 never flags slow consumers
+    pub max_game_duration_seconds: u64, // 0 lets games run until someone wins
+    pub rename_on_rejoin: bool, // true shows rejoining players under their current display name
+    pub max_solo_wait_seconds: u64, // 0 leaves players waiting for a match however long it takes
+    pub rating_window_base: u32, // 0 matches in queue order regardless of rating
+    pub rating_window_growth_per_sec: f64, // How fast the rating window widens while waiting
+    pub round_time_limit_seconds: u64, // 0 waits for every connected player's guess
+    pub hints_per_round: usize, // 0 turns hints off
+    pub hint_penalty: i32,
+    pub lobby_host_leave: HostLeavePolicy, // "dissolve" closes a lobby whose host leaves
+    pub max_spectators_per_game: usize,    // 0 lets anyone watch
 }
 
 impl Config {
@@ -20,10 +87,15 @@ impl Config {
                 .unwrap_or_else(|_| "8080".to_string())
                 .parse()
                 .expect("Invalid PORT"),
-            points_to_win: env::var("POINTS_TO_WIN")
-                .unwrap_or_else(|_| "25".to_string())
-                .parse()
-                .expect("Invalid POINTS_TO_WIN"),
+            points_to_win: env::var("POINTS_TO_WIN").ok().map(|value| {
+                value
+                    .parse()
+                    .map_err(|e: std::num::ParseIntError| e.to_string())
+                    .and_then(|threshold| {
+                        validate_point_threshold(threshold).map_err(|e| e.to_string())
+                    })
+                    .expect("Invalid POINTS_TO_WIN")
+            }),
             max_players_per_game: env::var("MAX_PLAYERS_PER_GAME")
                 .unwrap_or_else(|_| "16".to_string())
                 .parse()
@@ -44,8 +116,421 @@ impl Config {
                 .unwrap_or_else(|_| "300".to_string())
                 .parse()
                 .expect("Invalid CONNECTION_TIMEOUT_SECONDS"),
+            final_round_on_threshold: env::var("FINAL_ROUND_ON_THRESHOLD")
+                .unwrap_or_else(|_| "false".to_string())
+                .parse()
+                .expect("Invalid FINAL_ROUND_ON_THRESHOLD"),
+            ready_check_timeout_seconds: env::var("READY_CHECK_TIMEOUT_SECONDS")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .expect("Invalid READY_CHECK_TIMEOUT_SECONDS"),
+            max_board_size: env::var("MAX_BOARD_SIZE")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .expect("Invalid MAX_BOARD_SIZE"),
+            max_token_age_seconds: env::var("MAX_TOKEN_AGE_SECONDS")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .expect("Invalid MAX_TOKEN_AGE_SECONDS"),
+            token_replay_window_seconds: env::var("TOKEN_REPLAY_WINDOW_SECONDS")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .expect("Invalid TOKEN_REPLAY_WINDOW_SECONDS"),
+            max_active_games: env::var("MAX_ACTIVE_GAMES")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .expect("Invalid MAX_ACTIVE_GAMES"),
+            catch_up_multiplier: env::var("CATCH_UP_MULTIPLIER")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .expect("Invalid CATCH_UP_MULTIPLIER"),
+            catch_up_gap: env::var("CATCH_UP_GAP")
+                .unwrap_or_else(|_| "0.4".to_string())
+                .parse()
+                .expect("Invalid CATCH_UP_GAP"),
+            allow_word_reuse: env::var("ALLOW_WORD_REUSE")
+                .unwrap_or_else(|_| "true".to_string())
+                .parse()
+                .expect("Invalid ALLOW_WORD_REUSE"),
+            admin_user_ids: env::var("ADMIN_USER_IDS")
+                .unwrap_or_default()
+                .split(',')
+                .map(|id| id.trim().to_string())
+                .filter(|id| !id.is_empty())
+                .collect(),
+            send_timeout_seconds: env::var("SEND_TIMEOUT_SECONDS")
+                .unwrap_or_else(|_| "10".to_string())
+                .parse()
+                .expect("Invalid SEND_TIMEOUT_SECONDS"),
+            team_mode: env::var("TEAM_MODE")
+                .unwrap_or_else(|_| "false".to_string())
+                .parse()
+                .expect("Invalid TEAM_MODE"),
+            abandon_cooldown_seconds: env::var("ABANDON_COOLDOWN_SECONDS")
+                .unwrap_or_else(|_| "60".to_string())
+                .parse()
+                .expect("Invalid ABANDON_COOLDOWN_SECONDS"),
+            abandon_grace_seconds: env::var("ABANDON_GRACE_SECONDS")
+                .unwrap_or_else(|_| "120".to_string())
+                .parse()
+                .expect("Invalid ABANDON_GRACE_SECONDS"),
+            empty_game_grace_seconds: env::var("EMPTY_GAME_GRACE_SECONDS")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()
+                .expect("Invalid EMPTY_GAME_GRACE_SECONDS"),
+            recent_word_window: env::var("RECENT_WORD_WINDOW")
+                .unwrap_or_else(|_| "50".to_string())
+                .parse()
+                .expect("Invalid RECENT_WORD_WINDOW"),
+            queue_hold_seconds: env::var("QUEUE_HOLD_SECONDS")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()
+                .expect("Invalid QUEUE_HOLD_SECONDS"),
+            uninformed_solve_bonus: env::var("UNINFORMED_SOLVE_BONUS")
+                .ok()
+                .map(|value| value.parse().expect("Invalid UNINFORMED_SOLVE_BONUS")),
+            leaderboard_cache_seconds: env::var("LEADERBOARD_CACHE_SECONDS")
+                .unwrap_or_else(|_| "10".to_string())
+                .parse()
+                .expect("Invalid LEADERBOARD_CACHE_SECONDS"),
+            allow_turn_skip: env::var("ALLOW_TURN_SKIP")
+                .unwrap_or_else(|_| "true".to_string())
+                .parse()
+                .expect("Invalid ALLOW_TURN_SKIP"),
+            max_concurrent_guesses: env::var("MAX_CONCURRENT_GUESSES")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .expect("Invalid MAX_CONCURRENT_GUESSES"),
+            round_quorum: env::var("ROUND_QUORUM")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .expect("Invalid ROUND_QUORUM"),
+            intermission_seconds: env::var("INTERMISSION_SECONDS")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .expect("Invalid INTERMISSION_SECONDS"),
+            strict_guesses: env::var("STRICT_GUESSES")
+                .unwrap_or_else(|_| "false".to_string())
+                .parse()
+                .expect("Invalid STRICT_GUESSES"),
+            auth_deadline_seconds: env::var("AUTH_DEADLINE_SECONDS")
+                .unwrap_or_else(|_| "60".to_string())
+                .parse()
+                .expect("Invalid AUTH_DEADLINE_SECONDS"),
+            carry_progress: env::var("CARRY_PROGRESS")
+                .unwrap_or_else(|_| "false".to_string())
+                .parse()
+                .expect("Invalid CARRY_PROGRESS"),
+            guess_punctuation: env::var("GUESS_PUNCTUATION")
+                .unwrap_or_else(|_| "strip".to_string())
+                .parse()
+                .expect("Invalid GUESS_PUNCTUATION"),
+            tie_break: env::var("TIE_BREAK")
+                .unwrap_or_else(|_| "first".to_string())
+                .parse()
+                .expect("Invalid TIE_BREAK"),
+            matchmaking_tick_millis: env::var("MATCHMAKING_TICK_MILLIS")
+                .unwrap_or_else(|_| "1000".to_string())
+                .parse()
+                .expect("Invalid MATCHMAKING_TICK_MILLIS"),
+            max_matches_per_tick: env::var("MAX_MATCHES_PER_TICK")
+                .unwrap_or_else(|_| "2".to_string())
+                .parse()
+                .expect("Invalid MAX_MATCHES_PER_TICK"),
+            word_categories: env::var("WORD_CATEGORIES")
+                .unwrap_or_default()
+                .split(',')
+                .map(|name| name.trim().to_lowercase())
+                .filter(|name| !name.is_empty())
+                .collect(),
+            category_guesses_only: env::var("CATEGORY_GUESSES_ONLY")
+                .unwrap_or_else(|_| "false".to_string())
+                .parse()
+                .expect("Invalid CATEGORY_GUESSES_ONLY"),
+            points_per_letter: env::var("POINTS_PER_LETTER")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .expect("Invalid POINTS_PER_LETTER"),
+            points_floor: env::var("POINTS_FLOOR")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .expect("Invalid POINTS_FLOOR"),
+            allow_mismatched_length: env::var("ALLOW_MISMATCHED_LENGTH")
+                .unwrap_or_else(|_| "false".to_string())
+                .parse()
+                .expect("Invalid ALLOW_MISMATCHED_LENGTH"),
+            individual_guess: env::var("INDIVIDUAL_GUESS")
+                .unwrap_or_else(|_| "true".to_string())
+                .parse()
+                .expect("Invalid INDIVIDUAL_GUESS"),
+            rejoin_token_ttl_seconds: env::var("REJOIN_TOKEN_TTL_SECONDS")
+                .ok()
+                .map(|value| value.parse().expect("Invalid REJOIN_TOKEN_TTL_SECONDS")),
+            abuse_window_seconds: env::var("ABUSE_WINDOW_SECONDS")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .expect("Invalid ABUSE_WINDOW_SECONDS"),
+            abuse_cooldown_after: env::var("ABUSE_COOLDOWN_AFTER")
+                .unwrap_or_else(|_| "3".to_string())
+                .parse()
+                .expect("Invalid ABUSE_COOLDOWN_AFTER"),
+            abuse_ban_after: env::var("ABUSE_BAN_AFTER")
+                .unwrap_or_else(|_| "10".to_string())
+                .parse()
+                .expect("Invalid ABUSE_BAN_AFTER"),
+            min_words_per_length: env::var("MIN_WORDS_PER_LENGTH")
+                .unwrap_or_else(|_| "100".to_string())
+                .parse()
+                .expect("Invalid MIN_WORDS_PER_LENGTH"),
+            require_dictionary_coverage: env::var("REQUIRE_DICTIONARY_COVERAGE")
+                .unwrap_or_else(|_| "true".to_string())
+                .parse()
+                .expect("Invalid REQUIRE_DICTIONARY_COVERAGE"),
+            assist_fraction: env::var("ASSIST_FRACTION")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .expect("Invalid ASSIST_FRACTION"),
+            max_queue_size: env::var("MAX_QUEUE_SIZE")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .expect("Invalid MAX_QUEUE_SIZE"),
+            min_guess_interval_millis: env::var("MIN_GUESS_INTERVAL_MILLIS")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .expect("Invalid MIN_GUESS_INTERVAL_MILLIS"),
+            themed_games: env::var("THEMED_GAMES")
+                .unwrap_or_else(|_| "false".to_string())
+                .parse()
+                .expect("Invalid THEMED_GAMES"),
+            hide_scores_until_end: env::var("HIDE_SCORES_UNTIL_END")
+                .unwrap_or_else(|_| "false".to_string())
+                .parse()
+                .expect("Invalid HIDE_SCORES_UNTIL_END"),
+            skip_round_vote_fraction: env::var("SKIP_ROUND_VOTE_FRACTION")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .expect("Invalid SKIP_ROUND_VOTE_FRACTION"),
+            slow_consumer_queue_depth: env::var("SLOW_CONSUMER_QUEUE_DEPTH")
+                .unwrap_or_else(|_| "100".to_string())
+                .parse()
+                .expect("Invalid SLOW_CONSUMER_QUEUE_DEPTH"),
+            max_game_duration_seconds: env::var("MAX_GAME_DURATION_SECONDS")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .expect("Invalid MAX_GAME_DURATION_SECONDS"),
+            rename_on_rejoin: env::var("RENAME_ON_REJOIN")
+                .unwrap_or_else(|_| "false".to_string())
+                .parse()
+                .expect("Invalid RENAME_ON_REJOIN"),
+            max_solo_wait_seconds: env::var("MAX_SOLO_WAIT_SECONDS")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .expect("Invalid MAX_SOLO_WAIT_SECONDS"),
+            rating_window_base: env::var("RATING_WINDOW_BASE")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .expect("Invalid RATING_WINDOW_BASE"),
+            rating_window_growth_per_sec: env::var("RATING_WINDOW_GROWTH_PER_SEC")
+                .unwrap_or_else(|_| "5".to_string())
+                .parse()
+                .expect("Invalid RATING_WINDOW_GROWTH_PER_SEC"),
+            round_time_limit_seconds: env::var("ROUND_TIME_LIMIT_SECONDS")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .expect("Invalid ROUND_TIME_LIMIT_SECONDS"),
+            hints_per_round: env::var("HINTS_PER_ROUND")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .expect("Invalid HINTS_PER_ROUND"),
+            hint_penalty: env::var("HINT_PENALTY")
+                .unwrap_or_else(|_| "3".to_string())
+                .parse()
+                .expect("Invalid HINT_PENALTY"),
+            lobby_host_leave: env::var("LOBBY_HOST_LEAVE")
+                .unwrap_or_else(|_| "transfer".to_string())
+                .parse()
+                .expect("Invalid LOBBY_HOST_LEAVE"),
+            max_spectators_per_game: env::var("MAX_SPECTATORS_PER_GAME")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .expect("Invalid MAX_SPECTATORS_PER_GAME"),
+        }
+    }
+
+    /// Game rules derived from the server configuration
+    pub fn game_rules(&self) -> GameRules {
+        GameRules {
+            final_round_on_threshold: self.final_round_on_threshold,
+            max_board_size: (self.max_board_size > 0).then_some(self.max_board_size),
+            scoring: ScoringConfig {
+                catch_up_multiplier: self.catch_up_multiplier,
+                catch_up_gap: self.catch_up_gap,
+                uninformed_solve_bonus: self.uninformed_solve_bonus,
+                points_floor: self.points_floor,
+                tie_break: self.tie_break,
+                assist_fraction: self.assist_fraction,
+            },
+            allow_reuse_across_rounds: self.allow_word_reuse,
+            team_mode: self.team_mode,
+            allow_turn_skip: self.allow_turn_skip,
+            round_quorum: (self.round_quorum > 0).then_some(self.round_quorum),
+            intermission: (self.intermission_seconds > 0)
+                .then(|| Duration::from_secs(self.intermission_seconds)),
+            strict_guesses: self.strict_guesses,
+            carry_progress: self.carry_progress,
+            word_categories: self.word_categories.clone(),
+            category_guesses_only: self.category_guesses_only,
+            points_per_letter: (self.points_per_letter > 0).then_some(self.points_per_letter),
+            allow_mismatched_length: self.allow_mismatched_length,
+            individual_guess: self.individual_guess,
+            min_guess_interval: (self.min_guess_interval_millis > 0)
+                .then(|| Duration::from_millis(self.min_guess_interval_millis)),
+            hide_scores_until_end: self.hide_scores_until_end,
+            skip_round_vote_fraction: (self.skip_round_vote_fraction > 0.0)
+                .then_some(self.skip_round_vote_fraction),
+            max_hints_per_round: (self.hints_per_round > 0).then_some(self.hints_per_round),
+            hint_penalty: self.hint_penalty,
+        }
+    }
+
+    /// How long new games wait for players to ready up, if at all
+    pub fn ready_check_timeout(&self) -> Option<Duration> {
+        (self.ready_check_timeout_seconds > 0)
+            .then(|| Duration::from_secs(self.ready_check_timeout_seconds))
+    }
+
+    /// Cap on concurrently running games, if any
+    pub fn max_active_games(&self) -> Option<usize> {
+        (self.max_active_games > 0).then_some(self.max_active_games)
+    }
+
+    /// Cap on guess submissions processed at once, if any
+    pub fn max_concurrent_guesses(&self) -> Option<usize> {
+        (self.max_concurrent_guesses > 0).then_some(self.max_concurrent_guesses)
+    }
+
+    /// Token hardening checks, both disabled unless configured
+    pub fn token_policy(&self) -> TokenPolicy {
+        TokenPolicy {
+            max_token_age: (self.max_token_age_seconds > 0)
+                .then(|| Duration::from_secs(self.max_token_age_seconds)),
+            replay_window: (self.token_replay_window_seconds > 0)
+                .then(|| Duration::from_secs(self.token_replay_window_seconds)),
         }
     }
+
+    pub fn abandon_cooldown(&self) -> Option<Duration> {
+        (self.abandon_cooldown_seconds > 0)
+            .then(|| Duration::from_secs(self.abandon_cooldown_seconds))
+    }
+
+    pub fn queue_hold(&self) -> Option<Duration> {
+        (self.queue_hold_seconds > 0).then(|| Duration::from_secs(self.queue_hold_seconds))
+    }
+
+    pub fn leaderboard_cache_ttl(&self) -> Option<Duration> {
+        (self.leaderboard_cache_seconds > 0)
+            .then(|| Duration::from_secs(self.leaderboard_cache_seconds))
+    }
+
+    /// How long a new connection has to authenticate, if limited
+    pub fn auth_deadline(&self) -> Option<Duration> {
+        (self.auth_deadline_seconds > 0).then(|| Duration::from_secs(self.auth_deadline_seconds))
+    }
+
+    pub fn matchmaking_tick(&self) -> Duration {
+        Duration::from_millis(self.matchmaking_tick_millis.max(1))
+    }
+
+    /// Cap on matches formed per matchmaking tick, if any
+    pub fn max_matches_per_tick(&self) -> Option<usize> {
+        (self.max_matches_per_tick > 0).then_some(self.max_matches_per_tick)
+    }
+
+    /// How long a player may wait without enough others queueing before
+    /// being told no match is coming
+    pub fn max_solo_wait(&self) -> Option<Duration> {
+        (self.max_solo_wait_seconds > 0).then(|| Duration::from_secs(self.max_solo_wait_seconds))
+    }
+
+    /// How long a game with every player disconnected survives, if at all
+    pub fn empty_game_grace(&self) -> Option<Duration> {
+        (self.empty_game_grace_seconds > 0)
+            .then(|| Duration::from_secs(self.empty_game_grace_seconds))
+    }
+
+    pub fn abandon_grace(&self) -> Duration {
+        Duration::from_secs(self.abandon_grace_seconds)
+    }
+
+    /// How long a rejoin token stays valid after a disconnect, if tokens are
+    /// required at all. Defaults to the abandon grace, past which the seat
+    /// is forfeit anyway.
+    pub fn rejoin_token_ttl(&self) -> Option<Duration> {
+        match self.rejoin_token_ttl_seconds {
+            Some(0) => None,
+            Some(seconds) => Some(Duration::from_secs(seconds)),
+            None => Some(self.abandon_grace()),
+        }
+    }
+
+    /// Words needed of every target length for the dictionary to be usable,
+    /// if checked at all
+    pub fn min_words_per_length(&self) -> Option<usize> {
+        (self.min_words_per_length > 0).then_some(self.min_words_per_length)
+    }
+
+    /// Rating gap matched players may have, if matching by skill
+    pub fn rating_window(&self) -> Option<RatingWindow> {
+        (self.rating_window_base > 0).then_some(RatingWindow {
+            base: self.rating_window_base,
+            growth_per_sec: self.rating_window_growth_per_sec,
+        })
+    }
+
+    /// Players the queue admits before refusing new joins, if capped
+    pub fn max_queue_size(&self) -> Option<usize> {
+        (self.max_queue_size > 0).then_some(self.max_queue_size)
+    }
+
+    /// Persisted abuse penalties, if abuse events are tracked at all
+    pub fn abuse_policy(&self) -> Option<AbusePolicy> {
+        (self.abuse_window_seconds > 0).then(|| AbusePolicy {
+            window: Duration::from_secs(self.abuse_window_seconds),
+            cooldown_after: self.abuse_cooldown_after,
+            ban_after: (self.abuse_ban_after > 0).then_some(self.abuse_ban_after),
+        })
+    }
+
+    pub fn send_timeout(&self) -> Duration {
+        Duration::from_secs(self.send_timeout_seconds)
+    }
+
+    /// Longest a game may run before its leader is declared the winner
+    pub fn max_game_duration(&self) -> Option<Duration> {
+        (self.max_game_duration_seconds > 0)
+            .then(|| Duration::from_secs(self.max_game_duration_seconds))
+    }
+
+    /// Longest a collaborative round waits for guesses before it is resolved
+    pub fn round_time_limit(&self) -> Option<Duration> {
+        (self.round_time_limit_seconds > 0)
+            .then(|| Duration::from_secs(self.round_time_limit_seconds))
+    }
+
+    /// Undelivered messages that mark a connection as a slow consumer
+    pub fn slow_consumer_depth(&self) -> Option<usize> {
+        (self.slow_consumer_queue_depth > 0).then_some(self.slow_consumer_queue_depth)
+    }
+
+    /// Spectators a single game accepts
+    pub fn max_spectators(&self) -> Option<usize> {
+        (self.max_spectators_per_game > 0).then_some(self.max_spectators_per_game)
+    }
+
+    pub fn admin_user_ids(&self) -> HashSet<String> {
+        self.admin_user_ids.iter().cloned().collect()
+    }
 }
 
 impl Default for Config {
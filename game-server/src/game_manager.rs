@@ -1,17 +1,25 @@
 use chrono;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock, Semaphore};
 use tracing::info;
 use uuid::Uuid;
 
-use crate::websocket::connection::{ConnectionId, ConnectionManager};
-use game_core::{Game, WordValidator};
+use crate::metrics::Metrics;
+use crate::websocket::connection::{ConnectionId, ConnectionManager, spectator_view};
+use game_core::{
+    Game, GameError, GameResult, GameRules, RecentWords, RoundGuesses, SharedClock, SystemClock,
+    WordValidator, assign_teams, letter_count,
+};
+use game_persistence::repositories::GameRepository;
+use game_persistence::repositories::game_repository::GameRecordScore;
 use game_types::PlayerId;
 use game_types::{
-    GamePhase, GameState, GuessResult, PersonalGuess, Player, RoundCompletion, RoundResult,
-    SafeGameState, User,
+    ErrorCode, GameHistory, GameOverReason, GamePhase, GameState, GameStatus, GuessResult,
+    LetterResult, LiveGameSummary, PersonalGuess, Player, RoundCompletion, RoundHistory,
+    RoundRecap, RoundResult, RoundSummary, SafeGameState, User,
 };
 
 #[derive(Debug, Clone)]
@@ -20,34 +28,195 @@ pub enum GameEvent {
         winning_guess: GuessResult,
         player_guesses: Vec<(ConnectionId, PersonalGuess)>,
         is_word_completed: bool,
+        revealed: Vec<LetterResult>,
     },
     GameOver {
         winner: Player,
         final_scores: Vec<Player>,
+        recap: Vec<RoundRecap>,
+        ranked: bool, // False for practice games, which stay out of player stats
+        categories: Vec<String>, // Theme the game's words came from; empty for the full dictionary
+        reason: GameOverReason,
     },
     StateUpdate {
         state: GameState,
     },
+    /// A player submitted or replaced their guess for the current round and
+    /// others are still deciding. Nothing visible to other players changed.
+    GuessPending {
+        player_id: PlayerId,
+        replaced: bool,
+    },
+}
+
+/// What a round timer check did to one game
+#[derive(Debug, Clone)]
+pub enum RoundTimer {
+    /// A collaborative round's timer was started
+    Started { seconds_remaining: u32 },
+    /// The timer ran out and the round was resolved with the guesses in
+    Expired(Box<GameEvent>),
+}
+
+/// Outcome of a player rejoining a game they dropped out of
+#[derive(Debug, Clone)]
+pub struct Rejoin {
+    pub state: GameState,
+    pub missed_rounds: Vec<RoundSummary>, // Oldest first, at most `MISSED_ROUNDS_REPLAY_LIMIT`
+    pub renamed: Option<String>, // New display name, when the rejoin picked up a changed one
+}
+
+/// Outcome of a player confirming they are ready to start
+#[derive(Debug, Clone)]
+pub struct ReadyUpdate {
+    pub player_id: PlayerId,
+    pub game_started: bool,
+    pub state: GameState,
+}
+
+/// Tally after a player votes to skip the current round
+#[derive(Debug, Clone)]
+pub struct SkipRoundUpdate {
+    pub votes: u32,
+    pub needed: u32,
+    pub skipped_word: Option<String>, // Revealed when the vote passed and a new round began
+    pub state: GameState,
+}
+
+/// A letter revealed to one player and what it cost them
+#[derive(Debug, Clone)]
+pub struct HintUpdate {
+    pub letter: LetterResult,
+    pub points_deducted: i32,
+    pub state: GameState,
+}
+
+/// Map a game-logic error to the code sent to clients alongside the message
+pub fn error_code(error: &GameError) -> ErrorCode {
+    match error {
+        GameError::GameNotFound => ErrorCode::GameNotFound,
+        GameError::PlayerNotInGame => ErrorCode::PlayerNotInGame,
+        GameError::EmptyGuess => ErrorCode::EmptyGuess,
+        GameError::WrongLength { .. } => ErrorCode::WrongLength,
+        GameError::InvalidWord { .. } => ErrorCode::InvalidWord,
+        GameError::WordNotAllowed { .. } => ErrorCode::WordNotAllowed,
+        GameError::WordAlreadyGuessed { .. } => ErrorCode::WordAlreadyGuessed,
+        GameError::WrongPhase { .. } => ErrorCode::WrongPhase,
+        GameError::NotYourTurn => ErrorCode::NotYourTurn,
+        GameError::TurnSkipNotAllowed => ErrorCode::TurnSkipNotAllowed,
+        GameError::GuessTooFast { .. } => ErrorCode::GuessTooFast,
+        GameError::RoundSkipNotAllowed => ErrorCode::RoundSkipNotAllowed,
+        GameError::SpectatorCannotGuess => ErrorCode::SpectatorCannotGuess,
+        GameError::HintsNotAllowed => ErrorCode::HintsNotAllowed,
+        GameError::HintLimitReached { .. } => ErrorCode::HintLimitReached,
+        GameError::NoHintAvailable => ErrorCode::NoHintAvailable,
+        GameError::PointThresholdOutOfRange { .. } => ErrorCode::InvalidPointThreshold,
+        GameError::PracticeWordLength { .. } => ErrorCode::WrongLength,
+        GameError::RejoinTokenRequired => ErrorCode::RejoinTokenRequired,
+        GameError::InvalidRejoinToken => ErrorCode::InvalidRejoinToken,
+        GameError::RejoinTokenUsed => ErrorCode::RejoinTokenUsed,
+        GameError::RejoinTokenExpired => ErrorCode::RejoinTokenExpired,
+        GameError::PlayerStillConnected | GameError::NoSeatToRejoin => ErrorCode::RejoinUnavailable,
+        GameError::InvalidPlayerCount { .. }
+        | GameError::WordSelection { .. }
+        | GameError::PlayerNotConnected
+        | GameError::PlayerNotAuthenticated
+        | GameError::DuplicatePlayer { .. }
+        | GameError::ServerAtCapacity => ErrorCode::Internal,
+    }
+}
+
+/// Number of finished games kept in memory for post-game review
+const FINISHED_GAME_CACHE_SIZE: usize = 100;
+
+/// Resolved rounds kept per game for players catching up after a reconnect
+pub const MISSED_ROUNDS_REPLAY_LIMIT: usize = 10;
+
+/// Seconds queued players are told to wait when the server is at capacity
+pub const SERVER_BUSY_RETRY_SECONDS: u32 = 30;
+
+/// Word lengths a practice game may be started on, matching random targets
+const PRACTICE_WORD_LENGTHS: std::ops::RangeInclusive<usize> = 5..=7;
+
+/// Points needed to win when a match doesn't ask for a different threshold
+pub const DEFAULT_POINT_THRESHOLD: i32 = 25;
+
+/// Point thresholds a match may be created with
+pub use game_core::POINT_THRESHOLD_RANGE;
+
+/// Check that a requested points-to-win threshold is within
+/// `POINT_THRESHOLD_RANGE`
+pub fn validate_point_threshold(point_threshold: i32) -> GameResult<i32> {
+    if POINT_THRESHOLD_RANGE.contains(&point_threshold) {
+        Ok(point_threshold)
+    } else {
+        Err(GameError::PointThresholdOutOfRange {
+            threshold: point_threshold,
+            min: *POINT_THRESHOLD_RANGE.start(),
+            max: *POINT_THRESHOLD_RANGE.end(),
+        })
+    }
+}
+
+/// Derive the word-selection seed for a round from the game's seed
+fn round_seed(game_seed: u64, round: i32) -> u64 {
+    game_seed.wrapping_add(round as u64)
 }
 
 #[derive(Debug)]
 struct ActiveGame {
-    id: String,
     game: Game,
+    seed: u64, // Drives target word selection; recorded for replaying reported games
     connection_to_player: HashMap<ConnectionId, PlayerId>,
     player_to_connection: HashMap<PlayerId, ConnectionId>,
+    ready_players: HashSet<PlayerId>,
+    completed_rounds: Vec<RoundHistory>,
     created_at: Instant,
     last_activity: Instant,
+    disconnected_at: HashMap<PlayerId, Instant>, // Players yet to rejoin a running game
+    emptied_at: Option<Instant>,                 // When the last connected player dropped
+    recent_rounds: VecDeque<(u64, RoundSummary)>, // Bounded log, tagged with a sequence number
+    rounds_resolved: u64,
+    missed_from: HashMap<PlayerId, u64>, // Sequence number at each disconnect
+    practice: bool, // Solo game on a chosen word; kept out of stats and game records
+    private: bool,  // Started from a private lobby; nobody else may watch
+    rejoin_tokens: HashMap<PlayerId, RejoinToken>, // Latest token issued to each player
+    skip_votes: HashSet<PlayerId>, // Players voting to skip the current round
+    recent_targets: RecentWords, // This game's latest targets, avoided by its next draws
+    round_deadline: Option<Instant>, // When the current round or individual guess resolves regardless
+}
+
+/// How a finished game ended, waiting to be written to the game records
+#[derive(Debug, Clone)]
+struct GameCompletion {
+    game_id: String,
+    winner_id: PlayerId,
+    words: Vec<String>,
+    final_scores: Vec<GameRecordScore>,
+}
+
+/// A token that lets a player reclaim their own seat after a disconnect
+#[derive(Debug, Clone)]
+struct RejoinToken {
+    token: String,
+    issued_at: Instant,
+    used: bool,
 }
 
 impl ActiveGame {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         id: String,
         authenticated_players: Vec<(ConnectionId, User)>,
-        word_validator: &WordValidator,
-    ) -> Result<Self, String> {
+        target_word: String,
+        point_threshold: i32,
+        rules: GameRules,
+        seed: u64,
+        ready_check: bool,
+        clock: SharedClock,
+    ) -> GameResult<Self> {
         if authenticated_players.is_empty() {
-            return Err("Cannot create game with no players".to_string());
+            return Err(GameError::InvalidPlayerCount { count: 0 });
         }
 
         let mut connection_to_player = HashMap::new();
@@ -61,6 +230,7 @@ impl ActiveGame {
                 points: 0,
                 guess_history: Vec::new(),
                 is_connected: true,
+                avatar: user.avatar.clone(),
             };
 
             game_players.push(player);
@@ -68,39 +238,293 @@ impl ActiveGame {
             player_to_connection.insert(user.id.clone(), *connection_id);
         }
 
-        // Get a random word from the shared word validator
-        let target_word = word_validator
-            .get_random_word_random_length()
-            .expect("Failed to get random word");
-
-        let mut game = Game::new(
-            id.clone(),
-            game_players,
-            target_word,
-            25, // Points to win from config
-        );
-
-        // Start the first round immediately
-        game.state.status = game_types::GameStatus::Active;
-        game.start_guessing_phase();
+        let game = Game::new_with_rules(id, game_players, target_word, point_threshold, rules)
+            .with_clock(clock.clone());
+
+        // Team sides are drawn from the game seed like the target words
+        let game = if game.rules.team_mode {
+            let player_ids: Vec<PlayerId> = game
+                .state
+                .players
+                .iter()
+                .map(|p| p.user_id.clone())
+                .collect();
+            game.with_teams(assign_teams(&player_ids, seed))
+        } else {
+            game
+        };
 
-        let now = Instant::now();
-        Ok(Self {
-            id,
+        let mut active_game = Self {
             game,
+            seed,
             connection_to_player,
             player_to_connection,
-            created_at: now,
-            last_activity: now,
-        })
+            ready_players: HashSet::new(),
+            completed_rounds: Vec::new(),
+            created_at: clock.now(),
+            last_activity: clock.now(),
+            disconnected_at: HashMap::new(),
+            emptied_at: None,
+            recent_rounds: VecDeque::new(),
+            rounds_resolved: 0,
+            missed_from: HashMap::new(),
+            practice: false,
+            private: false,
+            rejoin_tokens: HashMap::new(),
+            skip_votes: HashSet::new(),
+            recent_targets: RecentWords::default(),
+            round_deadline: None,
+        };
+
+        // Without a ready check the first round starts immediately; otherwise
+        // the game waits until everyone confirms or the timeout fires
+        if !ready_check {
+            active_game.begin_guessing();
+        }
+
+        Ok(active_game)
+    }
+
+    fn begin_guessing(&mut self) {
+        self.game.state.status = game_types::GameStatus::Active;
+        self.game.start_guessing_phase();
+    }
+
+    fn all_connected_ready(&self) -> bool {
+        self.game
+            .state
+            .players
+            .iter()
+            .filter(|p| p.is_connected)
+            .all(|p| self.ready_players.contains(&p.user_id))
     }
 
     fn update_activity(&mut self) {
-        self.last_activity = Instant::now();
+        self.last_activity = self.game.clock().now();
     }
 
     fn is_expired(&self, timeout: Duration) -> bool {
-        self.last_activity.elapsed() > timeout
+        self.game.clock().now().duration_since(self.last_activity) > timeout
+    }
+
+    /// Whether the game has been running for longer than `max_duration`
+    fn is_overlong(&self, max_duration: Option<Duration>) -> bool {
+        max_duration
+            .is_some_and(|max| self.game.clock().now().duration_since(self.created_at) > max)
+    }
+
+    /// Check a presented rejoin token and use it up, returning the player it
+    /// was issued to. Only that player's account can use a token, so a
+    /// stolen one is worthless alone. Expired and reused tokens get their
+    /// own errors.
+    fn redeem_rejoin_token(
+        &mut self,
+        token: Option<&str>,
+        user_id: Option<&PlayerId>,
+        ttl: Duration,
+    ) -> GameResult<PlayerId> {
+        let token = token.ok_or(GameError::RejoinTokenRequired)?;
+        let (player_id, issued) = self
+            .rejoin_tokens
+            .iter_mut()
+            .find(|(player_id, issued)| issued.token == token && Some(*player_id) == user_id)
+            .ok_or(GameError::InvalidRejoinToken)?;
+
+        if issued.used {
+            return Err(GameError::RejoinTokenUsed);
+        }
+        let player_connected = self
+            .game
+            .state
+            .players
+            .iter()
+            .any(|p| &p.user_id == player_id && p.is_connected);
+        if player_connected {
+            return Err(GameError::PlayerStillConnected);
+        }
+        // The window restarts at the disconnect, so a token issued at the
+        // start of a long game still works right after a drop
+        let valid_from = self
+            .disconnected_at
+            .get(player_id)
+            .map_or(issued.issued_at, |&since| since.max(issued.issued_at));
+        if self.game.clock().now().duration_since(valid_from) > ttl {
+            return Err(GameError::RejoinTokenExpired);
+        }
+
+        issued.used = true;
+        Ok(player_id.clone())
+    }
+
+    /// Whether every player has been gone for longer than `grace`
+    fn is_empty_past(&self, grace: Option<Duration>) -> bool {
+        if self.game.state.players.iter().any(|p| p.is_connected) {
+            return false;
+        }
+        match (grace, self.emptied_at) {
+            (Some(grace), Some(emptied_at)) => {
+                self.game.clock().now().duration_since(emptied_at) > grace
+            }
+            _ => true,
+        }
+    }
+
+    /// Whether enough connected players have guessed to resolve the round:
+    /// all of them, or the rules' quorum when one is set
+    fn round_quorum_reached(&self) -> bool {
+        let (connected, guessed) = self
+            .game
+            .state
+            .players
+            .iter()
+            .filter(|p| p.is_connected)
+            .fold((0, 0), |(connected, guessed), p| {
+                let has_guessed = self.game.current_guesses.contains_key(&p.user_id);
+                (connected + 1, guessed + usize::from(has_guessed))
+            });
+        let required = self
+            .game
+            .rules
+            .round_quorum
+            .map_or(connected, |quorum| quorum.min(connected));
+        guessed >= required
+    }
+
+    /// Log the round a guess resolved, if it resolved one
+    fn record_round_event(&mut self, round: i32, event: &GameResult<GameEvent>) {
+        if let Ok(GameEvent::RoundResult {
+            winning_guess,
+            is_word_completed,
+            ..
+        }) = event
+        {
+            self.record_round(RoundSummary {
+                round,
+                winning_guess: winning_guess.clone(),
+                is_word_completed: *is_word_completed,
+            });
+        }
+    }
+
+    /// Log a resolved round, dropping the oldest once the log is full
+    fn record_round(&mut self, summary: RoundSummary) {
+        if self.recent_rounds.len() == MISSED_ROUNDS_REPLAY_LIMIT {
+            self.recent_rounds.pop_front();
+        }
+        self.recent_rounds
+            .push_back((self.rounds_resolved, summary));
+        self.rounds_resolved += 1;
+    }
+
+    /// Rounds resolved since the player disconnected that are still in the log
+    fn take_missed_rounds(&mut self, player_id: &str) -> Vec<RoundSummary> {
+        let Some(from) = self.missed_from.remove(player_id) else {
+            return Vec::new();
+        };
+
+        self.recent_rounds
+            .iter()
+            .filter(|(sequence, _)| *sequence >= from)
+            .map(|(_, summary)| summary.clone())
+            .collect()
+    }
+
+    /// Pair each connected player who guessed in the round just resolved with
+    /// the most recent entry in their guess history
+    fn last_player_guesses(
+        &self,
+        guessed: &HashSet<PlayerId>,
+    ) -> Vec<(ConnectionId, PersonalGuess)> {
+        self.game
+            .state
+            .players
+            .iter()
+            .filter(|player| guessed.contains(&player.user_id))
+            .filter_map(|player| {
+                let conn_id = self.player_to_connection.get(&player.user_id)?;
+                let last_guess = player.guess_history.last()?;
+                Some((*conn_id, last_guess.clone()))
+            })
+            .collect()
+    }
+
+    /// Build the game's history. Once the game is over everything is revealed;
+    /// before that the current target word and other players' guesses are hidden.
+    fn history_for(&self, viewer_id: &str) -> GameHistory {
+        let finished = self.game.state.status == GameStatus::Completed;
+
+        let mut rounds = self.completed_rounds.clone();
+        rounds.push(RoundHistory {
+            round: self.game.state.current_round,
+            target_word: finished.then(|| self.game.target_word.clone()),
+            board: self.game.state.official_board.clone(),
+        });
+
+        let players = if finished {
+            self.game.state.players.clone()
+        } else {
+            self.game
+                .state
+                .personalized_for_player(viewer_id.to_string())
+                .players
+        };
+
+        GameHistory {
+            game_id: self.game.state.id.clone(),
+            status: self.game.state.status.clone(),
+            rounds,
+            players,
+        }
+    }
+
+    /// Whether the game still occupies a slot towards `max_active_games`
+    fn is_running(&self) -> bool {
+        !matches!(
+            self.game.state.status,
+            GameStatus::Completed | GameStatus::Abandoned | GameStatus::TimedOut
+        )
+    }
+
+    /// Whether spectators may watch: public games only, while they run
+    fn allows_spectators(&self) -> bool {
+        self.is_running() && !self.practice && !self.private
+    }
+
+    /// Pass on the individual guess while it belongs to a disconnected
+    /// player, so the players still here aren't kept waiting; during sudden
+    /// death that can take several turns. Returns whether any turn passed
+    /// and whether passing it ended the game.
+    fn pass_disconnected_turns(&mut self) -> (bool, bool) {
+        let mut passed = false;
+        // One full rotation at most; a game everyone left is paused instead
+        for _ in 0..self.game.state.players.len() {
+            let holder_disconnected =
+                self.game
+                    .state
+                    .current_winner
+                    .as_ref()
+                    .is_some_and(|holder| {
+                        self.game
+                            .state
+                            .players
+                            .iter()
+                            .any(|p| p.user_id == *holder && !p.is_connected)
+                    });
+            if self.game.current_phase != GamePhase::IndividualGuess || !holder_disconnected {
+                break;
+            }
+            match self.game.forfeit_turn() {
+                Ok(game_over) => {
+                    passed = true;
+                    self.round_deadline = None;
+                    if game_over {
+                        return (true, true);
+                    }
+                }
+                Err(_) => break,
+            }
+        }
+        (passed, false)
     }
 
     fn convert_to_api_state(&self) -> GameState {
@@ -112,8 +536,25 @@ impl ActiveGame {
 pub struct GameManager {
     active_games: RwLock<HashMap<String, ActiveGame>>,
     connection_to_game: RwLock<HashMap<ConnectionId, String>>,
-    word_validator: Arc<WordValidator>,
+    word_validator: std::sync::RwLock<Arc<WordValidator>>,
+    words_ready: AtomicBool, // False until the dictionary has finished loading
     connection_manager: Arc<ConnectionManager>,
+    game_rules: GameRules,
+    ready_timeout: Option<Duration>,
+    max_active_games: Option<usize>,
+    empty_game_grace: Option<Duration>,
+    rejoin_token_ttl: Option<Duration>, // None lets rejoins through without a token
+    finished_games: RwLock<VecDeque<GameHistory>>,
+    clock: SharedClock,
+    recent_word_window: usize, // Earlier targets of the same game each draw avoids
+    guess_permits: Option<Semaphore>, // Caps guess submissions in flight at once
+    game_repository: Option<Arc<GameRepository>>, // Records created and finished games
+    unrecorded_completions: Mutex<Vec<GameCompletion>>, // Written once the games lock is released
+    themed_games: bool, // Give each game one random category when no categories are configured
+    max_game_duration: Option<Duration>, // Games running longer end at their next guess or sweep
+    rename_on_rejoin: bool, // Take a rejoining player's current display name
+    metrics: Metrics,
+    round_time_limit: Option<Duration>, // None waits for every guess however long it takes
 }
 
 impl GameManager {
@@ -125,23 +566,74 @@ impl GameManager {
         Self {
             active_games: RwLock::new(HashMap::new()),
             connection_to_game: RwLock::new(HashMap::new()),
-            word_validator: Arc::new(word_validator),
+            word_validator: std::sync::RwLock::new(Arc::new(word_validator)),
+            words_ready: AtomicBool::new(true),
             connection_manager,
+            game_rules: GameRules::default(),
+            ready_timeout: None,
+            max_active_games: None,
+            empty_game_grace: None,
+            rejoin_token_ttl: None,
+            finished_games: RwLock::new(VecDeque::new()),
+            clock: SystemClock::shared(),
+            recent_word_window: 0,
+            guess_permits: None,
+            game_repository: None,
+            unrecorded_completions: Mutex::new(Vec::new()),
+            themed_games: false,
+            max_game_duration: None,
+            rename_on_rejoin: false,
+            metrics: Metrics::new(),
+            round_time_limit: None,
         }
     }
 
-    /// Create a new GameManager with word lists loaded from a directory
+    /// Create a new GameManager with word lists loaded from a directory,
+    /// falling back to the built-in word list if the directory is unusable
     pub fn new<P: AsRef<std::path::Path>>(
         connection_manager: Arc<ConnectionManager>,
         words_dir: P,
     ) -> Result<Self, Box<dyn std::error::Error>> {
-        let word_validator = WordValidator::new(words_dir)?;
-        Ok(Self {
-            active_games: RwLock::new(HashMap::new()),
-            connection_to_game: RwLock::new(HashMap::new()),
-            word_validator: Arc::new(word_validator),
-            connection_manager,
-        })
+        let word_validator = WordValidator::new_or_embedded(words_dir);
+        Ok(Self::new_with_validator(connection_manager, word_validator))
+    }
+
+    /// Create a GameManager whose dictionary is still loading. It reports not
+    /// ready, and cannot pick target words, until `set_word_validator` is called.
+    pub fn new_loading(connection_manager: Arc<ConnectionManager>) -> Self {
+        let game_manager =
+            Self::new_with_validator(connection_manager, WordValidator::from_word_list(""));
+        game_manager.words_ready.store(false, Ordering::Release);
+        game_manager
+    }
+
+    /// Swap in a loaded dictionary and mark the manager ready
+    pub fn set_word_validator(&self, word_validator: WordValidator) {
+        *self.word_validator.write().unwrap() = Arc::new(word_validator);
+        self.words_ready.store(true, Ordering::Release);
+    }
+
+    /// Whether the dictionary has been loaded
+    pub fn is_ready(&self) -> bool {
+        self.words_ready.load(Ordering::Acquire)
+    }
+
+    /// Counters exposed on `/metrics`
+    pub fn metrics(&self) -> &Metrics {
+        &self.metrics
+    }
+
+    /// Whether any word of `text` is on the blocklist, for vetting free
+    /// text players show one another
+    pub fn contains_blocked_word(&self, text: &str) -> bool {
+        let validator = self.word_validator();
+        text.split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .any(|word| validator.is_blocked(word))
+    }
+
+    fn word_validator(&self) -> Arc<WordValidator> {
+        self.word_validator.read().unwrap().clone()
     }
 
     /// Create a new GameManager with word lists loaded from the default directory
@@ -154,11 +646,278 @@ impl GameManager {
         Self::new(connection_manager, words_dir)
     }
 
-    pub async fn create_game(&self, players: Vec<ConnectionId>) -> Result<String, String> {
+    /// Set the rules applied to games created from now on
+    pub fn with_rules(mut self, rules: GameRules) -> Self {
+        self.game_rules = rules;
+        self
+    }
+
+    /// Hold new games in the `Waiting` phase until every player sends `Ready`,
+    /// or until `timeout` elapses. `None` starts games immediately.
+    pub fn with_ready_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.ready_timeout = timeout;
+        self
+    }
+
+    pub fn ready_timeout(&self) -> Option<Duration> {
+        self.ready_timeout
+    }
+
+    /// Drive game timers from `clock` instead of the system clock
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Refuse to create games while `max` games are still running.
+    /// `None` leaves the number of games unbounded.
+    pub fn with_max_active_games(mut self, max: Option<usize>) -> Self {
+        self.max_active_games = max;
+        self
+    }
+
+    /// Keep a game whose players have all disconnected for `grace` before
+    /// cleaning it up, so a shared outage doesn't end it. `None` removes it
+    /// on the next cleanup pass.
+    pub fn with_empty_game_grace(mut self, grace: Option<Duration>) -> Self {
+        self.empty_game_grace = grace;
+        self
+    }
+
+    /// Require a single-use token to rejoin a game. A token stays valid for
+    /// `ttl` after it was issued or after its player disconnected, whichever
+    /// is later. `None` lets any authenticated player rejoin without one.
+    pub fn with_rejoin_token_ttl(mut self, ttl: Option<Duration>) -> Self {
+        self.rejoin_token_ttl = ttl;
+        self
+    }
+
+    /// Whether rejoining a game requires a token from `issue_rejoin_token`
+    pub fn rejoin_tokens_required(&self) -> bool {
+        self.rejoin_token_ttl.is_some()
+    }
+
+    /// Issue a fresh rejoin token for `player_id`, replacing any earlier one.
+    /// Returns `None` when tokens are disabled or the player isn't in the game.
+    pub async fn issue_rejoin_token(&self, game_id: &str, player_id: &PlayerId) -> Option<String> {
+        self.rejoin_token_ttl?;
+        let mut games = self.active_games.write().await;
+        let active_game = games.get_mut(game_id)?;
+        if !active_game.player_to_connection.contains_key(player_id) {
+            return None;
+        }
+
+        let token = Uuid::new_v4().to_string();
+        active_game.rejoin_tokens.insert(
+            player_id.clone(),
+            RejoinToken {
+                token: token.clone(),
+                issued_at: active_game.game.clock().now(),
+                used: false,
+            },
+        );
+        Some(token)
+    }
+
+    /// Avoid reusing any of a game's last `window` target words when the
+    /// dictionary allows it. 0 lets targets repeat freely.
+    pub fn with_recent_word_window(mut self, window: usize) -> Self {
+        self.recent_word_window = window;
+        self
+    }
+
+    /// Let at most `max` guess submissions be processed at once; the rest
+    /// queue in arrival order. `None` leaves submissions unbounded.
+    pub fn with_max_concurrent_guesses(mut self, max: Option<usize>) -> Self {
+        self.guess_permits = max.map(Semaphore::new);
+        self
+    }
+
+    /// Record each game in `repository` when it is created, completed or
+    /// abandoned
+    pub fn with_game_repository(mut self, repository: Arc<GameRepository>) -> Self {
+        self.game_repository = Some(repository);
+        self
+    }
+
+    /// Draw every word of a game from one category picked at random when it
+    /// is created, so its rounds feel related. Only applies when the rules
+    /// name no categories of their own and some categories are loaded.
+    pub fn with_themed_games(mut self, themed: bool) -> Self {
+        self.themed_games = themed;
+        self
+    }
+
+    /// End games that have run longer than `max` since they were created,
+    /// however active they still are. The player leading at that point wins.
+    /// `None` lets games run until someone reaches the point threshold.
+    pub fn with_max_game_duration(mut self, max: Option<Duration>) -> Self {
+        self.max_game_duration = max;
+        self
+    }
+
+    /// Show a rejoining player under the display name on their current
+    /// login, if it changed since the game started. Otherwise players keep
+    /// the name they started the game with.
+    pub fn with_rename_on_rejoin(mut self, rename: bool) -> Self {
+        self.rename_on_rejoin = rename;
+        self
+    }
+
+    /// Theme categories a game draws its words from; empty for the whole
+    /// dictionary or an unknown game
+    /// Resolve collaborative rounds with the guesses in once `limit` has
+    /// passed, so one idle player cannot stall the game. `None` waits for
+    /// every connected player.
+    pub fn with_round_time_limit(mut self, limit: Option<Duration>) -> Self {
+        self.round_time_limit = limit;
+        self
+    }
+
+    pub async fn game_categories(&self, game_id: &str) -> Vec<String> {
+        let games = self.active_games.read().await;
+        games
+            .get(game_id)
+            .map(|game| game.game.rules.word_categories.clone())
+            .unwrap_or_default()
+    }
+
+    /// Rules for a new game created from `seed`, with its theme picked
+    fn rules_for_new_game(&self, seed: u64, practice: bool) -> GameRules {
+        let mut rules = self.game_rules.clone();
+        if self.themed_games && !practice && rules.word_categories.is_empty() {
+            let validator = self.word_validator();
+            let names = validator.category_names();
+            if !names.is_empty() {
+                rules.word_categories =
+                    vec![names[(seed % names.len() as u64) as usize].to_string()];
+            }
+        }
+        rules
+    }
+
+    /// Pick the target word for a round from the game seed, skipping the
+    /// game's recent targets. Depends on nothing outside the game, so
+    /// `replay_target_word` can repeat the draw.
+    fn choose_target_word(
+        &self,
+        seed: u64,
+        round: i32,
+        categories: &[String],
+        recent: &mut RecentWords,
+    ) -> GameResult<String> {
+        let word = self
+            .word_validator()
+            .get_random_word_in_categories_avoiding(categories, round_seed(seed, round), recent)
+            .map_err(|e| GameError::WordSelection {
+                reason: e.to_string(),
+            })?;
+        recent.push(word.clone());
+        Ok(word)
+    }
+
+    /// Whether the server is at its configured game capacity
+    pub async fn at_capacity(&self) -> bool {
+        let games = self.active_games.read().await;
+        self.is_at_capacity(&games)
+    }
+
+    fn is_at_capacity(&self, games: &HashMap<String, ActiveGame>) -> bool {
+        self.max_active_games
+            .is_some_and(|max| games.values().filter(|g| g.is_running()).count() >= max)
+    }
+
+    pub async fn create_game(&self, players: Vec<ConnectionId>) -> GameResult<String> {
+        self.create_game_with_seed(players, rand::random()).await
+    }
+
+    /// Create a game whose target words are chosen from a fixed seed, so the
+    /// word sequence can be reproduced
+    pub async fn create_game_with_seed(
+        &self,
+        players: Vec<ConnectionId>,
+        seed: u64,
+    ) -> GameResult<String> {
+        if players.len() < 2 {
+            return Err(GameError::InvalidPlayerCount {
+                count: players.len(),
+            });
+        }
+        self.start_game(players, seed, None, None, false).await
+    }
+
+    /// Create a game won at `point_threshold` points, refusing thresholds
+    /// outside `POINT_THRESHOLD_RANGE`. A threshold given here is played as
+    /// announced even when the rules scale thresholds by word length; `None`
+    /// leaves it to the rules.
+    pub async fn create_game_with_threshold(
+        &self,
+        players: Vec<ConnectionId>,
+        point_threshold: Option<i32>,
+    ) -> GameResult<String> {
+        let point_threshold = point_threshold.map(validate_point_threshold).transpose()?;
         if players.len() < 2 {
-            return Err("Need at least 2 players to create a game".to_string());
+            return Err(GameError::InvalidPlayerCount {
+                count: players.len(),
+            });
         }
+        self.start_game(players, rand::random(), None, point_threshold, false)
+            .await
+    }
+
+    /// Create the game for a private lobby, won at `point_threshold` like
+    /// `create_game_with_threshold`. It is left out of the live games list
+    /// and can't be spectated.
+    pub async fn create_private_game(
+        &self,
+        players: Vec<ConnectionId>,
+        point_threshold: Option<i32>,
+    ) -> GameResult<String> {
+        let point_threshold = point_threshold.map(validate_point_threshold).transpose()?;
+        if players.len() < 2 {
+            return Err(GameError::InvalidPlayerCount {
+                count: players.len(),
+            });
+        }
+        self.start_game(players, rand::random(), None, point_threshold, true)
+            .await
+    }
+
+    /// Create a solo practice game whose first target is `word`, so scoring
+    /// and edge cases can be reproduced on a known word. Later words follow
+    /// the game seed as usual. Practice games never count toward stats.
+    pub async fn create_practice_game(
+        &self,
+        connection_id: ConnectionId,
+        word: &str,
+    ) -> GameResult<String> {
+        let word = self.word_validator().normalize_guess(word);
+        if !PRACTICE_WORD_LENGTHS.contains(&letter_count(&word)) {
+            return Err(GameError::PracticeWordLength {
+                min: *PRACTICE_WORD_LENGTHS.start(),
+                max: *PRACTICE_WORD_LENGTHS.end(),
+            });
+        }
+        if !self.word_validator().is_valid_word(&word) {
+            return Err(GameError::InvalidWord { word });
+        }
+
+        self.start_game(vec![connection_id], rand::random(), Some(word), None, false)
+            .await
+    }
 
+    /// Set up and register a game for `players`. Without a `practice_word`
+    /// the first target is drawn from the seed. Without a `point_threshold`
+    /// the game plays to the default, scaled by word length when the rules
+    /// ask for it. `private` games can't be spectated.
+    async fn start_game(
+        &self,
+        players: Vec<ConnectionId>,
+        seed: u64,
+        practice_word: Option<String>,
+        point_threshold: Option<i32>,
+        private: bool,
+    ) -> GameResult<String> {
         // Validate that all players are authenticated and get their user info
         let mut authenticated_players = Vec::new();
         for connection_id in &players {
@@ -166,15 +925,12 @@ impl GameManager {
                 .connection_manager
                 .get_connection(*connection_id)
                 .await
-                .ok_or_else(|| format!("Connection {} not found", connection_id))?;
-
-            if !connection.is_authenticated {
-                return Err(format!("Connection {} is not authenticated", connection_id));
-            }
+                .ok_or(GameError::PlayerNotConnected)?;
 
             let user = connection
                 .user
-                .ok_or_else(|| format!("No user info for connection {}", connection_id))?;
+                .filter(|_| connection.is_authenticated)
+                .ok_or(GameError::PlayerNotAuthenticated)?;
 
             authenticated_players.push((*connection_id, user));
         }
@@ -183,254 +939,826 @@ impl GameManager {
         let mut user_ids = std::collections::HashSet::new();
         for (_, user) in &authenticated_players {
             if !user_ids.insert(user.id.clone()) {
-                return Err(format!("User {} is already in the game", user.display_name));
+                return Err(GameError::DuplicatePlayer {
+                    display_name: user.display_name.clone(),
+                });
+            }
+        }
+
+        let practice = practice_word.is_some();
+        let mut rules = self.rules_for_new_game(seed, practice);
+        // A threshold the match asked for is the one players were promised
+        if point_threshold.is_some() {
+            rules.points_per_letter = None;
+        }
+        let point_threshold = point_threshold.unwrap_or(DEFAULT_POINT_THRESHOLD);
+        let mut recent_targets = RecentWords::new(self.recent_word_window);
+        let target_word = match practice_word {
+            Some(word) => word,
+            // Pick the first word from the game seed
+            None => {
+                self.choose_target_word(seed, 1, &rules.word_categories, &mut recent_targets)?
+            }
+        };
+
+        let game_id = Uuid::new_v4().to_string();
+        let player_ids: Vec<PlayerId> = authenticated_players
+            .iter()
+            .map(|(_, user)| user.id.clone())
+            .collect();
+        let mut active_game = ActiveGame::new(
+            game_id.clone(),
+            authenticated_players,
+            target_word,
+            point_threshold,
+            rules,
+            seed,
+            // A lone practice player has nobody to wait for
+            self.ready_timeout.is_some() && !practice,
+            self.clock.clone(),
+        )?;
+        active_game.practice = practice;
+        active_game.private = private;
+        active_game.recent_targets = recent_targets;
+        let word_length = active_game.game.state.word_length;
+
+        {
+            // Check capacity under the same lock as the insert so concurrent
+            // matches cannot over-admit
+            let mut games = self.active_games.write().await;
+            if self.is_at_capacity(&games) {
+                tracing::warn!(
+                    "Refusing to create game: limit of {} active games reached",
+                    self.max_active_games.unwrap_or_default()
+                );
+                return Err(GameError::ServerAtCapacity);
+            }
+            games.insert(game_id.clone(), active_game);
+        }
+
+        {
+            let mut connection_to_game = self.connection_to_game.write().await;
+            for &player_id in &players {
+                connection_to_game.insert(player_id, game_id.clone());
+            }
+        }
+
+        if let Some(repository) = &self.game_repository
+            && !practice
+        {
+            let mode = if self.game_rules.team_mode {
+                "team"
+            } else {
+                "classic"
+            };
+            if let Err(e) = repository
+                .record_game_created(
+                    &game_id,
+                    &player_ids,
+                    word_length,
+                    chrono::Utc::now(),
+                    mode,
+                    seed,
+                )
+                .await
+            {
+                tracing::warn!("Failed to record creation of game {}: {}", game_id, e);
+            }
+        }
+
+        info!(
+            "Created {} {} with {} authenticated players (seed {})",
+            if practice { "practice game" } else { "game" },
+            game_id,
+            players.len(),
+            seed
+        );
+        Ok(game_id)
+    }
+
+    /// Record a guess. Recording only holds the games lock briefly; when the
+    /// guess completes a collaborative round, the winner is picked with the
+    /// lock released and applied under a second, short lock.
+    pub async fn submit_guess(
+        &self,
+        game_id: &str,
+        connection_id: ConnectionId,
+        word: String,
+    ) -> GameResult<GameEvent> {
+        let event = self.apply_guess(game_id, connection_id, word).await;
+        // A guess that ended the game leaves its record to write
+        self.record_completions().await;
+        event
+    }
+
+    async fn apply_guess(
+        &self,
+        game_id: &str,
+        connection_id: ConnectionId,
+        word: String,
+    ) -> GameResult<GameEvent> {
+        if word.trim().is_empty() {
+            return Err(GameError::EmptyGuess);
+        }
+
+        let _permit = match &self.guess_permits {
+            Some(permits) => Some(permits.acquire().await.expect("Guess permits never close")),
+            None => None,
+        };
+
+        let round_guesses = {
+            let mut games = self.active_games.write().await;
+            let active_game = games.get_mut(game_id).ok_or(GameError::GameNotFound)?;
+
+            active_game.update_activity();
+            active_game.game.end_intermission_if_elapsed();
+
+            let Some(player_id) = active_game
+                .connection_to_player
+                .get(&connection_id)
+                .cloned()
+            else {
+                // Spectators can see the game but have no seat to guess from
+                if self
+                    .connection_manager
+                    .is_spectating(connection_id, game_id)
+                    .await
+                {
+                    return Err(GameError::SpectatorCannotGuess);
+                }
+                return Err(GameError::PlayerNotInGame);
+            };
+
+            // Out of time: the guess is dropped and the game ends here
+            if active_game.is_running() && active_game.is_overlong(self.max_game_duration) {
+                return self.end_at_time_limit(active_game).await;
+            }
+            active_game.game.throttle_guess(&player_id)?;
+
+            // Normalize the input the way the dictionary stores words, then
+            // validate it. A length mismatch is reported ahead of dictionary
+            // misses, as it is the clearer mistake.
+            let word = self.word_validator().normalize_guess(&word);
+            let rules = &active_game.game.rules;
+            let expected = active_game.game.state.word_length as usize;
+            let actual = letter_count(&word);
+            if !rules.allow_mismatched_length && actual != expected {
+                return Err(GameError::WrongLength { expected, actual });
+            }
+            self.word_validator()
+                .check_guess(&word, rules.strict_guesses)?;
+            if rules.category_guesses_only
+                && !rules.word_categories.is_empty()
+                && !self
+                    .word_validator()
+                    .is_in_categories(&word, &rules.word_categories)
+            {
+                return Err(GameError::InvalidWord { word });
+            }
+
+            // Handle different game phases
+            tracing::info!(
+                "Processing guess '{}' from player {} in phase {:?}",
+                word,
+                player_id,
+                active_game.game.current_phase
+            );
+            if active_game.game.current_phase != GamePhase::Guessing {
+                return self
+                    .submit_individual_guess(active_game, connection_id, player_id, word)
+                    .await;
+            }
+
+            // Collaborative guessing phase
+            let replaced = active_game.game.current_guesses.contains_key(&player_id);
+            active_game.game.add_guess(&player_id, word.clone())?;
+
+            // Only process the round once enough connected players have guessed
+            if !active_game.round_quorum_reached() {
+                // Not enough players have guessed yet; a pending guess reveals
+                // nothing, so there is no state for others to refresh
+                return Ok(GameEvent::GuessPending {
+                    player_id,
+                    replaced,
+                });
+            }
+
+            active_game.game.round_guesses()
+        };
+
+        // Enough players have guessed. Scoring every guess to find the winner is
+        // the expensive part, so other games and players are not held up by it.
+        let winner_index = round_guesses.pick_winner();
+        self.resolve_collected_round(game_id, &round_guesses, winner_index)
+            .await
+    }
+
+    /// Finish the round `round_guesses` was copied out of, back under the
+    /// games lock. Other submissions may have resolved that round, and even
+    /// brought the next one to quorum, while the lock was released, so the
+    /// round is read again here rather than trusted from the copy.
+    async fn resolve_collected_round(
+        &self,
+        game_id: &str,
+        round_guesses: &RoundGuesses,
+        winner_index: Option<usize>,
+    ) -> GameResult<GameEvent> {
+        let mut games = self.active_games.write().await;
+        let active_game = games.get_mut(game_id).ok_or(GameError::GameNotFound)?;
+
+        // Another submission may have finished the round in between
+        if active_game.game.current_phase != GamePhase::Guessing
+            || !active_game.round_quorum_reached()
+        {
+            return Ok(GameEvent::StateUpdate {
+                state: active_game.game.state.clone(),
+            });
+        }
+
+        let round = active_game.game.state.current_round;
+        self.finish_round(active_game, round, round_guesses, winner_index)
+            .await
+    }
+
+    /// Score a collaborative round and turn its result into an event
+    async fn finish_round(
+        &self,
+        active_game: &mut ActiveGame,
+        round: i32,
+        round_guesses: &RoundGuesses,
+        winner_index: Option<usize>,
+    ) -> GameResult<GameEvent> {
+        // Stragglers who had not guessed get no personal result this round
+        let guessed: HashSet<PlayerId> = active_game.game.current_guesses.keys().cloned().collect();
+
+        let event = match active_game
+            .game
+            .resolve_round(round_guesses, winner_index)?
+        {
+            Some(RoundResult::Continuing(winning_guess)) => {
+                // The winning_guess is already in the correct format from game-core
+                // Create personal guess results for each player
+                Ok(GameEvent::RoundResult {
+                    winning_guess,
+                    player_guesses: active_game.last_player_guesses(&guessed),
+                    is_word_completed: false, // Regular round result
+                    revealed: active_game.game.take_reveals(),
+                })
+            }
+            Some(RoundResult::GameOver(winning_guess)) => {
+                // Find the winner
+                match active_game.game.leader() {
+                    Some(winner) => {
+                        let winner = winner.clone();
+                        let history = active_game.history_for(&winner.user_id);
+                        let recap = history.recap();
+                        self.archive_finished_game(history, &winner.user_id, active_game.practice)
+                            .await;
+                        Ok(GameEvent::GameOver {
+                            winner,
+                            final_scores: active_game.game.state.players.clone(),
+                            recap,
+                            ranked: !active_game.practice,
+                            categories: active_game.game.rules.word_categories.clone(),
+                            reason: GameOverReason::PointThreshold,
+                        })
+                    }
+                    // Fallback to round result if no winner found
+                    None => Ok(GameEvent::RoundResult {
+                        winning_guess,
+                        player_guesses: active_game.last_player_guesses(&guessed),
+                        is_word_completed: false, // Game over case
+                        revealed: active_game.game.take_reveals(),
+                    }),
+                }
+            }
+            Some(RoundResult::WordCompleted(round_completion)) => {
+                // Start a new round with a fresh word
+                self.start_new_round(active_game, round_completion).await
+            }
+            None => {
+                // No round result yet (waiting for more guesses)
+                Ok(GameEvent::StateUpdate {
+                    state: active_game.game.state.clone(),
+                })
+            }
+        };
+
+        // The next round or individual guess gets a fresh timer
+        active_game.round_deadline = None;
+        active_game.record_round_event(round, &event);
+        event
+    }
+
+    /// Start timers on rounds and individual guesses that lack one, and
+    /// resolve those whose time has run out. A round nobody guessed in gets
+    /// a fresh timer instead, as there is nothing to score; a round winner
+    /// who runs out of time forfeits their individual guess. Paused games
+    /// hold no timer and get a fresh one once a player is back.
+    pub async fn tick_round_timers(&self) -> Vec<(String, RoundTimer)> {
+        let Some(limit) = self.round_time_limit else {
+            return Vec::new();
+        };
+
+        let mut timers = Vec::new();
+        let mut games = self.active_games.write().await;
+        for (game_id, active_game) in games.iter_mut() {
+            let phase = active_game.game.current_phase.clone();
+            if active_game.game.state.status != GameStatus::Active
+                || !matches!(phase, GamePhase::Guessing | GamePhase::IndividualGuess)
+            {
+                active_game.round_deadline = None;
+                continue;
+            }
+
+            let now = active_game.game.clock().now();
+            match active_game.round_deadline {
+                Some(deadline) if now < deadline => {}
+                Some(_) if phase == GamePhase::IndividualGuess => {
+                    active_game.round_deadline = None;
+                    match active_game.game.forfeit_turn() {
+                        Ok(game_over) => {
+                            let event = if game_over {
+                                self.declare_winner(active_game, GameOverReason::PointThreshold)
+                                    .await
+                            } else {
+                                GameEvent::StateUpdate {
+                                    state: active_game.game.state.clone(),
+                                }
+                            };
+                            timers.push((game_id.clone(), RoundTimer::Expired(Box::new(event))))
+                        }
+                        Err(e) => {
+                            tracing::warn!("Failed to end timed out turn in {}: {}", game_id, e)
+                        }
+                    }
+                }
+                Some(_) if !active_game.game.current_guesses.is_empty() => {
+                    tracing::info!(
+                        "Round timer ran out in game {} with {} guesses in",
+                        game_id,
+                        active_game.game.current_guesses.len()
+                    );
+                    let round = active_game.game.state.current_round;
+                    let round_guesses = active_game.game.round_guesses();
+                    let winner_index = round_guesses.pick_winner();
+                    match self
+                        .finish_round(active_game, round, &round_guesses, winner_index)
+                        .await
+                    {
+                        Ok(event) => {
+                            timers.push((game_id.clone(), RoundTimer::Expired(Box::new(event))))
+                        }
+                        Err(e) => {
+                            tracing::warn!(
+                                "Failed to resolve timed out round in {}: {}",
+                                game_id,
+                                e
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    active_game.round_deadline = Some(now + limit);
+                    timers.push((
+                        game_id.clone(),
+                        RoundTimer::Started {
+                            seconds_remaining: limit.as_secs_f64().ceil() as u32,
+                        },
+                    ));
+                }
+            }
+        }
+        drop(games);
+
+        // Timed out rounds and turns may have ended games
+        self.record_completions().await;
+        timers
+    }
+
+    /// End the games that ran past `max_game_duration` without a guess to
+    /// notice, returning the events to publish for them
+    pub async fn end_overlong_games(&self) -> Vec<(String, GameEvent)> {
+        if self.max_game_duration.is_none() {
+            return Vec::new();
+        }
+
+        let mut ended = Vec::new();
+        let mut games = self.active_games.write().await;
+        for (game_id, active_game) in games.iter_mut() {
+            if !active_game.is_running() || !active_game.is_overlong(self.max_game_duration) {
+                continue;
             }
+            match self.end_at_time_limit(active_game).await {
+                Ok(event) => ended.push((game_id.clone(), event)),
+                Err(e) => tracing::warn!("Failed to end overlong game {}: {}", game_id, e),
+            }
+        }
+        drop(games);
+
+        self.record_completions().await;
+        ended
+    }
+
+    /// End a game that ran past `max_game_duration`, declaring whoever is
+    /// leading the winner
+    async fn end_at_time_limit(&self, active_game: &mut ActiveGame) -> GameResult<GameEvent> {
+        active_game.game.finish();
+        if let Some(winner) = active_game.game.leader() {
+            tracing::info!(
+                "Game {} reached its time limit; {} wins with {} points",
+                active_game.game.state.id,
+                winner.display_name,
+                winner.points
+            );
         }
+        Ok(self
+            .declare_winner(active_game, GameOverReason::TimeLimit)
+            .await)
+    }
+
+    /// Archive a game that just ended and announce its leader as the winner
+    async fn declare_winner(
+        &self,
+        active_game: &mut ActiveGame,
+        reason: GameOverReason,
+    ) -> GameEvent {
+        let Some(winner) = active_game.game.leader().cloned() else {
+            return GameEvent::StateUpdate {
+                state: active_game.game.state.clone(),
+            };
+        };
+
+        let history = active_game.history_for(&winner.user_id);
+        let recap = history.recap();
+        self.archive_finished_game(history, &winner.user_id, active_game.practice)
+            .await;
+        GameEvent::GameOver {
+            winner,
+            final_scores: active_game.game.state.players.clone(),
+            recap,
+            ranked: !active_game.practice,
+            categories: active_game.game.rules.word_categories.clone(),
+            reason,
+        }
+    }
+
+    /// Handle a guess outside collaborative guessing: the round winner's
+    /// individual guess, or a guess in a phase that allows none
+    async fn submit_individual_guess(
+        &self,
+        active_game: &mut ActiveGame,
+        connection_id: ConnectionId,
+        player_id: PlayerId,
+        word: String,
+    ) -> GameResult<GameEvent> {
+        if active_game.game.current_phase != GamePhase::IndividualGuess {
+            // Other phases like Waiting, Countdown, GameOver - no guessing allowed
+            return Err(GameError::WrongPhase {
+                phase: active_game.game.current_phase.clone(),
+            });
+        }
+
+        // Individual guess phase - only winner can guess
+        tracing::info!(
+            "Individual guess phase - current winner: {:?}, submitting player: {}",
+            active_game.game.state.current_winner,
+            player_id
+        );
+        let round = active_game.game.state.current_round;
+        let event = match active_game
+            .game
+            .process_individual_guess(player_id.clone(), word)?
+        {
+            Some(RoundResult::Continuing(guess_result)) => {
+                // Create personal guess for the player
+                let personal_guess = active_game
+                    .game
+                    .state
+                    .players
+                    .iter()
+                    .find(|p| p.user_id == player_id)
+                    .and_then(|p| p.guess_history.last())
+                    .map(|last_guess| vec![(connection_id, last_guess.clone())])
+                    .unwrap_or_default();
+
+                Ok(GameEvent::RoundResult {
+                    winning_guess: guess_result,
+                    player_guesses: personal_guess,
+                    is_word_completed: false, // Regular round, not word completion
+                    revealed: active_game.game.take_reveals(),
+                })
+            }
+            Some(RoundResult::WordCompleted(round_completion)) => {
+                // Start a new round with a fresh word
+                self.start_new_round(active_game, round_completion).await
+            }
+            Some(RoundResult::GameOver(_guess_result)) => Ok(self
+                .declare_winner(active_game, GameOverReason::PointThreshold)
+                .await),
+            None => Ok(GameEvent::StateUpdate {
+                state: active_game.game.state.clone(),
+            }),
+        };
+
+        // Whatever comes next gets a fresh timer
+        active_game.round_deadline = None;
+        active_game.record_round_event(round, &event);
+        event
+    }
+
+    /// Pass on the individual guess the player earned by winning the round
+    pub async fn skip_turn(
+        &self,
+        game_id: &str,
+        connection_id: ConnectionId,
+    ) -> GameResult<GameState> {
+        let mut games = self.active_games.write().await;
+        let active_game = games.get_mut(game_id).ok_or(GameError::GameNotFound)?;
+
+        let player_id = active_game
+            .connection_to_player
+            .get(&connection_id)
+            .ok_or(GameError::PlayerNotInGame)?
+            .clone();
+
+        active_game.game.end_intermission_if_elapsed();
+        active_game.game.skip_turn(&player_id)?;
+        active_game.round_deadline = None;
+        active_game.update_activity();
 
-        let game_id = Uuid::new_v4().to_string();
-        let active_game =
-            ActiveGame::new(game_id.clone(), authenticated_players, &self.word_validator)?;
+        Ok(active_game.convert_to_api_state())
+    }
 
-        {
-            let mut games = self.active_games.write().await;
-            games.insert(game_id.clone(), active_game);
-        }
+    /// Record a player's vote to skip the current collaborative round. Once
+    /// enough connected players agree, the word is revealed and a new round
+    /// begins without crediting anyone.
+    pub async fn vote_skip_round(
+        &self,
+        game_id: &str,
+        connection_id: ConnectionId,
+    ) -> GameResult<SkipRoundUpdate> {
+        let mut games = self.active_games.write().await;
+        let active_game = games.get_mut(game_id).ok_or(GameError::GameNotFound)?;
 
-        {
-            let mut connection_to_game = self.connection_to_game.write().await;
-            for &player_id in &players {
-                connection_to_game.insert(player_id, game_id.clone());
-            }
+        let player_id = active_game
+            .connection_to_player
+            .get(&connection_id)
+            .ok_or(GameError::PlayerNotInGame)?
+            .clone();
+
+        let fraction = active_game
+            .game
+            .rules
+            .skip_round_vote_fraction
+            .ok_or(GameError::RoundSkipNotAllowed)?;
+
+        active_game.game.end_intermission_if_elapsed();
+        if active_game.game.current_phase != GamePhase::Guessing {
+            return Err(GameError::WrongPhase {
+                phase: active_game.game.current_phase.clone(),
+            });
         }
 
-        info!(
-            "Created game {} with {} authenticated players",
-            game_id,
-            players.len()
-        );
-        Ok(game_id)
+        active_game.update_activity();
+        active_game.skip_votes.insert(player_id);
+
+        // Only players still connected count, toward the votes and the total
+        let connected = active_game.player_to_connection.len();
+        let votes = active_game
+            .skip_votes
+            .iter()
+            .filter(|voter| active_game.player_to_connection.contains_key(*voter))
+            .count() as u32;
+        let needed = ((connected as f64 * fraction).ceil() as u32).max(1);
+
+        let skipped_word = if votes >= needed {
+            let word = active_game.game.target_word.clone();
+            self.advance_to_next_word(active_game, None).await?;
+            tracing::info!(
+                "Round skipped by vote in game {}: word was '{}'",
+                game_id,
+                word
+            );
+            Some(word)
+        } else {
+            None
+        };
+
+        Ok(SkipRoundUpdate {
+            votes,
+            needed,
+            skipped_word,
+            state: active_game.convert_to_api_state(),
+        })
     }
 
-    pub async fn submit_guess(
+    /// Reveal a letter of the current word to one player for the rules' hint
+    /// penalty
+    pub async fn request_hint(
         &self,
         game_id: &str,
         connection_id: ConnectionId,
-        word: String,
-    ) -> Result<GameEvent, String> {
+    ) -> GameResult<HintUpdate> {
         let mut games = self.active_games.write().await;
-        let active_game = games.get_mut(game_id).ok_or("Game not found")?;
+        let active_game = games.get_mut(game_id).ok_or(GameError::GameNotFound)?;
+
+        let player_id = active_game
+            .connection_to_player
+            .get(&connection_id)
+            .ok_or(GameError::PlayerNotInGame)?
+            .clone();
 
+        active_game.game.end_intermission_if_elapsed();
+        let points = |game: &Game| {
+            game.state
+                .players
+                .iter()
+                .find(|p| p.user_id == player_id)
+                .map_or(0, |p| p.points)
+        };
+        let points_before = points(&active_game.game);
+        let letter = active_game.game.request_hint(&player_id)?;
         active_game.update_activity();
 
+        Ok(HintUpdate {
+            letter,
+            points_deducted: points_before - points(&active_game.game),
+            state: active_game.convert_to_api_state(),
+        })
+    }
+
+    /// Record that a player is ready. Starts the first round once every
+    /// connected player has confirmed.
+    pub async fn mark_player_ready(
+        &self,
+        game_id: &str,
+        connection_id: ConnectionId,
+    ) -> GameResult<ReadyUpdate> {
+        let mut games = self.active_games.write().await;
+        let active_game = games.get_mut(game_id).ok_or(GameError::GameNotFound)?;
+
         let player_id = active_game
             .connection_to_player
             .get(&connection_id)
-            .ok_or("Player not in game")?
+            .ok_or(GameError::PlayerNotInGame)?
             .clone();
 
-        // Validate word
-        if !self.word_validator.is_valid_word(&word) {
-            return Err("Invalid word".to_string());
+        if active_game.game.current_phase != GamePhase::Waiting {
+            return Err(GameError::WrongPhase {
+                phase: active_game.game.current_phase.clone(),
+            });
         }
 
-        // Handle different game phases
-        tracing::info!(
-            "Processing guess '{}' from player {} in phase {:?}",
-            word,
+        active_game.update_activity();
+        active_game.ready_players.insert(player_id.clone());
+
+        let game_started = active_game.all_connected_ready();
+        if game_started {
+            info!(
+                "All players ready in game {}, starting first round",
+                game_id
+            );
+            active_game.begin_guessing();
+        }
+
+        Ok(ReadyUpdate {
             player_id,
-            active_game.game.current_phase
+            game_started,
+            state: active_game.convert_to_api_state(),
+        })
+    }
+
+    /// Time left in the game's post-round intermission, if one is running
+    pub async fn intermission_remaining(&self, game_id: &str) -> Option<Duration> {
+        let games = self.active_games.read().await;
+        games.get(game_id)?.game.intermission_remaining()
+    }
+
+    /// Resume play in a game whose intermission has elapsed. Returns the new
+    /// state if the intermission ended.
+    pub async fn end_intermission(&self, game_id: &str) -> Option<GameState> {
+        let mut games = self.active_games.write().await;
+        let active_game = games.get_mut(game_id)?;
+
+        if !active_game.game.end_intermission_if_elapsed() {
+            return None;
+        }
+        Some(active_game.convert_to_api_state())
+    }
+
+    /// Start a game still waiting on its ready check, treating unready players
+    /// as ready. Returns the new state if the game was started.
+    pub async fn start_after_ready_timeout(&self, game_id: &str) -> Option<GameState> {
+        let mut games = self.active_games.write().await;
+        let active_game = games.get_mut(game_id)?;
+
+        if active_game.game.current_phase != GamePhase::Waiting {
+            return None;
+        }
+
+        info!(
+            "Ready check timed out for game {} ({}/{} ready), starting first round",
+            game_id,
+            active_game.ready_players.len(),
+            active_game.game.state.players.len()
         );
-        match active_game.game.current_phase {
-            GamePhase::IndividualGuess => {
-                // Individual guess phase - only winner can guess
-                tracing::info!(
-                    "Individual guess phase - current winner: {:?}, submitting player: {}",
-                    active_game.game.state.current_winner,
-                    player_id
-                );
-                match active_game
-                    .game
-                    .process_individual_guess(player_id.clone(), word)
-                {
-                    Ok(Some(round_result)) => {
-                        match round_result {
-                            RoundResult::Continuing(guess_result) => {
-                                // Create personal guess for the player
-                                let personal_guess = if let Some(last_guess) = active_game
-                                    .game
-                                    .state
-                                    .players
-                                    .iter()
-                                    .find(|p| p.user_id == player_id)
-                                    .and_then(|p| p.guess_history.last())
-                                {
-                                    vec![(connection_id, last_guess.clone())]
-                                } else {
-                                    vec![]
-                                };
-
-                                return Ok(GameEvent::RoundResult {
-                                    winning_guess: guess_result,
-                                    player_guesses: personal_guess,
-                                    is_word_completed: false, // Regular round, not word completion
-                                });
-                            }
-                            RoundResult::WordCompleted(round_completion) => {
-                                // Start a new round with a fresh word
-                                self.start_new_round(active_game, round_completion).await
-                            }
-                            RoundResult::GameOver(_guess_result) => {
-                                if let Some(winner) = active_game
-                                    .game
-                                    .state
-                                    .players
-                                    .iter()
-                                    .max_by_key(|p| p.points)
-                                {
-                                    let final_scores = active_game.game.state.players.clone();
-                                    return Ok(GameEvent::GameOver {
-                                        winner: winner.clone(),
-                                        final_scores,
-                                    });
-                                } else {
-                                    return Err("Game over but no winner found".to_string());
-                                }
-                            }
-                        }
-                    }
-                    Ok(None) => {
-                        return Ok(GameEvent::StateUpdate {
-                            state: active_game.game.state.clone(),
-                        });
-                    }
-                    Err(e) => {
-                        return Err(format!("Individual guess error: {:?}", e));
-                    }
-                }
-            }
-            GamePhase::Guessing => {
-                // Collaborative guessing phase
-                if let Err(e) = active_game.game.add_guess(&player_id, word.clone()) {
-                    return Err(format!("Failed to add guess: {:?}", e));
-                }
+        active_game.begin_guessing();
+        Some(active_game.convert_to_api_state())
+    }
 
-                // Check if all connected players have submitted guesses
-                let connected_players: Vec<_> = active_game
-                    .game
-                    .state
-                    .players
+    /// Full history of a game for one of its participants. Finished games are
+    /// served from a cache of recently completed games.
+    pub async fn get_game_history(&self, game_id: &str, user_id: &str) -> GameResult<GameHistory> {
+        let history = {
+            let games = self.active_games.read().await;
+            games.get(game_id).map(|game| game.history_for(user_id))
+        };
+
+        let history = match history {
+            Some(history) => history,
+            None => {
+                let finished_games = self.finished_games.read().await;
+                finished_games
                     .iter()
-                    .filter(|p| p.is_connected)
-                    .map(|p| p.user_id.clone())
-                    .collect();
+                    .find(|h| h.game_id == game_id)
+                    .cloned()
+                    .ok_or(GameError::GameNotFound)?
+            }
+        };
 
-                let all_connected_guessed = connected_players
-                    .iter()
-                    .all(|player_id| active_game.game.current_guesses.contains_key(player_id));
+        if !history.players.iter().any(|p| p.user_id == user_id) {
+            return Err(GameError::PlayerNotInGame);
+        }
 
-                // Only process the round if all connected players have guessed
-                if !all_connected_guessed {
-                    // Not all players have guessed yet, return state update
-                    return Ok(GameEvent::StateUpdate {
-                        state: active_game.game.state.clone(),
-                    });
-                }
+        Ok(history)
+    }
 
-                // All players have guessed, process the round
-                match active_game.game.process_round() {
-                    Ok(Some(round_result)) => {
-                        match round_result {
-                            RoundResult::Continuing(winning_guess) => {
-                                // The winning_guess is already in the correct format from game-core
-                                // Create personal guess results for each player
-                                let player_guesses: Vec<(ConnectionId, PersonalGuess)> =
-                                    active_game
-                                        .game
-                                        .state
-                                        .players
-                                        .iter()
-                                        .filter_map(|player| {
-                                            let conn_id = active_game
-                                                .player_to_connection
-                                                .get(&player.user_id)?;
-
-                                            // Get the last guess from their history (most recent)
-                                            if let Some(last_guess) = player.guess_history.last() {
-                                                Some((*conn_id, last_guess.clone()))
-                                            } else {
-                                                None
-                                            }
-                                        })
-                                        .collect();
-
-                                Ok(GameEvent::RoundResult {
-                                    winning_guess,
-                                    player_guesses,
-                                    is_word_completed: false, // Regular round result
-                                })
-                            }
-                            RoundResult::GameOver(winning_guess) => {
-                                // Find the winner
-                                if let Some(winner) = active_game
-                                    .game
-                                    .state
-                                    .players
-                                    .iter()
-                                    .max_by_key(|p| p.points)
-                                {
-                                    let final_scores = active_game.game.state.players.clone();
-
-                                    Ok(GameEvent::GameOver {
-                                        winner: winner.clone(),
-                                        final_scores,
-                                    })
-                                } else {
-                                    // Fallback to round result if no winner found
-                                    let player_guesses: Vec<(ConnectionId, PersonalGuess)> =
-                                        active_game
-                                            .game
-                                            .state
-                                            .players
-                                            .iter()
-                                            .filter_map(|player| {
-                                                let conn_id = active_game
-                                                    .player_to_connection
-                                                    .get(&player.user_id)?;
-                                                if let Some(last_guess) =
-                                                    player.guess_history.last()
-                                                {
-                                                    Some((*conn_id, last_guess.clone()))
-                                                } else {
-                                                    None
-                                                }
-                                            })
-                                            .collect();
-
-                                    Ok(GameEvent::RoundResult {
-                                        winning_guess,
-                                        player_guesses,
-                                        is_word_completed: false, // Game over case
-                                    })
-                                }
-                            }
-                            RoundResult::WordCompleted(round_completion) => {
-                                // Start a new round with a fresh word
-                                self.start_new_round(active_game, round_completion).await
-                            }
-                        }
-                    }
-                    Ok(None) => {
-                        // No round result yet (waiting for more guesses)
-                        Ok(GameEvent::StateUpdate {
-                            state: active_game.game.state.clone(),
-                        })
-                    }
-                    Err(e) => Err(format!("Game error: {:?}", e)),
-                }
-            }
-            _ => {
-                // Other phases like Waiting, Countdown, GameOver - no guessing allowed
-                Err("Cannot submit guess in current phase".to_string())
+    /// Keep a finished game's history for later lookups. Its game record is
+    /// only queued here, as callers hold the games lock; `record_completions`
+    /// writes it once they have let go.
+    async fn archive_finished_game(
+        &self,
+        history: GameHistory,
+        winner_id: &PlayerId,
+        practice: bool,
+    ) {
+        self.metrics.record_game_completed();
+        if self.game_repository.is_some() && !practice {
+            let words = history
+                .rounds
+                .iter()
+                .filter_map(|round| round.target_word.clone())
+                .collect();
+            let final_scores = history
+                .players
+                .iter()
+                .map(|player| GameRecordScore {
+                    player_id: player.user_id.clone(),
+                    points: player.points,
+                })
+                .collect();
+            self.unrecorded_completions
+                .lock()
+                .await
+                .push(GameCompletion {
+                    game_id: history.game_id.clone(),
+                    winner_id: winner_id.clone(),
+                    words,
+                    final_scores,
+                });
+        }
+
+        let mut finished_games = self.finished_games.write().await;
+        finished_games.retain(|h| h.game_id != history.game_id);
+        if finished_games.len() >= FINISHED_GAME_CACHE_SIZE {
+            finished_games.pop_front();
+        }
+        finished_games.push_back(history);
+    }
+
+    /// Write the game records of games finished since the last call. Must
+    /// be called without holding the games lock.
+    async fn record_completions(&self) {
+        let Some(repository) = &self.game_repository else {
+            return;
+        };
+        let completions = std::mem::take(&mut *self.unrecorded_completions.lock().await);
+        for completion in completions {
+            if let Err(e) = repository
+                .record_game_completed(
+                    &completion.game_id,
+                    &completion.winner_id,
+                    &completion.words,
+                    &completion.final_scores,
+                )
+                .await
+            {
+                tracing::warn!(
+                    "Failed to record completion of game {}: {}",
+                    completion.game_id,
+                    e
+                );
             }
         }
     }
@@ -449,6 +1777,38 @@ impl GameManager {
         })
     }
 
+    /// Whether spectators may watch `game_id`
+    pub async fn is_spectatable(&self, game_id: &str) -> bool {
+        let games = self.active_games.read().await;
+        games
+            .get(game_id)
+            .is_some_and(ActiveGame::allows_spectators)
+    }
+
+    /// Running games anyone may spectate, newest first. Private lobby and
+    /// practice games are left out, and the summaries are built from what
+    /// spectators see, so they never give away the word.
+    pub async fn list_spectatable_games(&self) -> Vec<LiveGameSummary> {
+        let games = self.active_games.read().await;
+        let mut listed: Vec<(Instant, LiveGameSummary)> = games
+            .iter()
+            .filter(|(_, game)| game.allows_spectators())
+            .map(|(game_id, game)| {
+                let view = spectator_view(&game.convert_to_api_state());
+                let summary = LiveGameSummary {
+                    game_id: game_id.clone(),
+                    players: view.players,
+                    current_round: view.current_round,
+                    word_length: view.word_length,
+                    point_threshold: view.point_threshold,
+                };
+                (game.created_at, summary)
+            })
+            .collect();
+        listed.sort_by(|a, b| b.0.cmp(&a.0));
+        listed.into_iter().map(|(_, summary)| summary).collect()
+    }
+
     /// Check if a user is a participant in the given game
     pub async fn is_user_in_game(&self, game_id: &str, user_id: &str) -> bool {
         let games = self.active_games.read().await;
@@ -460,47 +1820,143 @@ impl GameManager {
         }
     }
 
+    /// Mark a player as disconnected. Returns the event to publish when the
+    /// player held the individual guess and it passed to someone else.
     pub async fn handle_player_disconnect(
         &self,
         game_id: &str,
         connection_id: ConnectionId,
-    ) -> Result<(), String> {
+    ) -> GameResult<Option<GameEvent>> {
+        let event = self.mark_disconnected(game_id, connection_id).await;
+        // Passing on a sudden-death turn can end the game
+        self.record_completions().await;
+        event
+    }
+
+    async fn mark_disconnected(
+        &self,
+        game_id: &str,
+        connection_id: ConnectionId,
+    ) -> GameResult<Option<GameEvent>> {
         let mut games = self.active_games.write().await;
-        if let Some(active_game) = games.get_mut(game_id) {
-            if let Some(player_id) = active_game.connection_to_player.get(&connection_id) {
-                // Find player in game state and mark as disconnected
-                for player in &mut active_game.game.state.players {
-                    if player.user_id == *player_id {
-                        player.is_connected = false;
-                        info!("Player {} disconnected from game {}", player_id, game_id);
-                        break;
-                    }
+        if let Some(active_game) = games.get_mut(game_id)
+            && let Some(player_id) = active_game.connection_to_player.get(&connection_id)
+        {
+            // Find player in game state and mark as disconnected
+            for player in &mut active_game.game.state.players {
+                if player.user_id == *player_id {
+                    player.is_connected = false;
+                    info!("Player {} disconnected from game {}", player_id, game_id);
+                    break;
+                }
+            }
+
+            let now = active_game.game.clock().now();
+            if active_game
+                .game
+                .state
+                .players
+                .iter()
+                .all(|p| !p.is_connected)
+            {
+                active_game.emptied_at.get_or_insert(now);
+                // Nobody is left to finish the round. Pause it with its
+                // guesses kept, so a returning player picks up where it
+                // stopped; the empty-game grace decides when to give up.
+                if active_game.game.state.status == GameStatus::Active {
+                    active_game.game.state.status = GameStatus::Paused;
+                    info!(
+                        "Game {} paused: every player disconnected ({} guesses held)",
+                        game_id,
+                        active_game.game.current_guesses.len()
+                    );
                 }
             }
+            if active_game.is_running() {
+                active_game.disconnected_at.insert(player_id.clone(), now);
+                active_game
+                    .missed_from
+                    .insert(player_id.clone(), active_game.rounds_resolved);
+            }
+
+            if active_game.game.state.status == GameStatus::Active {
+                return Ok(match active_game.pass_disconnected_turns() {
+                    (_, true) => Some(
+                        self.declare_winner(active_game, GameOverReason::PointThreshold)
+                            .await,
+                    ),
+                    (true, false) => Some(GameEvent::StateUpdate {
+                        state: active_game.game.state.clone(),
+                    }),
+                    (false, false) => None,
+                });
+            }
         }
-        Ok(())
+        Ok(None)
+    }
+
+    /// Players who have stayed disconnected from a running game for longer
+    /// than `grace`. Each player is reported once per disconnect.
+    pub async fn take_abandoned_players(&self, grace: Duration) -> Vec<PlayerId> {
+        let mut games = self.active_games.write().await;
+        let mut abandoned = Vec::new();
+
+        for active_game in games.values_mut() {
+            if !active_game.is_running() || active_game.practice {
+                active_game.disconnected_at.clear();
+                continue;
+            }
+
+            let now = active_game.game.clock().now();
+            active_game.disconnected_at.retain(|player_id, since| {
+                if now.duration_since(*since) > grace {
+                    abandoned.push(player_id.clone());
+                    false
+                } else {
+                    true
+                }
+            });
+        }
+
+        abandoned
     }
 
+    /// Reconnect to a disconnected player's seat. Alongside the current state
+    /// comes the tail of the rounds resolved while the player was away.
     pub async fn rejoin_player(
         &self,
         game_id: &str,
         connection_id: ConnectionId,
-    ) -> Result<GameState, String> {
+        token: Option<&str>,
+    ) -> GameResult<Rejoin> {
+        let connection = self.connection_manager.get_connection(connection_id).await;
+        let user_id = connection.as_ref().and_then(|c| c.user_id.clone());
         let mut games = self.active_games.write().await;
-        let active_game = games.get_mut(game_id).ok_or("Game not found")?;
+        let active_game = games.get_mut(game_id).ok_or(GameError::GameNotFound)?;
 
-        // For now, let's check if this connection was previously in the game
-        // In a more robust system, we'd verify the user ID matches a disconnected player
-
-        // Find a disconnected player to reconnect to
-        let disconnected_player = active_game
-            .game
-            .state
-            .players
-            .iter()
-            .find(|p| !p.is_connected)
-            .ok_or("No disconnected players to rejoin")?
-            .clone();
+        let disconnected_player = match self.rejoin_token_ttl {
+            // The token names the seat being reclaimed
+            Some(ttl) => {
+                let player_id = active_game.redeem_rejoin_token(token, user_id.as_ref(), ttl)?;
+                active_game
+                    .game
+                    .state
+                    .players
+                    .iter()
+                    .find(|p| p.user_id == player_id)
+                    .ok_or(GameError::PlayerNotInGame)?
+                    .clone()
+            }
+            // Without tokens, take the first disconnected seat
+            None => active_game
+                .game
+                .state
+                .players
+                .iter()
+                .find(|p| !p.is_connected)
+                .ok_or(GameError::NoSeatToRejoin)?
+                .clone(),
+        };
 
         // Update connection mappings
         active_game
@@ -510,13 +1966,37 @@ impl GameManager {
             .player_to_connection
             .insert(disconnected_player.user_id.clone(), connection_id);
 
+        // The login's display name may have changed since the game started
+        let current_name = connection
+            .and_then(|c| c.user)
+            .filter(|user| user.id == disconnected_player.user_id)
+            .map(|user| user.display_name);
+        let renamed = current_name
+            .filter(|name| self.rename_on_rejoin && *name != disconnected_player.display_name);
+
         // Mark player as connected in game state
         for player in &mut active_game.game.state.players {
             if player.user_id == disconnected_player.user_id {
                 player.is_connected = true;
+                if let Some(name) = &renamed {
+                    info!(
+                        "Player {} rejoined as '{}' (was '{}')",
+                        player.user_id, name, player.display_name
+                    );
+                    player.display_name = name.clone();
+                }
                 break;
             }
         }
+        active_game
+            .disconnected_at
+            .remove(&disconnected_player.user_id);
+        active_game.emptied_at = None;
+        if active_game.game.state.status == GameStatus::Paused {
+            active_game.game.state.status = GameStatus::Active;
+            info!("Game {} resumed", game_id);
+        }
+        let missed_rounds = active_game.take_missed_rounds(&disconnected_player.user_id);
 
         // Update connection_to_game mapping
         {
@@ -527,53 +2007,96 @@ impl GameManager {
         active_game.update_activity();
         info!("Player {} rejoined game {}", connection_id, game_id);
 
-        Ok(active_game.convert_to_api_state())
+        Ok(Rejoin {
+            state: active_game.convert_to_api_state(),
+            missed_rounds,
+            renamed,
+        })
+    }
+
+    /// Remove a player who asked to leave. Returns true when the game was
+    /// still being played, i.e. the player abandoned it rather than leaving
+    /// after it ended.
+    pub async fn leave_game(&self, game_id: &str, connection_id: ConnectionId) -> GameResult<bool> {
+        let abandoned = {
+            let games = self.active_games.read().await;
+            games
+                .get(game_id)
+                .is_some_and(|game| game.is_running() && !game.practice)
+        };
+
+        self.remove_player(game_id, connection_id).await?;
+        Ok(abandoned)
     }
 
     pub async fn remove_player(
         &self,
         game_id: &str,
         connection_id: ConnectionId,
-    ) -> Result<(), String> {
+    ) -> GameResult<()> {
         {
-            let mut games = self.active_games.write().await;
-            if let Some(active_game) = games.get_mut(game_id) {
-                if let Some(player_id) = active_game.connection_to_player.remove(&connection_id) {
-                    active_game.player_to_connection.remove(&player_id);
-
-                    // Remove player from game state
-                    active_game
-                        .game
-                        .state
-                        .players
-                        .retain(|p| p.user_id != player_id);
-                }
+            let mut connection_to_game = self.connection_to_game.write().await;
+            connection_to_game.remove(&connection_id);
+        }
 
-                // If no players left, remove the game
-                if active_game.game.state.players.is_empty() {
-                    games.remove(game_id);
-                    info!("Removed empty game {}", game_id);
-                }
-            }
+        let mut games = self.active_games.write().await;
+        let active_game = games.get_mut(game_id).ok_or(GameError::GameNotFound)?;
+        let player_id = active_game
+            .connection_to_player
+            .remove(&connection_id)
+            .ok_or(GameError::PlayerNotInGame)?;
+        active_game.player_to_connection.remove(&player_id);
+
+        // Remove player from game state
+        active_game
+            .game
+            .state
+            .players
+            .retain(|p| p.user_id != player_id);
+
+        // If no players left, remove the game
+        if active_game.game.state.players.is_empty() {
+            games.remove(game_id);
+            info!("Removed empty game {}", game_id);
         }
 
+        Ok(())
+    }
+
+    /// Tear down a game that never got going, such as a match whose players
+    /// could not all be told about it. The game is recorded as abandoned.
+    pub async fn discard_game(&self, game_id: &str) {
+        let Some(game) = self.active_games.write().await.remove(game_id) else {
+            return;
+        };
         {
             let mut connection_to_game = self.connection_to_game.write().await;
-            connection_to_game.remove(&connection_id);
+            for connection_id in game.connection_to_player.keys() {
+                connection_to_game.remove(connection_id);
+            }
         }
+        info!("Discarded game {}", game_id);
+        self.connection_manager.stop_spectating_game(game_id).await;
 
-        Ok(())
+        if let Some(repository) = &self.game_repository
+            && !game.practice
+            && let Err(e) = repository.mark_abandoned(game_id).await
+        {
+            tracing::warn!("Failed to record game {} as abandoned: {}", game_id, e);
+        }
     }
 
+    /// Remove games idle for longer than `timeout`, and games every player
+    /// left more than the empty-game grace ago
     pub async fn cleanup_abandoned_games(&self, timeout: Duration) {
         let mut games_to_remove = Vec::new();
+        let mut abandoned_games = Vec::new();
+        let mut removed_games = Vec::new();
 
         {
             let games = self.active_games.read().await;
             for (game_id, game) in games.iter() {
-                if game.is_expired(timeout)
-                    || game.game.state.players.iter().all(|p| !p.is_connected)
-                {
+                if game.is_expired(timeout) || game.is_empty_past(self.empty_game_grace) {
                     games_to_remove.push(game_id.clone());
                 }
             }
@@ -589,34 +2112,60 @@ impl GameManager {
                     for connection_id in game.connection_to_player.keys() {
                         connection_to_game.remove(connection_id);
                     }
+                    if game.is_running() && !game.practice {
+                        abandoned_games.push(game_id.clone());
+                    }
+                    removed_games.push(game_id.clone());
                     info!("Removed abandoned game {}", game_id);
                 }
             }
         }
+
+        // Nothing is left to watch
+        for game_id in &removed_games {
+            self.connection_manager.stop_spectating_game(game_id).await;
+        }
+
+        // Games that never finished are recorded as abandoned
+        if let Some(repository) = &self.game_repository {
+            for game_id in abandoned_games {
+                if let Err(e) = repository.mark_abandoned(&game_id).await {
+                    tracing::warn!("Failed to record game {} as abandoned: {}", game_id, e);
+                }
+            }
+        }
     }
 
-    /// Starts a new round with a fresh word after completing a word
-    async fn start_new_round(
+    /// Archive the current round and draw the next word, crediting the
+    /// solver if there was one. Returns the new target word.
+    async fn advance_to_next_word(
         &self,
         active_game: &mut ActiveGame,
-        round_completion: RoundCompletion,
-    ) -> Result<GameEvent, String> {
-        // Get a new random word with random length (5-8 letters)
-        let new_word = self
-            .word_validator
-            .get_random_word_random_length()
-            .map_err(|e| format!("Failed to get new random word: {:?}", e))?;
-
-        println!(
-            "Starting new round: completed word '{}' by player '{}', new word '{}'",
-            round_completion.word, round_completion.player_id, new_word
-        );
+        solver: Option<&PlayerId>,
+    ) -> GameResult<String> {
+        // Get the next word with random length (5-7 letters) from the game seed
+        let next_round = active_game.game.state.current_round + 1;
+        let new_word = self.choose_target_word(
+            active_game.seed,
+            next_round,
+            &active_game.game.rules.word_categories,
+            &mut active_game.recent_targets,
+        )?;
+
+        // Keep the finished round's board for post-game review
+        active_game.completed_rounds.push(RoundHistory {
+            round: active_game.game.state.current_round,
+            target_word: Some(active_game.game.target_word.clone()),
+            board: active_game.game.state.official_board.clone(),
+        });
 
         // Update game with new word and reset state for new round
-        active_game.game.target_word = new_word.clone();
-        active_game.game.state.word = "*".repeat(new_word.len()); // Masked word for display
-        active_game.game.state.word_length = new_word.len() as i32;
+        active_game.game.set_target_word(new_word.clone());
 
+        active_game.game.reset_board(); // Clear the official board for new round
+        if let Some(solver) = solver {
+            active_game.game.record_solve(solver);
+        }
         println!(
             "Before round increment: round = {}",
             active_game.game.state.current_round
@@ -626,13 +2175,12 @@ impl GameManager {
             "After round increment: round = {}",
             active_game.game.state.current_round
         );
-        active_game.game.state.official_board.clear(); // Clear the official board for new round
-        active_game.game.state.current_winner = None;
         active_game.game.current_guesses.clear();
+        active_game.skip_votes.clear();
+        active_game.round_deadline = None;
 
-        // Reset to collaborative guessing phase
-        active_game.game.current_phase = GamePhase::Guessing;
-        active_game.game.start_guessing_phase();
+        // Reset to collaborative guessing, or the next sudden-death turn
+        active_game.game.start_round_phase();
 
         tracing::info!(
             "New round started: round {}, new word length {}",
@@ -640,6 +2188,24 @@ impl GameManager {
             active_game.game.state.word_length
         );
 
+        Ok(new_word)
+    }
+
+    /// Starts a new round with a fresh word after completing a word
+    async fn start_new_round(
+        &self,
+        active_game: &mut ActiveGame,
+        round_completion: RoundCompletion,
+    ) -> GameResult<GameEvent> {
+        let new_word = self
+            .advance_to_next_word(active_game, Some(&round_completion.player_id))
+            .await?;
+
+        println!(
+            "Started new round: completed word '{}' by player '{}', new word '{}'",
+            round_completion.word, round_completion.player_id, new_word
+        );
+
         // Get all players to send the round completion message to everyone
         let player_guesses: Vec<(ConnectionId, PersonalGuess)> = active_game
             .player_to_connection
@@ -665,6 +2231,7 @@ impl GameManager {
                             points_earned: 0,
                             was_winning_guess: false,
                             timestamp: chrono::Utc::now().to_rfc3339(),
+                            assist_points: 0,
                         },
                     ))
                 }
@@ -679,12 +2246,53 @@ impl GameManager {
                 letters: vec![], // Frontend will handle displaying the completed word
                 points_earned: round_completion.points_earned,
                 timestamp: chrono::Utc::now().to_rfc3339(),
+                breakdown: Default::default(),
             },
             player_guesses, // Now includes all players so everyone gets notified
             is_word_completed: true, // This is explicitly a word completion event
+            revealed: active_game.game.take_reveals(),
         })
     }
 
+    /// Current target word of a game the connection is playing in, for
+    /// recording word reports. Must not be sent back to the reporter.
+    pub async fn reportable_word(
+        &self,
+        game_id: &str,
+        connection_id: ConnectionId,
+    ) -> GameResult<String> {
+        let games = self.active_games.read().await;
+        let active_game = games.get(game_id).ok_or(GameError::GameNotFound)?;
+
+        if !active_game
+            .connection_to_player
+            .contains_key(&connection_id)
+        {
+            return Err(GameError::PlayerNotInGame);
+        }
+
+        Ok(active_game.game.target_word.clone())
+    }
+
+    /// Seed a game's target words were chosen from
+    pub async fn get_game_seed(&self, game_id: &str) -> Option<u64> {
+        let games = self.active_games.read().await;
+        games.get(game_id).map(|game| game.seed)
+    }
+
+    /// Replay word selection for a round of a game created with `seed`,
+    /// drawing every earlier round's word again so the same recent targets
+    /// are skipped
+    pub fn replay_target_word(&self, seed: u64, round: i32) -> GameResult<String> {
+        let rules = self.rules_for_new_game(seed, false);
+        let mut recent = RecentWords::new(self.recent_word_window);
+        let mut word = String::new();
+        for round in 1..=round {
+            word = self.choose_target_word(seed, round, &rules.word_categories, &mut recent)?;
+        }
+        Ok(word)
+    }
+
     pub async fn get_active_games_count(&self) -> usize {
         let games = self.active_games.read().await;
         games.len()
@@ -697,3 +2305,108 @@ impl Default for GameManager {
             .expect("Failed to load word directory for GameManager. Run './scripts/download_and_split_words.sh' to generate word lists.")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A started game between `names`, each seated from a signed-in connection
+    async fn seated_game(names: &[&str]) -> (GameManager, String, Vec<ConnectionId>) {
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let game_manager = GameManager::new_with_validator(
+            connection_manager.clone(),
+            WordValidator::new_with_test_words(),
+        );
+        let mut connections = Vec::new();
+        for name in names {
+            let connection_id = ConnectionId::new();
+            let _ = connection_manager.create_connection(connection_id).await;
+            let user = User {
+                id: name.to_string(),
+                email: format!("{}@example.com", name),
+                display_name: name.to_string(),
+                total_points: 0,
+                total_wins: 0,
+                total_games: 0,
+                created_at: chrono::Utc::now().to_rfc3339(),
+                avatar: None,
+                roles: Vec::new(),
+            };
+            connection_manager
+                .authenticate_connection(connection_id, user.id.clone())
+                .await
+                .unwrap();
+            connection_manager
+                .set_connection_user(connection_id, Some(user))
+                .await;
+            connections.push(connection_id);
+        }
+        let game_id = game_manager.create_game(connections.clone()).await.unwrap();
+        (game_manager, game_id, connections)
+    }
+
+    /// Record one wrong guess per player straight into the game, as the
+    /// first half of a submission does, and copy out the round
+    async fn guess_wrong_under_lock(
+        game_manager: &GameManager,
+        game_id: &str,
+        players: &[&str],
+    ) -> RoundGuesses {
+        let mut games = game_manager.active_games.write().await;
+        let game = &mut games.get_mut(game_id).unwrap().game;
+        let words = match game.state.word_length {
+            5 => ["about", "above", "after"],
+            6 => ["second", "fourth", "before"],
+            _ => ["example", "nothing", "another"],
+        };
+        let target = game.target_word.clone();
+        let mut words = words.iter().filter(|word| **word != target);
+        for player in players {
+            let word = words.next().unwrap().to_string();
+            game.add_guess(&player.to_string(), word).unwrap();
+        }
+        game.round_guesses()
+    }
+
+    #[tokio::test]
+    async fn test_round_resolved_after_being_overtaken_is_logged_under_the_current_round() {
+        let (game_manager, game_id, connections) = seated_game(&["alice", "bob"]).await;
+
+        // A submission brings round 1 to quorum and copies its guesses out
+        let stale = guess_wrong_under_lock(&game_manager, &game_id, &["alice", "bob"]).await;
+        let stale_winner = stale.pick_winner();
+
+        // Before it locks the game again, Alice solves the word, and round 2
+        // reaches quorum in a submission that hasn't locked again either
+        let target = game_manager.active_games.read().await[&game_id]
+            .game
+            .target_word
+            .clone();
+        game_manager
+            .submit_guess(&game_id, connections[0], target)
+            .await
+            .unwrap();
+        assert_eq!(
+            game_manager
+                .get_game_state(&game_id)
+                .await
+                .unwrap()
+                .current_round,
+            2
+        );
+        guess_wrong_under_lock(&game_manager, &game_id, &["alice", "bob"]).await;
+
+        game_manager
+            .resolve_collected_round(&game_id, &stale, stale_winner)
+            .await
+            .unwrap();
+
+        let games = game_manager.active_games.read().await;
+        let logged: Vec<i32> = games[&game_id]
+            .recent_rounds
+            .iter()
+            .map(|(_, summary)| summary.round)
+            .collect();
+        assert_eq!(logged, vec![1, 2]);
+    }
+}
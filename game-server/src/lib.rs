@@ -3,26 +3,85 @@ use std::sync::Arc;
 use warp::Filter;
 
 use crate::auth::AuthService;
+use crate::authorization::{authenticated, authorize_self, authorize_self_or_role, require_role};
 use crate::game_manager::GameManager;
 use crate::matchmaking::MatchmakingQueue;
 use crate::websocket::ConnectionManager;
-use game_persistence::repositories::UserRepository;
+use crate::websocket::audit::ClientInfo;
+use crate::websocket::compression::ConnectOptions;
+use game_core::GameError;
+use game_persistence::repositories::game_repository::GameRecord;
+use game_persistence::repositories::user_repository::LeaderboardEntry;
+use game_persistence::repositories::word_report_repository::WordReport;
+use game_persistence::repositories::{GameRepository, UserRepository, WordReportRepository};
+use game_types::{Role, User};
 
 #[derive(Deserialize)]
 struct LeaderboardQuery {
     limit: Option<u64>,
+    active_within_days: Option<u32>, // Only rank players who finished a game this recently
+}
+
+#[derive(Deserialize)]
+struct RankContextQuery {
+    window: Option<u32>, // Places shown either side of the user
+}
+
+#[derive(Deserialize)]
+struct UserGamesQuery {
+    page: Option<u64>, // Zero-based
+    per_page: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct WordReportsQuery {
+    limit: Option<u64>,
 }
 
 #[derive(serde::Serialize)]
 struct UserStatsResponse {
-    user: game_types::User,
+    user: User,
     rank: Option<u32>,
 }
 
+/// The user's leaderboard neighbourhood; empty while they are unranked
+#[derive(serde::Serialize)]
+struct RankContextResponse {
+    entries: Vec<LeaderboardEntry>,
+}
+
+/// Everything the server stores about one user
+#[derive(serde::Serialize, serde::Deserialize)]
+struct UserDataExport {
+    user: User, // Includes the avatar, the only stored preference
+    games: Vec<GameRecord>,
+    word_reports: Vec<WordReport>,
+    exported_at: String,
+}
+
+#[derive(serde::Serialize)]
+struct ReadinessResponse {
+    ready: bool,
+    checks: Vec<ReadinessCheck>,
+}
+
+#[derive(serde::Serialize)]
+struct ReadinessCheck {
+    name: &'static str,
+    healthy: bool,
+    error: Option<String>,
+}
+
+pub mod abuse;
 pub mod auth;
+pub mod authorization;
 pub mod config;
 pub mod game_manager;
+pub mod lobby;
+pub mod matchmaker;
 pub mod matchmaking;
+pub mod metrics;
+pub mod stats;
 pub mod websocket;
 
 pub fn create_routes(
@@ -31,6 +90,8 @@ pub fn create_routes(
     matchmaking_queue: Arc<MatchmakingQueue>,
     auth_service: Arc<AuthService>,
     user_repository: Arc<UserRepository>,
+    word_report_repository: Arc<WordReportRepository>,
+    game_repository: Arc<GameRepository>,
 ) -> impl Filter<Extract = impl warp::Reply, Error = warp::Rejection> + Clone {
     // Clone for filters
     let connection_manager_filter = warp::any().map({
@@ -58,23 +119,84 @@ pub fn create_routes(
         move || user_repository.clone()
     });
 
+    let word_report_repository_filter = warp::any().map({
+        let word_report_repository = word_report_repository.clone();
+        move || word_report_repository.clone()
+    });
+
+    let game_repository_filter = warp::any().map({
+        let game_repository = game_repository.clone();
+        move || game_repository.clone()
+    });
+
+    // Client address and user agent from the upgrade request, for the audit log
+    let client_info = warp::addr::remote()
+        .and(warp::header::optional::<String>("x-real-ip"))
+        .and(warp::header::optional::<String>("user-agent"))
+        .map(ClientInfo::new);
+
     // WebSocket endpoint
+    // Clients may opt into gzip for large messages with `/ws?compression=gzip`
     let websocket = warp::path("ws")
         .and(warp::ws())
+        .and(warp::query::<ConnectOptions>())
+        .and(client_info)
         .and(connection_manager_filter.clone())
         .and(game_manager_filter.clone())
         .and(matchmaking_filter.clone())
         .and(auth_filter.clone())
-        .map(|ws: warp::ws::Ws, conn_mgr, game_mgr, queue, auth| {
-            ws.on_upgrade(move |socket| {
-                websocket::handle_connection(socket, conn_mgr, game_mgr, queue, auth)
-            })
-        });
+        .and(user_repository_filter.clone())
+        .and(word_report_repository_filter.clone())
+        .map(
+            |ws: warp::ws::Ws,
+             options: ConnectOptions,
+             client: ClientInfo,
+             conn_mgr,
+             game_mgr,
+             queue,
+             auth,
+             user_repo,
+             report_repo| {
+                ws.on_upgrade(move |socket| {
+                    websocket::handle_connection(
+                        socket,
+                        conn_mgr,
+                        game_mgr,
+                        queue,
+                        auth,
+                        user_repo,
+                        report_repo,
+                        options,
+                        client,
+                    )
+                })
+            },
+        );
 
-    // Health check endpoint
+    // Health check endpoint - "Not ready" until the dictionary has loaded
     let health = warp::path("health")
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(game_manager_filter.clone())
+        .map(|game_manager: Arc<GameManager>| {
+            if game_manager.is_ready() {
+                warp::reply::with_status("OK", warp::http::StatusCode::OK)
+            } else {
+                warp::reply::with_status("Not ready", warp::http::StatusCode::SERVICE_UNAVAILABLE)
+            }
+        });
+
+    // Readiness probe - 503 listing the failed checks unless every dependency is healthy
+    let readiness = warp::path!("health" / "ready")
+        .and(warp::get())
+        .and(game_manager_filter.clone())
+        .and(user_repository_filter.clone())
+        .and_then(handle_readiness_request);
+
+    // WebSocket protocol description for third-party clients
+    let schema = warp::path("schema")
         .and(warp::get())
-        .map(|| warp::reply::with_status("OK", warp::http::StatusCode::OK));
+        .map(|| warp::reply::json(&game_types::protocol_schema()));
 
     // Game state endpoint - safe for reconnection
     let game_state = warp::path!("api" / "game" / String / "state")
@@ -84,6 +206,20 @@ pub fn create_routes(
         .and(auth_filter.clone())
         .and_then(handle_game_state_request);
 
+    // Game history endpoint - participants only, full reveal once the game ends
+    let game_history = warp::path!("api" / "game" / String / "history")
+        .and(warp::get())
+        .and(warp::header::optional::<String>("authorization"))
+        .and(game_manager_filter.clone())
+        .and(auth_filter.clone())
+        .and_then(handle_game_history_request);
+
+    // Live games browser - public games to spectate, no sign-in needed
+    let public_games = warp::path!("api" / "games" / "public")
+        .and(warp::get())
+        .and(game_manager_filter.clone())
+        .and_then(handle_public_games_request);
+
     // Leaderboard endpoint
     let leaderboard = warp::path!("api" / "leaderboard")
         .and(warp::get())
@@ -91,13 +227,95 @@ pub fn create_routes(
         .and(user_repository_filter.clone())
         .and_then(handle_leaderboard_request);
 
-    // User stats endpoint
+    // User stats endpoint - the user themself or an admin
     let user_stats = warp::path!("api" / "user" / String / "stats")
         .and(warp::get())
-        .and(warp::header::optional::<String>("authorization"))
+        .and(authenticated(auth_service.clone()))
         .and(user_repository_filter.clone())
-        .and(auth_filter.clone())
-        .and_then(handle_user_stats_request);
+        .and_then(handle_user_stats_request)
+        .recover(authorization::handle_rejection);
+
+    // Leaderboard neighbours endpoint - the user themself only
+    let user_rank_context = warp::path!("api" / "user" / String / "rank-context")
+        .and(warp::get())
+        .and(authenticated(auth_service.clone()))
+        .and(warp::query::<RankContextQuery>())
+        .and(user_repository_filter.clone())
+        .and_then(handle_rank_context_request)
+        .recover(authorization::handle_rejection);
+
+    // Finished game history endpoint - the user themself only
+    let user_games = warp::path!("api" / "user" / String / "games")
+        .and(warp::get())
+        .and(authenticated(auth_service.clone()))
+        .and(warp::query::<UserGamesQuery>())
+        .and(game_repository_filter.clone())
+        .and_then(handle_user_games_request)
+        .recover(authorization::handle_rejection);
+
+    // Data export endpoint - the user themself only
+    let user_export = warp::path!("api" / "user" / String / "export")
+        .and(warp::get())
+        .and(authenticated(auth_service.clone()))
+        .and(user_repository_filter.clone())
+        .and(game_repository_filter.clone())
+        .and(word_report_repository_filter.clone())
+        .and_then(handle_user_export_request)
+        .recover(authorization::handle_rejection);
+
+    // Account deletion endpoint - the user themself only
+    let user_delete = warp::path!("api" / "user" / String)
+        .and(warp::delete())
+        .and(authenticated(auth_service.clone()))
+        .and(user_repository_filter.clone())
+        .and_then(handle_user_delete_request)
+        .recover(authorization::handle_rejection);
+
+    // Word report review endpoint - admins only
+    let word_reports = warp::path!("api" / "admin" / "word-reports")
+        .and(warp::get())
+        .and(require_role(
+            auth_service.clone(),
+            Role::Admin,
+            "Admin access required",
+        ))
+        .and(warp::query::<WordReportsQuery>())
+        .and(word_report_repository_filter.clone())
+        .and_then(handle_word_reports_request)
+        .recover(authorization::handle_rejection);
+
+    // Matchmaking queue and wait-time percentiles - admins only
+    let matchmaking_stats = warp::path!("api" / "admin" / "matchmaking")
+        .and(warp::get())
+        .and(require_role(
+            auth_service.clone(),
+            Role::Admin,
+            "Admin access required",
+        ))
+        .and(matchmaking_filter.clone())
+        .and_then(handle_matchmaking_stats_request)
+        .recover(authorization::handle_rejection);
+
+    // Outbound queue depth, delivery latency and slow consumers - admins only
+    let connection_stats = warp::path!("api" / "admin" / "connections")
+        .and(warp::get())
+        .and(require_role(
+            auth_service.clone(),
+            Role::Admin,
+            "Admin access required",
+        ))
+        .and(connection_manager_filter.clone())
+        .and_then(handle_connection_stats_request)
+        .recover(authorization::handle_rejection);
+
+    // Prometheus scrape endpoint
+    let metrics = warp::path("metrics")
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(game_manager_filter.clone())
+        .and(matchmaking_filter.clone())
+        .and(connection_manager_filter.clone())
+        .and_then(handle_metrics_request);
 
     // CORS configuration
     let cors = warp::cors()
@@ -107,9 +325,21 @@ pub fn create_routes(
 
     websocket
         .or(health)
+        .or(readiness)
+        .or(schema)
         .or(game_state)
+        .or(game_history)
+        .or(public_games)
         .or(leaderboard)
         .or(user_stats)
+        .or(user_rank_context)
+        .or(user_games)
+        .or(user_export)
+        .or(user_delete)
+        .or(word_reports)
+        .or(matchmaking_stats)
+        .or(connection_stats)
+        .or(metrics)
         .with(cors)
         .with(warp::log("word_arena"))
 }
@@ -164,13 +394,72 @@ async fn handle_game_state_request(
     }
 }
 
+async fn handle_game_history_request(
+    game_id: String,
+    auth_header: Option<String>,
+    game_manager: Arc<GameManager>,
+    auth_service: Arc<AuthService>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let Some(auth_header) = auth_header else {
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&serde_json::json!({
+                "error": "Authentication required"
+            })),
+            warp::http::StatusCode::UNAUTHORIZED,
+        ));
+    };
+
+    let token = auth_header.strip_prefix("Bearer ").unwrap_or(&auth_header);
+    let user = match auth_service.validate_token(token).await {
+        Ok(user) => user,
+        Err(_) => {
+            return Ok(warp::reply::with_status(
+                warp::reply::json(&serde_json::json!({
+                    "error": "Invalid authentication token"
+                })),
+                warp::http::StatusCode::UNAUTHORIZED,
+            ));
+        }
+    };
+
+    match game_manager.get_game_history(&game_id, &user.id).await {
+        Ok(history) => Ok(warp::reply::with_status(
+            warp::reply::json(&history),
+            warp::http::StatusCode::OK,
+        )),
+        Err(GameError::PlayerNotInGame) => Ok(warp::reply::with_status(
+            warp::reply::json(&serde_json::json!({
+                "error": "Not authorized to view this game"
+            })),
+            warp::http::StatusCode::FORBIDDEN,
+        )),
+        Err(_) => Ok(warp::reply::with_status(
+            warp::reply::json(&serde_json::json!({
+                "error": "Game not found"
+            })),
+            warp::http::StatusCode::NOT_FOUND,
+        )),
+    }
+}
+
+async fn handle_public_games_request(
+    game_manager: Arc<GameManager>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let games = game_manager.list_spectatable_games().await;
+    Ok(warp::reply::json(&games))
+}
+
 async fn handle_leaderboard_request(
     query: LeaderboardQuery,
     user_repository: Arc<UserRepository>,
 ) -> Result<impl warp::Reply, warp::Rejection> {
     let limit = query.limit.unwrap_or(10).min(100); // Default 10, max 100
 
-    match user_repository.get_leaderboard(limit).await {
+    let active_within = query
+        .active_within_days
+        .map(|days| chrono::Duration::days(days.into()));
+
+    match user_repository.get_leaderboard(limit, active_within).await {
         Ok(leaderboard) => Ok(warp::reply::with_status(
             warp::reply::json(&leaderboard),
             warp::http::StatusCode::OK,
@@ -189,46 +478,15 @@ async fn handle_leaderboard_request(
 
 async fn handle_user_stats_request(
     user_id: String,
-    auth_header: Option<String>,
+    authenticated_user: User,
     user_repository: Arc<UserRepository>,
-    auth_service: Arc<AuthService>,
 ) -> Result<impl warp::Reply, warp::Rejection> {
-    // User ID is now a string, no parsing needed
-
-    // Check authentication if provided - user can only view their own stats unless admin
-    if let Some(auth_header) = auth_header {
-        let token = auth_header.strip_prefix("Bearer ").unwrap_or(&auth_header);
-
-        match auth_service.validate_token(token).await {
-            Ok(authenticated_user) => {
-                // Only allow users to view their own stats
-                if authenticated_user.id != user_id {
-                    return Ok(warp::reply::with_status(
-                        warp::reply::json(&serde_json::json!({
-                            "error": "Not authorized to view this user's stats"
-                        })),
-                        warp::http::StatusCode::FORBIDDEN,
-                    ));
-                }
-            }
-            Err(_) => {
-                return Ok(warp::reply::with_status(
-                    warp::reply::json(&serde_json::json!({
-                        "error": "Invalid authentication token"
-                    })),
-                    warp::http::StatusCode::UNAUTHORIZED,
-                ));
-            }
-        }
-    } else {
-        // No auth header - public stats viewing not allowed
-        return Ok(warp::reply::with_status(
-            warp::reply::json(&serde_json::json!({
-                "error": "Authentication required"
-            })),
-            warp::http::StatusCode::UNAUTHORIZED,
-        ));
-    }
+    authorize_self_or_role(
+        &authenticated_user,
+        &user_id,
+        Role::Admin,
+        "Not authorized to view this user's stats",
+    )?;
 
     // Get user and their rank
     match user_repository.find_by_id(&user_id).await {
@@ -265,12 +523,283 @@ async fn handle_user_stats_request(
     }
 }
 
+async fn handle_rank_context_request(
+    user_id: String,
+    authenticated_user: User,
+    query: RankContextQuery,
+    user_repository: Arc<UserRepository>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    authorize_self(
+        &authenticated_user,
+        &user_id,
+        "Not authorized to view this user's rank",
+    )?;
+
+    let window = query.window.unwrap_or(2).min(10); // Default 2, max 10
+
+    let context = async {
+        if user_repository.find_by_id(&user_id).await?.is_none() {
+            return Ok(None);
+        }
+        let entries = user_repository
+            .get_rank_context(&user_id, window)
+            .await?
+            .unwrap_or_default();
+        Ok::<_, Box<dyn std::error::Error + Send + Sync>>(Some(RankContextResponse { entries }))
+    };
+
+    match context.await {
+        Ok(Some(response)) => Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::OK,
+        )),
+        Ok(None) => Ok(warp::reply::with_status(
+            warp::reply::json(&serde_json::json!({
+                "error": "User not found"
+            })),
+            warp::http::StatusCode::NOT_FOUND,
+        )),
+        Err(err) => {
+            tracing::error!("Failed to fetch rank context: {}", err);
+            Ok(warp::reply::with_status(
+                warp::reply::json(&serde_json::json!({
+                    "error": "Failed to fetch rank context"
+                })),
+                warp::http::StatusCode::INTERNAL_SERVER_ERROR,
+            ))
+        }
+    }
+}
+
+async fn handle_user_games_request(
+    user_id: String,
+    authenticated_user: User,
+    query: UserGamesQuery,
+    game_repository: Arc<GameRepository>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    authorize_self(
+        &authenticated_user,
+        &user_id,
+        "Not authorized to view this user's games",
+    )?;
+
+    let page = query.page.unwrap_or(0);
+    let per_page = query.per_page.unwrap_or(20).clamp(1, 100); // Default 20, max 100
+
+    match game_repository
+        .find_finished_by_player(&user_id, page, per_page)
+        .await
+    {
+        Ok(games) => Ok(warp::reply::with_status(
+            warp::reply::json(&games),
+            warp::http::StatusCode::OK,
+        )),
+        Err(err) => {
+            tracing::error!("Failed to fetch game history: {}", err);
+            Ok(warp::reply::with_status(
+                warp::reply::json(&serde_json::json!({
+                    "error": "Failed to fetch game history"
+                })),
+                warp::http::StatusCode::INTERNAL_SERVER_ERROR,
+            ))
+        }
+    }
+}
+
+async fn handle_user_export_request(
+    user_id: String,
+    authenticated_user: User,
+    user_repository: Arc<UserRepository>,
+    game_repository: Arc<GameRepository>,
+    word_report_repository: Arc<WordReportRepository>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    authorize_self(
+        &authenticated_user,
+        &user_id,
+        "Not authorized to export this user's data",
+    )?;
+
+    let export = async {
+        let Some(user) = user_repository.find_by_id(&user_id).await? else {
+            return Ok(None);
+        };
+        Ok::<_, Box<dyn std::error::Error + Send + Sync>>(Some(UserDataExport {
+            user,
+            games: game_repository.find_by_player(&user_id).await?,
+            word_reports: word_report_repository.list_by_reporter(&user_id).await?,
+            exported_at: chrono::Utc::now().to_rfc3339(),
+        }))
+    };
+
+    match export.await {
+        Ok(Some(export)) => Ok(warp::reply::with_status(
+            warp::reply::json(&export),
+            warp::http::StatusCode::OK,
+        )),
+        Ok(None) => Ok(warp::reply::with_status(
+            warp::reply::json(&serde_json::json!({
+                "error": "User not found"
+            })),
+            warp::http::StatusCode::NOT_FOUND,
+        )),
+        Err(err) => {
+            tracing::error!("Failed to export user data: {}", err);
+            Ok(warp::reply::with_status(
+                warp::reply::json(&serde_json::json!({
+                    "error": "Failed to export user data"
+                })),
+                warp::http::StatusCode::INTERNAL_SERVER_ERROR,
+            ))
+        }
+    }
+}
+
+/// Erase the user's personal data. The account row stays, anonymized, so
+/// recorded games keep pointing at a valid player and aggregate stats remain.
+async fn handle_user_delete_request(
+    user_id: String,
+    authenticated_user: User,
+    user_repository: Arc<UserRepository>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    authorize_self(
+        &authenticated_user,
+        &user_id,
+        "Not authorized to delete this user",
+    )?;
+
+    match user_repository.anonymize_user(&user_id).await {
+        Ok(true) => {
+            tracing::info!("Anonymized user {} at their request", user_id);
+            Ok(warp::reply::with_status(
+                warp::reply::json(&serde_json::json!({
+                    "status": "anonymized"
+                })),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Ok(false) => Ok(warp::reply::with_status(
+            warp::reply::json(&serde_json::json!({
+                "error": "User not found"
+            })),
+            warp::http::StatusCode::NOT_FOUND,
+        )),
+        Err(err) => {
+            tracing::error!("Failed to anonymize user: {}", err);
+            Ok(warp::reply::with_status(
+                warp::reply::json(&serde_json::json!({
+                    "error": "Failed to delete user"
+                })),
+                warp::http::StatusCode::INTERNAL_SERVER_ERROR,
+            ))
+        }
+    }
+}
+
+async fn handle_word_reports_request(
+    _admin: User,
+    query: WordReportsQuery,
+    word_report_repository: Arc<WordReportRepository>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let limit = query.limit.unwrap_or(50).min(500); // Default 50, max 500
+
+    match word_report_repository.list_reports(limit).await {
+        Ok(reports) => Ok(warp::reply::with_status(
+            warp::reply::json(&reports),
+            warp::http::StatusCode::OK,
+        )),
+        Err(err) => {
+            tracing::error!("Failed to fetch word reports: {}", err);
+            Ok(warp::reply::with_status(
+                warp::reply::json(&serde_json::json!({
+                    "error": "Failed to fetch word reports"
+                })),
+                warp::http::StatusCode::INTERNAL_SERVER_ERROR,
+            ))
+        }
+    }
+}
+
+async fn handle_readiness_request(
+    game_manager: Arc<GameManager>,
+    user_repository: Arc<UserRepository>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let database = user_repository.ping().await;
+    let checks = vec![
+        ReadinessCheck {
+            name: "database",
+            healthy: database.is_ok(),
+            error: database.err().map(|e| e.to_string()),
+        },
+        ReadinessCheck {
+            name: "words",
+            healthy: game_manager.is_ready(),
+            error: (!game_manager.is_ready()).then(|| "Word lists still loading".to_string()),
+        },
+    ];
+
+    let ready = checks.iter().all(|check| check.healthy);
+    if !ready {
+        tracing::warn!(
+            "Readiness check failed: {:?}",
+            checks
+                .iter()
+                .filter(|check| !check.healthy)
+                .map(|check| check.name)
+                .collect::<Vec<_>>()
+        );
+    }
+    let status = if ready {
+        warp::http::StatusCode::OK
+    } else {
+        warp::http::StatusCode::SERVICE_UNAVAILABLE
+    };
+    Ok(warp::reply::with_status(
+        warp::reply::json(&ReadinessResponse { ready, checks }),
+        status,
+    ))
+}
+
+async fn handle_matchmaking_stats_request(
+    _admin: User,
+    matchmaking_queue: Arc<MatchmakingQueue>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    Ok(warp::reply::json(
+        &matchmaking_queue.get_queue_stats().await,
+    ))
+}
+
+async fn handle_connection_stats_request(
+    _admin: User,
+    connection_manager: Arc<ConnectionManager>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    Ok(warp::reply::json(
+        &connection_manager.outbound_stats().await,
+    ))
+}
+
+async fn handle_metrics_request(
+    game_manager: Arc<GameManager>,
+    matchmaking_queue: Arc<MatchmakingQueue>,
+    connection_manager: Arc<ConnectionManager>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let gauges = metrics::Gauges {
+        active_games: game_manager.get_active_games_count().await,
+        queue_length: matchmaking_queue.get_queue_length().await,
+        connections: connection_manager.connection_count().await,
+    };
+    Ok(warp::reply::with_header(
+        game_manager.metrics().render(gauges),
+        "content-type",
+        "text/plain; version=0.0.4",
+    ))
+}
+
 #[cfg(test)]
 mod integration_tests {
     use super::*;
     use game_persistence::repositories::user_repository::LeaderboardEntry;
-    use game_types::{ClientMessage, ServerMessage, User};
-    use migration::{Migrator, MigratorTrait};
+    use game_types::{ClientMessage, ServerMessage};
+    use migration::MigratorTrait;
     use std::time::Duration;
 
     async fn create_test_app()
@@ -289,7 +818,9 @@ mod integration_tests {
             .await
             .unwrap();
         migration::Migrator::up(&db, None).await.unwrap();
-        let user_repository = Arc::new(UserRepository::new(db));
+        let user_repository = Arc::new(UserRepository::new(db.clone()));
+        let word_report_repository = Arc::new(WordReportRepository::new(db.clone()));
+        let game_repository = Arc::new(GameRepository::new(db));
 
         create_routes(
             connection_manager,
@@ -297,6 +828,8 @@ mod integration_tests {
             matchmaking_queue,
             auth_service,
             user_repository,
+            word_report_repository,
+            game_repository,
         )
     }
 
@@ -319,7 +852,9 @@ mod integration_tests {
             .await
             .unwrap();
         migration::Migrator::up(&db, None).await.unwrap();
-        let user_repository = Arc::new(UserRepository::new(db));
+        let user_repository = Arc::new(UserRepository::new(db.clone()));
+        let word_report_repository = Arc::new(WordReportRepository::new(db.clone()));
+        let game_repository = Arc::new(GameRepository::new(db));
 
         create_routes(
             connection_manager,
@@ -327,6 +862,8 @@ mod integration_tests {
             matchmaking_queue,
             auth_service,
             user_repository,
+            word_report_repository,
+            game_repository,
         )
     }
 
@@ -345,7 +882,273 @@ mod integration_tests {
     }
 
     #[tokio::test]
-    async fn test_websocket_connection_upgrade() {
+    async fn test_health_endpoint_not_ready_while_words_load() {
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let game_manager = Arc::new(GameManager::new_loading(connection_manager.clone()));
+        let db = game_persistence::connection::connect_to_memory_database()
+            .await
+            .unwrap();
+        migration::Migrator::up(&db, None).await.unwrap();
+
+        let app = create_routes(
+            connection_manager,
+            game_manager.clone(),
+            Arc::new(MatchmakingQueue::new()),
+            Arc::new(AuthService::new_dev_mode()),
+            Arc::new(UserRepository::new(db.clone())),
+            Arc::new(WordReportRepository::new(db.clone())),
+            Arc::new(GameRepository::new(db)),
+        );
+
+        let response = warp::test::request().path("/health").reply(&app).await;
+        assert_eq!(response.status(), 503);
+        assert_eq!(response.body(), "Not ready");
+
+        game_manager
+            .set_word_validator(game_core::word_validation::WordValidator::new_with_test_words());
+
+        let response = warp::test::request().path("/health").reply(&app).await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.body(), "OK");
+    }
+
+    #[tokio::test]
+    async fn test_public_games_lists_only_public_games_without_words() {
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let game_manager = Arc::new(GameManager::new_with_validator(
+            connection_manager.clone(),
+            game_core::word_validation::WordValidator::new_with_test_words(),
+        ));
+        let db = game_persistence::connection::connect_to_memory_database()
+            .await
+            .unwrap();
+        migration::Migrator::up(&db, None).await.unwrap();
+
+        let mut players = Vec::new();
+        let mut _receivers = Vec::new();
+        for user_id in ["alice", "bob", "carol", "dave"] {
+            let connection_id = crate::websocket::connection::ConnectionId::new();
+            _receivers.push(connection_manager.create_connection(connection_id).await);
+            connection_manager
+                .set_connection_user(
+                    connection_id,
+                    Some(User {
+                        id: user_id.to_string(),
+                        email: format!("{}@example.com", user_id),
+                        display_name: user_id.to_string(),
+                        total_points: 0,
+                        total_wins: 0,
+                        total_games: 0,
+                        created_at: chrono::Utc::now().to_rfc3339(),
+                        avatar: None,
+                        roles: Vec::new(),
+                    }),
+                )
+                .await;
+            players.push(connection_id);
+        }
+        let public_game = game_manager
+            .create_game(players[..2].to_vec())
+            .await
+            .unwrap();
+        let private_game = game_manager
+            .create_private_game(players[2..].to_vec(), None)
+            .await
+            .unwrap();
+        assert!(game_manager.is_spectatable(&public_game).await);
+        assert!(!game_manager.is_spectatable(&private_game).await);
+
+        let app = create_routes(
+            connection_manager,
+            game_manager.clone(),
+            Arc::new(MatchmakingQueue::new()),
+            Arc::new(AuthService::new_dev_mode()),
+            Arc::new(UserRepository::new(db.clone())),
+            Arc::new(WordReportRepository::new(db.clone())),
+            Arc::new(GameRepository::new(db)),
+        );
+
+        // No sign-in needed
+        let response = warp::test::request()
+            .path("/api/games/public")
+            .reply(&app)
+            .await;
+        assert_eq!(response.status(), 200);
+        let games: Vec<serde_json::Value> = serde_json::from_slice(response.body()).unwrap();
+        assert_eq!(games.len(), 1);
+
+        let game = games[0].as_object().unwrap();
+        let mut fields: Vec<&str> = game.keys().map(String::as_str).collect();
+        fields.sort();
+        assert_eq!(
+            fields,
+            [
+                "current_round",
+                "game_id",
+                "players",
+                "point_threshold",
+                "word_length"
+            ]
+        );
+        assert_eq!(game["game_id"], public_game.as_str());
+        assert_eq!(game["current_round"], 1);
+        let target = game_manager
+            .reportable_word(&public_game, players[0])
+            .await
+            .unwrap();
+        assert_eq!(game["word_length"], target.chars().count());
+        assert_eq!(game["players"].as_array().unwrap().len(), 2);
+        assert!(
+            game["players"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .all(|player| player["guess_history"].as_array().unwrap().is_empty())
+        );
+        let body = String::from_utf8_lossy(response.body()).to_lowercase();
+        assert!(!body.contains(&target.to_lowercase()));
+    }
+
+    #[tokio::test]
+    async fn test_metrics_endpoint_reports_live_gauges() {
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let game_manager = Arc::new(GameManager::new_with_validator(
+            connection_manager.clone(),
+            game_core::word_validation::WordValidator::new_with_test_words(),
+        ));
+        let db = game_persistence::connection::connect_to_memory_database()
+            .await
+            .unwrap();
+        migration::Migrator::up(&db, None).await.unwrap();
+
+        let mut players = Vec::new();
+        let mut _receivers = Vec::new();
+        for user_id in ["alice", "bob", "carol"] {
+            let connection_id = crate::websocket::connection::ConnectionId::new();
+            _receivers.push(connection_manager.create_connection(connection_id).await);
+            connection_manager
+                .set_connection_user(
+                    connection_id,
+                    Some(User {
+                        id: user_id.to_string(),
+                        email: format!("{}@example.com", user_id),
+                        display_name: user_id.to_string(),
+                        total_points: 0,
+                        total_wins: 0,
+                        total_games: 0,
+                        created_at: chrono::Utc::now().to_rfc3339(),
+                        avatar: None,
+                        roles: Vec::new(),
+                    }),
+                )
+                .await;
+            players.push(connection_id);
+        }
+        game_manager
+            .create_game(players[..2].to_vec())
+            .await
+            .unwrap();
+        let matchmaking_queue = Arc::new(MatchmakingQueue::new());
+        matchmaking_queue.add_player(players[2]).await.unwrap();
+
+        let app = create_routes(
+            connection_manager,
+            game_manager,
+            matchmaking_queue,
+            Arc::new(AuthService::new_dev_mode()),
+            Arc::new(UserRepository::new(db.clone())),
+            Arc::new(WordReportRepository::new(db.clone())),
+            Arc::new(GameRepository::new(db)),
+        );
+
+        let response = warp::test::request().path("/metrics").reply(&app).await;
+        assert_eq!(response.status(), 200);
+        let body = String::from_utf8(response.body().to_vec()).unwrap();
+        assert!(body.contains("word_arena_active_games 1\n"));
+        assert!(body.contains("word_arena_queue_length 1\n"));
+        assert!(body.contains("word_arena_connections 3\n"));
+        assert!(body.contains("word_arena_guesses_submitted_total 0\n"));
+    }
+
+    #[tokio::test]
+    async fn test_readiness_reports_failed_database_check() {
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let game_manager = Arc::new(GameManager::new_with_validator(
+            connection_manager.clone(),
+            game_core::word_validation::WordValidator::new_with_test_words(),
+        ));
+        let db = game_persistence::connection::connect_to_memory_database()
+            .await
+            .unwrap();
+        migration::Migrator::up(&db, None).await.unwrap();
+
+        let app = create_routes(
+            connection_manager,
+            game_manager,
+            Arc::new(MatchmakingQueue::new()),
+            Arc::new(AuthService::new_dev_mode()),
+            Arc::new(UserRepository::new(db.clone())),
+            Arc::new(WordReportRepository::new(db.clone())),
+            Arc::new(GameRepository::new(db.clone())),
+        );
+
+        let response = warp::test::request()
+            .path("/health/ready")
+            .reply(&app)
+            .await;
+        assert_eq!(response.status(), 200);
+        let body: serde_json::Value = serde_json::from_slice(response.body()).unwrap();
+        assert_eq!(body["ready"], true);
+
+        // Closing the pool makes every query fail, as with a database outage
+        db.close().await.unwrap();
+
+        let response = warp::test::request()
+            .path("/health/ready")
+            .reply(&app)
+            .await;
+        assert_eq!(response.status(), 503);
+        let body: serde_json::Value = serde_json::from_slice(response.body()).unwrap();
+        assert_eq!(body["ready"], false);
+        let failed: Vec<&str> = body["checks"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|check| check["healthy"] == false)
+            .map(|check| check["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(failed, vec!["database"]);
+
+        // The liveness probe does not depend on the database
+        let response = warp::test::request().path("/health").reply(&app).await;
+        assert_eq!(response.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn test_schema_endpoint() {
+        let app = create_test_app().await;
+
+        let response = warp::test::request()
+            .method("GET")
+            .path("/schema")
+            .reply(&app)
+            .await;
+
+        assert_eq!(response.status(), 200);
+        let body: serde_json::Value = serde_json::from_slice(response.body()).unwrap();
+        assert_eq!(body["protocol_version"], game_types::PROTOCOL_VERSION);
+
+        // Every variant is described, in its serde representation
+        let client = body["client_message"].to_string();
+        assert!(client.contains("SubmitGuess"));
+        assert!(client.contains("Heartbeat"));
+        let server = body["server_message"].to_string();
+        assert!(server.contains("GameStateUpdate"));
+        assert!(server.contains("RankChanged"));
+    }
+
+    #[tokio::test]
+    async fn test_websocket_connection_upgrade() {
         let app = create_test_app().await;
 
         let mut ws = warp::test::ws()
@@ -363,6 +1166,64 @@ mod integration_tests {
         // Heartbeat doesn't send a response, so if no error occurs, connection is working
     }
 
+    #[tokio::test]
+    async fn test_hello_with_compatible_version() {
+        let app = create_test_app().await;
+
+        let mut ws = warp::test::ws()
+            .path("/ws")
+            .handshake(app)
+            .await
+            .expect("WebSocket handshake should succeed");
+
+        let hello = ClientMessage::Hello {
+            protocol_version: game_types::PROTOCOL_VERSION,
+        };
+        ws.send_text(serde_json::to_string(&hello).unwrap()).await;
+
+        let msg = ws.recv().await.expect("Should receive response");
+        let server_msg: ServerMessage = serde_json::from_str(msg.to_str().unwrap()).unwrap();
+        match server_msg {
+            ServerMessage::Welcome {
+                server_version,
+                protocol_version,
+            } => {
+                assert_eq!(server_version, env!("CARGO_PKG_VERSION"));
+                assert_eq!(protocol_version, game_types::PROTOCOL_VERSION);
+            }
+            other => panic!("Expected Welcome, got: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hello_with_incompatible_version() {
+        let app = create_test_app().await;
+
+        let mut ws = warp::test::ws()
+            .path("/ws")
+            .handshake(app)
+            .await
+            .expect("WebSocket handshake should succeed");
+
+        let hello = ClientMessage::Hello {
+            protocol_version: game_types::PROTOCOL_VERSION + 1,
+        };
+        ws.send_text(serde_json::to_string(&hello).unwrap()).await;
+
+        let msg = ws.recv().await.expect("Should receive response");
+        let server_msg: ServerMessage = serde_json::from_str(msg.to_str().unwrap()).unwrap();
+        assert!(matches!(
+            server_msg,
+            ServerMessage::IncompatibleVersion { min, max }
+                if min == game_types::MIN_PROTOCOL_VERSION && max == game_types::PROTOCOL_VERSION
+        ));
+
+        // Followed by a clean close
+        ws.recv_closed()
+            .await
+            .expect("Connection should close after IncompatibleVersion");
+    }
+
     #[tokio::test]
     async fn test_websocket_invalid_message_handling() {
         let app = create_test_app().await;
@@ -381,8 +1242,8 @@ mod integration_tests {
             Ok(msg) if msg.is_text() => {
                 let text = msg.to_str().unwrap();
                 let server_msg: ServerMessage =
-                    serde_json::from_str(&text).expect("Should be valid ServerMessage");
-                if let ServerMessage::Error { message } = server_msg {
+                    serde_json::from_str(text).expect("Should be valid ServerMessage");
+                if let ServerMessage::Error { message, .. } = server_msg {
                     assert!(message.contains("Invalid JSON message"));
                 } else {
                     panic!("Expected error message, got: {:?}", server_msg);
@@ -408,6 +1269,7 @@ mod integration_tests {
         // First authenticate
         let auth_msg = ClientMessage::Authenticate {
             token: "user1:test@example.com:Test User".to_string(),
+            auto_queue: false,
         };
         let auth_json = serde_json::to_string(&auth_msg).expect("Should serialize");
         ws.send_text(&auth_json).await;
@@ -425,7 +1287,7 @@ mod integration_tests {
         if msg.is_text() {
             let text = msg.to_str().unwrap();
             let server_msg: ServerMessage =
-                serde_json::from_str(&text).expect("Should be valid ServerMessage");
+                serde_json::from_str(text).expect("Should be valid ServerMessage");
             if let ServerMessage::QueueJoined { position } = server_msg {
                 assert_eq!(position, 1); // First player in queue
             } else {
@@ -456,6 +1318,7 @@ mod integration_tests {
         // Authenticate both clients
         let auth_msg1 = ClientMessage::Authenticate {
             token: "user3:test3@example.com:Test User 3".to_string(),
+            auto_queue: false,
         };
         let auth_json1 = serde_json::to_string(&auth_msg1).expect("Should serialize");
         ws1.send_text(&auth_json1).await;
@@ -463,6 +1326,7 @@ mod integration_tests {
 
         let auth_msg2 = ClientMessage::Authenticate {
             token: "user4:test4@example.com:Test User 4".to_string(),
+            auto_queue: false,
         };
         let auth_json2 = serde_json::to_string(&auth_msg2).expect("Should serialize");
         ws2.send_text(&auth_json2).await;
@@ -480,7 +1344,7 @@ mod integration_tests {
         if msg1.is_text() {
             let text = msg1.to_str().unwrap();
             let server_msg: ServerMessage =
-                serde_json::from_str(&text).expect("Should be valid ServerMessage");
+                serde_json::from_str(text).expect("Should be valid ServerMessage");
             if let ServerMessage::QueueJoined { position } = server_msg {
                 assert_eq!(position, 1);
             } else {
@@ -493,7 +1357,7 @@ mod integration_tests {
         if msg2.is_text() {
             let text = msg2.to_str().unwrap();
             let server_msg: ServerMessage =
-                serde_json::from_str(&text).expect("Should be valid ServerMessage");
+                serde_json::from_str(text).expect("Should be valid ServerMessage");
             if let ServerMessage::QueueJoined { position } = server_msg {
                 assert_eq!(position, 2);
             } else {
@@ -551,6 +1415,7 @@ mod integration_tests {
         // First authenticate
         let auth_msg = ClientMessage::Authenticate {
             token: "user2:test2@example.com:Test User 2".to_string(),
+            auto_queue: false,
         };
         let auth_json = serde_json::to_string(&auth_msg).expect("Should serialize");
         ws.send_text(&auth_json).await;
@@ -576,7 +1441,7 @@ mod integration_tests {
         if msg.is_text() {
             let text = msg.to_str().unwrap();
             let server_msg: ServerMessage =
-                serde_json::from_str(&text).expect("Should be valid ServerMessage");
+                serde_json::from_str(text).expect("Should be valid ServerMessage");
             if let ServerMessage::QueueLeft = server_msg {
                 // Success
             } else {
@@ -607,8 +1472,8 @@ mod integration_tests {
             Ok(msg) if msg.is_text() => {
                 let text = msg.to_str().unwrap();
                 let server_msg: ServerMessage =
-                    serde_json::from_str(&text).expect("Should be valid ServerMessage");
-                if let ServerMessage::Error { message } = server_msg {
+                    serde_json::from_str(text).expect("Should be valid ServerMessage");
+                if let ServerMessage::Error { message, .. } = server_msg {
                     assert!(
                         message.contains("Not in a game")
                             || message.contains("Connection not found")
@@ -728,8 +1593,8 @@ mod integration_tests {
         if msg.is_text() {
             let text = msg.to_str().unwrap();
             let server_msg: ServerMessage =
-                serde_json::from_str(&text).expect("Should be valid ServerMessage");
-            if let ServerMessage::Error { message } = server_msg {
+                serde_json::from_str(text).expect("Should be valid ServerMessage");
+            if let ServerMessage::Error { message, .. } = server_msg {
                 assert!(message.contains("Authentication required"));
             } else {
                 panic!("Expected error message, got: {:?}", server_msg);
@@ -752,6 +1617,7 @@ mod integration_tests {
         // Authenticate with simple string token
         let auth_msg = ClientMessage::Authenticate {
             token: "user1:alice@example.com:Alice".to_string(),
+            auto_queue: false,
         };
         let auth_json = serde_json::to_string(&auth_msg).expect("Should serialize");
         ws.send_text(&auth_json).await;
@@ -761,7 +1627,7 @@ mod integration_tests {
         if msg.is_text() {
             let text = msg.to_str().unwrap();
             let server_msg: ServerMessage =
-                serde_json::from_str(&text).expect("Should be valid ServerMessage");
+                serde_json::from_str(text).expect("Should be valid ServerMessage");
             if let ServerMessage::AuthenticationSuccess { user } = server_msg {
                 assert_eq!(user.email, "alice@example.com");
                 assert_eq!(user.display_name, "Alice");
@@ -787,6 +1653,7 @@ mod integration_tests {
         let json_token = r#"{"user_id":"test-bob-id","email":"bob@example.com","name":"Bob"}"#;
         let auth_msg = ClientMessage::Authenticate {
             token: json_token.to_string(),
+            auto_queue: false,
         };
         let auth_json = serde_json::to_string(&auth_msg).expect("Should serialize");
         ws.send_text(&auth_json).await;
@@ -796,7 +1663,7 @@ mod integration_tests {
         if msg.is_text() {
             let text = msg.to_str().unwrap();
             let server_msg: ServerMessage =
-                serde_json::from_str(&text).expect("Should be valid ServerMessage");
+                serde_json::from_str(text).expect("Should be valid ServerMessage");
             if let ServerMessage::AuthenticationSuccess { user } = server_msg {
                 assert_eq!(user.email, "bob@example.com");
                 assert_eq!(user.display_name, "Bob");
@@ -822,6 +1689,7 @@ mod integration_tests {
         // Try to authenticate with invalid token
         let auth_msg = ClientMessage::Authenticate {
             token: "invalid:token".to_string(),
+            auto_queue: false,
         };
         let auth_json = serde_json::to_string(&auth_msg).expect("Should serialize");
         ws.send_text(&auth_json).await;
@@ -831,7 +1699,7 @@ mod integration_tests {
         if msg.is_text() {
             let text = msg.to_str().unwrap();
             let server_msg: ServerMessage =
-                serde_json::from_str(&text).expect("Should be valid ServerMessage");
+                serde_json::from_str(text).expect("Should be valid ServerMessage");
             if let ServerMessage::AuthenticationFailed { reason } = server_msg {
                 assert!(reason.contains("Invalid token"));
             } else {
@@ -855,6 +1723,7 @@ mod integration_tests {
         // First authenticate
         let auth_msg = ClientMessage::Authenticate {
             token: "user1:charlie@example.com:Charlie".to_string(),
+            auto_queue: false,
         };
         let auth_json = serde_json::to_string(&auth_msg).expect("Should serialize");
         ws.send_text(&auth_json).await;
@@ -872,7 +1741,7 @@ mod integration_tests {
         if msg.is_text() {
             let text = msg.to_str().unwrap();
             let server_msg: ServerMessage =
-                serde_json::from_str(&text).expect("Should be valid ServerMessage");
+                serde_json::from_str(text).expect("Should be valid ServerMessage");
             if let ServerMessage::QueueJoined { position } = server_msg {
                 assert_eq!(position, 1); // First player in queue
             } else {
@@ -903,6 +1772,7 @@ mod integration_tests {
         // Authenticate first user
         let auth_msg1 = ClientMessage::Authenticate {
             token: "user1:alice@example.com:Alice".to_string(),
+            auto_queue: false,
         };
         ws1.send_text(&serde_json::to_string(&auth_msg1).unwrap())
             .await;
@@ -911,6 +1781,7 @@ mod integration_tests {
         // Authenticate second user
         let auth_msg2 = ClientMessage::Authenticate {
             token: "user2:bob@example.com:Bob".to_string(),
+            auto_queue: false,
         };
         ws2.send_text(&serde_json::to_string(&auth_msg2).unwrap())
             .await;
@@ -928,7 +1799,7 @@ mod integration_tests {
         if msg1.is_text() {
             let text = msg1.to_str().unwrap();
             let server_msg: ServerMessage =
-                serde_json::from_str(&text).expect("Should be valid ServerMessage");
+                serde_json::from_str(text).expect("Should be valid ServerMessage");
             if let ServerMessage::QueueJoined { position } = server_msg {
                 assert_eq!(position, 1);
             } else {
@@ -941,7 +1812,7 @@ mod integration_tests {
         if msg2.is_text() {
             let text = msg2.to_str().unwrap();
             let server_msg: ServerMessage =
-                serde_json::from_str(&text).expect("Should be valid ServerMessage");
+                serde_json::from_str(text).expect("Should be valid ServerMessage");
             if let ServerMessage::QueueJoined { position } = server_msg {
                 assert_eq!(position, 2);
             } else {
@@ -955,15 +1826,14 @@ mod integration_tests {
 
         // Try to receive any additional messages (like match found)
         // This is optional since matchmaking might not trigger immediately
-        if let Ok(msg) = tokio::time::timeout(Duration::from_millis(100), ws1.recv()).await {
-            if let Ok(msg) = msg {
-                if msg.is_text() {
-                    let text = msg.to_str().unwrap();
-                    let server_msg: ServerMessage =
-                        serde_json::from_str(&text).expect("Should be valid ServerMessage");
-                    println!("User 1 received additional message: {:?}", server_msg);
-                }
-            }
+        if let Ok(msg) = tokio::time::timeout(Duration::from_millis(100), ws1.recv()).await
+            && let Ok(msg) = msg
+            && msg.is_text()
+        {
+            let text = msg.to_str().unwrap();
+            let server_msg: ServerMessage =
+                serde_json::from_str(text).expect("Should be valid ServerMessage");
+            println!("User 1 received additional message: {:?}", server_msg);
         }
     }
 
@@ -980,6 +1850,7 @@ mod integration_tests {
         // Authenticate
         let auth_msg = ClientMessage::Authenticate {
             token: "user1:test@example.com:TestUser".to_string(),
+            auto_queue: false,
         };
         ws.send_text(&serde_json::to_string(&auth_msg).unwrap())
             .await;
@@ -989,7 +1860,7 @@ mod integration_tests {
         if auth_response.is_text() {
             let text = auth_response.to_str().unwrap();
             let server_msg: ServerMessage =
-                serde_json::from_str(&text).expect("Should be valid ServerMessage");
+                serde_json::from_str(text).expect("Should be valid ServerMessage");
             assert!(matches!(
                 server_msg,
                 ServerMessage::AuthenticationSuccess { .. }
@@ -1006,7 +1877,7 @@ mod integration_tests {
         if queue_response.is_text() {
             let text = queue_response.to_str().unwrap();
             let server_msg: ServerMessage =
-                serde_json::from_str(&text).expect("Should be valid ServerMessage");
+                serde_json::from_str(text).expect("Should be valid ServerMessage");
             if let ServerMessage::QueueJoined { position } = server_msg {
                 assert_eq!(position, 1);
                 println!("✅ User successfully joined queue at position {}", position);
@@ -1025,59 +1896,42 @@ mod integration_tests {
         if leave_response.is_text() {
             let text = leave_response.to_str().unwrap();
             let server_msg: ServerMessage =
-                serde_json::from_str(&text).expect("Should be valid ServerMessage");
+                serde_json::from_str(text).expect("Should be valid ServerMessage");
             assert!(matches!(server_msg, ServerMessage::QueueLeft));
             println!("✅ User successfully left queue");
         }
     }
 
-    // Helper function to create test users in the database
-    async fn create_test_users(app: &warp::filters::BoxedFilter<(impl warp::Reply,)>) -> Vec<User> {
-        // Access the user repository to create test data
-        // Since we can't easily extract it from the app, we'll use HTTP requests to create users
-        let users = vec![
-            User {
-                id: "test-alice".to_string(),
-                email: "alice@test.com".to_string(),
-                display_name: "Alice".to_string(),
-                total_points: 100,
-                total_wins: 5,
-                total_games: 10,
-                created_at: chrono::Utc::now().to_rfc3339(),
-            },
-            User {
-                id: "test-bob".to_string(),
-                email: "bob@test.com".to_string(),
-                display_name: "Bob".to_string(),
-                total_points: 200,
-                total_wins: 8,
-                total_games: 12,
-                created_at: chrono::Utc::now().to_rfc3339(),
-            },
-            User {
-                id: "test-charlie".to_string(),
-                email: "charlie@test.com".to_string(),
-                display_name: "Charlie".to_string(),
-                total_points: 50,
-                total_wins: 2,
-                total_games: 8,
-                created_at: chrono::Utc::now().to_rfc3339(),
-            },
-        ];
+    #[tokio::test]
+    async fn test_game_history_endpoint_requires_auth() {
+        let app = create_dev_test_app().await;
 
-        // We would need to populate the database through the repository directly
-        // Since we can't access it from the test app, we'll create a fresh DB connection
-        let db = game_persistence::connection::connect_to_memory_database()
-            .await
-            .unwrap();
-        Migrator::up(&db, None).await.unwrap();
-        let repo = UserRepository::new(db);
+        let response = warp::test::request()
+            .method("GET")
+            .path("/api/game/some-game/history")
+            .reply(&app)
+            .await;
 
-        for user in &users {
-            repo.create_user(user.clone()).await.unwrap();
-        }
+        assert_eq!(response.status(), 401);
+    }
 
-        users
+    #[tokio::test]
+    async fn test_game_history_endpoint_game_not_found() {
+        let app = create_dev_test_app().await;
+
+        let response = warp::test::request()
+            .method("GET")
+            .path("/api/game/nonexistent-game/history")
+            .header("authorization", "test-user-1:test@example.com:Test")
+            .reply(&app)
+            .await;
+
+        assert_eq!(response.status(), 404);
+
+        let error: serde_json::Value =
+            serde_json::from_slice(response.body()).expect("Should parse JSON");
+
+        assert_eq!(error["error"], "Game not found");
     }
 
     #[tokio::test]
@@ -1131,6 +1985,25 @@ mod integration_tests {
         assert_eq!(response.status(), 200);
     }
 
+    #[tokio::test]
+    async fn test_leaderboard_endpoint_with_activity_window() {
+        let app = create_dev_test_app().await;
+
+        let response = warp::test::request()
+            .method("GET")
+            .path("/api/leaderboard?active_within_days=30")
+            .reply(&app)
+            .await;
+        assert_eq!(response.status(), 200);
+
+        let response = warp::test::request()
+            .method("GET")
+            .path("/api/leaderboard?active_within_days=soon")
+            .reply(&app)
+            .await;
+        assert_eq!(response.status(), 400);
+    }
+
     #[tokio::test]
     async fn test_user_stats_endpoint_unauthorized() {
         let app = create_dev_test_app().await;
@@ -1217,4 +2090,538 @@ mod integration_tests {
 
         assert_eq!(error["error"], "User not found");
     }
+
+    #[tokio::test]
+    async fn test_user_stats_endpoint_admin_can_view_others() {
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let game_manager = Arc::new(GameManager::new_with_validator(
+            connection_manager.clone(),
+            game_core::word_validation::WordValidator::new_with_test_words(),
+        ));
+        let auth_service = Arc::new(
+            AuthService::new_dev_mode()
+                .with_admin_user_ids(["admin-user".to_string()].into_iter().collect()),
+        );
+
+        let db = game_persistence::connection::connect_to_memory_database()
+            .await
+            .unwrap();
+        migration::Migrator::up(&db, None).await.unwrap();
+        let user_repository = Arc::new(UserRepository::new(db.clone()));
+        user_repository
+            .create_user(User {
+                id: "player-1".to_string(),
+                email: "player@example.com".to_string(),
+                display_name: "Player".to_string(),
+                total_points: 0,
+                total_wins: 0,
+                total_games: 0,
+                created_at: chrono::Utc::now().to_rfc3339(),
+                avatar: None,
+                roles: Vec::new(),
+            })
+            .await
+            .unwrap();
+
+        let app = create_routes(
+            connection_manager,
+            game_manager,
+            Arc::new(MatchmakingQueue::new()),
+            auth_service,
+            user_repository,
+            Arc::new(WordReportRepository::new(db.clone())),
+            Arc::new(GameRepository::new(db)),
+        );
+
+        let response = warp::test::request()
+            .method("GET")
+            .path("/api/user/player-1/stats")
+            .header("authorization", "Bearer admin-user:admin@example.com:Admin")
+            .reply(&app)
+            .await;
+        assert_eq!(response.status(), 200);
+        let stats: serde_json::Value = serde_json::from_slice(response.body()).unwrap();
+        assert_eq!(stats["user"]["id"], "player-1");
+
+        let response = warp::test::request()
+            .method("GET")
+            .path("/api/user/player-1/stats")
+            .header("authorization", "Bearer player-2:other@example.com:Other")
+            .reply(&app)
+            .await;
+        assert_eq!(response.status(), 403);
+    }
+
+    #[tokio::test]
+    async fn test_rank_context_endpoint() {
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let game_manager = Arc::new(GameManager::new_with_validator(
+            connection_manager.clone(),
+            game_core::word_validation::WordValidator::new_with_test_words(),
+        ));
+        let db = game_persistence::connection::connect_to_memory_database()
+            .await
+            .unwrap();
+        migration::Migrator::up(&db, None).await.unwrap();
+        let user_repository = Arc::new(UserRepository::new(db.clone()));
+        for (id, total_points, total_games) in [
+            ("top", 300, 3),
+            ("middle", 200, 2),
+            ("bottom", 100, 1),
+            ("newcomer", 0, 0),
+        ] {
+            user_repository
+                .create_user(User {
+                    id: id.to_string(),
+                    email: format!("{}@example.com", id),
+                    display_name: id.to_string(),
+                    total_points,
+                    total_wins: 0,
+                    total_games,
+                    created_at: chrono::Utc::now().to_rfc3339(),
+                    avatar: None,
+                    roles: Vec::new(),
+                })
+                .await
+                .unwrap();
+        }
+
+        let app = create_routes(
+            connection_manager,
+            game_manager,
+            Arc::new(MatchmakingQueue::new()),
+            Arc::new(AuthService::new_dev_mode()),
+            user_repository,
+            Arc::new(WordReportRepository::new(db.clone())),
+            Arc::new(GameRepository::new(db)),
+        );
+
+        let response = warp::test::request()
+            .method("GET")
+            .path("/api/user/middle/rank-context?window=1")
+            .header("authorization", "Bearer middle:middle@example.com:Middle")
+            .reply(&app)
+            .await;
+        assert_eq!(response.status(), 200);
+        let context: serde_json::Value = serde_json::from_slice(response.body()).unwrap();
+        let neighbours: Vec<(&str, u64)> = context["entries"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|entry| {
+                (
+                    entry["user"]["id"].as_str().unwrap(),
+                    entry["rank"].as_u64().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(neighbours, vec![("top", 1), ("middle", 2), ("bottom", 3)]);
+
+        // Unranked until the first finished game
+        let response = warp::test::request()
+            .method("GET")
+            .path("/api/user/newcomer/rank-context")
+            .header("authorization", "Bearer newcomer:new@example.com:Newcomer")
+            .reply(&app)
+            .await;
+        assert_eq!(response.status(), 200);
+        let context: serde_json::Value = serde_json::from_slice(response.body()).unwrap();
+        assert!(context["entries"].as_array().unwrap().is_empty());
+
+        // Only the user themself may look
+        let response = warp::test::request()
+            .method("GET")
+            .path("/api/user/middle/rank-context")
+            .header("authorization", "Bearer top:top@example.com:Top")
+            .reply(&app)
+            .await;
+        assert_eq!(response.status(), 403);
+    }
+
+    #[tokio::test]
+    async fn test_user_games_lists_finished_games_for_the_user_only() {
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let game_manager = Arc::new(GameManager::new_with_validator(
+            connection_manager.clone(),
+            game_core::word_validation::WordValidator::new_with_test_words(),
+        ));
+        let db = game_persistence::connection::connect_to_memory_database()
+            .await
+            .unwrap();
+        migration::Migrator::up(&db, None).await.unwrap();
+        let game_repository = Arc::new(GameRepository::new(db.clone()));
+
+        let player = "player-1".to_string();
+        let other = "player-2".to_string();
+        for game_id in ["game-1", "game-2"] {
+            game_repository
+                .record_game_created(
+                    game_id,
+                    &[player.clone(), other.clone()],
+                    5,
+                    chrono::Utc::now(),
+                    "classic",
+                    7,
+                )
+                .await
+                .unwrap();
+        }
+        game_repository
+            .record_game_completed(
+                "game-1",
+                &player,
+                &["crane".to_string()],
+                &[
+                    game_persistence::repositories::game_repository::GameRecordScore {
+                        player_id: player.clone(),
+                        points: 25,
+                    },
+                ],
+            )
+            .await
+            .unwrap();
+
+        let app = create_routes(
+            connection_manager,
+            game_manager,
+            Arc::new(MatchmakingQueue::new()),
+            Arc::new(AuthService::new_dev_mode()),
+            Arc::new(UserRepository::new(db.clone())),
+            Arc::new(WordReportRepository::new(db)),
+            game_repository,
+        );
+
+        let response = warp::test::request()
+            .path("/api/user/player-1/games")
+            .header("authorization", "Bearer player-2:other@example.com:Other")
+            .reply(&app)
+            .await;
+        assert_eq!(response.status(), 403);
+
+        let response = warp::test::request()
+            .path("/api/user/player-1/games?page=0&per_page=10")
+            .header("authorization", "Bearer player-1:player@example.com:Player")
+            .reply(&app)
+            .await;
+        assert_eq!(response.status(), 200);
+        let page: game_persistence::repositories::game_repository::GameRecordPage =
+            serde_json::from_slice(response.body()).unwrap();
+        assert_eq!(page.total, 1);
+        assert_eq!(page.games[0].id, "game-1");
+        assert_eq!(page.games[0].words, vec!["crane"]);
+        assert_eq!(page.games[0].final_scores[0].points, 25);
+    }
+
+    #[tokio::test]
+    async fn test_user_export_and_delete() {
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let game_manager = Arc::new(GameManager::new_with_validator(
+            connection_manager.clone(),
+            game_core::word_validation::WordValidator::new_with_test_words(),
+        ));
+        let db = game_persistence::connection::connect_to_memory_database()
+            .await
+            .unwrap();
+        migration::Migrator::up(&db, None).await.unwrap();
+        let user_repository = Arc::new(UserRepository::new(db.clone()));
+        let game_repository = Arc::new(GameRepository::new(db.clone()));
+        let word_report_repository = Arc::new(WordReportRepository::new(db));
+
+        let player = "player-1".to_string();
+        let other = "player-2".to_string();
+        user_repository
+            .create_user(User {
+                id: player.clone(),
+                email: "player@example.com".to_string(),
+                display_name: "Player".to_string(),
+                total_points: 30,
+                total_wins: 1,
+                total_games: 1,
+                created_at: chrono::Utc::now().to_rfc3339(),
+                avatar: Some(
+                    game_types::Avatar::new(game_types::AvatarColor::Green, "🐸").unwrap(),
+                ),
+                roles: Vec::new(),
+            })
+            .await
+            .unwrap();
+        game_repository
+            .record_game_created(
+                "game-1",
+                &[player.clone(), other.clone()],
+                5,
+                chrono::Utc::now(),
+                "classic",
+                7,
+            )
+            .await
+            .unwrap();
+        game_repository
+            .record_game_completed("game-1", &player, &[], &[])
+            .await
+            .unwrap();
+        word_report_repository
+            .create_report("game-1", "hello", &player, "Not a word")
+            .await
+            .unwrap();
+        word_report_repository
+            .create_report("game-1", "hello", &other, "Misspelled")
+            .await
+            .unwrap();
+
+        let app = create_routes(
+            connection_manager,
+            game_manager,
+            Arc::new(MatchmakingQueue::new()),
+            Arc::new(AuthService::new_dev_mode()),
+            user_repository.clone(),
+            word_report_repository,
+            game_repository.clone(),
+        );
+        let player_token = "Bearer player-1:player@example.com:Player";
+        let other_token = "Bearer player-2:other@example.com:Other";
+
+        // Only the user themself may export or delete their data
+        let response = warp::test::request()
+            .path("/api/user/player-1/export")
+            .reply(&app)
+            .await;
+        assert_eq!(response.status(), 401);
+        let response = warp::test::request()
+            .path("/api/user/player-1/export")
+            .header("authorization", other_token)
+            .reply(&app)
+            .await;
+        assert_eq!(response.status(), 403);
+        let response = warp::test::request()
+            .method("DELETE")
+            .path("/api/user/player-1")
+            .header("authorization", other_token)
+            .reply(&app)
+            .await;
+        assert_eq!(response.status(), 403);
+
+        let response = warp::test::request()
+            .path("/api/user/player-1/export")
+            .header("authorization", player_token)
+            .reply(&app)
+            .await;
+        assert_eq!(response.status(), 200);
+        let export: UserDataExport = serde_json::from_slice(response.body()).unwrap();
+        assert_eq!(export.user.email, "player@example.com");
+        assert!(export.user.avatar.is_some());
+        assert_eq!(export.games.len(), 1);
+        assert_eq!(export.games[0].winner_id, Some(player.clone()));
+        assert_eq!(export.word_reports.len(), 1);
+        assert_eq!(export.word_reports[0].reason, "Not a word");
+
+        let response = warp::test::request()
+            .method("DELETE")
+            .path("/api/user/player-1")
+            .header("authorization", player_token)
+            .reply(&app)
+            .await;
+        assert_eq!(response.status(), 200);
+
+        // Personal data is gone but the stats and the game record remain
+        let user = user_repository.find_by_id(&player).await.unwrap().unwrap();
+        assert_eq!(
+            user.display_name,
+            game_persistence::repositories::user_repository::DELETED_DISPLAY_NAME
+        );
+        assert!(!user.email.contains("player@example.com"));
+        assert!(user.avatar.is_none());
+        assert_eq!(user.total_points, 30);
+        let game = game_repository.find_by_id("game-1").await.unwrap().unwrap();
+        assert_eq!(game.player_ids, vec![player.clone(), other]);
+        assert_eq!(game.winner_id, Some(player));
+
+        let response = warp::test::request()
+            .method("DELETE")
+            .path("/api/user/player-3")
+            .header("authorization", "Bearer player-3:new@example.com:New")
+            .reply(&app)
+            .await;
+        assert_eq!(response.status(), 404);
+    }
+
+    async fn recv_server_message(ws: &mut warp::test::WsClient) -> ServerMessage {
+        let msg = ws.recv().await.expect("Should receive response");
+        serde_json::from_str(msg.to_str().unwrap()).expect("Should be valid ServerMessage")
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_with_auto_queue_joins_queue() {
+        let app = create_dev_test_app().await;
+
+        let mut ws = warp::test::ws()
+            .path("/ws")
+            .handshake(app)
+            .await
+            .expect("WebSocket handshake should succeed");
+        let auth_json = serde_json::to_string(&ClientMessage::Authenticate {
+            token: "requeue-user:requeue@example.com:Requeue".to_string(),
+            auto_queue: true,
+        })
+        .unwrap();
+        ws.send_text(&auth_json).await;
+
+        assert!(matches!(
+            recv_server_message(&mut ws).await,
+            ServerMessage::AuthenticationSuccess { .. }
+        ));
+        match recv_server_message(&mut ws).await {
+            ServerMessage::QueueJoined { position } => assert_eq!(position, 1),
+            other => panic!("Expected QueueJoined, got: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_set_avatar_persists_across_sessions() {
+        let app = create_dev_test_app().await;
+        let token = "avatar-user:avatar@example.com:Ava".to_string();
+
+        let mut ws = warp::test::ws()
+            .path("/ws")
+            .handshake(app.clone())
+            .await
+            .expect("WebSocket handshake should succeed");
+        let auth_json = serde_json::to_string(&ClientMessage::Authenticate {
+            token: token.clone(),
+            auto_queue: false,
+        })
+        .unwrap();
+        ws.send_text(&auth_json).await;
+        match recv_server_message(&mut ws).await {
+            ServerMessage::AuthenticationSuccess { user } => assert!(user.avatar.is_none()),
+            other => panic!("Expected AuthenticationSuccess, got: {:?}", other),
+        }
+
+        // Emoji outside the allowlist is rejected
+        let set_json = serde_json::to_string(&ClientMessage::SetAvatar {
+            color: game_types::AvatarColor::Blue,
+            emoji: "not-an-emoji".to_string(),
+        })
+        .unwrap();
+        ws.send_text(&set_json).await;
+        assert!(matches!(
+            recv_server_message(&mut ws).await,
+            ServerMessage::Error { .. }
+        ));
+
+        let set_json = serde_json::to_string(&ClientMessage::SetAvatar {
+            color: game_types::AvatarColor::Blue,
+            emoji: "🐳".to_string(),
+        })
+        .unwrap();
+        ws.send_text(&set_json).await;
+        let avatar = match recv_server_message(&mut ws).await {
+            ServerMessage::AvatarUpdated { avatar } => avatar,
+            other => panic!("Expected AvatarUpdated, got: {:?}", other),
+        };
+        assert_eq!(avatar.emoji, "🐳");
+
+        // A new session picks the stored avatar back up
+        let mut ws2 = warp::test::ws()
+            .path("/ws")
+            .handshake(app)
+            .await
+            .expect("WebSocket handshake should succeed");
+        let auth_json = serde_json::to_string(&ClientMessage::ForceAuthenticate { token }).unwrap();
+        ws2.send_text(&auth_json).await;
+        match recv_server_message(&mut ws2).await {
+            ServerMessage::AuthenticationSuccess { user } => {
+                assert_eq!(user.avatar, Some(avatar))
+            }
+            other => panic!("Expected AuthenticationSuccess, got: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_word_reports_require_admin() {
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let game_manager = Arc::new(GameManager::new_with_validator(
+            connection_manager.clone(),
+            game_core::word_validation::WordValidator::new_with_test_words(),
+        ));
+        let auth_service = Arc::new(
+            AuthService::new_dev_mode()
+                .with_admin_user_ids(["admin-user".to_string()].into_iter().collect()),
+        );
+
+        let db = game_persistence::connection::connect_to_memory_database()
+            .await
+            .unwrap();
+        migration::Migrator::up(&db, None).await.unwrap();
+        let word_report_repository = Arc::new(WordReportRepository::new(db.clone()));
+        word_report_repository
+            .create_report("game-1", "hello", &"player-1".to_string(), "Not a word")
+            .await
+            .unwrap();
+
+        let app = create_routes(
+            connection_manager,
+            game_manager,
+            Arc::new(MatchmakingQueue::new()),
+            auth_service,
+            Arc::new(UserRepository::new(db.clone())),
+            word_report_repository,
+            Arc::new(GameRepository::new(db)),
+        );
+
+        let response = warp::test::request()
+            .method("GET")
+            .path("/api/admin/word-reports")
+            .reply(&app)
+            .await;
+        assert_eq!(response.status(), 401);
+
+        let response = warp::test::request()
+            .method("GET")
+            .path("/api/admin/word-reports")
+            .header("authorization", "Bearer player-1:player@example.com:Player")
+            .reply(&app)
+            .await;
+        assert_eq!(response.status(), 403);
+
+        let response = warp::test::request()
+            .method("GET")
+            .path("/api/admin/word-reports")
+            .header("authorization", "Bearer admin-user:admin@example.com:Admin")
+            .reply(&app)
+            .await;
+        assert_eq!(response.status(), 200);
+        let reports: Vec<game_persistence::repositories::word_report_repository::WordReport> =
+            serde_json::from_slice(response.body()).unwrap();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].word, "hello");
+    }
+
+    #[tokio::test]
+    async fn test_report_word_requires_game() {
+        let app = create_dev_test_app().await;
+
+        let mut ws = warp::test::ws()
+            .path("/ws")
+            .handshake(app)
+            .await
+            .expect("WebSocket handshake should succeed");
+        let auth_json = serde_json::to_string(&ClientMessage::Authenticate {
+            token: "reporter:reporter@example.com:Reporter".to_string(),
+            auto_queue: false,
+        })
+        .unwrap();
+        ws.send_text(&auth_json).await;
+        let _auth_response = recv_server_message(&mut ws).await;
+
+        let report_json = serde_json::to_string(&ClientMessage::ReportWord {
+            reason: "Not a real word".to_string(),
+        })
+        .unwrap();
+        ws.send_text(&report_json).await;
+        match recv_server_message(&mut ws).await {
+            ServerMessage::Error { message, .. } => assert_eq!(message, "Not in a game"),
+            other => panic!("Expected Error, got: {:?}", other),
+        }
+    }
 }
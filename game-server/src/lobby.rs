@@ -0,0 +1,457 @@
+use rand::Rng;
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+use tracing::info;
+
+use crate::websocket::connection::ConnectionId;
+
+/// Letters and digits that can't be mistaken for one another when a code is
+/// read out loud or copied by hand
+const CODE_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+const CODE_LENGTH: usize = 6;
+
+/// Settings a private game is started with, fixed when the lobby is created
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LobbySettings {
+    pub min_players: usize,
+    pub max_players: usize,
+    pub point_threshold: Option<i32>, // Points needed to win the lobby's game, if set
+}
+
+/// A private group of players waiting for their host to start a game
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lobby {
+    pub code: String,
+    pub host: ConnectionId,
+    pub members: Vec<ConnectionId>, // In joining order, host first
+    pub settings: LobbySettings,
+    pub announcement: Option<String>, // Set by the host for everyone who joins to see
+}
+
+/// What becomes of a lobby when its host leaves before starting the game
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HostLeavePolicy {
+    /// Close the lobby, sending every other member back out
+    DissolveOnHostLeave,
+    /// Promote the longest-waiting member, so a host's dropped connection
+    /// doesn't cost the group its lobby
+    #[default]
+    TransferHost,
+}
+
+impl std::str::FromStr for HostLeavePolicy {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_lowercase().as_str() {
+            "dissolve" => Ok(HostLeavePolicy::DissolveOnHostLeave),
+            "transfer" => Ok(HostLeavePolicy::TransferHost),
+            other => Err(format!(
+                "expected 'dissolve' or 'transfer', got '{}'",
+                other
+            )),
+        }
+    }
+}
+
+/// How a lobby stands after one of its members left
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LobbyDeparture {
+    /// The last member left and the lobby is closed
+    Emptied,
+    /// The host left and the lobby was closed under
+    /// `HostLeavePolicy::DissolveOnHostLeave`; these members were in it
+    Dissolved { members: Vec<ConnectionId> },
+    /// The lobby carries on; `host_changed` is set when the departing member
+    /// was its host and `lobby.host` took over
+    Continues { lobby: Lobby, host_changed: bool },
+}
+
+/// Why a lobby could not be created, joined, left or started
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum LobbyError {
+    #[error("No lobby found with code {code}")]
+    NotFound { code: String },
+    #[error("Lobby {code} is full ({max_players} players)")]
+    Full { code: String, max_players: usize },
+    #[error("Already in a lobby")]
+    AlreadyInLobby,
+    #[error("Not in a lobby")]
+    NotInLobby,
+    #[error("Only the lobby host can do that")]
+    NotHost,
+    #[error("Need at least {min_players} players to start the game")]
+    NotEnoughPlayers { min_players: usize },
+}
+
+#[derive(Debug, Default)]
+struct LobbyState {
+    lobbies: HashMap<String, Lobby>,
+    memberships: HashMap<ConnectionId, String>, // Code of the lobby each connection is in
+}
+
+/// Private lobbies joined by shareable code, kept apart from the public
+/// matchmaking queue
+#[derive(Debug, Default)]
+pub struct LobbyManager {
+    state: RwLock<LobbyState>,
+    host_leave_policy: HostLeavePolicy,
+}
+
+/// Codes are matched regardless of case and surrounding whitespace
+fn normalize_code(code: &str) -> String {
+    code.trim().to_ascii_uppercase()
+}
+
+impl LobbyManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Choose what happens to a lobby whose host leaves before starting
+    pub fn with_host_leave_policy(mut self, policy: HostLeavePolicy) -> Self {
+        self.host_leave_policy = policy;
+        self
+    }
+
+    /// Open a lobby with `host` as its only member, under a fresh code
+    pub async fn create(
+        &self,
+        host: ConnectionId,
+        settings: LobbySettings,
+    ) -> Result<Lobby, LobbyError> {
+        let mut state = self.state.write().await;
+        if state.memberships.contains_key(&host) {
+            return Err(LobbyError::AlreadyInLobby);
+        }
+
+        let code = loop {
+            let mut rng = rand::thread_rng();
+            let code: String = (0..CODE_LENGTH)
+                .map(|_| CODE_ALPHABET[rng.gen_range(0..CODE_ALPHABET.len())] as char)
+                .collect();
+            if !state.lobbies.contains_key(&code) {
+                break code;
+            }
+        };
+
+        let lobby = Lobby {
+            code: code.clone(),
+            host,
+            members: vec![host],
+            settings,
+            announcement: None,
+        };
+        state.lobbies.insert(code.clone(), lobby.clone());
+        state.memberships.insert(host, code.clone());
+        info!("Connection {} opened lobby {}", host, code);
+        Ok(lobby)
+    }
+
+    /// Add `connection_id` to the lobby with `code`
+    pub async fn join(&self, code: &str, connection_id: ConnectionId) -> Result<Lobby, LobbyError> {
+        let code = normalize_code(code);
+        let mut state = self.state.write().await;
+        if state.memberships.contains_key(&connection_id) {
+            return Err(LobbyError::AlreadyInLobby);
+        }
+
+        let lobby = state
+            .lobbies
+            .get_mut(&code)
+            .ok_or_else(|| LobbyError::NotFound { code: code.clone() })?;
+        if lobby.members.len() >= lobby.settings.max_players {
+            return Err(LobbyError::Full {
+                code,
+                max_players: lobby.settings.max_players,
+            });
+        }
+        lobby.members.push(connection_id);
+        let lobby = lobby.clone();
+        state.memberships.insert(connection_id, code);
+        Ok(lobby)
+    }
+
+    /// Take `connection_id` out of its lobby. The lobby closes once its
+    /// last member has gone; a departing host either hands over to the
+    /// longest-waiting member or closes it, following the host leave policy.
+    pub async fn leave(&self, connection_id: ConnectionId) -> Result<LobbyDeparture, LobbyError> {
+        let mut state = self.state.write().await;
+        let code = state
+            .memberships
+            .remove(&connection_id)
+            .ok_or(LobbyError::NotInLobby)?;
+        let Some(lobby) = state.lobbies.get_mut(&code) else {
+            return Ok(LobbyDeparture::Emptied);
+        };
+
+        lobby.members.retain(|member| *member != connection_id);
+        let Some(&next_host) = lobby.members.first() else {
+            state.lobbies.remove(&code);
+            info!("Closed empty lobby {}", code);
+            return Ok(LobbyDeparture::Emptied);
+        };
+        if lobby.host != connection_id {
+            return Ok(LobbyDeparture::Continues {
+                lobby: lobby.clone(),
+                host_changed: false,
+            });
+        }
+
+        match self.host_leave_policy {
+            HostLeavePolicy::TransferHost => {
+                lobby.host = next_host;
+                info!("Lobby {} handed over to {}", code, next_host);
+                Ok(LobbyDeparture::Continues {
+                    lobby: lobby.clone(),
+                    host_changed: true,
+                })
+            }
+            HostLeavePolicy::DissolveOnHostLeave => {
+                let members = std::mem::take(&mut lobby.members);
+                state.lobbies.remove(&code);
+                for member in &members {
+                    state.memberships.remove(member);
+                }
+                info!("Closed lobby {} after its host left", code);
+                Ok(LobbyDeparture::Dissolved { members })
+            }
+        }
+    }
+
+    /// Set or, with `None`, clear the announcement of the lobby
+    /// `connection_id` is hosting
+    pub async fn set_announcement(
+        &self,
+        connection_id: ConnectionId,
+        announcement: Option<String>,
+    ) -> Result<Lobby, LobbyError> {
+        let mut state = self.state.write().await;
+        let code = state
+            .memberships
+            .get(&connection_id)
+            .cloned()
+            .ok_or(LobbyError::NotInLobby)?;
+        let lobby = state.lobbies.get_mut(&code).ok_or(LobbyError::NotInLobby)?;
+        if lobby.host != connection_id {
+            return Err(LobbyError::NotHost);
+        }
+        lobby.announcement = announcement;
+        Ok(lobby.clone())
+    }
+
+    /// The lobby `connection_id` is waiting in, if any
+    pub async fn lobby_of(&self, connection_id: ConnectionId) -> Option<Lobby> {
+        let state = self.state.read().await;
+        let code = state.memberships.get(&connection_id)?;
+        state.lobbies.get(code).cloned()
+    }
+
+    /// Close the lobby `connection_id` is hosting so its game can start.
+    /// They must be its host and enough players must have joined. The lobby
+    /// and its code are gone from here on, so a second start or a late
+    /// joiner can't reach it; hand it to `restore` if the game falls through.
+    pub async fn take_startable(&self, connection_id: ConnectionId) -> Result<Lobby, LobbyError> {
+        let mut state = self.state.write().await;
+        let code = state
+            .memberships
+            .get(&connection_id)
+            .ok_or(LobbyError::NotInLobby)?;
+        let lobby = state.lobbies.get(code).ok_or(LobbyError::NotInLobby)?;
+        if lobby.host != connection_id {
+            return Err(LobbyError::NotHost);
+        }
+        if lobby.members.len() < lobby.settings.min_players {
+            return Err(LobbyError::NotEnoughPlayers {
+                min_players: lobby.settings.min_players,
+            });
+        }
+
+        let code = code.clone();
+        let lobby = state.lobbies.remove(&code).expect("lobby was just found");
+        for member in &lobby.members {
+            state.memberships.remove(member);
+        }
+        Ok(lobby)
+    }
+
+    /// Reopen a lobby taken by `take_startable` whose game did not start.
+    /// Members who have since moved on to another lobby are left out, and
+    /// the host role passes on if the host was one of them. Returns the
+    /// lobby as reopened, or `None` if nobody is left to reopen it for.
+    pub async fn restore(&self, mut lobby: Lobby) -> Option<Lobby> {
+        let mut state = self.state.write().await;
+        if state.lobbies.contains_key(&lobby.code) {
+            return None;
+        }
+
+        lobby
+            .members
+            .retain(|member| !state.memberships.contains_key(member));
+        let &next_host = lobby.members.first()?;
+        if !lobby.members.contains(&lobby.host) {
+            lobby.host = next_host;
+        }
+        for &member in &lobby.members {
+            state.memberships.insert(member, lobby.code.clone());
+        }
+        state.lobbies.insert(lobby.code.clone(), lobby.clone());
+        info!("Reopened lobby {}", lobby.code);
+        Some(lobby)
+    }
+
+    pub async fn lobby_count(&self) -> usize {
+        self.state.read().await.lobbies.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SETTINGS: LobbySettings = LobbySettings {
+        min_players: 2,
+        max_players: 3,
+        point_threshold: None,
+    };
+
+    #[tokio::test]
+    async fn test_join_by_code_until_full() {
+        let lobbies = LobbyManager::new();
+        let host = ConnectionId::new();
+        let lobby = lobbies.create(host, SETTINGS).await.unwrap();
+        assert_eq!(lobby.code.len(), CODE_LENGTH);
+        assert!(lobby.code.bytes().all(|byte| CODE_ALPHABET.contains(&byte)));
+
+        // Codes are accepted in any case
+        let guest = ConnectionId::new();
+        let joined = lobbies
+            .join(&format!(" {} ", lobby.code.to_lowercase()), guest)
+            .await
+            .unwrap();
+        assert_eq!(joined.members, vec![host, guest]);
+        lobbies
+            .join(&lobby.code, ConnectionId::new())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            lobbies.join(&lobby.code, ConnectionId::new()).await,
+            Err(LobbyError::Full {
+                code: lobby.code.clone(),
+                max_players: 3,
+            })
+        );
+        assert_eq!(
+            lobbies.join("NOPE42", ConnectionId::new()).await,
+            Err(LobbyError::NotFound {
+                code: "NOPE42".to_string(),
+            })
+        );
+        assert_eq!(
+            lobbies.join(&lobby.code, guest).await,
+            Err(LobbyError::AlreadyInLobby)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_host_hands_over_and_empty_lobby_closes() {
+        let lobbies = LobbyManager::new();
+        let host = ConnectionId::new();
+        let guest = ConnectionId::new();
+        let lobby = lobbies.create(host, SETTINGS).await.unwrap();
+
+        // The host can't start alone
+        assert_eq!(
+            lobbies.take_startable(host).await.unwrap_err(),
+            LobbyError::NotEnoughPlayers { min_players: 2 }
+        );
+        lobbies.join(&lobby.code, guest).await.unwrap();
+        assert_eq!(
+            lobbies.take_startable(guest).await.unwrap_err(),
+            LobbyError::NotHost
+        );
+
+        let LobbyDeparture::Continues {
+            lobby: remaining,
+            host_changed: true,
+        } = lobbies.leave(host).await.unwrap()
+        else {
+            panic!("The lobby should carry on under a new host");
+        };
+        assert_eq!(remaining.host, guest);
+        assert_eq!(remaining.members, vec![guest]);
+
+        assert_eq!(lobbies.leave(guest).await, Ok(LobbyDeparture::Emptied));
+        assert_eq!(lobbies.lobby_count().await, 0);
+        assert_eq!(
+            lobbies.join(&lobby.code, host).await.unwrap_err(),
+            LobbyError::NotFound { code: lobby.code }
+        );
+        assert_eq!(lobbies.leave(guest).await, Err(LobbyError::NotInLobby));
+    }
+
+    #[tokio::test]
+    async fn test_started_lobby_is_taken_once_and_can_be_restored() {
+        let lobbies = LobbyManager::new();
+        let host = ConnectionId::new();
+        let guest = ConnectionId::new();
+        let lobby = lobbies.create(host, SETTINGS).await.unwrap();
+        lobbies.join(&lobby.code, guest).await.unwrap();
+
+        let taken = lobbies.take_startable(host).await.unwrap();
+        assert_eq!(taken.members, vec![host, guest]);
+        assert_eq!(lobbies.lobby_count().await, 0);
+
+        // A repeated start or a late joiner finds nothing
+        assert_eq!(
+            lobbies.take_startable(host).await,
+            Err(LobbyError::NotInLobby)
+        );
+        assert_eq!(
+            lobbies.join(&lobby.code, ConnectionId::new()).await,
+            Err(LobbyError::NotFound {
+                code: lobby.code.clone(),
+            })
+        );
+
+        // The game fell through after the host opened another lobby
+        lobbies.create(host, SETTINGS).await.unwrap();
+        let restored = lobbies.restore(taken).await.unwrap();
+        assert_eq!(restored.host, guest);
+        assert_eq!(restored.members, vec![guest]);
+        assert_eq!(lobbies.lobby_of(guest).await, Some(restored));
+    }
+
+    #[tokio::test]
+    async fn test_host_leaving_dissolves_lobby_under_dissolve_policy() {
+        let lobbies =
+            LobbyManager::new().with_host_leave_policy(HostLeavePolicy::DissolveOnHostLeave);
+        let host = ConnectionId::new();
+        let guest = ConnectionId::new();
+        let other = ConnectionId::new();
+        let lobby = lobbies.create(host, SETTINGS).await.unwrap();
+        lobbies.join(&lobby.code, guest).await.unwrap();
+        lobbies.join(&lobby.code, other).await.unwrap();
+
+        // Anyone else leaving doesn't disturb it
+        assert!(matches!(
+            lobbies.leave(other).await,
+            Ok(LobbyDeparture::Continues {
+                host_changed: false,
+                ..
+            })
+        ));
+
+        assert_eq!(
+            lobbies.leave(host).await,
+            Ok(LobbyDeparture::Dissolved {
+                members: vec![guest],
+            })
+        );
+        assert_eq!(lobbies.lobby_count().await, 0);
+        assert_eq!(lobbies.lobby_of(guest).await, None);
+        // The members are free to open or join another lobby
+        assert!(lobbies.create(guest, SETTINGS).await.is_ok());
+    }
+}
@@ -1,12 +1,22 @@
+use game_types::ServerMessage;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::signal;
 use tracing::info;
 
-use game_persistence::{connection::connect_and_migrate, repositories::UserRepository};
+use game_persistence::{
+    connection::connect_and_migrate,
+    repositories::{AbuseRepository, GameRepository, UserRepository, WordReportRepository},
+};
 use game_server::{
-    auth::AuthService, config::Config, create_routes, game_manager::GameManager,
-    matchmaking::MatchmakingQueue, websocket::ConnectionManager,
+    abuse::AbuseTracker,
+    auth::AuthService,
+    config::Config,
+    create_routes,
+    game_manager::GameManager,
+    matchmaker::Matchmaker,
+    matchmaking::MatchmakingQueue,
+    websocket::{self, ConnectionManager, close::CloseReason},
 };
 
 #[tokio::main]
@@ -18,51 +28,160 @@ async fn main() {
 
     // Initialize application state
     let config = Config::new();
-    let connection_manager = Arc::new(ConnectionManager::new());
+    let connection_manager = Arc::new(
+        ConnectionManager::new()
+            .with_send_timeout(config.send_timeout())
+            .with_slow_consumer_depth(config.slow_consumer_depth())
+            .with_max_spectators(config.max_spectators()),
+    );
+
+    // Initialize database connection and run migrations
+    let db = match connect_and_migrate().await {
+        Ok(db) => db,
+        Err(e) => {
+            tracing::error!("Failed to connect to database and run migrations: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let game_repository = Arc::new(GameRepository::new(db.clone()));
 
-    // Initialize game manager with directory-based word loading
+    // Initialize game manager; words load in the background and /health
+    // reports "Not ready" until they are available
     let words_dir =
         std::env::var("WORDS_DIRECTORY").unwrap_or_else(|_| "./shared/words".to_string());
     info!("Loading words from directory: {}", words_dir);
 
-    let game_manager = match GameManager::new(connection_manager.clone(), &words_dir) {
-        Ok(gm) => {
-            info!("Successfully loaded words from directory");
-            Arc::new(gm)
+    let game_manager = Arc::new(
+        GameManager::new_loading(connection_manager.clone())
+            .with_rules(config.game_rules())
+            .with_ready_timeout(config.ready_check_timeout())
+            .with_max_active_games(config.max_active_games())
+            .with_empty_game_grace(config.empty_game_grace())
+            .with_rejoin_token_ttl(config.rejoin_token_ttl())
+            .with_max_concurrent_guesses(config.max_concurrent_guesses())
+            .with_recent_word_window(config.recent_word_window)
+            .with_themed_games(config.themed_games)
+            .with_max_game_duration(config.max_game_duration())
+            .with_rename_on_rejoin(config.rename_on_rejoin)
+            .with_round_time_limit(config.round_time_limit())
+            .with_game_repository(game_repository.clone()),
+    );
+    // Optional list of offensive words that are never picked as targets
+    let blocklist_file = std::env::var("WORD_BLOCKLIST_FILE").ok();
+    // Optional curated target list; WORDS_DIRECTORY then only validates guesses
+    let answers_dir = std::env::var("ANSWERS_DIRECTORY").ok();
+    // Optional directory of themed word lists, one category per .txt file
+    let categories_dir = std::env::var("WORD_CATEGORIES_DIRECTORY").ok();
+    let word_categories = config.word_categories.clone();
+    let guess_punctuation = config.guess_punctuation;
+    let min_words_per_length = config.min_words_per_length();
+    let require_dictionary_coverage = config.require_dictionary_coverage;
+    let loading_game_manager = game_manager.clone();
+    tokio::task::spawn_blocking(move || {
+        let mut word_validator = game_core::WordValidator::new_or_embedded(&words_dir)
+            .with_punctuation(guess_punctuation);
+        if let Some(path) = blocklist_file {
+            word_validator = match word_validator.with_blocklist_file(&path) {
+                Ok(validator) => validator,
+                Err(e) => {
+                    tracing::error!("Failed to load word blocklist: {}", e);
+                    std::process::exit(1);
+                }
+            };
         }
-        Err(e) => {
-            tracing::error!("Failed to load words from directory '{}': {}", words_dir, e);
-            tracing::error!("The server requires word files to function.");
-            tracing::error!("Run './scripts/download_and_split_words.sh' to generate word lists.");
+        if let Some(path) = answers_dir {
+            word_validator = match word_validator.with_answers_dir(&path) {
+                Ok(validator) => validator,
+                Err(e) => {
+                    tracing::error!("Failed to load answer words: {}", e);
+                    std::process::exit(1);
+                }
+            };
+        }
+        if let Some(path) = categories_dir {
+            word_validator = match word_validator.with_categories_dir(&path) {
+                Ok(validator) => validator,
+                Err(e) => {
+                    tracing::error!("Failed to load word categories: {}", e);
+                    std::process::exit(1);
+                }
+            };
+        }
+        if let Err(e) = word_validator.check_categories(&word_categories) {
+            tracing::error!("Invalid WORD_CATEGORIES: {}", e);
+            std::process::exit(1);
+        }
+        // A near-empty WORDS_DIRECTORY loads fine but leaves games with a
+        // handful of words
+        if let Some(min_words) = min_words_per_length
+            && let Err(e) = word_validator.check_coverage(min_words)
+        {
+            tracing::error!("{} (check WORDS_DIRECTORY)", e);
+            if require_dictionary_coverage {
+                std::process::exit(1);
+            }
             tracing::error!(
-                "Or set WORDS_DIRECTORY to point to a directory containing .txt word files."
+                "REQUIRE_DICTIONARY_COVERAGE=false: starting anyway, expect repeated target words"
             );
-            std::process::exit(1);
         }
-    };
+        loading_game_manager.set_word_validator(word_validator);
+        info!("Word validator ready");
+    });
 
-    let matchmaking_queue = Arc::new(MatchmakingQueue::new());
+    let mut matchmaking_queue = MatchmakingQueue::new()
+        .with_abandon_cooldown(config.abandon_cooldown())
+        .with_disconnect_hold(config.queue_hold())
+        .with_max_queue_size(config.max_queue_size())
+        .with_rating_window(config.rating_window())
+        .with_point_threshold(config.points_to_win)
+        .with_host_leave_policy(config.lobby_host_leave);
+    if let Some(policy) = config.abuse_policy() {
+        let abuse_repository = Arc::new(AbuseRepository::new(db.clone()));
+        matchmaking_queue =
+            matchmaking_queue.with_abuse_tracker(AbuseTracker::new(abuse_repository, policy));
+    }
+    let matchmaking_queue = Arc::new(matchmaking_queue);
 
-    // Initialize database connection and run migrations
-    let db = match connect_and_migrate().await {
-        Ok(db) => db,
-        Err(e) => {
-            tracing::error!("Failed to connect to database and run migrations: {}", e);
-            std::process::exit(1);
-        }
-    };
-    let user_repository = Arc::new(UserRepository::new(db));
+    let user_repository = Arc::new(
+        UserRepository::new(db.clone()).with_leaderboard_cache(config.leaderboard_cache_ttl()),
+    );
+    let word_report_repository = Arc::new(WordReportRepository::new(db));
+
+    // Comma-separated, so tokens from several app registrations validate
+    let client_ids: Vec<String> = std::env::var("AZURE_CLIENT_ID")
+        .unwrap_or_else(|_| "your-client-id".to_string())
+        .split(',')
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty())
+        .collect();
+
+    // Google sign-in stays off unless a client ID is configured
+    let google_client_ids: Vec<String> = std::env::var("GOOGLE_CLIENT_ID")
+        .unwrap_or_default()
+        .split(',')
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty())
+        .collect();
 
     // Check for dev mode
     let auth_service =
         if std::env::var("AUTH_DEV_MODE").unwrap_or_else(|_| "false".to_string()) == "true" {
             info!("Starting in development authentication mode - JWT validation disabled");
-            Arc::new(AuthService::new_dev_mode())
+            Arc::new(
+                AuthService::new_dev_mode()
+                    .with_token_policy(config.token_policy())
+                    .with_admin_user_ids(config.admin_user_ids()),
+            )
         } else {
-            Arc::new(AuthService::new(
-                std::env::var("AZURE_TENANT_ID").unwrap_or_else(|_| "common".to_string()),
-                std::env::var("AZURE_CLIENT_ID").unwrap_or_else(|_| "your-client-id".to_string()),
-            ))
+            Arc::new(
+                AuthService::new_with_audiences(
+                    std::env::var("AZURE_TENANT_ID").unwrap_or_else(|_| "common".to_string()),
+                    client_ids,
+                )
+                .with_google_client_ids(google_client_ids)
+                .with_token_policy(config.token_policy())
+                .with_admin_user_ids(config.admin_user_ids()),
+            )
         };
 
     let routes = create_routes(
@@ -70,12 +189,49 @@ async fn main() {
         game_manager.clone(),
         matchmaking_queue.clone(),
         auth_service,
-        user_repository,
+        user_repository.clone(),
+        word_report_repository,
+        game_repository,
     );
 
+    // Form matches from the queue at a steady pace
+    Arc::new(
+        Matchmaker::new(
+            connection_manager.clone(),
+            game_manager.clone(),
+            matchmaking_queue.clone(),
+        )
+        .with_max_matches_per_tick(config.max_matches_per_tick())
+        .with_max_solo_wait(config.max_solo_wait()),
+    )
+    .spawn(config.matchmaking_tick());
+
+    // Resolve rounds that have waited out their time limit
+    if config.round_time_limit().is_some() {
+        let timer_connection_manager = connection_manager.clone();
+        let timer_game_manager = game_manager.clone();
+        let timer_user_repository = user_repository.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(1));
+            loop {
+                interval.tick().await;
+                websocket::handlers::run_round_timers(
+                    &timer_connection_manager,
+                    &timer_game_manager,
+                    &timer_user_repository,
+                )
+                .await;
+            }
+        });
+    }
+
     // Start cleanup task
     let cleanup_connection_manager = connection_manager.clone();
     let cleanup_game_manager = game_manager.clone();
+    let cleanup_matchmaking_queue = matchmaking_queue.clone();
+    let cleanup_user_repository = user_repository.clone();
+    let abandon_grace = config.abandon_grace();
+    let auth_deadline = config.auth_deadline();
     tokio::spawn(async move {
         let mut interval = tokio::time::interval(std::time::Duration::from_secs(30));
         loop {
@@ -86,6 +242,26 @@ async fn main() {
             cleanup_connection_manager
                 .cleanup_inactive_connections(connection_timeout)
                 .await;
+            if let Some(deadline) = auth_deadline {
+                cleanup_connection_manager
+                    .close_unauthenticated_connections(deadline)
+                    .await;
+            }
+            // Penalize players who never came back to a live game before
+            // their game may be cleaned up
+            for user_id in cleanup_game_manager
+                .take_abandoned_players(abandon_grace)
+                .await
+            {
+                cleanup_matchmaking_queue.record_abandonment(&user_id).await;
+            }
+            // Games nobody guesses in would otherwise outlast the time limit
+            websocket::handlers::end_overlong_games(
+                &cleanup_connection_manager,
+                &cleanup_game_manager,
+                &cleanup_user_repository,
+            )
+            .await;
             cleanup_game_manager
                 .cleanup_abandoned_games(game_timeout)
                 .await;
@@ -99,7 +275,9 @@ async fn main() {
         config.port,
     );
 
-    let (addr, server) = warp::serve(routes).bind_with_graceful_shutdown(addr, async {
+    let shutdown_connection_manager = connection_manager.clone();
+    let shutdown_matchmaking_queue = matchmaking_queue.clone();
+    let (addr, server) = warp::serve(routes).bind_with_graceful_shutdown(addr, async move {
         // Wait for SIGINT (Ctrl+C) or SIGTERM
         #[cfg(unix)]
         {
@@ -121,6 +299,18 @@ async fn main() {
             signal::ctrl_c().await.expect("Failed to listen for ctrl+c");
             info!("Received Ctrl+C, shutting down gracefully...");
         }
+
+        // The queue does not survive a restart; queued clients rejoin it on reconnect
+        for connection_id in shutdown_matchmaking_queue.get_queue_players().await {
+            let _ = shutdown_connection_manager
+                .send_to_connection(connection_id, ServerMessage::RequeueOnReconnect)
+                .await;
+        }
+
+        // Let clients know this is a restart they can reconnect after
+        shutdown_connection_manager
+            .close_all(CloseReason::ServerShutdown)
+            .await;
     });
 
     info!(
@@ -0,0 +1,696 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tracing::{error, info, warn};
+
+use crate::game_manager::{GameManager, SERVER_BUSY_RETRY_SECONDS};
+use crate::matchmaking::MatchmakingQueue;
+use crate::websocket::connection::{ConnectionId, ConnectionManager};
+use game_types::ServerMessage;
+
+/// What players whose match couldn't be started are told
+const MATCH_FAILED_REASON: &str = "The game could not be started";
+
+/// Most ticks skipped after repeated failures to start a game
+const MAX_FAILURE_BACKOFF_TICKS: u32 = 32;
+
+/// Forms matches from the queue on a fixed tick, so a large backlog turns
+/// into games at a steady pace instead of all at once
+pub struct Matchmaker {
+    connection_manager: Arc<ConnectionManager>,
+    game_manager: Arc<GameManager>,
+    matchmaking_queue: Arc<MatchmakingQueue>,
+    max_matches_per_tick: Option<usize>,
+    busy_notified: AtomicBool, // Queued players were told the server is full
+    max_solo_wait: Option<Duration>,
+    stranded_notified: Mutex<HashSet<ConnectionId>>, // Already told no match is coming
+    failure_notified: Mutex<HashSet<ConnectionId>>,  // Told their match failed, until one starts
+    failure_streak: AtomicU32, // Consecutive ticks whose match failed to start
+    backoff_ticks: AtomicU32,  // Ticks left to skip forming matches
+}
+
+impl Matchmaker {
+    pub fn new(
+        connection_manager: Arc<ConnectionManager>,
+        game_manager: Arc<GameManager>,
+        matchmaking_queue: Arc<MatchmakingQueue>,
+    ) -> Self {
+        Self {
+            connection_manager,
+            game_manager,
+            matchmaking_queue,
+            max_matches_per_tick: None,
+            busy_notified: AtomicBool::new(false),
+            max_solo_wait: None,
+            stranded_notified: Mutex::new(HashSet::new()),
+            failure_notified: Mutex::new(HashSet::new()),
+            failure_streak: AtomicU32::new(0),
+            backoff_ticks: AtomicU32::new(0),
+        }
+    }
+
+    /// Form at most `max` matches per tick. `None` drains every ready match
+    /// in one tick.
+    pub fn with_max_matches_per_tick(mut self, max: Option<usize>) -> Self {
+        self.max_matches_per_tick = max;
+        self
+    }
+
+    /// Tell players who have waited `max` without enough others queueing
+    /// that no match is available. `None` lets them wait indefinitely.
+    pub fn with_max_solo_wait(mut self, max: Option<Duration>) -> Self {
+        self.max_solo_wait = max;
+        self
+    }
+
+    /// Run `tick` every `period` in the background
+    pub fn spawn(self: Arc<Self>, period: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(period);
+            loop {
+                interval.tick().await;
+                self.tick().await;
+            }
+        })
+    }
+
+    /// Form up to the per-tick cap of matches from the queue. Returns how
+    /// many matches were formed. A match that fails to start ends the tick,
+    /// and formation then sits out a doubling number of ticks so a lasting
+    /// failure isn't retried every interval.
+    pub async fn tick(&self) -> usize {
+        let mut formed = 0;
+        let backing_off = self
+            .backoff_ticks
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |ticks| {
+                ticks.checked_sub(1)
+            })
+            .is_ok();
+        while !backing_off && self.max_matches_per_tick.is_none_or(|max| formed < max) {
+            match self.form_match().await {
+                Ok(true) => {
+                    self.failure_streak.store(0, Ordering::Relaxed);
+                    formed += 1;
+                }
+                Ok(false) => break,
+                Err(e) => {
+                    let streak = self.failure_streak.fetch_add(1, Ordering::Relaxed);
+                    let backoff = 2u32.saturating_pow(streak).min(MAX_FAILURE_BACKOFF_TICKS);
+                    self.backoff_ticks.store(backoff, Ordering::Relaxed);
+                    error!(
+                        "Matchmaking tick stopped: {}; retrying in {} ticks",
+                        e, backoff
+                    );
+                    break;
+                }
+            }
+        }
+        if formed > 0 {
+            self.broadcast_queue_status().await;
+        }
+        self.notify_stranded_players().await;
+        formed
+    }
+
+    /// Suggest a practice game to players stuck waiting past the solo wait
+    /// limit. There are no bots to fill a match, so each player is told once
+    /// and stays queued in case someone else joins.
+    async fn notify_stranded_players(&self) {
+        let Some(max_wait) = self.max_solo_wait else {
+            return;
+        };
+
+        let stranded = self.matchmaking_queue.stranded_players(max_wait).await;
+        let mut notified = self.stranded_notified.lock().await;
+        notified.retain(|connection_id| stranded.contains(connection_id));
+        for connection_id in stranded {
+            if notified.insert(connection_id) {
+                info!("No match available for {} after solo wait", connection_id);
+                let _ = self
+                    .connection_manager
+                    .send_to_connection(
+                        connection_id,
+                        ServerMessage::NoMatchAvailable {
+                            suggest_practice: true,
+                        },
+                    )
+                    .await;
+            }
+        }
+    }
+
+    /// Tell queue status subscribers how many players are left in the queue
+    async fn broadcast_queue_status(&self) {
+        let players_in_queue = self.matchmaking_queue.get_queue_length().await as u32;
+        for subscriber in self.matchmaking_queue.get_status_subscribers().await {
+            let _ = self
+                .connection_manager
+                .send_to_connection(subscriber, ServerMessage::QueueStatus { players_in_queue })
+                .await;
+        }
+    }
+
+    /// Form the next match from the queue and start its game. Returns whether
+    /// players were taken from the queue.
+    async fn form_match(&self) -> Result<bool, String> {
+        // Hold matched players in the queue until a game slot frees up
+        if self.game_manager.at_capacity().await {
+            if self.matchmaking_queue.should_start_match().await
+                && !self.busy_notified.swap(true, Ordering::Relaxed)
+            {
+                warn!("Server at game capacity, holding matched players in queue");
+                for player_id in self.matchmaking_queue.get_queue_players().await {
+                    let _ = self
+                        .connection_manager
+                        .send_to_connection(
+                            player_id,
+                            ServerMessage::ServerBusy {
+                                retry_after: SERVER_BUSY_RETRY_SECONDS,
+                            },
+                        )
+                        .await;
+                }
+            }
+            return Ok(false);
+        }
+        self.busy_notified.store(false, Ordering::Relaxed);
+
+        if let Ok(Some(match_info)) = self.matchmaking_queue.try_create_match().await {
+            info!("Creating match with {} players", match_info.players.len());
+
+            // Create game
+            match self
+                .game_manager
+                .create_game_with_threshold(match_info.players.clone(), match_info.point_threshold)
+                .await
+            {
+                Ok(game_id) => {
+                    // Games start again, so a later failure is news
+                    self.failure_notified.lock().await.clear();
+                    if let Err(notified) = announce_match(
+                        &self.connection_manager,
+                        &self.game_manager,
+                        &game_id,
+                        &match_info.players,
+                    )
+                    .await
+                    {
+                        self.abandon_match_start(&game_id, &notified).await;
+                        return Ok(true);
+                    }
+
+                    info!(
+                        "Successfully created match {} with {} players and sent initial state",
+                        game_id,
+                        match_info.players.len()
+                    );
+                    Ok(true)
+                }
+                Err(e) => {
+                    error!("Failed to create game: {}", e);
+                    // Put players back where they were, and tell them once
+                    // why the countdown ended without a game. The cause
+                    // stays in the server log.
+                    let returned = self.matchmaking_queue.return_match(&match_info).await;
+                    let mut notified = self.failure_notified.lock().await;
+                    for (player_id, position) in returned {
+                        if !notified.insert(player_id) {
+                            continue;
+                        }
+                        let _ = self
+                            .connection_manager
+                            .send_to_connection(
+                                player_id,
+                                ServerMessage::MatchFailed {
+                                    reason: MATCH_FAILED_REASON.to_string(),
+                                    requeued: position.is_ok(),
+                                },
+                            )
+                            .await;
+                        if let Ok(position) = position {
+                            let _ = self
+                                .connection_manager
+                                .send_to_connection(
+                                    player_id,
+                                    ServerMessage::QueueJoined { position },
+                                )
+                                .await;
+                        }
+                    }
+                    Err(format!("Failed to create game: {}", e))
+                }
+            }
+        } else {
+            Ok(false) // No match to create
+        }
+    }
+
+    /// Call off a match some players could not be told about: drop the game
+    /// and put the players who did hear about it back in the queue
+    async fn abandon_match_start(&self, game_id: &str, notified: &[ConnectionId]) {
+        warn!(
+            "Calling off match {}: only {} players were notified",
+            game_id,
+            notified.len()
+        );
+        self.game_manager.discard_game(game_id).await;
+
+        for &player_id in notified {
+            let _ = self
+                .connection_manager
+                .send_to_connection(player_id, ServerMessage::GameLeft)
+                .await;
+            if let Ok(position) = self.matchmaking_queue.add_player(player_id).await {
+                let _ = self
+                    .connection_manager
+                    .send_to_connection(player_id, ServerMessage::QueueJoined { position })
+                    .await;
+            }
+        }
+    }
+}
+
+/// Tell every player about a newly created game, then seat them in it with
+/// their initial state and rejoin token. Nobody is seated unless everyone
+/// heard about the match; the players who did are returned so the caller
+/// can call the start off.
+pub async fn announce_match(
+    connection_manager: &Arc<ConnectionManager>,
+    game_manager: &Arc<GameManager>,
+    game_id: &str,
+    players: &[ConnectionId],
+) -> Result<(), Vec<ConnectionId>> {
+    let game_id = game_id.to_string();
+    // Get player info for the match
+    let mut players_info = Vec::new();
+    for &player_id in players {
+        if let Some(connection) = connection_manager.get_connection(player_id).await
+            && let Some(ref user) = connection.user
+        {
+            players_info.push(game_types::Player {
+                user_id: user.id.clone(),
+                display_name: user.display_name.clone(),
+                points: 0,
+                guess_history: Vec::new(),
+                is_connected: true,
+                avatar: user.avatar.clone(),
+            });
+        }
+    }
+
+    // Every player has to hear about the match before anyone is
+    // put in the game; otherwise the start is called off
+    let categories = game_manager.game_categories(&game_id).await;
+    let Some(initial_game_state) = game_manager.get_game_state(&game_id).await else {
+        return Err(Vec::new());
+    };
+    let mut notified = Vec::new();
+    for &player_id in players {
+        match connection_manager
+            .send_to_connection(
+                player_id,
+                ServerMessage::MatchFound {
+                    game_id: game_id.clone(),
+                    players: players_info.clone(),
+                    categories: categories.clone(),
+                    // The threshold the game actually plays to
+                    point_threshold: initial_game_state.point_threshold,
+                },
+            )
+            .await
+        {
+            Ok(()) => notified.push(player_id),
+            Err(e) => {
+                warn!("Failed to notify player {} of match: {}", player_id, e);
+            }
+        }
+    }
+    if notified.len() < players.len() {
+        return Err(notified);
+    }
+
+    for &player_id in players {
+        connection_manager
+            .set_connection_game(player_id, Some(game_id.clone()))
+            .await;
+
+        // Send personalized initial game state
+        if let Some(connection) = connection_manager.get_connection(player_id).await
+            && let Some(ref user) = connection.user
+        {
+            let personalized_state = initial_game_state.personalized_for_player(user.id.clone());
+            if let Err(e) = connection_manager
+                .send_to_connection(
+                    player_id,
+                    ServerMessage::GameStateUpdate {
+                        state: personalized_state,
+                    },
+                )
+                .await
+            {
+                warn!("Failed to send initial game state to {}: {}", player_id, e);
+            }
+        }
+
+        // Hand out the token that lets the player reclaim
+        // their seat after a disconnect
+        if let Some(connection) = connection_manager.get_connection(player_id).await
+            && let Some(user_id) = connection.user_id
+            && let Some(token) = game_manager.issue_rejoin_token(&game_id, &user_id).await
+            && let Err(e) = connection_manager
+                .send_to_connection(
+                    player_id,
+                    ServerMessage::RejoinToken {
+                        game_id: game_id.clone(),
+                        token,
+                    },
+                )
+                .await
+        {
+            warn!("Failed to send rejoin token to {}: {}", player_id, e);
+        }
+    }
+
+    // Start the game anyway if some players never confirm they're ready
+    if let Some(timeout) = game_manager.ready_timeout() {
+        let game_manager = game_manager.clone();
+        let connection_manager = connection_manager.clone();
+        let game_id = game_id.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(timeout).await;
+            if let Some(state) = game_manager.start_after_ready_timeout(&game_id).await {
+                connection_manager
+                    .send_personalized_game_state(&game_id, &state)
+                    .await;
+            }
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn queue_players(
+        connection_manager: &ConnectionManager,
+        matchmaking_queue: &MatchmakingQueue,
+        count: usize,
+    ) -> Vec<tokio::sync::mpsc::UnboundedReceiver<ServerMessage>> {
+        let mut receivers = Vec::new();
+        for i in 0..count {
+            let connection_id = ConnectionId::new();
+            receivers.push(connection_manager.create_connection(connection_id).await);
+            let user_id = format!("player{}", i);
+            connection_manager
+                .authenticate_connection(connection_id, user_id.clone())
+                .await
+                .unwrap();
+            connection_manager
+                .set_connection_user(
+                    connection_id,
+                    Some(game_types::User {
+                        id: user_id.clone(),
+                        email: format!("{}@example.com", user_id),
+                        display_name: user_id,
+                        total_points: 0,
+                        total_wins: 0,
+                        total_games: 0,
+                        created_at: chrono::Utc::now().to_rfc3339(),
+                        avatar: None,
+                        roles: Vec::new(),
+                    }),
+                )
+                .await;
+            matchmaking_queue.add_player(connection_id).await.unwrap();
+        }
+        receivers
+    }
+
+    #[tokio::test]
+    async fn test_large_queue_drains_over_several_ticks() {
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let game_manager = Arc::new(GameManager::new_with_validator(
+            connection_manager.clone(),
+            game_core::WordValidator::new_with_test_words(),
+        ));
+        let matchmaking_queue = Arc::new(MatchmakingQueue::new_with_config(
+            2,
+            2,
+            Duration::from_secs(300),
+        ));
+        let _receivers = queue_players(&connection_manager, &matchmaking_queue, 7).await;
+        let matchmaker = Matchmaker::new(
+            connection_manager.clone(),
+            game_manager.clone(),
+            matchmaking_queue.clone(),
+        )
+        .with_max_matches_per_tick(Some(1));
+
+        // Nothing forms until the countdown fires
+        assert_eq!(matchmaker.tick().await, 0);
+        for player_id in matchmaking_queue.get_queue_players().await {
+            let _ = matchmaking_queue.vote_to_start(player_id).await;
+        }
+
+        // One match per tick until too few players remain for another
+        for expected_games in 1..=3 {
+            assert_eq!(matchmaker.tick().await, 1);
+            assert_eq!(game_manager.get_active_games_count().await, expected_games);
+        }
+        assert_eq!(matchmaker.tick().await, 0);
+        assert_eq!(matchmaking_queue.get_queue_length().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_expired_countdown_forms_match_without_client_messages() {
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let game_manager = Arc::new(GameManager::new_with_validator(
+            connection_manager.clone(),
+            game_core::WordValidator::new_with_test_words(),
+        ));
+        let clock = Arc::new(game_core::MockClock::new());
+        let matchmaking_queue = Arc::new(MatchmakingQueue::new().with_clock(clock.clone()));
+        let mut receivers = queue_players(&connection_manager, &matchmaking_queue, 2).await;
+        let matchmaker = Matchmaker::new(
+            connection_manager.clone(),
+            game_manager.clone(),
+            matchmaking_queue.clone(),
+        );
+
+        // Nobody votes; the queue just waits out the countdown
+        assert_eq!(matchmaker.tick().await, 0);
+        clock.advance(Duration::from_secs(59));
+        assert_eq!(matchmaker.tick().await, 0);
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(matchmaker.tick().await, 1);
+
+        assert_eq!(game_manager.get_active_games_count().await, 1);
+        for receiver in &mut receivers {
+            assert!(matches!(
+                receiver.try_recv(),
+                Ok(ServerMessage::MatchFound { .. })
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_uncapped_tick_drains_the_queue_at_once() {
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let game_manager = Arc::new(GameManager::new_with_validator(
+            connection_manager.clone(),
+            game_core::WordValidator::new_with_test_words(),
+        ));
+        let matchmaking_queue = Arc::new(MatchmakingQueue::new_with_config(
+            2,
+            2,
+            Duration::from_secs(300),
+        ));
+        let _receivers = queue_players(&connection_manager, &matchmaking_queue, 6).await;
+        for player_id in matchmaking_queue.get_queue_players().await {
+            let _ = matchmaking_queue.vote_to_start(player_id).await;
+        }
+
+        let matchmaker = Matchmaker::new(
+            connection_manager.clone(),
+            game_manager.clone(),
+            matchmaking_queue.clone(),
+        );
+        assert_eq!(matchmaker.tick().await, 3);
+        assert_eq!(matchmaking_queue.get_queue_length().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_match_found_carries_the_queue_point_threshold() {
+        let connection_manager = Arc::new(ConnectionManager::new());
+        // The queue's threshold wins over scaling it by word length
+        let game_manager = Arc::new(
+            GameManager::new_with_validator(
+                connection_manager.clone(),
+                game_core::WordValidator::new_with_test_words(),
+            )
+            .with_rules(game_core::GameRules {
+                points_per_letter: Some(10),
+                ..Default::default()
+            }),
+        );
+        let matchmaking_queue = Arc::new(
+            MatchmakingQueue::new_with_config(2, 2, Duration::from_secs(300))
+                .with_point_threshold(Some(50)),
+        );
+        let mut receivers = queue_players(&connection_manager, &matchmaking_queue, 2).await;
+        for player_id in matchmaking_queue.get_queue_players().await {
+            let _ = matchmaking_queue.vote_to_start(player_id).await;
+        }
+
+        let matchmaker = Matchmaker::new(
+            connection_manager.clone(),
+            game_manager.clone(),
+            matchmaking_queue.clone(),
+        );
+        assert_eq!(matchmaker.tick().await, 1);
+        for receiver in &mut receivers {
+            assert!(matches!(
+                receiver.try_recv(),
+                Ok(ServerMessage::MatchFound {
+                    point_threshold: 50,
+                    ..
+                })
+            ));
+            assert!(matches!(
+                receiver.try_recv(),
+                Ok(ServerMessage::GameStateUpdate { state }) if state.point_threshold == 50
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failed_game_creation_requeues_and_notifies_players() {
+        let connection_manager = Arc::new(ConnectionManager::new());
+        // No dictionary yet, so no target word can be picked
+        let game_manager = Arc::new(GameManager::new_loading(connection_manager.clone()));
+        let matchmaking_queue = Arc::new(MatchmakingQueue::new_with_config(
+            2,
+            2,
+            Duration::from_secs(300),
+        ));
+        let mut receivers = queue_players(&connection_manager, &matchmaking_queue, 3).await;
+        let players = matchmaking_queue.get_queue_players().await;
+        for &player_id in &players {
+            let _ = matchmaking_queue.vote_to_start(player_id).await;
+        }
+
+        let matchmaker = Matchmaker::new(
+            connection_manager.clone(),
+            game_manager.clone(),
+            matchmaking_queue.clone(),
+        );
+        assert_eq!(matchmaker.tick().await, 0);
+        assert_eq!(game_manager.get_active_games_count().await, 0);
+
+        // The failed pair goes back ahead of the player left waiting
+        for (position, &player_id) in players.iter().enumerate() {
+            assert_eq!(
+                matchmaking_queue.get_queue_position(player_id).await,
+                Some(position as u32 + 1)
+            );
+        }
+
+        for receiver in &mut receivers[..2] {
+            let messages: Vec<_> = std::iter::from_fn(|| receiver.try_recv().ok()).collect();
+            match messages.as_slice() {
+                [
+                    ServerMessage::MatchFailed {
+                        reason,
+                        requeued: true,
+                    },
+                    ServerMessage::QueueJoined { .. },
+                ] => assert_eq!(reason, MATCH_FAILED_REASON),
+                other => panic!("unexpected messages: {:?}", other),
+            }
+        }
+        assert!(receivers[2].try_recv().is_err());
+
+        // The same pair failing again isn't announced a second time
+        for &player_id in &players {
+            let _ = matchmaking_queue.vote_to_start(player_id).await;
+        }
+        matchmaker.tick().await; // Backing off
+        matchmaker.tick().await;
+        assert!(!matchmaking_queue.should_start_match().await);
+        for receiver in &mut receivers {
+            assert!(receiver.try_recv().is_err());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failing_game_creation_backs_off_between_attempts() {
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let game_manager = Arc::new(GameManager::new_loading(connection_manager.clone()));
+        let matchmaking_queue = Arc::new(MatchmakingQueue::new_with_config(
+            2,
+            2,
+            Duration::from_secs(300),
+        ));
+        let _receivers = queue_players(&connection_manager, &matchmaking_queue, 2).await;
+        let players = matchmaking_queue.get_queue_players().await;
+        let matchmaker = Matchmaker::new(
+            connection_manager.clone(),
+            game_manager.clone(),
+            matchmaking_queue.clone(),
+        )
+        .with_max_matches_per_tick(Some(4));
+
+        // An attempt uses up the votes, so a match still ready to start
+        // after a tick means the tick didn't try to form it
+        let mut attempts = Vec::new();
+        for _ in 0..7 {
+            for &player_id in &players {
+                let _ = matchmaking_queue.vote_to_start(player_id).await;
+            }
+            assert_eq!(matchmaker.tick().await, 0);
+            attempts.push(!matchmaking_queue.should_start_match().await);
+        }
+        assert_eq!(attempts, [true, false, true, false, false, true, false]);
+    }
+
+    #[tokio::test]
+    async fn test_lone_player_past_solo_wait_is_told_no_match_is_available() {
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let game_manager = Arc::new(GameManager::new_with_validator(
+            connection_manager.clone(),
+            game_core::WordValidator::new_with_test_words(),
+        ));
+        let clock = Arc::new(game_core::MockClock::new());
+        let matchmaking_queue = Arc::new(MatchmakingQueue::new().with_clock(clock.clone()));
+        let mut receivers = queue_players(&connection_manager, &matchmaking_queue, 1).await;
+        let matchmaker = Matchmaker::new(
+            connection_manager.clone(),
+            game_manager.clone(),
+            matchmaking_queue.clone(),
+        )
+        .with_max_solo_wait(Some(Duration::from_secs(120)));
+
+        clock.advance(Duration::from_secs(119));
+        assert_eq!(matchmaker.tick().await, 0);
+        assert!(receivers[0].try_recv().is_err());
+
+        clock.advance(Duration::from_secs(1));
+        matchmaker.tick().await;
+        assert!(matches!(
+            receivers[0].try_recv(),
+            Ok(ServerMessage::NoMatchAvailable {
+                suggest_practice: true
+            })
+        ));
+
+        // Told once, and still queued in case someone else turns up
+        matchmaker.tick().await;
+        assert!(receivers[0].try_recv().is_err());
+        assert_eq!(matchmaking_queue.get_queue_length().await, 1);
+    }
+}
@@ -1,20 +1,140 @@
+use game_core::{SharedClock, SystemClock};
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 use tracing::{info, warn};
 
+use crate::abuse::AbuseTracker;
+use crate::lobby::{HostLeavePolicy, LobbyManager, LobbySettings};
 use crate::websocket::connection::ConnectionId;
+use game_persistence::repositories::abuse_repository::AbuseKind;
+use game_types::PlayerId;
 
 #[derive(Debug, Clone)]
 pub struct QueuedPlayer {
     pub connection_id: ConnectionId,
+    pub user_id: Option<PlayerId>, // Recognizes the same player on a new connection
     pub queued_at: Instant,
+    pub rating: Option<i32>, // Unrated players can be matched with anyone
+}
+
+/// How far apart in skill rating matched players may be. The window starts
+/// at `base` and widens by `growth_per_sec` for every second a player has
+/// waited, so nobody is held back forever for want of a close opponent.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RatingWindow {
+    pub base: u32,
+    pub growth_per_sec: f64,
+}
+
+impl RatingWindow {
+    /// Widest rating gap accepted for a player who has waited `wait`
+    pub fn after(&self, wait: Duration) -> u32 {
+        let width = f64::from(self.base) + self.growth_per_sec * wait.as_secs_f64();
+        width.min(f64::from(u32::MAX)) as u32
+    }
+
+    /// Whether `a` and `b` are close enough in rating to play each other,
+    /// using whichever of the two has waited longer
+    fn allows(&self, a: &QueuedPlayer, b: &QueuedPlayer, now: Instant) -> bool {
+        let (Some(a_rating), Some(b_rating)) = (a.rating, b.rating) else {
+            return true;
+        };
+        let longest_wait = now.duration_since(a.queued_at.min(b.queued_at));
+        a_rating.abs_diff(b_rating) <= self.after(longest_wait)
+    }
+}
+
+/// Queue place kept for a player whose connection dropped while queued
+#[derive(Debug, Clone)]
+struct HeldSlot {
+    queued_at: Instant,
+    expires_at: Instant,
+}
+
+/// Why a connection could not join the queue
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum QueueError {
+    #[error("Player already in queue")]
+    AlreadyQueued,
+    #[error("Queue is full, try again in {retry_after} seconds")]
+    QueueFull { retry_after: u32 },
 }
 
 #[derive(Debug, Clone)]
 pub struct MatchInfo {
     pub players: Vec<ConnectionId>,
     pub created_at: Instant,
+    pub point_threshold: Option<i32>, // Points needed to win the match's game, if set
+    pub queued: Vec<QueuedPlayer>, // The players' queue entries, to put them back if the game fails
+}
+
+/// Recent matchmaking wait times, from joining the queue to being matched.
+/// Only the latest `capacity` waits are kept so the percentiles track
+/// current conditions.
+#[derive(Debug, Clone)]
+pub struct WaitTimeStats {
+    samples: VecDeque<Duration>,
+    capacity: usize,
+}
+
+impl Default for WaitTimeStats {
+    fn default() -> Self {
+        Self::new(WAIT_TIME_SAMPLES)
+    }
+}
+
+/// Number of recent player wait times kept for percentiles
+const WAIT_TIME_SAMPLES: usize = 1000;
+
+impl WaitTimeStats {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    pub fn record(&mut self, wait: Duration) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.samples.len() >= self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(wait);
+    }
+
+    /// Nearest-rank percentile of the recorded waits, `None` when empty
+    pub fn percentile(&self, percentile: f64) -> Option<Duration> {
+        if self.samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted: Vec<Duration> = self.samples.iter().copied().collect();
+        sorted.sort();
+        let rank = ((percentile / 100.0) * sorted.len() as f64).ceil() as usize;
+        Some(sorted[rank.clamp(1, sorted.len()) - 1])
+    }
+
+    pub fn summary(&self) -> WaitTimeSummary {
+        let seconds = |percentile| self.percentile(percentile).map(|wait| wait.as_secs_f64());
+        WaitTimeSummary {
+            samples: self.samples.len(),
+            p50_seconds: seconds(50.0),
+            p90_seconds: seconds(90.0),
+            p99_seconds: seconds(99.0),
+        }
+    }
+}
+
+/// Wait-time percentiles over the recent sample, in seconds
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct WaitTimeSummary {
+    pub samples: usize,
+    pub p50_seconds: Option<f64>,
+    pub p90_seconds: Option<f64>,
+    pub p99_seconds: Option<f64>,
 }
 
 #[derive(Debug, Clone)]
@@ -22,6 +142,7 @@ pub struct CountdownInfo {
     pub seconds_remaining: u32,
     pub players_ready: u32,
     pub total_players: u32,
+    pub rating_window: Option<u32>, // Widest rating gap accepted right now, if matching by skill
 }
 
 pub struct MatchmakingQueue {
@@ -34,6 +155,25 @@ pub struct MatchmakingQueue {
     countdown_started_at: RwLock<Option<Instant>>,
     countdown_duration: Duration,
     votes_to_start: RwLock<HashSet<ConnectionId>>,
+    draining: RwLock<bool>, // A countdown fired; keep forming matches while enough players remain
+    clock: SharedClock,
+    abandon_cooldown: Option<Duration>,
+    cooldowns: RwLock<HashMap<PlayerId, Instant>>, // When each penalized user may queue again
+    abuse: Option<AbuseTracker>, // Persisted penalties that outlive connections and restarts
+    disconnect_hold: Option<Duration>,
+    held_slots: RwLock<HashMap<PlayerId, HeldSlot>>,
+    wait_times: RwLock<WaitTimeStats>,
+    status_subscribers: RwLock<HashSet<ConnectionId>>, // Watching the queue without being in it
+    max_queue_size: Option<usize>,
+    rating_window: Option<RatingWindow>,
+    point_threshold: Option<i32>,
+    lobbies: LobbyManager, // Private games that bypass the queue
+}
+
+impl Default for MatchmakingQueue {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl MatchmakingQueue {
@@ -55,33 +195,242 @@ impl MatchmakingQueue {
             countdown_started_at: RwLock::new(None),
             countdown_duration: Duration::from_secs(60), // 60 second countdown
             votes_to_start: RwLock::new(HashSet::new()),
+            draining: RwLock::new(false),
+            clock: SystemClock::shared(),
+            abandon_cooldown: None,
+            cooldowns: RwLock::new(HashMap::new()),
+            abuse: None,
+            disconnect_hold: None,
+            held_slots: RwLock::new(HashMap::new()),
+            wait_times: RwLock::new(WaitTimeStats::default()),
+            status_subscribers: RwLock::new(HashSet::new()),
+            max_queue_size: None,
+            rating_window: None,
+            point_threshold: None,
+            lobbies: LobbyManager::new(),
         }
     }
 
-    pub async fn add_player(&self, connection_id: ConnectionId) -> Result<u32, String> {
+    /// Keep the queue place of a player whose connection drops for `hold`, so
+    /// a reconnecting session rejoins where it left off. `None` drops them.
+    pub fn with_disconnect_hold(mut self, hold: Option<Duration>) -> Self {
+        self.disconnect_hold = hold;
+        self
+    }
+
+    /// Refuse new players once `max` are queued, until matches make room.
+    /// Players returning to a held place are always let back in. `None`
+    /// leaves the queue unbounded.
+    pub fn with_max_queue_size(mut self, max: Option<usize>) -> Self {
+        self.max_queue_size = max;
+        self
+    }
+
+    /// Only match players whose ratings fall within `window` of each other.
+    /// Players stay queued until close enough opponents turn up or their
+    /// window has widened to reach someone. `None` matches in queue order.
+    pub fn with_rating_window(mut self, window: Option<RatingWindow>) -> Self {
+        self.rating_window = window;
+        self
+    }
+
+    /// Create matches whose games are won at `point_threshold` points. The
+    /// threshold is checked when each game is created. `None` leaves it to
+    /// the game rules.
+    pub fn with_point_threshold(mut self, point_threshold: Option<i32>) -> Self {
+        self.point_threshold = point_threshold;
+        self
+    }
+
+    /// Keep players who abandon a live game out of the queue for `cooldown`.
+    /// `None` disables the penalty.
+    pub fn with_abandon_cooldown(mut self, cooldown: Option<Duration>) -> Self {
+        self.abandon_cooldown = cooldown;
+        self
+    }
+
+    /// Choose whether a private lobby whose host leaves is handed to another
+    /// member or closed
+    pub fn with_host_leave_policy(mut self, policy: HostLeavePolicy) -> Self {
+        self.lobbies = self.lobbies.with_host_leave_policy(policy);
+        self
+    }
+
+    /// Keep users out of the queue, or refuse them altogether, once enough
+    /// abuse events are recorded against them
+    pub fn with_abuse_tracker(mut self, tracker: AbuseTracker) -> Self {
+        self.abuse = Some(tracker);
+        self
+    }
+
+    /// Record behaviour that counts towards a user's persisted penalties
+    pub async fn record_abuse(&self, user_id: &PlayerId, kind: AbuseKind) {
+        if let Some(abuse) = &self.abuse {
+            abuse.record(user_id, kind).await;
+        }
+    }
+
+    /// Time left before a user banned for repeated abuse may authenticate
+    pub async fn ban_remaining(&self, user_id: &PlayerId) -> Option<Duration> {
+        self.abuse.as_ref()?.ban_remaining(user_id).await
+    }
+
+    /// Start the re-queue cooldown for a player who abandoned a live game
+    pub async fn record_abandonment(&self, user_id: &PlayerId) {
+        self.record_abuse(user_id, AbuseKind::Abandoned).await;
+
+        let Some(cooldown) = self.abandon_cooldown else {
+            return;
+        };
+
+        let mut cooldowns = self.cooldowns.write().await;
+        cooldowns.insert(user_id.clone(), self.clock.now() + cooldown);
+        info!(
+            "User {} abandoned a game, queue cooldown {:?}",
+            user_id, cooldown
+        );
+    }
+
+    /// Time left before a penalized player may join the queue again
+    pub async fn cooldown_remaining(&self, user_id: &PlayerId) -> Option<Duration> {
+        let abandoned = self.abandon_cooldown_remaining(user_id).await;
+        let abused = match &self.abuse {
+            Some(abuse) => abuse.cooldown_remaining(user_id).await,
+            None => None,
+        };
+        abandoned.max(abused)
+    }
+
+    async fn abandon_cooldown_remaining(&self, user_id: &PlayerId) -> Option<Duration> {
+        let now = self.clock.now();
+        let mut cooldowns = self.cooldowns.write().await;
+        match cooldowns.get(user_id) {
+            Some(until) if *until > now => Some(*until - now),
+            Some(_) => {
+                cooldowns.remove(user_id);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Measure queue waits and countdowns with `clock` instead of the system clock
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub async fn add_player(&self, connection_id: ConnectionId) -> Result<u32, QueueError> {
+        self.insert_player(connection_id, None, None, self.clock.now(), false)
+            .await
+    }
+
+    /// Add a signed-in player, putting them back in their old place if their
+    /// previous connection dropped within the disconnect hold. Joining again
+    /// while still queued, from this or a newer connection, keeps the
+    /// existing place instead of adding a second entry. `rating` is only
+    /// used when matching by skill.
+    pub async fn add_user(
+        &self,
+        connection_id: ConnectionId,
+        user_id: &PlayerId,
+        rating: Option<i32>,
+    ) -> Result<u32, QueueError> {
+        let now = self.clock.now();
+        let held = self.held_slots.write().await.remove(user_id);
+        let (queued_at, restored) = match held {
+            Some(slot) if slot.expires_at > now => {
+                info!("Restoring held queue place for user {}", user_id);
+                (slot.queued_at, true)
+            }
+            _ => (now, false),
+        };
+
+        self.insert_player(connection_id, Some(user_id), rating, queued_at, restored)
+            .await
+    }
+
+    async fn insert_player(
+        &self,
+        connection_id: ConnectionId,
+        user_id: Option<&PlayerId>,
+        rating: Option<i32>,
+        queued_at: Instant,
+        restored: bool,
+    ) -> Result<u32, QueueError> {
         let mut queue = self.queue.write().await;
         let mut positions = self.player_positions.write().await;
 
+        // The user is still queued, e.g. from a connection whose drop has
+        // not been noticed yet: move that entry over to this connection
+        if let Some(user_id) = user_id
+            && let Some(index) = queue
+                .iter()
+                .position(|p| p.user_id.as_ref() == Some(user_id))
+        {
+            let previous = std::mem::replace(&mut queue[index].connection_id, connection_id);
+            if previous != connection_id {
+                positions.remove(&previous);
+                positions.insert(connection_id, index);
+                self.votes_to_start.write().await.remove(&previous);
+                info!(
+                    "User {} rejoined the queue from {}, replacing {}",
+                    user_id, connection_id, previous
+                );
+            }
+            return Ok(index as u32 + 1);
+        }
+
         // Check if player is already in queue
         if positions.contains_key(&connection_id) {
-            return Err("Player already in queue".to_string());
+            return Err(QueueError::AlreadyQueued);
         }
 
-        let player = QueuedPlayer {
-            connection_id,
-            queued_at: Instant::now(),
-        };
+        if !restored
+            && let Some(max) = self.max_queue_size
+            && queue.len() >= max
+        {
+            // Places open up when the next match forms
+            let countdown_started = self.countdown_started_at.read().await;
+            let wait = match *countdown_started {
+                Some(started_at) => self
+                    .countdown_duration
+                    .saturating_sub(self.clock.now().duration_since(started_at)),
+                None => self.countdown_duration,
+            };
+            warn!("Queue full ({} players), refusing {}", max, connection_id);
+            return Err(QueueError::QueueFull {
+                retry_after: (wait.as_secs_f64().ceil() as u32).max(1),
+            });
+        }
 
-        queue.push_back(player);
-        positions.insert(connection_id, queue.len() - 1);
+        // Keep the queue ordered by when players first queued
+        let index = queue
+            .iter()
+            .position(|p| p.queued_at > queued_at)
+            .unwrap_or(queue.len());
+        queue.insert(
+            index,
+            QueuedPlayer {
+                connection_id,
+                user_id: user_id.cloned(),
+                queued_at,
+                rating,
+            },
+        );
+
+        positions.clear();
+        for (i, player) in queue.iter().enumerate() {
+            positions.insert(player.connection_id, i);
+        }
 
-        let position = queue.len() as u32;
+        let position = index as u32 + 1;
 
         // Start countdown if we reach minimum players for the first time
         if queue.len() == self.min_players {
             let mut countdown_started = self.countdown_started_at.write().await;
             if countdown_started.is_none() {
-                *countdown_started = Some(Instant::now());
+                *countdown_started = Some(self.clock.now());
                 info!("Countdown started: {} players in queue", queue.len());
             }
         }
@@ -94,6 +443,45 @@ impl MatchmakingQueue {
         Ok(position)
     }
 
+    /// Take a player out of the queue because their connection dropped. Unlike
+    /// `remove_player`, which is an intentional leave, this holds their place
+    /// for a returning session when a disconnect hold is configured.
+    pub async fn hold_player(
+        &self,
+        connection_id: ConnectionId,
+        user_id: Option<&PlayerId>,
+    ) -> Result<(), String> {
+        let queued_at = self
+            .queue
+            .read()
+            .await
+            .iter()
+            .find(|p| p.connection_id == connection_id)
+            .map(|p| p.queued_at);
+
+        self.remove_player(connection_id).await?;
+
+        if let (Some(hold), Some(user_id), Some(queued_at)) =
+            (self.disconnect_hold, user_id, queued_at)
+        {
+            self.held_slots.write().await.insert(
+                user_id.clone(),
+                HeldSlot {
+                    queued_at,
+                    expires_at: self.clock.now() + hold,
+                },
+            );
+            info!("Holding queue place for user {} for {:?}", user_id, hold);
+        }
+
+        Ok(())
+    }
+
+    /// Forget a held queue place, e.g. when the player leaves on purpose
+    pub async fn release_hold(&self, user_id: &PlayerId) {
+        self.held_slots.write().await.remove(user_id);
+    }
+
     pub async fn remove_player(&self, connection_id: ConnectionId) -> Result<(), String> {
         let mut queue = self.queue.write().await;
         let mut positions = self.player_positions.write().await;
@@ -125,6 +513,7 @@ impl MatchmakingQueue {
                         );
                     }
                     votes.clear(); // Clear all votes when countdown stops
+                    *self.draining.write().await = false;
                 }
 
                 info!("Player {} removed from queue", connection_id);
@@ -151,8 +540,11 @@ impl MatchmakingQueue {
         let countdown_started = self.countdown_started_at.read().await;
         let votes = self.votes_to_start.read().await;
 
-        let should_create_match = if let Some(started_at) = *countdown_started {
-            let elapsed = Instant::now().duration_since(started_at);
+        // Once a countdown fires the whole queue is matched, one match per call
+        let should_create_match = if *self.draining.read().await {
+            true
+        } else if let Some(started_at) = *countdown_started {
+            let elapsed = self.clock.now().duration_since(started_at);
 
             // Only create match if countdown has expired OR enough votes
             if elapsed >= self.countdown_duration {
@@ -172,6 +564,12 @@ impl MatchmakingQueue {
             return Ok(None);
         }
 
+        // Nobody has close enough opponents yet; keep everyone queued
+        let now = self.clock.now();
+        let Some(selected) = self.select_match(&queue, now) else {
+            return Ok(None);
+        };
+
         // Release the read locks before getting write locks
         drop(countdown_started);
         drop(votes);
@@ -186,45 +584,114 @@ impl MatchmakingQueue {
             votes.clear();
         }
 
-        // Determine how many players to take (up to max_players)
-        let players_to_take = queue.len().min(self.max_players);
-
-        // Take players from the front of the queue
-        let mut match_players = Vec::with_capacity(players_to_take);
-        for _ in 0..players_to_take {
-            if let Some(player) = queue.pop_front() {
-                match_players.push(player.connection_id);
-                positions.remove(&player.connection_id);
-            }
+        // Take the selected players, keeping everyone else in order
+        let mut match_players = Vec::with_capacity(selected.len());
+        let mut queued = Vec::with_capacity(selected.len());
+        let mut waits = Vec::with_capacity(selected.len());
+        for &index in &selected {
+            let player = &queue[index];
+            match_players.push(player.connection_id);
+            queued.push(player.clone());
+            waits.push(now.duration_since(player.queued_at));
         }
+        queue.retain(|player| !match_players.contains(&player.connection_id));
 
         // Update positions for remaining players
         positions.clear();
         for (i, player) in queue.iter().enumerate() {
             positions.insert(player.connection_id, i);
         }
+        *self.draining.write().await = queue.len() >= self.min_players;
 
         if !match_players.is_empty() {
             let match_info = MatchInfo {
                 players: match_players,
-                created_at: Instant::now(),
+                created_at: now,
+                point_threshold: self.point_threshold,
+                queued,
             };
 
-            info!("Created match with {} players", match_info.players.len());
+            let mut wait_times = self.wait_times.write().await;
+            for &wait in &waits {
+                wait_times.record(wait);
+            }
+            let longest_wait = waits.iter().max().copied().unwrap_or_default();
+            let mean_wait = waits.iter().sum::<Duration>() / waits.len() as u32;
+            info!(
+                players = match_info.players.len(),
+                longest_wait_secs = longest_wait.as_secs_f64(),
+                mean_wait_secs = mean_wait.as_secs_f64(),
+                "Created match with {} players",
+                match_info.players.len()
+            );
             Ok(Some(match_info))
         } else {
             Ok(None)
         }
     }
 
+    /// Put the players of a match whose game couldn't be created back in
+    /// their old places, ahead of anyone who joined since. The queue size
+    /// limit doesn't apply to them. Returns where each player ended up.
+    pub async fn return_match(
+        &self,
+        match_info: &MatchInfo,
+    ) -> Vec<(ConnectionId, Result<u32, QueueError>)> {
+        let mut returned = Vec::with_capacity(match_info.queued.len());
+        for player in &match_info.queued {
+            let position = self
+                .insert_player(
+                    player.connection_id,
+                    player.user_id.as_ref(),
+                    player.rating,
+                    player.queued_at,
+                    true,
+                )
+                .await;
+            returned.push((player.connection_id, position));
+        }
+        returned
+    }
+
+    /// Queue indices of the next match, in queue order. Without a rating
+    /// window that is simply the front of the queue. With one, the match is
+    /// built around the longest-waiting player who has enough opponents
+    /// within range; `None` when nobody does.
+    fn select_match(&self, queue: &VecDeque<QueuedPlayer>, now: Instant) -> Option<Vec<usize>> {
+        let Some(window) = self.rating_window else {
+            return Some((0..queue.len().min(self.max_players)).collect());
+        };
+
+        for (anchor_index, anchor) in queue.iter().enumerate() {
+            let mut selected = vec![anchor_index];
+            for (index, player) in queue.iter().enumerate() {
+                if selected.len() >= self.max_players {
+                    break;
+                }
+                if index != anchor_index && window.allows(anchor, player, now) {
+                    selected.push(index);
+                }
+            }
+            if selected.len() >= self.min_players {
+                selected.sort_unstable();
+                return Some(selected);
+            }
+        }
+        None
+    }
+
     // Check if countdown has expired or if there are enough votes to start
     pub async fn should_start_match(&self) -> bool {
         let countdown_started = self.countdown_started_at.read().await;
         let queue = self.queue.read().await;
         let votes = self.votes_to_start.read().await;
 
+        if *self.draining.read().await && queue.len() >= self.min_players {
+            return true;
+        }
+
         if let Some(started_at) = *countdown_started {
-            let elapsed = Instant::now().duration_since(started_at);
+            let elapsed = self.clock.now().duration_since(started_at);
 
             // Check if countdown has expired
             if elapsed >= self.countdown_duration {
@@ -283,19 +750,42 @@ impl MatchmakingQueue {
         let votes = self.votes_to_start.read().await;
 
         if let Some(started_at) = *countdown_started {
-            let elapsed = Instant::now().duration_since(started_at);
+            let elapsed = self.clock.now().duration_since(started_at);
             let remaining = self.countdown_duration.saturating_sub(elapsed);
 
+            // The longest-waiting player has the widest window
+            let rating_window = self
+                .rating_window
+                .zip(queue.front())
+                .map(|(window, player)| {
+                    window.after(self.clock.now().duration_since(player.queued_at))
+                });
+
             Some(CountdownInfo {
                 seconds_remaining: remaining.as_secs() as u32,
                 players_ready: votes.len() as u32,
                 total_players: queue.len() as u32,
+                rating_window,
             })
         } else {
             None
         }
     }
 
+    /// Private lobbies, which start games without going through the queue
+    pub fn lobbies(&self) -> &LobbyManager {
+        &self.lobbies
+    }
+
+    /// Lobbies get the same player limits and win condition as queued matches
+    pub fn lobby_settings(&self) -> LobbySettings {
+        LobbySettings {
+            min_players: self.min_players,
+            max_players: self.max_players,
+            point_threshold: self.point_threshold,
+        }
+    }
+
     pub async fn get_queue_position(&self, connection_id: ConnectionId) -> Option<u32> {
         let positions = self.player_positions.read().await;
         positions.get(&connection_id).map(|&pos| (pos + 1) as u32)
@@ -311,11 +801,62 @@ impl MatchmakingQueue {
         queue.iter().map(|player| player.connection_id).collect()
     }
 
+    /// Players who have waited at least `max_wait` while the queue holds too
+    /// few players for a match
+    pub async fn stranded_players(&self, max_wait: Duration) -> Vec<ConnectionId> {
+        let queue = self.queue.read().await;
+        if queue.len() >= self.min_players {
+            return Vec::new();
+        }
+
+        let now = self.clock.now();
+        queue
+            .iter()
+            .filter(|player| now.duration_since(player.queued_at) >= max_wait)
+            .map(|player| player.connection_id)
+            .collect()
+    }
+
+    /// Send countdown and queue size updates to `connection_id` without
+    /// making it a queued player
+    pub async fn subscribe_status(&self, connection_id: ConnectionId) {
+        self.status_subscribers.write().await.insert(connection_id);
+    }
+
+    /// Stop sending queue updates to `connection_id`. Returns whether it was
+    /// subscribed.
+    pub async fn unsubscribe_status(&self, connection_id: ConnectionId) -> bool {
+        self.status_subscribers.write().await.remove(&connection_id)
+    }
+
+    pub async fn get_status_subscribers(&self) -> Vec<ConnectionId> {
+        let subscribers = self.status_subscribers.read().await;
+        subscribers.iter().copied().collect()
+    }
+
+    /// Queued players followed by status subscribers who aren't queued, so
+    /// nobody hears about the countdown twice
+    pub async fn get_countdown_recipients(&self) -> Vec<ConnectionId> {
+        let mut recipients = self.get_queue_players().await;
+        for subscriber in self.get_status_subscribers().await {
+            if !recipients.contains(&subscriber) {
+                recipients.push(subscriber);
+            }
+        }
+        recipients
+    }
+
     pub async fn cleanup_expired_players(&self) {
+        {
+            let now = self.clock.now();
+            let mut held_slots = self.held_slots.write().await;
+            held_slots.retain(|_, slot| slot.expires_at > now);
+        }
+
         let mut queue = self.queue.write().await;
         let mut positions = self.player_positions.write().await;
 
-        let now = Instant::now();
+        let now = self.clock.now();
         let expired_players: Vec<ConnectionId> = queue
             .iter()
             .filter(|player| now.duration_since(player.queued_at) > self.queue_timeout)
@@ -348,32 +889,43 @@ impl MatchmakingQueue {
         }
     }
 
-    #[allow(dead_code)]
     pub async fn get_queue_stats(&self) -> QueueStats {
         let queue = self.queue.read().await;
         let votes = self.votes_to_start.read().await;
         let countdown_started = self.countdown_started_at.read().await;
+        let wait_times = self.wait_times.read().await;
 
         QueueStats {
             total_players: queue.len(),
             votes_count: votes.len(),
             countdown_active: countdown_started.is_some(),
+            wait_times: wait_times.summary(),
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, serde::Serialize)]
 pub struct QueueStats {
     pub total_players: usize,
     pub votes_count: usize,
     pub countdown_active: bool,
+    pub wait_times: WaitTimeSummary, // How long recently matched players waited
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use game_core::MockClock;
+    use std::sync::Arc;
     use std::time::Duration;
 
+    fn queue_with_mock_clock(min: usize, max: usize) -> (MatchmakingQueue, Arc<MockClock>) {
+        let clock = Arc::new(MockClock::new());
+        let queue = MatchmakingQueue::new_with_config(min, max, Duration::from_secs(300))
+            .with_clock(clock.clone());
+        (queue, clock)
+    }
+
     #[tokio::test]
     async fn test_basic_queue_operations() {
         let queue = MatchmakingQueue::new();
@@ -385,7 +937,7 @@ mod tests {
         players.push(conn_id1);
 
         assert_eq!(queue.get_queue_length().await, 1);
-        assert!(queue.should_start_match().await == false);
+        assert!(!(queue.should_start_match().await));
 
         // Add second player - countdown should start, but no immediate match
         let conn_id2 = ConnectionId::new();
@@ -397,7 +949,7 @@ mod tests {
         // Countdown should be active but match should not start immediately
         let countdown_info = queue.get_countdown_info().await;
         assert!(countdown_info.is_some());
-        assert!(queue.should_start_match().await == false); // Not enough time elapsed or votes
+        assert!(!(queue.should_start_match().await)); // Not enough time elapsed or votes
 
         // Cleanup
         for player in players {
@@ -407,19 +959,19 @@ mod tests {
 
     #[tokio::test]
     async fn test_countdown_expiration() {
-        let queue = MatchmakingQueue::new_with_config(2, 16, Duration::from_secs(300));
-        // Override countdown to be very short for testing
-        {
-            let mut countdown = queue.countdown_started_at.write().await;
-            *countdown = Some(Instant::now() - Duration::from_secs(65)); // Simulate expired countdown
-        }
+        let (queue, clock) = queue_with_mock_clock(2, 16);
 
         let conn_id1 = ConnectionId::new();
         let conn_id2 = ConnectionId::new();
         queue.add_player(conn_id1).await.unwrap();
         queue.add_player(conn_id2).await.unwrap();
 
+        // Countdown is running but has not expired yet
+        clock.advance(Duration::from_secs(59));
+        assert!(!queue.should_start_match().await);
+
         // Should indicate match should start due to expired countdown
+        clock.advance(Duration::from_secs(1));
         assert!(queue.should_start_match().await);
 
         // Now we can create the match
@@ -525,7 +1077,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_max_players_limit() {
-        let queue = MatchmakingQueue::new_with_config(2, 3, Duration::from_secs(300));
+        let (queue, clock) = queue_with_mock_clock(2, 3);
         let mut players = Vec::new();
 
         // Add more than max players
@@ -535,11 +1087,8 @@ mod tests {
             players.push(conn_id);
         }
 
-        // Force match creation (simulate countdown expiration)
-        {
-            let mut countdown = queue.countdown_started_at.write().await;
-            *countdown = Some(Instant::now() - Duration::from_secs(65));
-        }
+        // Let the countdown expire
+        clock.advance(Duration::from_secs(65));
 
         // Try to create match - should take only 3 players (max)
         let match_result = queue.try_create_match().await.unwrap();
@@ -559,7 +1108,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_concurrent_operations() {
-        let queue = std::sync::Arc::new(MatchmakingQueue::new());
+        let (queue, clock) = queue_with_mock_clock(2, 16);
+        let queue = Arc::new(queue);
         let mut players = Vec::new();
 
         // Add players concurrently
@@ -577,11 +1127,8 @@ mod tests {
         let queue_clone2 = queue.clone();
         let player_to_remove = players[1];
 
-        // Force countdown expiration for one of the tasks
-        {
-            let mut countdown = queue.countdown_started_at.write().await;
-            *countdown = Some(Instant::now() - Duration::from_secs(65));
-        }
+        // Let the countdown expire before both tasks run
+        clock.advance(Duration::from_secs(65));
 
         let match_task = tokio::spawn(async move { queue_clone1.try_create_match().await });
 
@@ -600,6 +1147,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_wait_time_percentiles() {
+        let mut wait_times = WaitTimeStats::new(100);
+        assert_eq!(wait_times.percentile(50.0), None);
+
+        for seconds in (1..=100).rev() {
+            wait_times.record(Duration::from_secs(seconds));
+        }
+
+        assert_eq!(
+            wait_times.summary(),
+            WaitTimeSummary {
+                samples: 100,
+                p50_seconds: Some(50.0),
+                p90_seconds: Some(90.0),
+                p99_seconds: Some(99.0),
+            }
+        );
+
+        // Only the most recent waits count once the sample is full
+        for _ in 0..100 {
+            wait_times.record(Duration::from_secs(5));
+        }
+        assert_eq!(wait_times.percentile(99.0), Some(Duration::from_secs(5)));
+    }
+
+    #[tokio::test]
+    async fn test_match_records_player_wait_times() {
+        let (queue, clock) = queue_with_mock_clock(2, 16);
+
+        queue.add_player(ConnectionId::new()).await.unwrap();
+        clock.advance(Duration::from_secs(20));
+        queue.add_player(ConnectionId::new()).await.unwrap();
+        clock.advance(Duration::from_secs(60));
+        assert!(queue.try_create_match().await.unwrap().is_some());
+
+        let wait_times = queue.get_queue_stats().await.wait_times;
+        assert_eq!(wait_times.samples, 2);
+        assert_eq!(wait_times.p50_seconds, Some(60.0));
+        assert_eq!(wait_times.p99_seconds, Some(80.0));
+    }
+
     #[tokio::test]
     async fn test_edge_case_empty_queue_operations() {
         let queue = MatchmakingQueue::new();
@@ -724,4 +1313,195 @@ mod tests {
         queue.remove_player(conn_id2).await.ok();
         queue.remove_player(conn_id3).await.ok();
     }
+
+    #[tokio::test]
+    async fn test_full_queue_refuses_until_match_drains() {
+        let (queue, clock) = queue_with_mock_clock(2, 2);
+        let queue = queue.with_max_queue_size(Some(2));
+
+        queue.add_player(ConnectionId::new()).await.unwrap();
+        queue.add_player(ConnectionId::new()).await.unwrap();
+
+        // The countdown started with the second player
+        clock.advance(Duration::from_secs(20));
+        let late = ConnectionId::new();
+        assert_eq!(
+            queue.add_player(late).await,
+            Err(QueueError::QueueFull { retry_after: 40 })
+        );
+        assert_eq!(queue.get_queue_length().await, 2);
+
+        clock.advance(Duration::from_secs(40));
+        assert!(queue.try_create_match().await.unwrap().is_some());
+
+        assert_eq!(queue.add_player(late).await, Ok(1));
+    }
+
+    #[tokio::test]
+    async fn test_abandon_cooldown_expires() {
+        let (queue, clock) = queue_with_mock_clock(2, 16);
+        let queue = queue.with_abandon_cooldown(Some(Duration::from_secs(60)));
+        let user_id = "quitter".to_string();
+
+        assert_eq!(queue.cooldown_remaining(&user_id).await, None);
+
+        queue.record_abandonment(&user_id).await;
+        clock.advance(Duration::from_secs(45));
+        assert_eq!(
+            queue.cooldown_remaining(&user_id).await,
+            Some(Duration::from_secs(15))
+        );
+
+        clock.advance(Duration::from_secs(15));
+        assert_eq!(queue.cooldown_remaining(&user_id).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_abandon_cooldown_disabled_by_default() {
+        let queue = MatchmakingQueue::new();
+        let user_id = "quitter".to_string();
+
+        queue.record_abandonment(&user_id).await;
+        assert_eq!(queue.cooldown_remaining(&user_id).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_dropped_connection_keeps_queue_place() {
+        let (queue, clock) = queue_with_mock_clock(2, 16);
+        let queue = queue.with_disconnect_hold(Some(Duration::from_secs(30)));
+        let alice = "alice".to_string();
+
+        let alice_conn = ConnectionId::new();
+        queue.add_user(alice_conn, &alice, None).await.unwrap();
+        clock.advance(Duration::from_secs(1));
+        queue
+            .add_user(ConnectionId::new(), &"bob".to_string(), None)
+            .await
+            .unwrap();
+
+        queue.hold_player(alice_conn, Some(&alice)).await.unwrap();
+        assert_eq!(queue.get_queue_length().await, 1);
+
+        // Alice reconnects within the hold and gets her old place back
+        clock.advance(Duration::from_secs(10));
+        let reconnected = ConnectionId::new();
+        assert_eq!(queue.add_user(reconnected, &alice, None).await.unwrap(), 1);
+        assert_eq!(queue.get_queue_players().await[0], reconnected);
+
+        // Once the hold lapses she starts over at the back
+        queue.hold_player(reconnected, Some(&alice)).await.unwrap();
+        clock.advance(Duration::from_secs(31));
+        assert_eq!(
+            queue
+                .add_user(ConnectionId::new(), &alice, None)
+                .await
+                .unwrap(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rejoin_from_new_connection_keeps_single_entry() {
+        let (queue, clock) = queue_with_mock_clock(3, 16);
+        let queue = queue.with_disconnect_hold(Some(Duration::from_secs(30)));
+        let alice = "alice".to_string();
+
+        queue
+            .add_user(ConnectionId::new(), &"bob".to_string(), None)
+            .await
+            .unwrap();
+        clock.advance(Duration::from_secs(1));
+        let alice_conn = ConnectionId::new();
+        assert_eq!(queue.add_user(alice_conn, &alice, None).await.unwrap(), 2);
+        clock.advance(Duration::from_secs(1));
+        queue
+            .add_user(ConnectionId::new(), &"carol".to_string(), None)
+            .await
+            .unwrap();
+
+        // Alice reconnects and joins again before her old socket is dropped
+        let reconnected = ConnectionId::new();
+        assert_eq!(queue.add_user(reconnected, &alice, None).await.unwrap(), 2);
+        assert_eq!(queue.add_user(reconnected, &alice, None).await.unwrap(), 2);
+        assert_eq!(queue.get_queue_length().await, 3);
+        assert_eq!(queue.get_queue_players().await[1], reconnected);
+        assert!(!queue.get_queue_players().await.contains(&alice_conn));
+
+        // Noticing the stale connection later leaves the new entry alone
+        assert!(queue.hold_player(alice_conn, Some(&alice)).await.is_err());
+        assert_eq!(queue.get_queue_length().await, 3);
+        assert_eq!(queue.add_user(reconnected, &alice, None).await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_intentional_leave_gives_up_queue_place() {
+        let (queue, clock) = queue_with_mock_clock(2, 16);
+        let queue = queue.with_disconnect_hold(Some(Duration::from_secs(30)));
+        let alice = "alice".to_string();
+
+        let alice_conn = ConnectionId::new();
+        queue.add_user(alice_conn, &alice, None).await.unwrap();
+        clock.advance(Duration::from_secs(1));
+        queue
+            .add_user(ConnectionId::new(), &"bob".to_string(), None)
+            .await
+            .unwrap();
+
+        queue.remove_player(alice_conn).await.unwrap();
+        assert_eq!(
+            queue
+                .add_user(ConnectionId::new(), &alice, None)
+                .await
+                .unwrap(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rating_window_keeps_distant_players_queued_until_it_widens() {
+        let (queue, clock) = queue_with_mock_clock(2, 2);
+        let queue = queue.with_rating_window(Some(RatingWindow {
+            base: 150,
+            growth_per_sec: 5.0,
+        }));
+
+        let veteran = ConnectionId::new();
+        queue
+            .add_user(veteran, &"veteran".to_string(), Some(1600))
+            .await
+            .unwrap();
+        clock.advance(Duration::from_secs(1));
+        let newbie = ConnectionId::new();
+        queue
+            .add_user(newbie, &"newbie".to_string(), Some(1000))
+            .await
+            .unwrap();
+        clock.advance(Duration::from_secs(1));
+        let rookie = ConnectionId::new();
+        queue
+            .add_user(rookie, &"rookie".to_string(), Some(1100))
+            .await
+            .unwrap();
+
+        // The veteran has waited longest but nobody is within reach yet
+        clock.advance(Duration::from_secs(60));
+        let match_info = queue.try_create_match().await.unwrap().unwrap();
+        assert_eq!(match_info.players, vec![newbie, rookie]);
+        assert_eq!(queue.get_queue_players().await, vec![veteran]);
+
+        let latecomer = ConnectionId::new();
+        queue
+            .add_user(latecomer, &"latecomer".to_string(), Some(800))
+            .await
+            .unwrap();
+        let countdown = queue.get_countdown_info().await.unwrap();
+        assert_eq!(countdown.rating_window, Some(150 + 5 * 62));
+        clock.advance(Duration::from_secs(60));
+        assert!(queue.try_create_match().await.unwrap().is_none());
+
+        // Waiting long enough widens the window to cover the gap
+        clock.advance(Duration::from_secs(10));
+        let match_info = queue.try_create_match().await.unwrap().unwrap();
+        assert_eq!(match_info.players, vec![veteran, latecomer]);
+    }
 }
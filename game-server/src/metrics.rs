@@ -0,0 +1,114 @@
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Live counters for the `/metrics` endpoint. Gauges such as the number of
+/// active games are read from their owners at scrape time; only events that
+/// leave no other trace are counted here.
+#[derive(Debug, Default)]
+pub struct Metrics {
+    guesses_submitted: AtomicU64,
+    games_completed: AtomicU64,
+}
+
+/// Point-in-time readings taken from the game manager, queue and connections
+#[derive(Debug, Clone, Copy)]
+pub struct Gauges {
+    pub active_games: usize,
+    pub queue_length: usize,
+    pub connections: usize,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count a guess the game accepted
+    pub fn record_guess(&self) {
+        self.guesses_submitted.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Count a game that reached a winner
+    pub fn record_game_completed(&self) {
+        self.games_completed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn guesses_submitted(&self) -> u64 {
+        self.guesses_submitted.load(Ordering::Relaxed)
+    }
+
+    pub fn games_completed(&self) -> u64 {
+        self.games_completed.load(Ordering::Relaxed)
+    }
+
+    /// Render every metric in the Prometheus text exposition format
+    pub fn render(&self, gauges: Gauges) -> String {
+        let mut output = String::new();
+        let mut metric = |name: &str, kind: &str, help: &str, value: u64| {
+            let _ = writeln!(output, "# HELP {} {}", name, help);
+            let _ = writeln!(output, "# TYPE {} {}", name, kind);
+            let _ = writeln!(output, "{} {}", name, value);
+        };
+
+        metric(
+            "word_arena_active_games",
+            "gauge",
+            "Games currently in progress",
+            gauges.active_games as u64,
+        );
+        metric(
+            "word_arena_queue_length",
+            "gauge",
+            "Players waiting in the matchmaking queue",
+            gauges.queue_length as u64,
+        );
+        metric(
+            "word_arena_connections",
+            "gauge",
+            "Open WebSocket connections",
+            gauges.connections as u64,
+        );
+        metric(
+            "word_arena_guesses_submitted_total",
+            "counter",
+            "Guesses accepted since the server started",
+            self.guesses_submitted(),
+        );
+        metric(
+            "word_arena_games_completed_total",
+            "counter",
+            "Games finished with a winner since the server started",
+            self.games_completed(),
+        );
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_reports_gauges_and_counters() {
+        let metrics = Metrics::new();
+        metrics.record_guess();
+        metrics.record_guess();
+        metrics.record_game_completed();
+
+        let output = metrics.render(Gauges {
+            active_games: 3,
+            queue_length: 4,
+            connections: 9,
+        });
+
+        assert!(
+            output.contains("# TYPE word_arena_active_games gauge\nword_arena_active_games 3\n")
+        );
+        assert!(output.contains("word_arena_queue_length 4\n"));
+        assert!(output.contains("word_arena_connections 9\n"));
+        assert!(output.contains(
+            "# TYPE word_arena_guesses_submitted_total counter\nword_arena_guesses_submitted_total 2\n"
+        ));
+        assert!(output.contains("word_arena_games_completed_total 1\n"));
+    }
+}
@@ -0,0 +1,244 @@
+use tracing::{error, warn};
+
+use crate::websocket::connection::ConnectionManager;
+use game_persistence::repositories::UserRepository;
+use game_types::{Player, ServerMessage};
+
+/// Add a finished game to each player's lifetime stats and skill rating.
+/// Players still connected are told when the game moved them on the
+/// leaderboard; rank lookups are skipped for everyone else to keep the
+/// query count down.
+pub async fn record_game_results(
+    connection_manager: &ConnectionManager,
+    user_repository: &UserRepository,
+    winner: &Player,
+    final_scores: &[Player],
+) {
+    let mut losers = Vec::new();
+    for player in final_scores {
+        let connected_user = connection_manager
+            .get_connection_by_user(&player.user_id)
+            .await
+            .and_then(|connection| connection.user);
+
+        let old_rank = match &connected_user {
+            Some(_) => match user_repository.get_user_rank(&player.user_id).await {
+                Ok(rank) => rank,
+                Err(e) => {
+                    warn!("Failed to look up rank for {}: {}", player.user_id, e);
+                    None
+                }
+            },
+            None => None,
+        };
+
+        // Players who have never finished a game have no stored profile yet
+        let stored = match user_repository.find_by_id(&player.user_id).await {
+            Ok(Some(_)) => Ok(true),
+            Ok(None) => match &connected_user {
+                Some(user) => user_repository
+                    .create_user(user.clone())
+                    .await
+                    .map(|_| true),
+                None => Ok(false),
+            },
+            Err(e) => Err(e),
+        };
+        match stored {
+            Ok(true) => {}
+            Ok(false) => {
+                warn!(
+                    "Skipping stats for {}: no stored profile and no longer connected",
+                    player.user_id
+                );
+                continue;
+            }
+            Err(e) => {
+                error!("Failed to load profile for {}: {}", player.user_id, e);
+                continue;
+            }
+        }
+
+        let won = player.user_id == winner.user_id;
+        if let Err(e) = user_repository
+            .update_user_stats(&player.user_id, player.points, won)
+            .await
+        {
+            error!("Failed to update stats for {}: {}", player.user_id, e);
+            continue;
+        }
+        if !won {
+            losers.push(player.user_id.clone());
+        }
+
+        if connected_user.is_none() {
+            continue;
+        }
+        let new_rank = match user_repository.get_user_rank(&player.user_id).await {
+            Ok(Some(rank)) => rank,
+            Ok(None) => continue,
+            Err(e) => {
+                warn!("Failed to look up rank for {}: {}", player.user_id, e);
+                continue;
+            }
+        };
+
+        if old_rank != Some(new_rank) {
+            let message = ServerMessage::RankChanged { old_rank, new_rank };
+            if let Err(e) = connection_manager
+                .send_to_user(&player.user_id, message)
+                .await
+            {
+                warn!("Failed to send rank change to {}: {}", player.user_id, e);
+            }
+        }
+    }
+
+    if let Err(e) = user_repository
+        .update_ratings(&winner.user_id, &losers)
+        .await
+    {
+        error!("Failed to update ratings after game: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::websocket::connection::ConnectionId;
+    use game_types::User;
+    use migration::{Migrator, MigratorTrait};
+
+    fn user(id: &str, total_points: i32) -> User {
+        User {
+            id: id.to_string(),
+            email: format!("{}@example.com", id),
+            display_name: id.to_string(),
+            total_points,
+            total_wins: 0,
+            total_games: 0,
+            created_at: chrono::Utc::now().to_rfc3339(),
+            avatar: None,
+            roles: Vec::new(),
+        }
+    }
+
+    fn player(id: &str, points: i32) -> Player {
+        Player {
+            user_id: id.to_string(),
+            display_name: id.to_string(),
+            points,
+            guess_history: Vec::new(),
+            is_connected: true,
+            avatar: None,
+        }
+    }
+
+    async fn connect(
+        connection_manager: &ConnectionManager,
+        user: User,
+    ) -> tokio::sync::mpsc::UnboundedReceiver<ServerMessage> {
+        let connection_id = ConnectionId::new();
+        let receiver = connection_manager.create_connection(connection_id).await;
+        connection_manager
+            .authenticate_connection(connection_id, user.id.clone())
+            .await
+            .unwrap();
+        connection_manager
+            .set_connection_user(connection_id, Some(user))
+            .await;
+        receiver
+    }
+
+    async fn setup() -> (ConnectionManager, UserRepository) {
+        let db = game_persistence::connection::connect_to_memory_database()
+            .await
+            .unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        (ConnectionManager::new(), UserRepository::new(db))
+    }
+
+    #[tokio::test]
+    async fn test_rank_change_sent_to_connected_players() {
+        let (connection_manager, user_repository) = setup().await;
+        for (id, points) in [("leader", 100), ("middle", 40), ("alice", 30)] {
+            user_repository.create_user(user(id, points)).await.unwrap();
+        }
+
+        let mut alice_rx = connect(&connection_manager, user("alice", 30)).await;
+
+        // Bob is connected but has never finished a game
+        let mut bob_rx = connect(&connection_manager, user("bob", 0)).await;
+
+        let alice = player("alice", 25);
+        let bob = player("bob", 5);
+        record_game_results(
+            &connection_manager,
+            &user_repository,
+            &alice,
+            &[alice.clone(), bob.clone()],
+        )
+        .await;
+
+        // Alice passes "middle" (55 > 40) and moves from third to second
+        assert!(matches!(
+            alice_rx.try_recv().unwrap(),
+            ServerMessage::RankChanged {
+                old_rank: Some(3),
+                new_rank: 2,
+            }
+        ));
+
+        // Bob was unranked before his first game
+        assert!(matches!(
+            bob_rx.try_recv().unwrap(),
+            ServerMessage::RankChanged {
+                old_rank: None,
+                new_rank: 4,
+            }
+        ));
+
+        let stored = user_repository
+            .find_by_id(&"alice".to_string())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.total_points, 55);
+        assert_eq!(stored.total_wins, 1);
+        assert_eq!(stored.total_games, 1);
+    }
+
+    #[tokio::test]
+    async fn test_unchanged_rank_sends_nothing() {
+        let (connection_manager, user_repository) = setup().await;
+        user_repository
+            .create_user(user("leader", 100))
+            .await
+            .unwrap();
+        user_repository
+            .create_user(user("alice", 30))
+            .await
+            .unwrap();
+
+        let mut alice_rx = connect(&connection_manager, user("alice", 30)).await;
+
+        // The leader has disconnected; their stats still count
+        let alice = player("alice", 10);
+        let leader = player("leader", 25);
+        record_game_results(
+            &connection_manager,
+            &user_repository,
+            &leader,
+            &[leader.clone(), alice.clone()],
+        )
+        .await;
+
+        assert!(alice_rx.try_recv().is_err());
+        let stored = user_repository
+            .find_by_id(&"leader".to_string())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.total_points, 125);
+    }
+}
@@ -0,0 +1,79 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+/// Longest user agent kept for the audit log
+const MAX_USER_AGENT_LEN: usize = 200;
+
+/// Who opened a connection, captured from the WebSocket upgrade request for
+/// the connection audit log. Addresses are only ever logged masked.
+#[derive(Debug, Clone, Default)]
+pub struct ClientInfo {
+    pub remote_addr: Option<SocketAddr>, // Peer socket; the proxy when deployed behind nginx
+    pub forwarded_for: Option<IpAddr>,   // `X-Real-IP` as set by the proxy, unverified
+    pub user_agent: Option<String>,
+}
+
+impl ClientInfo {
+    pub fn new(
+        remote_addr: Option<SocketAddr>,
+        real_ip: Option<String>,
+        user_agent: Option<String>,
+    ) -> Self {
+        Self {
+            remote_addr,
+            forwarded_for: real_ip.and_then(|ip| ip.trim().parse().ok()),
+            user_agent: user_agent.map(|agent| agent.chars().take(MAX_USER_AGENT_LEN).collect()),
+        }
+    }
+
+    /// The client's address with the host part masked, preferring the
+    /// proxy-reported address over the peer socket
+    pub fn masked_addr(&self) -> String {
+        self.forwarded_for
+            .or(self.remote_addr.map(|addr| addr.ip()))
+            .map(|ip| mask_ip(ip).to_string())
+            .unwrap_or_else(|| "unknown".to_string())
+    }
+
+    pub fn user_agent(&self) -> &str {
+        self.user_agent.as_deref().unwrap_or("unknown")
+    }
+}
+
+/// Drop the host part of an address so logs identify a network rather than a
+/// person: the last octet of IPv4 and everything past the /48 prefix of IPv6
+pub fn mask_ip(ip: IpAddr) -> IpAddr {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [a, b, c, _] = ip.octets();
+            IpAddr::V4(Ipv4Addr::new(a, b, c, 0))
+        }
+        IpAddr::V6(ip) => {
+            let [a, b, c, ..] = ip.segments();
+            IpAddr::V6(Ipv6Addr::new(a, b, c, 0, 0, 0, 0, 0))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_masked_addr_prefers_proxy_address() {
+        let client = ClientInfo::new(
+            Some("10.0.0.5:4321".parse().unwrap()),
+            Some("203.0.113.77".to_string()),
+            None,
+        );
+        assert_eq!(client.masked_addr(), "203.0.113.0");
+
+        let direct = ClientInfo::new(
+            Some("[2001:db8:85a3:1:2:3:4:5]:443".parse().unwrap()),
+            Some("not an address".to_string()),
+            None,
+        );
+        assert_eq!(direct.masked_addr(), "2001:db8:85a3::");
+
+        assert_eq!(ClientInfo::default().masked_addr(), "unknown");
+    }
+}
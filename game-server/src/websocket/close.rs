@@ -0,0 +1,59 @@
+use warp::ws::Message;
+
+/// Why the server ended a connection. Every cause is sent to the client as a
+/// close frame so it can decide whether to reconnect:
+///
+/// | Code | Cause                 | Client should                             |
+/// |------|-----------------------|-------------------------------------------|
+/// | 4000 | `ProtocolViolation`   | Stop; the client sent something malformed |
+/// | 4001 | `RateLimited`         | Back off before reconnecting              |
+/// | 4002 | `SessionTerminated`   | Not reconnect; another login took over    |
+/// | 4003 | `ServerShutdown`      | Reconnect after a short delay             |
+/// | 4004 | `Kicked`              | Not reconnect automatically               |
+/// | 4005 | `IncompatibleVersion` | Upgrade; the protocol versions differ     |
+/// | 4006 | `AuthTimeout`         | Reconnect and authenticate right away     |
+/// | 1011 | `InternalError`       | Reconnect; the server failed the request  |
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloseReason {
+    ProtocolViolation,
+    RateLimited,
+    SessionTerminated,
+    ServerShutdown,
+    Kicked,
+    IncompatibleVersion,
+    AuthTimeout,
+    InternalError,
+}
+
+impl CloseReason {
+    pub fn code(self) -> u16 {
+        match self {
+            CloseReason::ProtocolViolation => 4000,
+            CloseReason::RateLimited => 4001,
+            CloseReason::SessionTerminated => 4002,
+            CloseReason::ServerShutdown => 4003,
+            CloseReason::Kicked => 4004,
+            CloseReason::IncompatibleVersion => 4005,
+            CloseReason::AuthTimeout => 4006,
+            CloseReason::InternalError => 1011, // Standard "unexpected condition"
+        }
+    }
+
+    pub fn reason(self) -> &'static str {
+        match self {
+            CloseReason::ProtocolViolation => "Protocol violation",
+            CloseReason::RateLimited => "Rate limit exceeded",
+            CloseReason::SessionTerminated => "Session taken over by another login",
+            CloseReason::ServerShutdown => "Server shutting down",
+            CloseReason::Kicked => "Removed by the server",
+            CloseReason::IncompatibleVersion => "Incompatible protocol version",
+            CloseReason::AuthTimeout => "Did not authenticate in time",
+            CloseReason::InternalError => "Internal server error",
+        }
+    }
+
+    /// Close frame carrying this reason's code and description
+    pub fn frame(self) -> Message {
+        Message::close_with(self.code(), self.reason())
+    }
+}
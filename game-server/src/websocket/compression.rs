@@ -0,0 +1,167 @@
+use flate2::{Compression, write::GzEncoder};
+use serde::Deserialize;
+use std::io::Write;
+use tracing::warn;
+use warp::ws::Message;
+
+/// Messages smaller than this are always sent as plain text frames; gzip
+/// overhead outweighs the savings for heartbeats, errors and the like.
+pub const COMPRESSION_THRESHOLD_BYTES: usize = 1024;
+
+/// Compression schemes a client can request when connecting
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CompressionMode {
+    Gzip,
+}
+
+/// Options negotiated through the WebSocket upgrade query string,
+/// e.g. `/ws?compression=gzip`
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub struct ConnectOptions {
+    pub compression: Option<CompressionMode>,
+}
+
+/// Encode a serialized server message for the wire. When the client negotiated
+/// gzip and the payload is large enough, the JSON is compressed and sent as a
+/// binary frame; everything else goes out as a text frame.
+pub fn encode_message(json: String, compression: Option<CompressionMode>) -> Message {
+    match compression {
+        Some(CompressionMode::Gzip) if json.len() >= COMPRESSION_THRESHOLD_BYTES => {
+            match gzip(json.as_bytes()) {
+                Ok(compressed) if compressed.len() < json.len() => Message::binary(compressed),
+                Ok(_) => Message::text(json),
+                Err(e) => {
+                    warn!("Failed to compress message, sending uncompressed: {}", e);
+                    Message::text(json)
+                }
+            }
+        }
+        _ => Message::text(json),
+    }
+}
+
+fn gzip(bytes: &[u8]) -> std::io::Result<Vec<u8>> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(bytes)?;
+    encoder.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::GzDecoder;
+    use game_types::{
+        GamePhase, GameState, GameStatus, GuessResult, LetterResult, LetterStatus, PersonalGuess,
+        Player, ServerMessage,
+    };
+    use std::io::Read;
+
+    fn late_game_state() -> GameState {
+        let words = [
+            "about", "above", "after", "again", "beach", "black", "brown", "chair",
+        ];
+        let players = (0..16)
+            .map(|i| Player {
+                user_id: format!("user-{:02}.tenant-0000-1111-2222", i),
+                display_name: format!("Player {}", i),
+                points: 20 + i,
+                guess_history: words
+                    .iter()
+                    .map(|w| PersonalGuess {
+                        word: w.to_string(),
+                        points_earned: 2,
+                        was_winning_guess: false,
+                        timestamp: "2024-01-01T12:00:00+00:00".to_string(),
+                        assist_points: 0,
+                    })
+                    .collect(),
+                is_connected: true,
+                avatar: None,
+            })
+            .collect();
+
+        let official_board = words
+            .iter()
+            .map(|w| GuessResult {
+                word: w.to_string(),
+                player_id: "user-00.tenant-0000-1111-2222".to_string(),
+                letters: w
+                    .chars()
+                    .enumerate()
+                    .map(|(i, c)| LetterResult {
+                        letter: c.to_string(),
+                        status: LetterStatus::Absent,
+                        position: i as i32,
+                    })
+                    .collect(),
+                points_earned: 2,
+                timestamp: "2024-01-01T12:00:00+00:00".to_string(),
+                breakdown: Default::default(),
+            })
+            .collect();
+
+        GameState {
+            id: "6f1c2b9e-0d3a-4c1e-9a57-3e2f7b8c9d10".to_string(),
+            word: "*****".to_string(),
+            word_length: 5,
+            current_round: 4,
+            status: GameStatus::Active,
+            current_phase: GamePhase::Guessing,
+            players,
+            official_board,
+            current_winner: None,
+            created_at: "2024-01-01T12:00:00+00:00".to_string(),
+            point_threshold: 25,
+            teams: Vec::new(),
+            campaign: None,
+            hide_scores_until_end: false,
+        }
+    }
+
+    fn decompress(bytes: &[u8]) -> String {
+        let mut decoded = String::new();
+        GzDecoder::new(bytes).read_to_string(&mut decoded).unwrap();
+        decoded
+    }
+
+    #[test]
+    fn test_large_state_is_compressed() {
+        let json = serde_json::to_string(&ServerMessage::GameStateUpdate {
+            state: late_game_state(),
+        })
+        .unwrap();
+
+        let message = encode_message(json.clone(), Some(CompressionMode::Gzip));
+        assert!(message.is_binary());
+
+        let compressed = message.as_bytes();
+        let ratio = compressed.len() as f64 / json.len() as f64;
+        println!(
+            "16-player GameStateUpdate: {} bytes -> {} bytes ({:.0}%)",
+            json.len(),
+            compressed.len(),
+            ratio * 100.0
+        );
+        assert!(ratio < 0.25, "Expected at least a 4x reduction");
+        assert_eq!(decompress(compressed), json);
+    }
+
+    #[test]
+    fn test_small_messages_stay_text() {
+        let json = serde_json::to_string(&ServerMessage::QueueLeft).unwrap();
+        let message = encode_message(json.clone(), Some(CompressionMode::Gzip));
+        assert!(message.is_text());
+        assert_eq!(message.to_str().unwrap(), json);
+    }
+
+    #[test]
+    fn test_no_compression_without_negotiation() {
+        let json = serde_json::to_string(&ServerMessage::GameStateUpdate {
+            state: late_game_state(),
+        })
+        .unwrap();
+        let message = encode_message(json, None);
+        assert!(message.is_text());
+    }
+}
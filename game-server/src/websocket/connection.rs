@@ -1,13 +1,23 @@
-use game_types::{ServerMessage, User};
-use std::collections::HashMap;
+use game_types::{SafeGameState, ServerMessage, SharedGameState, User};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use tokio::sync::{RwLock, mpsc};
 use uuid::Uuid;
 
+use super::close::CloseReason;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ConnectionId(Uuid);
 
+impl Default for ConnectionId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ConnectionId {
     pub fn new() -> Self {
         Self(Uuid::new_v4())
@@ -29,7 +39,87 @@ pub struct Connection {
     pub last_activity: Instant,
     pub is_authenticated: bool,
     pub game_id: Option<String>,
+    pub spectating: Option<String>, // Game watched without a seat; never set alongside `game_id`
+    pub muted: HashSet<String>,     // User ids whose chat this connection doesn't receive
     pub sender: mpsc::UnboundedSender<ServerMessage>,
+    pub outbound: Arc<OutboundQueue>, // Shared with the socket writer that drains `sender`
+}
+
+/// Tracks messages queued for a connection until its socket writer delivers
+/// them, so clients that stop keeping up can be spotted
+#[derive(Debug, Default)]
+pub struct OutboundQueue {
+    pending: Mutex<VecDeque<Instant>>, // When each undelivered message was queued
+    slow_after: Option<usize>,         // Queue depth that marks a slow consumer
+    slow: AtomicBool,
+    last_latency_micros: AtomicU64,
+}
+
+impl OutboundQueue {
+    pub fn new(slow_after: Option<usize>) -> Self {
+        Self {
+            slow_after,
+            ..Default::default()
+        }
+    }
+
+    /// Note a queued message. True when this pushed the connection over the
+    /// slow-consumer threshold.
+    fn enqueued(&self) -> bool {
+        let depth = {
+            let mut pending = self.pending.lock().unwrap();
+            pending.push_back(Instant::now());
+            pending.len()
+        };
+        self.slow_after
+            .is_some_and(|slow_after| depth >= slow_after)
+            && !self.slow.swap(true, Ordering::Relaxed)
+    }
+
+    /// Drop the record of a message that never made it into the channel
+    fn unqueued(&self) {
+        self.pending.lock().unwrap().pop_back();
+    }
+
+    /// Note that the oldest queued message reached the socket. Returns how
+    /// long it waited. A connection stops counting as slow once it catches up.
+    pub fn delivered(&self) -> Option<Duration> {
+        let (queued_at, depth) = {
+            let mut pending = self.pending.lock().unwrap();
+            (pending.pop_front()?, pending.len())
+        };
+        let latency = queued_at.elapsed();
+        self.last_latency_micros
+            .store(latency.as_micros() as u64, Ordering::Relaxed);
+        if depth == 0 {
+            self.slow.store(false, Ordering::Relaxed);
+        }
+        Some(latency)
+    }
+
+    /// Messages queued but not yet written to the socket
+    pub fn depth(&self) -> usize {
+        self.pending.lock().unwrap().len()
+    }
+
+    pub fn is_slow(&self) -> bool {
+        self.slow.load(Ordering::Relaxed)
+    }
+
+    /// How long the most recently delivered message spent queued
+    pub fn last_latency(&self) -> Duration {
+        Duration::from_micros(self.last_latency_micros.load(Ordering::Relaxed))
+    }
+}
+
+/// Outbound queue health across every open connection
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize)]
+pub struct OutboundStats {
+    pub connections: usize,
+    pub queued_messages: usize,
+    pub max_queue_depth: usize,
+    pub slow_consumers: usize,
+    pub max_latency_ms: u64, // Worst recent delivery latency of any connection
 }
 
 impl Connection {
@@ -45,7 +135,10 @@ impl Connection {
             last_activity: now,
             is_authenticated: false,
             game_id: None,
+            spectating: None,
+            muted: HashSet::new(),
             sender,
+            outbound: Arc::new(OutboundQueue::new(Some(DEFAULT_SLOW_CONSUMER_DEPTH))),
         };
 
         (connection, receiver)
@@ -73,23 +166,68 @@ impl Connection {
     }
 
     pub fn set_game(&mut self, game_id: Option<String>) {
+        // Taking a seat in a game ends any spectating
+        if game_id.is_some() {
+            self.spectating = None;
+        }
         self.game_id = game_id;
     }
 
     pub fn send_message(&self, message: ServerMessage) -> Result<(), String> {
-        self.sender
-            .send(message)
-            .map_err(|_| "Connection closed".to_string())
+        // Recorded before sending so the writer never delivers an unrecorded message
+        let became_slow = self.outbound.enqueued();
+        if self.sender.send(message).is_err() {
+            self.outbound.unqueued();
+            return Err("Connection closed".to_string());
+        }
+
+        if became_slow {
+            tracing::warn!(
+                "Slow consumer: connection {} (user {}) has {} undelivered messages",
+                self.id,
+                self.user_id.as_deref().unwrap_or("none"),
+                self.outbound.depth()
+            );
+        }
+        Ok(())
     }
 
     pub fn is_inactive(&self, timeout: Duration) -> bool {
         self.last_activity.elapsed() > timeout
     }
+
+    /// Still unauthenticated longer than `deadline` after connecting.
+    /// Heartbeats do not extend this, unlike the inactivity timeout.
+    pub fn missed_auth_deadline(&self, deadline: Duration) -> bool {
+        !self.is_authenticated && self.connected_at.elapsed() > deadline
+    }
 }
 
+/// How long a single WebSocket write may take before the socket is treated as dead
+pub const DEFAULT_SEND_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// What a spectator sees of `game_state`: no player's guess history, scores
+/// hidden while the game hides them, and no target word
+pub fn spectator_view(game_state: &game_types::GameState) -> SafeGameState {
+    SafeGameState::from(&game_state.personalized_for_player(String::new()))
+}
+
+/// Undelivered messages after which a connection counts as a slow consumer
+pub const DEFAULT_SLOW_CONSUMER_DEPTH: usize = 100;
+
 pub struct ConnectionManager {
     connections: RwLock<HashMap<ConnectionId, Connection>>,
     user_to_connection: RwLock<HashMap<String, ConnectionId>>,
+    send_timeout: Duration,
+    close_reasons: RwLock<HashMap<ConnectionId, CloseReason>>, // Why the server closed each socket
+    slow_consumer_depth: Option<usize>,
+    max_spectators: Option<usize>, // Per game; `None` lets anyone watch
+}
+
+impl Default for ConnectionManager {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl ConnectionManager {
@@ -97,14 +235,43 @@ impl ConnectionManager {
         Self {
             connections: RwLock::new(HashMap::new()),
             user_to_connection: RwLock::new(HashMap::new()),
+            send_timeout: DEFAULT_SEND_TIMEOUT,
+            close_reasons: RwLock::new(HashMap::new()),
+            slow_consumer_depth: Some(DEFAULT_SLOW_CONSUMER_DEPTH),
+            max_spectators: None,
         }
     }
 
+    /// Drop connections whose socket does not accept a message within `timeout`
+    pub fn with_send_timeout(mut self, timeout: Duration) -> Self {
+        self.send_timeout = timeout;
+        self
+    }
+
+    pub fn send_timeout(&self) -> Duration {
+        self.send_timeout
+    }
+
+    /// Flag connections with `depth` or more undelivered messages as slow
+    /// consumers. `None` turns the check off.
+    pub fn with_slow_consumer_depth(mut self, depth: Option<usize>) -> Self {
+        self.slow_consumer_depth = depth;
+        self
+    }
+
+    /// Turn away spectators once a game has `max` of them. `None` lets any
+    /// number watch.
+    pub fn with_max_spectators(mut self, max: Option<usize>) -> Self {
+        self.max_spectators = max;
+        self
+    }
+
     pub async fn create_connection(
         &self,
         id: ConnectionId,
     ) -> mpsc::UnboundedReceiver<ServerMessage> {
-        let (conn, receiver) = Connection::new(id);
+        let (mut conn, receiver) = Connection::new(id);
+        conn.outbound = Arc::new(OutboundQueue::new(self.slow_consumer_depth));
 
         {
             let mut connections = self.connections.write().await;
@@ -126,6 +293,34 @@ impl ConnectionManager {
         }
     }
 
+    /// Remove a connection on the server's initiative. The socket is closed
+    /// with `reason`'s close code once its pending messages are flushed.
+    pub async fn close_connection(&self, id: ConnectionId, reason: CloseReason) {
+        {
+            let mut close_reasons = self.close_reasons.write().await;
+            close_reasons.insert(id, reason);
+        }
+        self.remove_connection(id).await;
+    }
+
+    /// Close every connection, e.g. when the server is shutting down
+    pub async fn close_all(&self, reason: CloseReason) {
+        let ids: Vec<ConnectionId> = {
+            let connections = self.connections.read().await;
+            connections.keys().copied().collect()
+        };
+
+        for id in ids {
+            self.close_connection(id, reason).await;
+        }
+    }
+
+    /// Why the server closed a connection, if it did
+    pub async fn take_close_reason(&self, id: ConnectionId) -> Option<CloseReason> {
+        let mut close_reasons = self.close_reasons.write().await;
+        close_reasons.remove(&id)
+    }
+
     pub async fn get_connection(&self, id: ConnectionId) -> Option<Connection> {
         let connections = self.connections.read().await;
         connections.get(&id).cloned()
@@ -194,8 +389,9 @@ impl ConnectionManager {
                 )
                 .await;
 
-            // Remove the old connection
-            self.remove_connection(old_conn_id).await;
+            // Close the old connection
+            self.close_connection(old_conn_id, CloseReason::SessionTerminated)
+                .await;
         }
 
         // Now authenticate the new connection
@@ -257,35 +453,61 @@ impl ConnectionManager {
     pub async fn send_to_game(&self, game_id: &str, message: ServerMessage) {
         let connections = self.connections.read().await;
         for connection in connections.values() {
-            if let Some(ref conn_game_id) = connection.game_id {
-                if conn_game_id == game_id {
-                    let _ = connection.send_message(message.clone());
-                }
+            if let Some(ref conn_game_id) = connection.game_id
+                && conn_game_id == game_id
+            {
+                let _ = connection.send_message(message.clone());
             }
         }
     }
 
     /// Send personalized game state updates to each player in a game
-    /// Each player only sees their own guess history, not other players' histories
+    /// Each player only sees their own guess history, not other players' histories.
+    /// Spectators get the state as seen by nobody in particular, without the
+    /// target word, serialized once and shared by all of them.
     pub async fn send_personalized_game_state(
         &self,
         game_id: &str,
         game_state: &game_types::GameState,
     ) {
         let connections = self.connections.read().await;
+        let mut spectator_state = None;
         for connection in connections.values() {
-            if let Some(ref conn_game_id) = connection.game_id {
-                if conn_game_id == game_id {
-                    if let Some(ref user) = connection.user {
-                        // Create personalized state for this player
-                        let personalized_state =
-                            game_state.personalized_for_player(user.id.clone());
-                        let message = ServerMessage::GameStateUpdate {
-                            state: personalized_state,
-                        };
-                        let _ = connection.send_message(message);
-                    }
-                }
+            if connection.spectating.as_deref() == Some(game_id) {
+                let state = spectator_state
+                    .get_or_insert_with(|| {
+                        let state = SharedGameState::new(spectator_view(game_state));
+                        state.render();
+                        state
+                    })
+                    .clone();
+                let _ = connection.send_message(ServerMessage::SpectatorStateUpdate { state });
+                continue;
+            }
+
+            if let Some(ref conn_game_id) = connection.game_id
+                && conn_game_id == game_id
+                && let Some(ref user) = connection.user
+            {
+                // Create personalized state for this player
+                let personalized_state = game_state.personalized_for_player(user.id.clone());
+                let message = ServerMessage::GameStateUpdate {
+                    state: personalized_state,
+                };
+                let _ = connection.send_message(message);
+            }
+        }
+    }
+
+    /// Send one player's chat to everyone seated in their game, skipping
+    /// connections that muted them
+    pub async fn send_chat_to_game(&self, game_id: &str, sender_id: &str, message: ServerMessage) {
+        let connections = self.connections.read().await;
+        for connection in connections.values() {
+            if connection.game_id.as_deref() == Some(game_id)
+                && !connection.muted.contains(sender_id)
+            {
+                let _ = connection.send_message(message.clone());
             }
         }
     }
@@ -298,12 +520,11 @@ impl ConnectionManager {
     ) {
         let connections = self.connections.read().await;
         for connection in connections.values() {
-            if connection.id != except_connection {
-                if let Some(ref conn_game_id) = connection.game_id {
-                    if conn_game_id == game_id {
-                        let _ = connection.send_message(message.clone());
-                    }
-                }
+            if connection.id != except_connection
+                && let Some(ref conn_game_id) = connection.game_id
+                && conn_game_id == game_id
+            {
+                let _ = connection.send_message(message.clone());
             }
         }
     }
@@ -320,8 +541,58 @@ impl ConnectionManager {
 
         for connection_id in inactive_connections {
             tracing::info!("Removing inactive connection: {}", connection_id);
-            self.remove_connection(connection_id).await;
+            self.close_connection(connection_id, CloseReason::Kicked)
+                .await;
+        }
+    }
+
+    /// Close connections that never authenticated within `deadline`
+    pub async fn close_unauthenticated_connections(&self, deadline: Duration) {
+        let expired: Vec<ConnectionId> = {
+            let connections = self.connections.read().await;
+            connections
+                .values()
+                .filter(|conn| conn.missed_auth_deadline(deadline))
+                .map(|conn| conn.id)
+                .collect()
+        };
+
+        for connection_id in expired {
+            tracing::info!(
+                "Closing connection {} that did not authenticate within {:?}",
+                connection_id,
+                deadline
+            );
+            self.close_connection(connection_id, CloseReason::AuthTimeout)
+                .await;
+        }
+    }
+
+    /// The queue a connection's socket writer reports deliveries to
+    pub async fn outbound_queue(&self, id: ConnectionId) -> Option<Arc<OutboundQueue>> {
+        let connections = self.connections.read().await;
+        connections.get(&id).map(|conn| conn.outbound.clone())
+    }
+
+    /// Aggregate outbound queue depth, latency and slow consumers
+    pub async fn outbound_stats(&self) -> OutboundStats {
+        let connections = self.connections.read().await;
+        let mut stats = OutboundStats {
+            connections: connections.len(),
+            ..Default::default()
+        };
+        for connection in connections.values() {
+            let depth = connection.outbound.depth();
+            stats.queued_messages += depth;
+            stats.max_queue_depth = stats.max_queue_depth.max(depth);
+            stats.max_latency_ms = stats
+                .max_latency_ms
+                .max(connection.outbound.last_latency().as_millis() as u64);
+            if connection.outbound.is_slow() {
+                stats.slow_consumers += 1;
+            }
         }
+        stats
     }
 
     pub async fn set_connection_game(&self, id: ConnectionId, game_id: Option<String>) {
@@ -331,6 +602,61 @@ impl ConnectionManager {
         }
     }
 
+    /// Watch `game_id` from this connection, unless the game already has as
+    /// many spectators as allowed. The error is that limit.
+    pub async fn start_spectating(&self, id: ConnectionId, game_id: &str) -> Result<(), usize> {
+        let mut connections = self.connections.write().await;
+        if let Some(max) = self.max_spectators {
+            let spectators = connections
+                .values()
+                .filter(|connection| {
+                    connection.id != id && connection.spectating.as_deref() == Some(game_id)
+                })
+                .count();
+            if spectators >= max {
+                return Err(max);
+            }
+        }
+        if let Some(connection) = connections.get_mut(&id) {
+            connection.spectating = Some(game_id.to_string());
+        }
+        Ok(())
+    }
+
+    /// Detach every spectator of `game_id` once the game is over or gone,
+    /// returning the connections that were watching
+    pub async fn stop_spectating_game(&self, game_id: &str) -> Vec<ConnectionId> {
+        let mut connections = self.connections.write().await;
+        let mut spectators = Vec::new();
+        for connection in connections.values_mut() {
+            if connection.spectating.as_deref() == Some(game_id) {
+                connection.spectating = None;
+                spectators.push(connection.id);
+            }
+        }
+        spectators
+    }
+
+    /// Mute or unmute `user_id` for this connection only; other players
+    /// still receive their chat
+    pub async fn set_muted(&self, id: ConnectionId, user_id: &str, muted: bool) {
+        let mut connections = self.connections.write().await;
+        if let Some(connection) = connections.get_mut(&id) {
+            if muted {
+                connection.muted.insert(user_id.to_string());
+            } else {
+                connection.muted.remove(user_id);
+            }
+        }
+    }
+
+    pub async fn is_spectating(&self, id: ConnectionId, game_id: &str) -> bool {
+        let connections = self.connections.read().await;
+        connections
+            .get(&id)
+            .is_some_and(|connection| connection.spectating.as_deref() == Some(game_id))
+    }
+
     pub async fn get_connections_in_game(&self, game_id: &str) -> Vec<ConnectionId> {
         let connections = self.connections.read().await;
         connections
@@ -348,10 +674,10 @@ impl ConnectionManager {
 
     pub async fn set_connection_user(&self, id: ConnectionId, user: Option<User>) {
         let mut connections = self.connections.write().await;
-        if let Some(connection) = connections.get_mut(&id) {
-            if let Some(user) = user {
-                connection.set_user(user);
-            }
+        if let Some(connection) = connections.get_mut(&id)
+            && let Some(user) = user
+        {
+            connection.set_user(user);
         }
     }
 
@@ -471,6 +797,40 @@ mod tests {
         assert_eq!(manager.connection_count().await, 0);
     }
 
+    #[tokio::test]
+    async fn test_auth_deadline_ignores_heartbeats() {
+        let manager = ConnectionManager::new();
+        let anonymous = ConnectionId::new();
+        let authenticated = ConnectionId::new();
+
+        let _receiver1 = manager.create_connection(anonymous).await;
+        let _receiver2 = manager.create_connection(authenticated).await;
+        manager
+            .authenticate_connection(authenticated, "user1".to_string())
+            .await
+            .unwrap();
+
+        let deadline = Duration::from_millis(30);
+        for _ in 0..4 {
+            // Heartbeats keep both connections active
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            manager.update_activity(anonymous).await;
+            manager.update_activity(authenticated).await;
+        }
+        manager
+            .cleanup_inactive_connections(Duration::from_millis(30))
+            .await;
+        assert_eq!(manager.connection_count().await, 2);
+
+        manager.close_unauthenticated_connections(deadline).await;
+        assert!(manager.get_connection(anonymous).await.is_none());
+        assert!(manager.get_connection(authenticated).await.is_some());
+        assert_eq!(
+            manager.take_close_reason(anonymous).await,
+            Some(CloseReason::AuthTimeout)
+        );
+    }
+
     #[tokio::test]
     async fn test_message_sending_to_nonexistent_connection() {
         let manager = ConnectionManager::new();
@@ -481,6 +841,8 @@ mod tests {
                 conn_id,
                 game_types::ServerMessage::Error {
                     message: "test".to_string(),
+                    code: None,
+                    prior_guess: None,
                 },
             )
             .await;
@@ -502,6 +864,8 @@ mod tests {
                 conn_id,
                 game_types::ServerMessage::Error {
                     message: "test".to_string(),
+                    code: None,
+                    prior_guess: None,
                 },
             )
             .await;
@@ -531,6 +895,8 @@ mod tests {
         // Send message to game
         let test_message = game_types::ServerMessage::Error {
             message: "game_message".to_string(),
+            code: None,
+            prior_guess: None,
         };
         manager.send_to_game(game_id, test_message).await;
 
@@ -575,4 +941,38 @@ mod tests {
         assert_eq!(manager.connection_count().await, 0);
         assert_eq!(manager.user_connection_count().await, 0);
     }
+
+    #[tokio::test]
+    async fn test_non_reading_connection_flagged_as_slow_consumer() {
+        let manager = ConnectionManager::new().with_slow_consumer_depth(Some(3));
+        let (slow, fast) = (ConnectionId::new(), ConnectionId::new());
+        let _slow_receiver = manager.create_connection(slow).await;
+        let mut fast_receiver = manager.create_connection(fast).await;
+        let fast_queue = manager.outbound_queue(fast).await.unwrap();
+
+        for _ in 0..3 {
+            for id in [slow, fast] {
+                manager
+                    .send_to_connection(id, ServerMessage::QueueLeft)
+                    .await
+                    .unwrap();
+            }
+            // Only the fast connection's writer keeps up
+            fast_receiver.try_recv().unwrap();
+            assert!(fast_queue.delivered().is_some());
+        }
+
+        let stats = manager.outbound_stats().await;
+        assert_eq!(stats.connections, 2);
+        assert_eq!(stats.slow_consumers, 1);
+        assert_eq!(stats.queued_messages, 3);
+        assert_eq!(stats.max_queue_depth, 3);
+        assert!(manager.outbound_queue(slow).await.unwrap().is_slow());
+        assert!(!fast_queue.is_slow());
+
+        // Catching up clears the flag
+        let slow_queue = manager.outbound_queue(slow).await.unwrap();
+        while slow_queue.delivered().is_some() {}
+        assert_eq!(manager.outbound_stats().await.slow_consumers, 0);
+    }
 }
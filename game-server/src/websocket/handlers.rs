@@ -2,10 +2,28 @@ use std::sync::Arc;
 use tracing::{error, info, warn};
 
 use crate::auth::AuthService;
-use crate::game_manager::GameManager;
-use crate::matchmaking::MatchmakingQueue;
-use crate::websocket::connection::{ConnectionId, ConnectionManager};
-use game_types::{ClientMessage, ServerMessage};
+use crate::game_manager::{
+    GameEvent, GameManager, RoundTimer, SERVER_BUSY_RETRY_SECONDS, error_code,
+};
+use crate::lobby::{Lobby, LobbyDeparture};
+use crate::matchmaker::announce_match;
+use crate::matchmaking::{MatchmakingQueue, QueueError};
+use crate::websocket::close::CloseReason;
+use crate::websocket::connection::{ConnectionId, ConnectionManager, spectator_view};
+use game_core::GameError;
+use game_persistence::repositories::abuse_repository::AbuseKind;
+use game_persistence::repositories::user_repository::DEFAULT_RATING;
+use game_persistence::repositories::{UserRepository, WordReportRepository};
+use game_types::{
+    Avatar, AvatarColor, ClientMessage, MIN_PROTOCOL_VERSION, PROTOCOL_VERSION, PlayerId, Role,
+    ServerMessage, SharedGameState, User,
+};
+
+/// Word reports a player may file per hour
+const MAX_WORD_REPORTS_PER_HOUR: u64 = 5;
+const MAX_REPORT_REASON_CHARS: usize = 280;
+const MAX_LOBBY_ANNOUNCEMENT_CHARS: usize = 200;
+const MAX_CHAT_MESSAGE_CHARS: usize = 200;
 
 #[derive(Clone)]
 pub struct MessageHandler {
@@ -14,6 +32,8 @@ pub struct MessageHandler {
     game_manager: Arc<GameManager>,
     matchmaking_queue: Arc<MatchmakingQueue>,
     auth_service: Arc<AuthService>,
+    user_repository: Arc<UserRepository>,
+    word_report_repository: Arc<WordReportRepository>,
 }
 
 impl MessageHandler {
@@ -23,6 +43,8 @@ impl MessageHandler {
         game_manager: Arc<GameManager>,
         matchmaking_queue: Arc<MatchmakingQueue>,
         auth_service: Arc<AuthService>,
+        user_repository: Arc<UserRepository>,
+        word_report_repository: Arc<WordReportRepository>,
     ) -> Self {
         Self {
             connection_id,
@@ -30,6 +52,8 @@ impl MessageHandler {
             game_manager,
             matchmaking_queue,
             auth_service,
+            user_repository,
+            word_report_repository,
         }
     }
 
@@ -40,7 +64,10 @@ impl MessageHandler {
             .await;
 
         match message {
-            ClientMessage::Authenticate { token } => self.handle_authenticate(token).await,
+            ClientMessage::Hello { protocol_version } => self.handle_hello(protocol_version).await,
+            ClientMessage::Authenticate { token, auto_queue } => {
+                self.handle_authenticate(token, auto_queue).await
+            }
             ClientMessage::ForceAuthenticate { token } => {
                 self.handle_force_authenticate(token).await
             }
@@ -48,8 +75,39 @@ impl MessageHandler {
             ClientMessage::LeaveQueue => self.handle_leave_queue().await,
             ClientMessage::VoteStartGame => self.handle_vote_start_game().await,
             ClientMessage::SubmitGuess { word } => self.handle_submit_guess(word).await,
+            ClientMessage::SkipTurn => self.handle_skip_turn().await,
+            ClientMessage::VoteSkipRound => self.handle_vote_skip_round().await,
+            ClientMessage::RequestHint => self.handle_request_hint().await,
             ClientMessage::LeaveGame => self.handle_leave_game().await,
-            ClientMessage::RejoinGame { game_id } => self.handle_rejoin_game(game_id).await,
+            ClientMessage::RejoinGame { game_id, token } => {
+                self.handle_rejoin_game(game_id, token).await
+            }
+            ClientMessage::Ready => self.handle_ready().await,
+            ClientMessage::SetAvatar { color, emoji } => self.handle_set_avatar(color, emoji).await,
+            ClientMessage::ReportWord { reason } => self.handle_report_word(reason).await,
+            ClientMessage::StartPracticeWord { word } => {
+                self.handle_start_practice_word(word).await
+            }
+            ClientMessage::SubscribeQueueStatus => self.handle_subscribe_queue_status().await,
+            ClientMessage::UnsubscribeQueueStatus => {
+                self.matchmaking_queue
+                    .unsubscribe_status(self.connection_id)
+                    .await;
+                Ok(())
+            }
+            ClientMessage::SpectateGame { game_id } => self.handle_spectate_game(game_id).await,
+            ClientMessage::CreateLobby => self.handle_create_lobby().await,
+            ClientMessage::JoinLobby { code } => self.handle_join_lobby(code).await,
+            ClientMessage::LeaveLobby => self.handle_leave_lobby().await,
+            ClientMessage::StartLobby => self.handle_start_lobby().await,
+            ClientMessage::SetLobbyAnnouncement { text } => {
+                self.handle_set_lobby_announcement(text).await
+            }
+            ClientMessage::SendChat { text } => self.handle_send_chat(text).await,
+            ClientMessage::MutePlayer { player_id } => self.handle_set_muted(player_id, true).await,
+            ClientMessage::UnmutePlayer { player_id } => {
+                self.handle_set_muted(player_id, false).await
+            }
             ClientMessage::Heartbeat => self.handle_heartbeat().await,
         }
     }
@@ -57,45 +115,106 @@ impl MessageHandler {
     pub async fn handle_disconnect(&self) {
         info!("Handling disconnect for connection {}", self.connection_id);
 
-        // Remove from queue if present
-        if let Err(e) = self
+        let connection = self
+            .connection_manager
+            .get_connection(self.connection_id)
+            .await;
+
+        self.matchmaking_queue
+            .unsubscribe_status(self.connection_id)
+            .await;
+        self.auth_service
+            .release_connection(self.connection_id)
+            .await;
+
+        // A dropped socket is not a decision to leave: hold the player's
+        // queue place in case they reconnect. `LeaveQueue` removes for good.
+        let user_id = connection.as_ref().and_then(|c| c.user_id.as_ref());
+        match self
             .matchmaking_queue
-            .remove_player(self.connection_id)
+            .hold_player(self.connection_id, user_id)
             .await
         {
-            // It's okay if they weren't in queue
-            info!(
-                "Player {} not in queue during disconnect: {}",
-                self.connection_id, e
-            );
+            Ok(()) => self.broadcast_queue_status().await,
+            Err(e) => {
+                // It's okay if they weren't in queue
+                info!(
+                    "Player {} not in queue during disconnect: {}",
+                    self.connection_id, e
+                );
+            }
         }
 
-        // Handle game disconnect if in a game
-        if let Some(connection) = self
-            .connection_manager
-            .get_connection(self.connection_id)
+        // Lobbies hold no places, so a dropped member just leaves
+        if let Ok(departure) = self
+            .matchmaking_queue
+            .lobbies()
+            .leave(self.connection_id)
             .await
         {
-            if let Some(game_id) = connection.game_id {
-                if let Err(e) = self
-                    .game_manager
-                    .handle_player_disconnect(&game_id, self.connection_id)
+            self.announce_lobby_departure(departure).await;
+        }
+
+        // Handle game disconnect if in a game
+        if let Some(connection) = connection
+            && let Some(game_id) = connection.game_id
+        {
+            match self
+                .game_manager
+                .handle_player_disconnect(&game_id, self.connection_id)
+                .await
+            {
+                // The player's individual guess passed to someone else
+                Ok(Some(event)) => {
+                    publish_game_event(
+                        &self.connection_manager,
+                        &self.game_manager,
+                        &self.user_repository,
+                        &game_id,
+                        event,
+                    )
                     .await
-                {
-                    error!(
-                        "Failed to handle game disconnect for {}: {}",
-                        self.connection_id, e
-                    );
                 }
+                Ok(None) => {}
+                Err(e) => error!(
+                    "Failed to handle game disconnect for {}: {}",
+                    self.connection_id, e
+                ),
             }
         }
     }
 
-    async fn handle_authenticate(&self, token: String) -> Result<(), String> {
+    /// Count a connection closed for flooding against its user, so the
+    /// penalty follows them to their next connection
+    pub async fn handle_rate_limited(&self) {
+        if let Some(user_id) = self
+            .connection_manager
+            .get_connection(self.connection_id)
+            .await
+            .and_then(|connection| connection.user_id)
+        {
+            self.matchmaking_queue
+                .record_abuse(&user_id, AbuseKind::RateLimited)
+                .await;
+        }
+    }
+
+    async fn handle_authenticate(&self, token: String, auto_queue: bool) -> Result<(), String> {
         info!("Authenticating connection {}", self.connection_id);
 
-        match self.auth_service.validate_token(&token).await {
+        match self
+            .auth_service
+            .validate_connection_token(&token, self.connection_id)
+            .await
+        {
             Ok(user) => {
+                let user = self.with_stored_profile(user).await;
+                if let Some(reason) = self.ban_reason(&user.id).await {
+                    return self
+                        .send_message(ServerMessage::AuthenticationFailed { reason })
+                        .await;
+                }
+
                 // Check if user already has an active session
                 if self
                     .connection_manager
@@ -112,8 +231,19 @@ impl MessageHandler {
                 self.connection_manager
                     .set_connection_user(self.connection_id, Some(user.clone()))
                     .await;
+                info!(
+                    connection_id = %self.connection_id,
+                    user_id = %user.id,
+                    "Connection authenticated"
+                );
                 self.send_message(ServerMessage::AuthenticationSuccess { user })
-                    .await
+                    .await?;
+
+                // Clients reconnecting after a server restart go straight back in line
+                if auto_queue {
+                    self.handle_join_queue().await?;
+                }
+                Ok(())
             }
             Err(e) => {
                 warn!(
@@ -128,11 +258,35 @@ impl MessageHandler {
         }
     }
 
+    /// Why a user banned for repeated abuse may not authenticate, if they are
+    async fn ban_reason(&self, user_id: &PlayerId) -> Option<String> {
+        let remaining = self.matchmaking_queue.ban_remaining(user_id).await?;
+        warn!(
+            "Refusing authentication for banned user {} on connection {}",
+            user_id, self.connection_id
+        );
+        Some(format!(
+            "Temporarily banned, try again in {} seconds",
+            remaining.as_secs_f64().ceil() as u32
+        ))
+    }
+
     async fn handle_force_authenticate(&self, token: String) -> Result<(), String> {
         info!("Force authenticating connection {}", self.connection_id);
 
-        match self.auth_service.validate_token(&token).await {
+        match self
+            .auth_service
+            .validate_connection_token(&token, self.connection_id)
+            .await
+        {
             Ok(user) => {
+                let user = self.with_stored_profile(user).await;
+                if let Some(reason) = self.ban_reason(&user.id).await {
+                    return self
+                        .send_message(ServerMessage::AuthenticationFailed { reason })
+                        .await;
+                }
+
                 // Force disconnect existing session and authenticate this one
                 match self
                     .connection_manager
@@ -147,6 +301,11 @@ impl MessageHandler {
                         self.connection_manager
                             .set_connection_user(self.connection_id, Some(user.clone()))
                             .await;
+                        info!(
+                            connection_id = %self.connection_id,
+                            user_id = %user.id,
+                            "Connection force authenticated"
+                        );
                         self.send_message(ServerMessage::AuthenticationSuccess { user })
                             .await
                     }
@@ -187,75 +346,796 @@ impl MessageHandler {
                 .await;
         }
 
+        // Players who recently abandoned a live game sit out for a while
+        if let Some(user_id) = &connection.user_id
+            && let Some(remaining) = self.matchmaking_queue.cooldown_remaining(user_id).await
+        {
+            return self
+                .send_message(ServerMessage::QueueCooldown {
+                    retry_after: remaining.as_secs_f64().ceil() as u32,
+                })
+                .await;
+        }
+
         // Check if already in a game
         if let Some(connection) = self
             .connection_manager
             .get_connection(self.connection_id)
             .await
+            && connection.game_id.is_some()
         {
-            if connection.game_id.is_some() {
-                return self.send_error("Already in a game").await;
-            }
+            return self.send_error("Already in a game").await;
+        }
+        if self
+            .matchmaking_queue
+            .lobbies()
+            .lobby_of(self.connection_id)
+            .await
+            .is_some()
+        {
+            return self
+                .send_error("Leave the lobby before joining the queue")
+                .await;
         }
 
-        // Add to queue
-        match self.matchmaking_queue.add_player(self.connection_id).await {
+        // Add to queue, restoring a place held from a dropped connection
+        let added = match &connection.user_id {
+            Some(user_id) => {
+                // Players who haven't finished a game yet start at the default
+                let rating = match self.user_repository.get_rating(user_id).await {
+                    Ok(rating) => Some(rating.unwrap_or(DEFAULT_RATING)),
+                    Err(e) => {
+                        warn!("Failed to look up rating for {}: {}", user_id, e);
+                        None
+                    }
+                };
+                self.matchmaking_queue
+                    .add_user(self.connection_id, user_id, rating)
+                    .await
+            }
+            None => self.matchmaking_queue.add_player(self.connection_id).await,
+        };
+        match added {
             Ok(position) => {
                 self.send_message(ServerMessage::QueueJoined { position })
                     .await?;
 
-                // Broadcast countdown info to all players if countdown is active
-                self.broadcast_countdown_to_queue().await;
+                // Broadcast countdown info to all players if countdown is active
+                self.broadcast_countdown_to_queue().await;
+                self.broadcast_queue_status().await;
+
+                Ok(())
+            }
+            Err(QueueError::QueueFull { retry_after }) => {
+                self.send_message(ServerMessage::QueueFull { retry_after })
+                    .await
+            }
+            Err(e) => {
+                self.send_error(&format!("Failed to join queue: {}", e))
+                    .await
+            }
+        }
+    }
+
+    async fn handle_leave_queue(&self) -> Result<(), String> {
+        info!("Player {} leaving queue", self.connection_id);
+
+        // Leaving on purpose gives up any place held from a dropped connection
+        if let Some(connection) = self
+            .connection_manager
+            .get_connection(self.connection_id)
+            .await
+            && let Some(user_id) = &connection.user_id
+        {
+            self.matchmaking_queue.release_hold(user_id).await;
+        }
+
+        match self
+            .matchmaking_queue
+            .remove_player(self.connection_id)
+            .await
+        {
+            Ok(_) => {
+                self.send_message(ServerMessage::QueueLeft).await?;
+                self.broadcast_queue_status().await;
+                Ok(())
+            }
+            Err(e) => {
+                self.send_error(&format!("Failed to leave queue: {}", e))
+                    .await
+            }
+        }
+    }
+
+    /// Follow the queue like a lobby display: current size and countdown
+    /// now, then updates as they happen, without joining the queue
+    async fn handle_subscribe_queue_status(&self) -> Result<(), String> {
+        info!("Connection {} watching queue status", self.connection_id);
+        self.matchmaking_queue
+            .subscribe_status(self.connection_id)
+            .await;
+
+        let players_in_queue = self.matchmaking_queue.get_queue_length().await as u32;
+        self.send_message(ServerMessage::QueueStatus { players_in_queue })
+            .await?;
+        if let Some(countdown_info) = self.matchmaking_queue.get_countdown_info().await {
+            self.send_message(ServerMessage::MatchmakingCountdown {
+                seconds_remaining: countdown_info.seconds_remaining,
+                players_ready: countdown_info.players_ready,
+                total_players: countdown_info.total_players,
+                rating_window: countdown_info.rating_window,
+            })
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// Watch a game without taking part: the current state now, then every
+    /// state update the players get, minus anything that would help them
+    async fn handle_spectate_game(&self, game_id: String) -> Result<(), String> {
+        let connection = self
+            .connection_manager
+            .get_connection(self.connection_id)
+            .await
+            .ok_or("Connection not found")?;
+
+        if !connection.is_authenticated {
+            return self.send_error("Authentication required to spectate").await;
+        }
+        if connection.game_id.is_some() {
+            return self.send_error("Already in a game").await;
+        }
+
+        let Some(state) = self.game_manager.get_game_state(&game_id).await else {
+            return self
+                .send_game_error("Game not found", &GameError::GameNotFound)
+                .await;
+        };
+        if !self.game_manager.is_spectatable(&game_id).await {
+            return self.send_error("This game can't be spectated").await;
+        }
+
+        if let Err(max_spectators) = self
+            .connection_manager
+            .start_spectating(self.connection_id, &game_id)
+            .await
+        {
+            return self
+                .send_message(ServerMessage::SpectatorsFull {
+                    max_spectators: max_spectators as u32,
+                })
+                .await;
+        }
+        info!(
+            "Connection {} spectating game {}",
+            self.connection_id, game_id
+        );
+        self.send_message(ServerMessage::SpectatorStateUpdate {
+            state: SharedGameState::new(spectator_view(&state)),
+        })
+        .await
+    }
+
+    /// Open a private lobby and hand its code back to the host to share
+    async fn handle_create_lobby(&self) -> Result<(), String> {
+        if let Err(reason) = self.check_can_enter_lobby().await {
+            return self.send_error(reason).await;
+        }
+
+        let settings = self.matchmaking_queue.lobby_settings();
+        match self
+            .matchmaking_queue
+            .lobbies()
+            .create(self.connection_id, settings)
+            .await
+        {
+            Ok(lobby) => {
+                self.broadcast_lobby_update(&lobby).await;
+                Ok(())
+            }
+            Err(e) => {
+                self.send_error(&format!("Failed to create lobby: {}", e))
+                    .await
+            }
+        }
+    }
+
+    async fn handle_join_lobby(&self, code: String) -> Result<(), String> {
+        if let Err(reason) = self.check_can_enter_lobby().await {
+            return self.send_error(reason).await;
+        }
+
+        match self
+            .matchmaking_queue
+            .lobbies()
+            .join(&code, self.connection_id)
+            .await
+        {
+            Ok(lobby) => {
+                info!("Player {} joined lobby {}", self.connection_id, lobby.code);
+                self.broadcast_lobby_update(&lobby).await;
+                if let Some(text) = lobby.announcement {
+                    self.send_message(ServerMessage::LobbyAnnouncement { text: Some(text) })
+                        .await?;
+                }
+                Ok(())
+            }
+            Err(e) => {
+                self.send_error(&format!("Failed to join lobby: {}", e))
+                    .await
+            }
+        }
+    }
+
+    async fn handle_leave_lobby(&self) -> Result<(), String> {
+        match self
+            .matchmaking_queue
+            .lobbies()
+            .leave(self.connection_id)
+            .await
+        {
+            Ok(departure) => {
+                self.send_message(ServerMessage::LobbyLeft).await?;
+                self.announce_lobby_departure(departure).await;
+                Ok(())
+            }
+            Err(e) => {
+                self.send_error(&format!("Failed to leave lobby: {}", e))
+                    .await
+            }
+        }
+    }
+
+    /// Show every lobby member the host's message. Announcements are checked
+    /// like anything else players see from one another.
+    async fn handle_set_lobby_announcement(&self, text: String) -> Result<(), String> {
+        let text = text.trim();
+        if text.chars().count() > MAX_LOBBY_ANNOUNCEMENT_CHARS {
+            return self
+                .send_error("Lobby announcement must be at most 200 characters")
+                .await;
+        }
+        if self.game_manager.contains_blocked_word(text) {
+            return self
+                .send_error("Lobby announcement contains a word that isn't allowed")
+                .await;
+        }
+
+        let announcement = (!text.is_empty()).then(|| text.to_string());
+        match self
+            .matchmaking_queue
+            .lobbies()
+            .set_announcement(self.connection_id, announcement.clone())
+            .await
+        {
+            Ok(lobby) => {
+                let message = ServerMessage::LobbyAnnouncement { text: announcement };
+                for &member in &lobby.members {
+                    let _ = self
+                        .connection_manager
+                        .send_to_connection(member, message.clone())
+                        .await;
+                }
+                Ok(())
+            }
+            Err(e) => {
+                self.send_error(&format!("Failed to set lobby announcement: {}", e))
+                    .await
+            }
+        }
+    }
+
+    /// Start a game with everyone in the host's lobby. The lobby is closed
+    /// up front so it can only start once, and reopened if the game can't
+    /// be created or a member can't be told about it.
+    async fn handle_start_lobby(&self) -> Result<(), String> {
+        let lobby = match self
+            .matchmaking_queue
+            .lobbies()
+            .take_startable(self.connection_id)
+            .await
+        {
+            Ok(lobby) => lobby,
+            Err(e) => {
+                return self
+                    .send_error(&format!("Failed to start lobby: {}", e))
+                    .await;
+            }
+        };
+
+        if self.game_manager.at_capacity().await {
+            self.restore_lobby(lobby).await;
+            return self
+                .send_message(ServerMessage::ServerBusy {
+                    retry_after: SERVER_BUSY_RETRY_SECONDS,
+                })
+                .await;
+        }
+
+        let game_id = match self
+            .game_manager
+            .create_private_game(lobby.members.clone(), lobby.settings.point_threshold)
+            .await
+        {
+            Ok(game_id) => game_id,
+            Err(e) => {
+                error!("Failed to create game for lobby {}: {}", lobby.code, e);
+                self.restore_lobby(lobby).await;
+                return self
+                    .send_error(&format!("Could not start the game: {}", e))
+                    .await;
+            }
+        };
+
+        if let Err(notified) = announce_match(
+            &self.connection_manager,
+            &self.game_manager,
+            &game_id,
+            &lobby.members,
+        )
+        .await
+        {
+            warn!(
+                "Calling off lobby {} game {}: only {} players were notified",
+                lobby.code,
+                game_id,
+                notified.len()
+            );
+            self.game_manager.discard_game(&game_id).await;
+            for &player_id in &notified {
+                let _ = self
+                    .connection_manager
+                    .send_to_connection(player_id, ServerMessage::GameLeft)
+                    .await;
+            }
+            self.restore_lobby(lobby).await;
+            return Ok(());
+        }
+
+        info!(
+            "Started game {} from lobby {} with {} players",
+            game_id,
+            lobby.code,
+            lobby.members.len()
+        );
+        Ok(())
+    }
+
+    /// Reopen a lobby whose game fell through, for the members still
+    /// connected, and show them where it stands
+    async fn restore_lobby(&self, mut lobby: Lobby) {
+        let mut connected = Vec::new();
+        for &member in &lobby.members {
+            if self
+                .connection_manager
+                .get_connection(member)
+                .await
+                .is_some()
+            {
+                connected.push(member);
+            }
+        }
+        lobby.members = connected;
+
+        if let Some(lobby) = self.matchmaking_queue.lobbies().restore(lobby).await {
+            self.broadcast_lobby_update(&lobby).await;
+        }
+    }
+
+    /// Lobbies are for players who aren't already playing or queueing
+    async fn check_can_enter_lobby(&self) -> Result<(), &'static str> {
+        let connection = self
+            .connection_manager
+            .get_connection(self.connection_id)
+            .await
+            .ok_or("Connection not found")?;
+
+        if !connection.is_authenticated {
+            return Err("Authentication required to use lobbies");
+        }
+        if connection.game_id.is_some() {
+            return Err("Already in a game");
+        }
+        if self
+            .matchmaking_queue
+            .get_queue_position(self.connection_id)
+            .await
+            .is_some()
+        {
+            return Err("Leave the queue before joining a lobby");
+        }
+        Ok(())
+    }
+
+    async fn handle_submit_guess(&self, word: String) -> Result<(), String> {
+        info!("Player {} submitting guess: {}", self.connection_id, word);
+
+        // Blank submissions are client slips; answer before any lookups
+        if word.trim().is_empty() {
+            return self
+                .send_game_error("Guess cannot be empty", &GameError::EmptyGuess)
+                .await;
+        }
+
+        // Get connection to find game
+        let connection = self
+            .connection_manager
+            .get_connection(self.connection_id)
+            .await
+            .ok_or("Connection not found")?;
+
+        // Spectators are turned away by the game itself
+        let game_id = connection
+            .game_id
+            .or(connection.spectating)
+            .ok_or("Not in a game")?;
+
+        // Submit guess to game manager
+        match self
+            .game_manager
+            .submit_guess(&game_id, self.connection_id, word)
+            .await
+        {
+            Ok(game_event) => {
+                self.game_manager.metrics().record_guess();
+                // Handle the game event and send appropriate messages
+                self.handle_game_event(&game_id, game_event).await
+            }
+            Err(GameError::GuessTooFast { retry_after_ms }) => {
+                self.send_message(ServerMessage::GuessCooldown {
+                    retry_after_ms: retry_after_ms as u32,
+                })
+                .await
+            }
+            Err(e @ GameError::SpectatorCannotGuess) => {
+                self.send_game_error(&e.to_string(), &e).await
+            }
+            Err(e) => {
+                self.send_game_error(&format!("Invalid guess: {}", e), &e)
+                    .await
+            }
+        }
+    }
+
+    async fn handle_ready(&self) -> Result<(), String> {
+        let connection = self
+            .connection_manager
+            .get_connection(self.connection_id)
+            .await
+            .ok_or("Connection not found")?;
+
+        let game_id = connection.game_id.ok_or("Not in a game")?;
+
+        match self
+            .game_manager
+            .mark_player_ready(&game_id, self.connection_id)
+            .await
+        {
+            Ok(update) => {
+                self.connection_manager
+                    .send_to_game(
+                        &game_id,
+                        ServerMessage::PlayerReady {
+                            player_id: update.player_id,
+                        },
+                    )
+                    .await;
+
+                if update.game_started {
+                    self.connection_manager
+                        .send_personalized_game_state(&game_id, &update.state)
+                        .await;
+                }
+                Ok(())
+            }
+            Err(e) => {
+                self.send_game_error(&format!("Failed to ready up: {}", e), &e)
+                    .await
+            }
+        }
+    }
+
+    async fn handle_skip_turn(&self) -> Result<(), String> {
+        let connection = self
+            .connection_manager
+            .get_connection(self.connection_id)
+            .await
+            .ok_or("Connection not found")?;
+
+        let game_id = connection.game_id.ok_or("Not in a game")?;
+
+        match self
+            .game_manager
+            .skip_turn(&game_id, self.connection_id)
+            .await
+        {
+            Ok(state) => {
+                self.connection_manager
+                    .send_personalized_game_state(&game_id, &state)
+                    .await;
+                Ok(())
+            }
+            Err(e) => {
+                self.send_game_error(&format!("Failed to skip turn: {}", e), &e)
+                    .await
+            }
+        }
+    }
+
+    async fn handle_vote_skip_round(&self) -> Result<(), String> {
+        let connection = self
+            .connection_manager
+            .get_connection(self.connection_id)
+            .await
+            .ok_or("Connection not found")?;
+
+        let game_id = connection.game_id.ok_or("Not in a game")?;
+
+        match self
+            .game_manager
+            .vote_skip_round(&game_id, self.connection_id)
+            .await
+        {
+            Ok(update) => {
+                self.connection_manager
+                    .send_to_game(
+                        &game_id,
+                        ServerMessage::SkipRoundVote {
+                            votes: update.votes,
+                            needed: update.needed,
+                        },
+                    )
+                    .await;
+
+                if let Some(word) = update.skipped_word {
+                    self.connection_manager
+                        .send_to_game(
+                            &game_id,
+                            ServerMessage::RoundSkipped {
+                                word,
+                                next_round: update.state.current_round as u32,
+                            },
+                        )
+                        .await;
+                    self.connection_manager
+                        .send_personalized_game_state(&game_id, &update.state)
+                        .await;
+                }
+                Ok(())
+            }
+            Err(e) => {
+                self.send_game_error(&format!("Failed to vote to skip round: {}", e), &e)
+                    .await
+            }
+        }
+    }
+
+    async fn handle_request_hint(&self) -> Result<(), String> {
+        let connection = self
+            .connection_manager
+            .get_connection(self.connection_id)
+            .await
+            .ok_or("Connection not found")?;
+
+        let game_id = connection.game_id.ok_or("Not in a game")?;
+
+        match self
+            .game_manager
+            .request_hint(&game_id, self.connection_id)
+            .await
+        {
+            Ok(update) => {
+                self.send_message(ServerMessage::HintRevealed {
+                    letter: update.letter,
+                    points_deducted: update.points_deducted,
+                })
+                .await?;
+
+                // The penalty changed a score everyone can see
+                if update.points_deducted > 0 {
+                    self.connection_manager
+                        .send_personalized_game_state(&game_id, &update.state)
+                        .await;
+                }
+                Ok(())
+            }
+            Err(e) => {
+                self.send_game_error(&format!("Failed to get a hint: {}", e), &e)
+                    .await
+            }
+        }
+    }
+
+    async fn handle_set_avatar(&self, color: AvatarColor, emoji: String) -> Result<(), String> {
+        let connection = self
+            .connection_manager
+            .get_connection(self.connection_id)
+            .await
+            .ok_or("Connection not found")?;
+
+        let Some(mut user) = connection.user else {
+            return self
+                .send_error("Must be authenticated to set an avatar")
+                .await;
+        };
+
+        let Some(avatar) = Avatar::new(color, &emoji) else {
+            return self.send_error("Unsupported avatar emoji").await;
+        };
+
+        // Players who have never finished a game have no stored profile yet
+        let stored = match self.user_repository.find_by_id(&user.id).await {
+            Ok(Some(_)) => self.user_repository.update_avatar(&user.id, &avatar).await,
+            Ok(None) => {
+                let mut profile = user.clone();
+                profile.avatar = Some(avatar.clone());
+                self.user_repository.create_user(profile).await.map(|_| ())
+            }
+            Err(e) => Err(e),
+        };
+        if let Err(e) = stored {
+            error!("Failed to save avatar for user {}: {}", user.id, e);
+            return self.send_error("Failed to save avatar").await;
+        }
+
+        user.avatar = Some(avatar.clone());
+        self.connection_manager
+            .set_connection_user(self.connection_id, Some(user))
+            .await;
+        self.send_message(ServerMessage::AvatarUpdated { avatar })
+            .await
+    }
+
+    async fn handle_report_word(&self, reason: String) -> Result<(), String> {
+        let connection = self
+            .connection_manager
+            .get_connection(self.connection_id)
+            .await
+            .ok_or("Connection not found")?;
+
+        let Some(user) = connection.user else {
+            return self
+                .send_error("Must be authenticated to report a word")
+                .await;
+        };
+        let Some(game_id) = connection.game_id else {
+            return self.send_error("Not in a game").await;
+        };
+
+        let reason = reason.trim();
+        if reason.is_empty() || reason.chars().count() > MAX_REPORT_REASON_CHARS {
+            return self
+                .send_error("Report reason must be between 1 and 280 characters")
+                .await;
+        }
 
-                Ok(())
+        let since = chrono::Utc::now() - chrono::Duration::hours(1);
+        match self
+            .word_report_repository
+            .count_reports_since(&user.id, since)
+            .await
+        {
+            Ok(count) if count >= MAX_WORD_REPORTS_PER_HOUR => {
+                return self
+                    .send_error("Too many reports, please try again later")
+                    .await;
             }
+            Ok(_) => {}
             Err(e) => {
-                self.send_error(&format!("Failed to join queue: {}", e))
-                    .await
+                error!("Failed to count word reports for user {}: {}", user.id, e);
+                return self.send_error("Failed to save report").await;
             }
         }
-    }
-
-    async fn handle_leave_queue(&self) -> Result<(), String> {
-        info!("Player {} leaving queue", self.connection_id);
 
-        match self
-            .matchmaking_queue
-            .remove_player(self.connection_id)
+        // The word is only stored server-side; the reporter gets a bare
+        // acknowledgement so an active round is not spoiled
+        let word = match self
+            .game_manager
+            .reportable_word(&game_id, self.connection_id)
             .await
         {
-            Ok(_) => self.send_message(ServerMessage::QueueLeft).await,
+            Ok(word) => word,
             Err(e) => {
-                self.send_error(&format!("Failed to leave queue: {}", e))
-                    .await
+                return self.send_game_error(&e.to_string(), &e).await;
             }
+        };
+
+        if let Err(e) = self
+            .word_report_repository
+            .create_report(&game_id, &word, &user.id, reason)
+            .await
+        {
+            error!("Failed to save word report from user {}: {}", user.id, e);
+            return self.send_error("Failed to save report").await;
         }
-    }
 
-    async fn handle_submit_guess(&self, word: String) -> Result<(), String> {
-        info!("Player {} submitting guess: {}", self.connection_id, word);
+        info!("User {} reported the word in game {}", user.id, game_id);
+        self.send_message(ServerMessage::WordReported).await
+    }
 
-        // Get connection to find game
+    /// Start a solo practice game on a chosen word. Only admins may do this,
+    /// or anyone when the server runs in dev mode.
+    async fn handle_start_practice_word(&self, word: String) -> Result<(), String> {
         let connection = self
             .connection_manager
             .get_connection(self.connection_id)
             .await
             .ok_or("Connection not found")?;
 
-        let game_id = connection.game_id.ok_or("Not in a game")?;
+        let Some(user) = connection.user else {
+            return self
+                .send_error("Must be authenticated to start a practice game")
+                .await;
+        };
+        if !user.has_role(Role::Admin) && !self.auth_service.is_dev_mode() {
+            return self
+                .send_error("Practice games are only available to admins")
+                .await;
+        }
+        if connection.game_id.is_some() {
+            return self.send_error("Already in a game").await;
+        }
+        if self
+            .matchmaking_queue
+            .get_queue_position(self.connection_id)
+            .await
+            .is_some()
+        {
+            return self
+                .send_error("Leave the queue before starting a practice game")
+                .await;
+        }
 
-        // Submit guess to game manager
-        match self
+        let game_id = match self
             .game_manager
-            .submit_guess(&game_id, self.connection_id, word)
+            .create_practice_game(self.connection_id, &word)
             .await
         {
-            Ok(game_event) => {
-                // Handle the game event and send appropriate messages
-                self.handle_game_event(&game_id, game_event).await
+            Ok(game_id) => game_id,
+            Err(e) => {
+                return self
+                    .send_game_error(&format!("Failed to start practice game: {}", e), &e)
+                    .await;
             }
-            Err(e) => self.send_error(&format!("Invalid guess: {}", e)).await,
+        };
+        info!("User {} started practice game {}", user.id, game_id);
+
+        self.connection_manager
+            .set_connection_game(self.connection_id, Some(game_id.clone()))
+            .await;
+        let Some(state) = self.game_manager.get_game_state(&game_id).await else {
+            return Err(format!("Practice game {} disappeared", game_id));
+        };
+        self.send_message(ServerMessage::MatchFound {
+            game_id: game_id.clone(),
+            players: state.players.clone(),
+            categories: Vec::new(),
+            point_threshold: state.point_threshold,
+        })
+        .await?;
+        self.send_message(ServerMessage::GameStateUpdate {
+            state: state.personalized_for_player(user.id.clone()),
+        })
+        .await?;
+        if let Some(token) = self
+            .game_manager
+            .issue_rejoin_token(&game_id, &user.id)
+            .await
+        {
+            self.send_message(ServerMessage::RejoinToken { game_id, token })
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Fill in profile data kept in the database, such as the avatar
+    async fn with_stored_profile(&self, mut user: User) -> User {
+        match self.user_repository.find_by_id(&user.id).await {
+            Ok(Some(stored)) => user.avatar = stored.avatar,
+            Ok(None) => {}
+            Err(e) => warn!("Failed to load profile for user {}: {}", user.id, e),
         }
+        user
     }
 
     async fn handle_leave_game(&self) -> Result<(), String> {
@@ -270,25 +1150,101 @@ impl MessageHandler {
         if let Some(game_id) = connection.game_id {
             match self
                 .game_manager
-                .remove_player(&game_id, self.connection_id)
+                .leave_game(&game_id, self.connection_id)
                 .await
             {
-                Ok(_) => {
-                    self.connection_manager
-                        .set_connection_game(self.connection_id, None)
-                        .await;
-                    self.send_message(ServerMessage::GameLeft).await
+                Ok(abandoned) => {
+                    if abandoned && let Some(user_id) = &connection.user_id {
+                        self.matchmaking_queue.record_abandonment(user_id).await;
+                    }
                 }
+                // The game was already cleaned up; leaving it is still fine
+                Err(GameError::GameNotFound | GameError::PlayerNotInGame) => {}
                 Err(e) => {
-                    self.send_error(&format!("Failed to leave game: {}", e))
-                        .await
+                    return self
+                        .send_game_error(&format!("Failed to leave game: {}", e), &e)
+                        .await;
                 }
             }
+
+            self.connection_manager
+                .set_connection_game(self.connection_id, None)
+                .await;
+            self.send_message(ServerMessage::GameLeft).await
         } else {
             self.send_error("Not in a game").await
         }
     }
 
+    /// Pass a seated player's message on to the rest of their game, checked
+    /// like lobby announcements
+    async fn handle_send_chat(&self, text: String) -> Result<(), String> {
+        let connection = self
+            .connection_manager
+            .get_connection(self.connection_id)
+            .await
+            .ok_or("Connection not found")?;
+
+        let (Some(game_id), Some(player_id)) = (connection.game_id, connection.user_id) else {
+            return self.send_error("Not in a game").await;
+        };
+
+        let text = text.trim();
+        if text.is_empty() {
+            return self.send_error("Chat message cannot be empty").await;
+        }
+        if text.chars().count() > MAX_CHAT_MESSAGE_CHARS {
+            return self
+                .send_error("Chat message must be at most 200 characters")
+                .await;
+        }
+        if self.game_manager.contains_blocked_word(text) {
+            return self
+                .send_error("Chat message contains a word that isn't allowed")
+                .await;
+        }
+
+        let message = ServerMessage::ChatMessage {
+            player_id: player_id.clone(),
+            text: text.to_string(),
+        };
+        self.connection_manager
+            .send_chat_to_game(&game_id, &player_id, message)
+            .await;
+        Ok(())
+    }
+
+    async fn handle_set_muted(&self, player_id: PlayerId, muted: bool) -> Result<(), String> {
+        self.connection_manager
+            .set_muted(self.connection_id, &player_id, muted)
+            .await;
+        Ok(())
+    }
+
+    async fn handle_hello(&self, protocol_version: u32) -> Result<(), String> {
+        if !(MIN_PROTOCOL_VERSION..=PROTOCOL_VERSION).contains(&protocol_version) {
+            warn!(
+                "Connection {} speaks protocol version {}, closing",
+                self.connection_id, protocol_version
+            );
+            self.send_message(ServerMessage::IncompatibleVersion {
+                min: MIN_PROTOCOL_VERSION,
+                max: PROTOCOL_VERSION,
+            })
+            .await?;
+            self.connection_manager
+                .close_connection(self.connection_id, CloseReason::IncompatibleVersion)
+                .await;
+            return Ok(());
+        }
+
+        self.send_message(ServerMessage::Welcome {
+            server_version: env!("CARGO_PKG_VERSION").to_string(),
+            protocol_version: PROTOCOL_VERSION,
+        })
+        .await
+    }
+
     async fn handle_heartbeat(&self) -> Result<(), String> {
         // Heartbeat just updates activity (already done in handle_message)
         Ok(())
@@ -308,6 +1264,17 @@ impl MessageHandler {
             return self.send_error("Authentication required to vote").await;
         }
 
+        // The host's vote is all it takes to start a private lobby
+        if self
+            .matchmaking_queue
+            .lobbies()
+            .lobby_of(self.connection_id)
+            .await
+            .is_some()
+        {
+            return self.handle_start_lobby().await;
+        }
+
         // Check if player is in queue and vote
         match self
             .matchmaking_queue
@@ -318,9 +1285,9 @@ impl MessageHandler {
                 // Broadcast updated countdown info to all players in queue
                 self.broadcast_countdown_to_queue().await;
 
-                // Check if we should start the match immediately
-                if has_enough_votes || self.matchmaking_queue.should_start_match().await {
-                    self.create_match_from_queue().await?;
+                // The matchmaking ticker forms the match on its next pass
+                if has_enough_votes {
+                    info!("Enough votes to start a match");
                 }
 
                 Ok(())
@@ -329,7 +1296,11 @@ impl MessageHandler {
         }
     }
 
-    async fn handle_rejoin_game(&self, game_id: String) -> Result<(), String> {
+    async fn handle_rejoin_game(
+        &self,
+        game_id: String,
+        token: Option<String>,
+    ) -> Result<(), String> {
         info!(
             "Player {} attempting to rejoin game {}",
             self.connection_id, game_id
@@ -351,10 +1322,10 @@ impl MessageHandler {
         // Check if the game exists and if the player was originally in it
         match self
             .game_manager
-            .rejoin_player(&game_id, self.connection_id)
+            .rejoin_player(&game_id, self.connection_id, token.as_deref())
             .await
         {
-            Ok(current_state) => {
+            Ok(rejoin) => {
                 // Set the game ID in the connection
                 self.connection_manager
                     .set_connection_game(self.connection_id, Some(game_id.clone()))
@@ -362,13 +1333,35 @@ impl MessageHandler {
 
                 // Send personalized game state to the rejoining player
                 if let Some(ref user) = connection.user {
-                    let personalized_state = current_state.personalized_for_player(user.id.clone());
+                    let personalized_state = rejoin.state.personalized_for_player(user.id.clone());
                     self.send_message(ServerMessage::GameStateUpdate {
                         state: personalized_state,
                     })
                     .await?;
                 }
 
+                // The used token is spent; hand out the next one
+                if let Some(ref user) = connection.user
+                    && let Some(token) = self
+                        .game_manager
+                        .issue_rejoin_token(&game_id, &user.id)
+                        .await
+                {
+                    self.send_message(ServerMessage::RejoinToken {
+                        game_id: game_id.clone(),
+                        token,
+                    })
+                    .await?;
+                }
+
+                // Let the client replay what happened while it was away
+                if !rejoin.missed_rounds.is_empty() {
+                    self.send_message(ServerMessage::MissedRounds {
+                        rounds: rejoin.missed_rounds,
+                    })
+                    .await?;
+                }
+
                 // Notify other players that this player has reconnected
                 if let Some(ref user) = connection.user {
                     self.connection_manager
@@ -380,6 +1373,18 @@ impl MessageHandler {
                             },
                         )
                         .await;
+                    if let Some(display_name) = rejoin.renamed {
+                        self.connection_manager
+                            .send_to_game_except(
+                                &game_id,
+                                self.connection_id,
+                                ServerMessage::PlayerRenamed {
+                                    player_id: user.id.clone(),
+                                    display_name,
+                                },
+                            )
+                            .await;
+                    }
                 }
 
                 info!(
@@ -389,108 +1394,38 @@ impl MessageHandler {
                 Ok(())
             }
             Err(e) => {
-                self.send_error(&format!("Failed to rejoin game: {}", e))
+                self.send_game_error(&format!("Failed to rejoin game: {}", e), &e)
                     .await
             }
         }
     }
 
-    async fn handle_game_event(
-        &self,
-        game_id: &str,
-        event: crate::game_manager::GameEvent,
-    ) -> Result<(), String> {
-        use crate::game_manager::GameEvent;
-
+    async fn handle_game_event(&self, game_id: &str, event: GameEvent) -> Result<(), String> {
         match event {
-            GameEvent::RoundResult {
-                winning_guess,
-                player_guesses,
-                is_word_completed,
+            GameEvent::GuessPending {
+                player_id,
+                replaced,
             } => {
-                // Get the current game state to determine the next phase
-                let next_phase =
-                    if let Some(game_state) = self.game_manager.get_game_state(game_id).await {
-                        game_state.current_phase
-                    } else {
-                        game_types::GamePhase::Guessing // Fallback
-                    };
-
-                // Send winning guess to all players
-                let message = ServerMessage::RoundResult {
-                    winning_guess: winning_guess.clone(),
-                    your_guess: None, // Will be set per player
-                    next_phase,
-                    is_word_completed, // Use the flag from the game event
-                };
-
-                // Send personalized messages to each player
-                for (player_id, personal_guess) in player_guesses {
-                    let mut personal_message = message.clone();
-                    if let ServerMessage::RoundResult {
-                        ref mut your_guess, ..
-                    } = personal_message
-                    {
-                        *your_guess = Some(personal_guess);
-                    }
-
-                    if let Err(e) = self
-                        .connection_manager
-                        .send_to_connection(player_id, personal_message)
-                        .await
-                    {
-                        warn!("Failed to send round result to {}: {}", player_id, e);
-                    }
-                }
+                let message = ServerMessage::PlayerGuessed { player_id };
 
-                // After sending round results, send personalized game state updates
-                if let Some(updated_state) = self.game_manager.get_game_state(game_id).await {
-                    tracing::info!(
-                        "Sending GameStateUpdate after RoundResult - phase: {:?}, current_winner: {:?}",
-                        updated_state.current_phase,
-                        updated_state.current_winner
-                    );
+                // Others already know about a replaced guess; only the
+                // submitter needs the acknowledgement
+                if !replaced {
                     self.connection_manager
-                        .send_personalized_game_state(game_id, &updated_state)
+                        .send_to_game_except(game_id, self.connection_id, message.clone())
                         .await;
                 }
+                self.send_message(message).await?;
             }
-            GameEvent::GameOver {
-                winner,
-                final_scores,
-            } => {
-                tracing::info!(
-                    "🏆 Game {} completed! Winner: {} ({} points) | Final standings: {:?}",
+            event => {
+                publish_game_event(
+                    &self.connection_manager,
+                    &self.game_manager,
+                    &self.user_repository,
                     game_id,
-                    winner.display_name,
-                    winner.points,
-                    final_scores
-                        .iter()
-                        .map(|p| format!("{}: {}", p.display_name, p.points))
-                        .collect::<Vec<_>>()
-                );
-
-                let message = ServerMessage::GameOver {
-                    winner: winner.clone(),
-                    final_scores: final_scores.clone(),
-                };
-                self.connection_manager.send_to_game(game_id, message).await;
-
-                // Clear game from all connections
-                let connections = self
-                    .connection_manager
-                    .get_connections_in_game(game_id)
-                    .await;
-                for connection_id in connections {
-                    self.connection_manager
-                        .set_connection_game(connection_id, None)
-                        .await;
-                }
-            }
-            GameEvent::StateUpdate { state } => {
-                self.connection_manager
-                    .send_personalized_game_state(game_id, &state)
-                    .await;
+                    event,
+                )
+                .await
             }
         }
 
@@ -506,27 +1441,46 @@ impl MessageHandler {
     async fn send_error(&self, error_message: &str) -> Result<(), String> {
         self.send_message(ServerMessage::Error {
             message: error_message.to_string(),
+            code: None,
+            prior_guess: None,
+        })
+        .await
+    }
+
+    /// Send a game-logic error with its code and, for repeated words, where
+    /// the word was first played
+    async fn send_game_error(&self, error_message: &str, error: &GameError) -> Result<(), String> {
+        let prior_guess = match error {
+            GameError::WordAlreadyGuessed { prior, .. } => prior.clone(),
+            _ => None,
+        };
+        self.send_message(ServerMessage::Error {
+            message: error_message.to_string(),
+            code: Some(error_code(error)),
+            prior_guess,
         })
         .await
     }
 
     // Helper method to broadcast countdown info to all players in queue
+    // and everyone watching it
     async fn broadcast_countdown_to_queue(&self) {
         if let Some(countdown_info) = self.matchmaking_queue.get_countdown_info().await {
             let message = ServerMessage::MatchmakingCountdown {
                 seconds_remaining: countdown_info.seconds_remaining,
                 players_ready: countdown_info.players_ready,
                 total_players: countdown_info.total_players,
+                rating_window: countdown_info.rating_window,
             };
 
-            // Get all players in queue and broadcast to each
-            let queue_players = self.matchmaking_queue.get_queue_players().await;
+            // Get all players in queue and subscribers and broadcast to each
+            let recipients = self.matchmaking_queue.get_countdown_recipients().await;
             info!(
-                "Broadcasting countdown to {} players in queue",
-                queue_players.len()
+                "Broadcasting countdown to {} players and watchers",
+                recipients.len()
             );
 
-            for player_id in queue_players {
+            for player_id in recipients {
                 if let Err(e) = self
                     .connection_manager
                     .send_to_connection(player_id, message.clone())
@@ -538,104 +1492,313 @@ impl MessageHandler {
         }
     }
 
-    // Helper method to create a match from the current queue
-    async fn create_match_from_queue(&self) -> Result<(), String> {
-        if let Ok(Some(match_info)) = self.matchmaking_queue.try_create_match().await {
-            info!("Creating match with {} players", match_info.players.len());
-
-            // Get player info for the match
-            let mut players_info = Vec::new();
-            for &player_id in &match_info.players {
-                if let Some(connection) = self.connection_manager.get_connection(player_id).await {
-                    if let Some(ref user) = connection.user {
-                        players_info.push(game_types::Player {
-                            user_id: user.id.clone(),
-                            display_name: user.display_name.clone(),
-                            points: 0,
-                            guess_history: Vec::new(),
-                            is_connected: true,
-                        });
+    /// Tell the rest of a lobby that someone left: a dissolved lobby's
+    /// members are told it closed, and a new host is announced before the
+    /// lobby update
+    async fn announce_lobby_departure(&self, departure: LobbyDeparture) {
+        match departure {
+            LobbyDeparture::Emptied => {}
+            LobbyDeparture::Dissolved { members } => {
+                for member in members {
+                    let _ = self
+                        .connection_manager
+                        .send_to_connection(member, ServerMessage::LobbyClosed)
+                        .await;
+                }
+            }
+            LobbyDeparture::Continues {
+                lobby,
+                host_changed,
+            } => {
+                if host_changed
+                    && let Some(connection) =
+                        self.connection_manager.get_connection(lobby.host).await
+                    && let Some(user) = connection.user
+                {
+                    for &member in &lobby.members {
+                        let _ = self
+                            .connection_manager
+                            .send_to_connection(
+                                member,
+                                ServerMessage::HostChanged {
+                                    new_host: user.id.clone(),
+                                },
+                            )
+                            .await;
                     }
                 }
+                self.broadcast_lobby_update(&lobby).await;
             }
+        }
+    }
 
-            // Create game
-            match self
-                .game_manager
-                .create_game(match_info.players.clone())
-                .await
+    /// Send every member of `lobby` its current code, host and players
+    async fn broadcast_lobby_update(&self, lobby: &Lobby) {
+        let mut players = Vec::new();
+        let mut host = None;
+        for &member in &lobby.members {
+            if let Some(connection) = self.connection_manager.get_connection(member).await
+                && let Some(user) = connection.user
             {
-                Ok(game_id) => {
-                    // Get initial game state
-                    let initial_game_state = self.game_manager.get_game_state(&game_id).await;
+                if member == lobby.host {
+                    host = Some(user.id.clone());
+                }
+                players.push(game_types::Player {
+                    user_id: user.id,
+                    display_name: user.display_name,
+                    points: 0,
+                    guess_history: Vec::new(),
+                    is_connected: true,
+                    avatar: user.avatar,
+                });
+            }
+        }
 
-                    // Notify all players of match and send initial game state
-                    for &player_id in &match_info.players {
-                        self.connection_manager
-                            .set_connection_game(player_id, Some(game_id.clone()))
-                            .await;
+        let message = ServerMessage::LobbyUpdate {
+            code: lobby.code.clone(),
+            host: host.unwrap_or_default(),
+            players,
+            max_players: lobby.settings.max_players as u32,
+        };
+        for &member in &lobby.members {
+            let _ = self
+                .connection_manager
+                .send_to_connection(member, message.clone())
+                .await;
+        }
+    }
 
-                        // Send MatchFound message
-                        if let Err(e) = self
-                            .connection_manager
-                            .send_to_connection(
-                                player_id,
-                                ServerMessage::MatchFound {
-                                    game_id: game_id.clone(),
-                                    players: players_info.clone(),
-                                },
-                            )
-                            .await
-                        {
-                            warn!("Failed to notify player {} of match: {}", player_id, e);
-                        }
+    /// Tell queue status subscribers how many players are queued now
+    async fn broadcast_queue_status(&self) {
+        let players_in_queue = self.matchmaking_queue.get_queue_length().await as u32;
+        for subscriber in self.matchmaking_queue.get_status_subscribers().await {
+            let _ = self
+                .connection_manager
+                .send_to_connection(subscriber, ServerMessage::QueueStatus { players_in_queue })
+                .await;
+        }
+    }
+}
 
-                        // Send personalized initial game state
-                        if let Some(ref game_state) = initial_game_state {
-                            if let Some(connection) =
-                                self.connection_manager.get_connection(player_id).await
-                            {
-                                if let Some(ref user) = connection.user {
-                                    let personalized_state =
-                                        game_state.personalized_for_player(user.id.clone());
-                                    if let Err(e) = self
-                                        .connection_manager
-                                        .send_to_connection(
-                                            player_id,
-                                            ServerMessage::GameStateUpdate {
-                                                state: personalized_state,
-                                            },
-                                        )
-                                        .await
-                                    {
-                                        warn!(
-                                            "Failed to send initial game state to {}: {}",
-                                            player_id, e
-                                        );
-                                    }
-                                }
-                            }
-                        }
-                    }
+/// Tell a game's players about a game event that changed what they can see.
+/// Pending guesses are acknowledged by the submitting connection's handler.
+pub async fn publish_game_event(
+    connection_manager: &Arc<ConnectionManager>,
+    game_manager: &Arc<GameManager>,
+    user_repository: &UserRepository,
+    game_id: &str,
+    event: GameEvent,
+) {
+    match event {
+        GameEvent::RoundResult {
+            winning_guess,
+            player_guesses,
+            is_word_completed,
+            revealed,
+        } => {
+            // Get the current game state to determine the next phase
+            let game_state = game_manager.get_game_state(game_id).await;
+            let next_phase = match &game_state {
+                Some(game_state) => game_state.current_phase.clone(),
+                None => game_types::GamePhase::Guessing, // Fallback
+            };
 
-                    info!(
-                        "Successfully created match {} with {} players and sent initial state",
-                        game_id,
-                        match_info.players.len()
-                    );
-                    Ok(())
+            // Send winning guess to all players
+            let message = ServerMessage::RoundResult {
+                winning_guess: winning_guess.clone(),
+                your_guess: None, // Will be set per player
+                next_phase,
+                is_word_completed, // Use the flag from the game event
+            };
+
+            // Send personalized messages to each player
+            for (player_id, personal_guess) in player_guesses {
+                let mut personal_message = message.clone();
+                if let ServerMessage::RoundResult {
+                    ref mut your_guess, ..
+                } = personal_message
+                {
+                    *your_guess = Some(personal_guess);
                 }
-                Err(e) => {
-                    error!("Failed to create game: {}", e);
-                    // Put players back in queue
-                    for &player_id in &match_info.players {
-                        let _ = self.matchmaking_queue.add_player(player_id).await;
-                    }
-                    Err(format!("Failed to create game: {}", e))
+
+                if let Err(e) = connection_manager
+                    .send_to_connection(player_id, personal_message)
+                    .await
+                {
+                    warn!("Failed to send round result to {}: {}", player_id, e);
                 }
             }
-        } else {
-            Ok(()) // No match to create
+
+            // Say plainly that a new word is starting, so clients need not
+            // infer it from `is_word_completed`
+            if is_word_completed && let Some(game_state) = &game_state {
+                connection_manager
+                    .send_to_game(
+                        game_id,
+                        ServerMessage::WordSolved {
+                            word: winning_guess.word.clone(),
+                            solver: winning_guess.player_id.clone(),
+                            next_round: game_state.current_round as u32,
+                        },
+                    )
+                    .await;
+            }
+
+            // Announce letters this round revealed for the first time
+            if !revealed.is_empty() {
+                connection_manager
+                    .send_to_game(
+                        game_id,
+                        ServerMessage::LettersRevealed {
+                            positions: revealed,
+                        },
+                    )
+                    .await;
+            }
+
+            // Pause before play resumes, then tell everyone it has
+            if let Some(remaining) = game_manager.intermission_remaining(game_id).await {
+                connection_manager
+                    .send_to_game(
+                        game_id,
+                        ServerMessage::Intermission {
+                            seconds: remaining.as_secs_f64().ceil() as u32,
+                        },
+                    )
+                    .await;
+
+                let game_manager = game_manager.clone();
+                let connection_manager = connection_manager.clone();
+                let game_id = game_id.to_string();
+                tokio::spawn(async move {
+                    tokio::time::sleep(remaining).await;
+                    if let Some(state) = game_manager.end_intermission(&game_id).await {
+                        connection_manager
+                            .send_personalized_game_state(&game_id, &state)
+                            .await;
+                    }
+                });
+            }
+
+            // After sending round results, send personalized game state updates
+            if let Some(updated_state) = game_manager.get_game_state(game_id).await {
+                tracing::info!(
+                    "Sending GameStateUpdate after RoundResult - phase: {:?}, current_winner: {:?}",
+                    updated_state.current_phase,
+                    updated_state.current_winner
+                );
+                connection_manager
+                    .send_personalized_game_state(game_id, &updated_state)
+                    .await;
+            }
+        }
+        GameEvent::GameOver {
+            winner,
+            final_scores,
+            recap,
+            ranked,
+            categories,
+            reason,
+        } => {
+            tracing::info!(
+                "🏆 Game {} completed! Winner: {} ({} points) | Final standings: {:?}",
+                game_id,
+                winner.display_name,
+                winner.points,
+                final_scores
+                    .iter()
+                    .map(|p| format!("{}: {}", p.display_name, p.points))
+                    .collect::<Vec<_>>()
+            );
+
+            let message = ServerMessage::GameOver {
+                winner: winner.clone(),
+                final_scores: final_scores.clone(),
+                recap,
+                categories,
+                reason,
+            };
+            connection_manager
+                .send_to_game(game_id, message.clone())
+                .await;
+            // Spectators see how it ended, then stop watching
+            for spectator in connection_manager.stop_spectating_game(game_id).await {
+                let _ = connection_manager
+                    .send_to_connection(spectator, message.clone())
+                    .await;
+            }
+
+            if ranked {
+                crate::stats::record_game_results(
+                    connection_manager,
+                    user_repository,
+                    &winner,
+                    &final_scores,
+                )
+                .await;
+            }
+
+            // Clear game from all connections
+            let connections = connection_manager.get_connections_in_game(game_id).await;
+            for connection_id in connections {
+                connection_manager
+                    .set_connection_game(connection_id, None)
+                    .await;
+            }
+        }
+        GameEvent::StateUpdate { state } => {
+            connection_manager
+                .send_personalized_game_state(game_id, &state)
+                .await;
+        }
+        GameEvent::GuessPending { .. } => {}
+    }
+}
+
+/// End games that ran past the server's time limit and publish their results
+pub async fn end_overlong_games(
+    connection_manager: &Arc<ConnectionManager>,
+    game_manager: &Arc<GameManager>,
+    user_repository: &UserRepository,
+) {
+    for (game_id, event) in game_manager.end_overlong_games().await {
+        publish_game_event(
+            connection_manager,
+            game_manager,
+            user_repository,
+            &game_id,
+            event,
+        )
+        .await;
+    }
+}
+
+/// Announce newly started round timers and publish the rounds they resolved
+pub async fn run_round_timers(
+    connection_manager: &Arc<ConnectionManager>,
+    game_manager: &Arc<GameManager>,
+    user_repository: &UserRepository,
+) {
+    for (game_id, timer) in game_manager.tick_round_timers().await {
+        match timer {
+            RoundTimer::Started { seconds_remaining } => {
+                connection_manager
+                    .send_to_game(
+                        &game_id,
+                        ServerMessage::RoundTimerUpdate { seconds_remaining },
+                    )
+                    .await;
+            }
+            RoundTimer::Expired(event) => {
+                publish_game_event(
+                    connection_manager,
+                    game_manager,
+                    user_repository,
+                    &game_id,
+                    *event,
+                )
+                .await;
+            }
         }
     }
 }
@@ -8,8 +8,9 @@ use super::connection::ConnectionManager;
 use crate::auth::AuthService;
 use crate::create_routes;
 use crate::game_manager::GameManager;
+use crate::matchmaker::Matchmaker;
 use crate::matchmaking::MatchmakingQueue;
-use game_persistence::repositories::UserRepository;
+use game_persistence::repositories::{GameRepository, UserRepository, WordReportRepository};
 use game_types::{ClientMessage, ServerMessage};
 use migration::{Migrator, MigratorTrait};
 use std::sync::Arc;
@@ -28,7 +29,9 @@ async fn test_complete_matchmaking_flow() {
         .await
         .unwrap();
     Migrator::up(&db, None).await.unwrap();
-    let user_repository = Arc::new(UserRepository::new(db));
+    let user_repository = Arc::new(UserRepository::new(db.clone()));
+    let word_report_repository = Arc::new(WordReportRepository::new(db.clone()));
+    let game_repository = Arc::new(GameRepository::new(db));
 
     let routes = create_routes(
         connection_manager.clone(),
@@ -36,7 +39,15 @@ async fn test_complete_matchmaking_flow() {
         matchmaking_queue.clone(),
         auth_service,
         user_repository,
+        word_report_repository,
+        game_repository,
     );
+    let matchmaker = Arc::new(Matchmaker::new(
+        connection_manager.clone(),
+        game_manager.clone(),
+        matchmaking_queue.clone(),
+    ))
+    .spawn(Duration::from_millis(50));
 
     // Test the complete flow with two players
     let mut ws1 = ws()
@@ -54,9 +65,11 @@ async fn test_complete_matchmaking_flow() {
     // Step 1: Authenticate both players
     let auth_msg1 = ClientMessage::Authenticate {
         token: "user1:alice@example.com:Alice".to_string(),
+        auto_queue: false,
     };
     let auth_msg2 = ClientMessage::Authenticate {
         token: "user2:bob@example.com:Bob".to_string(),
+        auto_queue: false,
     };
 
     ws1.send(warp::ws::Message::text(
@@ -160,6 +173,7 @@ async fn test_complete_matchmaking_flow() {
         seconds_remaining,
         players_ready,
         total_players,
+        ..
     } = countdown_msg1
     {
         assert!(seconds_remaining <= 60);
@@ -233,7 +247,10 @@ async fn test_complete_matchmaking_flow() {
     let match_msg2: ServerMessage = serde_json::from_str(match_found2.to_str().unwrap()).unwrap();
 
     // Verify MatchFound messages
-    let game_id = if let ServerMessage::MatchFound { game_id, players } = match_msg1 {
+    let game_id = if let ServerMessage::MatchFound {
+        game_id, players, ..
+    } = match_msg1
+    {
         assert!(!game_id.is_empty());
         assert_eq!(players.len(), 2); // Should have player info now
         game_id
@@ -245,6 +262,7 @@ async fn test_complete_matchmaking_flow() {
     if let ServerMessage::MatchFound {
         game_id: game_id2,
         players,
+        ..
     } = match_msg2
     {
         assert_eq!(game_id, game_id2);
@@ -284,6 +302,7 @@ async fn test_complete_matchmaking_flow() {
 
     // Player 2 should get the same state
     assert!(matches!(state_msg2, ServerMessage::GameStateUpdate { .. }));
+    matchmaker.abort();
 
     println!("✅ Complete matchmaking flow test passed!");
     println!("   - Both players authenticated");
@@ -1,14 +1,21 @@
-use futures_util::{SinkExt, StreamExt};
+use futures_util::{Sink, SinkExt, Stream, StreamExt};
 use serde_json;
+use std::fmt::Debug;
 use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::mpsc;
 use tracing::{error, info, warn};
 use warp::ws::{Message, WebSocket};
 
 use crate::auth::AuthService;
 use crate::game_manager::GameManager;
 use crate::matchmaking::MatchmakingQueue;
-use game_types::ClientMessage;
+use game_persistence::repositories::{UserRepository, WordReportRepository};
+use game_types::{ClientMessage, ServerMessage};
 
+pub mod audit;
+pub mod close;
+pub mod compression;
 pub mod connection;
 pub mod handlers;
 pub mod rate_limiter;
@@ -16,23 +23,34 @@ pub mod rate_limiter;
 #[cfg(test)]
 pub mod integration_tests;
 
+use audit::ClientInfo;
+use close::CloseReason;
+use compression::ConnectOptions;
 use connection::ConnectionId;
 pub use connection::ConnectionManager;
 use handlers::MessageHandler;
 use rate_limiter::RateLimiter;
 
+#[allow(clippy::too_many_arguments)]
 pub async fn handle_connection(
     websocket: WebSocket,
     connection_manager: Arc<ConnectionManager>,
     game_manager: Arc<GameManager>,
     matchmaking_queue: Arc<MatchmakingQueue>,
     auth_service: Arc<AuthService>,
+    user_repository: Arc<UserRepository>,
+    word_report_repository: Arc<WordReportRepository>,
+    options: ConnectOptions,
+    client: ClientInfo,
 ) {
     let connection_id = ConnectionId::new();
-    info!("New WebSocket connection: {}", connection_id);
-
-    let (mut ws_sender, mut ws_receiver) = websocket.split();
-    let rate_limiter = RateLimiter::new();
+    info!(
+        connection_id = %connection_id,
+        client_addr = %client.masked_addr(),
+        user_agent = client.user_agent(),
+        compression = ?options.compression,
+        "Connection opened"
+    );
 
     // Create connection and get receiver for outgoing messages
     let message_receiver = connection_manager.create_connection(connection_id).await;
@@ -44,11 +62,48 @@ pub async fn handle_connection(
         game_manager.clone(),
         matchmaking_queue.clone(),
         auth_service.clone(),
+        user_repository,
+        word_report_repository,
     );
 
-    // Handle incoming messages
+    let (ws_sender, ws_receiver) = websocket.split();
+    run_connection(
+        connection_id,
+        ws_sender,
+        ws_receiver,
+        message_receiver,
+        message_handler,
+        connection_manager,
+        options,
+        client,
+    )
+    .await;
+}
+
+/// Pump messages between the socket and the connection until either side
+/// stops, then clean up the connection
+#[allow(clippy::too_many_arguments)]
+async fn run_connection<Tx, Rx>(
+    connection_id: ConnectionId,
+    mut ws_sender: Tx,
+    mut ws_receiver: Rx,
+    message_receiver: mpsc::UnboundedReceiver<ServerMessage>,
+    message_handler: MessageHandler,
+    connection_manager: Arc<ConnectionManager>,
+    options: ConnectOptions,
+    client: ClientInfo,
+) where
+    Tx: Sink<Message> + Unpin,
+    Tx::Error: Debug,
+    Rx: Stream<Item = Result<Message, warp::Error>> + Unpin,
+{
+    let opened_at = Instant::now();
+    let rate_limiter = RateLimiter::new();
+    let send_timeout = connection_manager.send_timeout();
+
+    // Handle incoming messages; yields the close reason when the server
+    // gives up on the client
     let incoming_handler = {
-        let _connection_manager = connection_manager.clone();
         let message_handler = message_handler.clone();
         let mut rate_limiter = rate_limiter.clone();
 
@@ -56,28 +111,34 @@ pub async fn handle_connection(
             while let Some(result) = ws_receiver.next().await {
                 match result {
                     Ok(msg) => {
-                        if let Err(e) =
+                        if let Err(disconnect) =
                             handle_message(msg, &mut rate_limiter, &message_handler, connection_id)
                                 .await
                         {
-                            error!("Error handling message for {}: {}", connection_id, e);
-                            break;
+                            error!(
+                                "Error handling message for {}: {}",
+                                connection_id, disconnect.detail
+                            );
+                            return (Some(disconnect.reason), "server closed");
                         }
                     }
                     Err(e) => {
                         warn!("WebSocket error for {}: {}", connection_id, e);
-                        break;
+                        return (None, "socket error");
                     }
                 }
             }
+            (None, "client closed")
         }
     };
 
-    // Handle outgoing messages
+    // Handle outgoing messages. Ends when the connection is removed, which
+    // closes the channel, or when the socket stops accepting writes.
+    let mut receiver = message_receiver;
+    let outbound = connection_manager.outbound_queue(connection_id).await;
     let outgoing_handler = {
+        let ws_sender = &mut ws_sender;
         async move {
-            let mut receiver = message_receiver;
-
             while let Some(message) = receiver.recv().await {
                 let json = match serde_json::to_string(&message) {
                     Ok(json) => json,
@@ -87,36 +148,107 @@ pub async fn handle_connection(
                     }
                 };
 
-                if let Err(e) = ws_sender.send(Message::text(json)).await {
-                    warn!("Failed to send message to {}: {:?}", connection_id, e);
-                    break;
+                let frame = compression::encode_message(json, options.compression);
+                match tokio::time::timeout(send_timeout, ws_sender.send(frame)).await {
+                    Ok(Ok(())) => {
+                        if let Some(outbound) = &outbound {
+                            outbound.delivered();
+                        }
+                    }
+                    Ok(Err(e)) => {
+                        warn!("Failed to send message to {}: {:?}", connection_id, e);
+                        return "send failed";
+                    }
+                    Err(_) => {
+                        // A write that never completes means the peer stopped reading
+                        warn!(
+                            "Send to {} timed out after {:?}, treating socket as dead",
+                            connection_id, send_timeout
+                        );
+                        return "send timed out";
+                    }
                 }
             }
+            "connection removed"
         }
     };
 
     // Run both handlers concurrently
-    tokio::select! {
-        _ = incoming_handler => {},
-        _ = outgoing_handler => {},
+    let (close_reason, mut ended_by) = tokio::select! {
+        ended = incoming_handler => ended,
+        ended_by = outgoing_handler => (None, ended_by),
+    };
+
+    // Tell the client why the server ended the connection, whether the cause
+    // came from its own messages or from elsewhere (takeover, shutdown, ...)
+    let close_reason = match close_reason {
+        Some(reason) => Some(reason),
+        None => connection_manager.take_close_reason(connection_id).await,
+    };
+    let rate_limited = matches!(close_reason, Some(CloseReason::RateLimited));
+    if let Some(reason) = close_reason {
+        ended_by = reason.reason();
+        info!(
+            "Closing connection {} with code {} ({})",
+            connection_id,
+            reason.code(),
+            reason.reason()
+        );
+        if let Ok(Err(e)) = tokio::time::timeout(send_timeout, ws_sender.send(reason.frame())).await
+        {
+            warn!("Failed to send close frame to {}: {:?}", connection_id, e);
+        }
     }
 
     // Cleanup connection
-    info!("Connection {} disconnected", connection_id);
+    if rate_limited {
+        message_handler.handle_rate_limited().await;
+    }
+    let user_id = connection_manager
+        .get_connection(connection_id)
+        .await
+        .and_then(|connection| connection.user_id);
+    info!(
+        connection_id = %connection_id,
+        client_addr = %client.masked_addr(),
+        user_id = user_id.as_deref().unwrap_or("anonymous"),
+        duration_secs = opened_at.elapsed().as_secs_f64(),
+        reason = ended_by,
+        "Connection closed"
+    );
     message_handler.handle_disconnect().await;
     connection_manager.remove_connection(connection_id).await;
 }
 
+/// A message that ends the connection, and the close code to report
+#[derive(Debug)]
+struct Disconnect {
+    reason: CloseReason,
+    detail: String,
+}
+
+impl Disconnect {
+    fn new(reason: CloseReason, detail: impl Into<String>) -> Self {
+        Self {
+            reason,
+            detail: detail.into(),
+        }
+    }
+}
+
 async fn handle_message(
     msg: Message,
     rate_limiter: &mut RateLimiter,
     message_handler: &MessageHandler,
     connection_id: ConnectionId,
-) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+) -> Result<(), Disconnect> {
     // Check rate limiting
     if !rate_limiter.check_rate_limit().await {
         warn!("Rate limit exceeded for connection {}", connection_id);
-        return Err("Rate limit exceeded".into());
+        return Err(Disconnect::new(
+            CloseReason::RateLimited,
+            "Rate limit exceeded",
+        ));
     }
 
     // Only handle text messages
@@ -124,17 +256,1554 @@ async fn handle_message(
         return Ok(());
     }
 
-    let text = msg.to_str().map_err(|_| "Invalid text message")?;
+    let text = msg
+        .to_str()
+        .map_err(|_| Disconnect::new(CloseReason::ProtocolViolation, "Invalid text message"))?;
 
     // Parse client message
-    let client_message: ClientMessage =
-        serde_json::from_str(text).map_err(|e| format!("Invalid JSON message: {}", e))?;
+    let client_message: ClientMessage = serde_json::from_str(text).map_err(|e| {
+        Disconnect::new(
+            CloseReason::ProtocolViolation,
+            format!("Invalid JSON message: {}", e),
+        )
+    })?;
 
     // Handle the message
     message_handler
         .handle_message(client_message)
         .await
-        .map_err(|e| format!("Message handling error: {}", e))?;
+        .map_err(|e| {
+            Disconnect::new(
+                CloseReason::InternalError,
+                format!("Message handling error: {}", e),
+            )
+        })?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abuse::{AbusePolicy, AbuseTracker};
+    use crate::lobby::HostLeavePolicy;
+    use crate::matchmaker::Matchmaker;
+    use game_persistence::repositories::AbuseRepository;
+    use migration::MigratorTrait;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+    use std::time::Duration;
+
+    /// Sink for a peer that stopped reading: every write blocks forever
+    struct StalledSink;
+
+    impl Sink<Message> for StalledSink {
+        type Error = std::convert::Infallible;
+
+        fn poll_ready(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Poll::Pending
+        }
+
+        fn start_send(self: Pin<&mut Self>, _item: Message) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn poll_flush(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Poll::Pending
+        }
+
+        fn poll_close(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Poll::Pending
+        }
+    }
+
+    /// Sink that keeps every frame written to it
+    #[derive(Clone, Default)]
+    struct RecordingSink(Arc<std::sync::Mutex<Vec<Message>>>);
+
+    impl Sink<Message> for RecordingSink {
+        type Error = std::convert::Infallible;
+
+        fn poll_ready(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn start_send(self: Pin<&mut Self>, item: Message) -> Result<(), Self::Error> {
+            self.0.lock().unwrap().push(item);
+            Ok(())
+        }
+
+        fn poll_flush(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    /// A signed-in test user whose display name is their id
+    fn test_user(id: &str) -> game_types::User {
+        game_types::User {
+            id: id.to_string(),
+            email: format!("{}@example.com", id),
+            display_name: id.to_string(),
+            total_points: 0,
+            total_wins: 0,
+            total_games: 0,
+            created_at: chrono::Utc::now().to_rfc3339(),
+            avatar: None,
+            roles: Vec::new(),
+        }
+    }
+
+    type TestClient = (
+        ConnectionId,
+        MessageHandler,
+        mpsc::UnboundedReceiver<ServerMessage>,
+    );
+
+    /// The server pieces behind message handlers, for tests that drive
+    /// handlers directly instead of through a socket
+    #[derive(Clone)]
+    struct HandlerFixture {
+        connection_manager: Arc<ConnectionManager>,
+        game_manager: Arc<GameManager>,
+        matchmaking_queue: Arc<MatchmakingQueue>,
+        auth_service: Arc<AuthService>,
+        db: sea_orm::DatabaseConnection,
+    }
+
+    impl HandlerFixture {
+        async fn new() -> Self {
+            Self::with_managers(ConnectionManager::new(), |game_manager| game_manager).await
+        }
+
+        /// Fixture around `connection_manager`, with its game manager
+        /// configured by `configure`
+        async fn with_managers(
+            connection_manager: ConnectionManager,
+            configure: impl FnOnce(GameManager) -> GameManager,
+        ) -> Self {
+            let connection_manager = Arc::new(connection_manager);
+            let game_manager = GameManager::new_with_validator(
+                connection_manager.clone(),
+                game_core::WordValidator::new_with_test_words(),
+            );
+            let db = game_persistence::connection::connect_to_memory_database()
+                .await
+                .unwrap();
+            migration::Migrator::up(&db, None).await.unwrap();
+
+            Self {
+                connection_manager,
+                game_manager: Arc::new(configure(game_manager)),
+                matchmaking_queue: Arc::new(MatchmakingQueue::new()),
+                auth_service: Arc::new(AuthService::new_dev_mode()),
+                db,
+            }
+        }
+
+        fn with_queue(mut self, matchmaking_queue: Arc<MatchmakingQueue>) -> Self {
+            self.matchmaking_queue = matchmaking_queue;
+            self
+        }
+
+        fn with_auth_service(mut self, auth_service: AuthService) -> Self {
+            self.auth_service = Arc::new(auth_service);
+            self
+        }
+
+        /// Handler for an already open connection
+        fn handler(&self, connection_id: ConnectionId) -> MessageHandler {
+            MessageHandler::new(
+                connection_id,
+                self.connection_manager.clone(),
+                self.game_manager.clone(),
+                self.matchmaking_queue.clone(),
+                self.auth_service.clone(),
+                Arc::new(UserRepository::new(self.db.clone())),
+                Arc::new(WordReportRepository::new(self.db.clone())),
+            )
+        }
+
+        /// Open a connection that hasn't signed in
+        async fn connect_anonymous(&self) -> TestClient {
+            let connection_id = ConnectionId::new();
+            let receiver = self
+                .connection_manager
+                .create_connection(connection_id)
+                .await;
+            (connection_id, self.handler(connection_id), receiver)
+        }
+
+        /// Open a connection signed in as `user`
+        async fn connect_as(&self, user: game_types::User) -> TestClient {
+            let client = self.connect_anonymous().await;
+            self.connection_manager
+                .authenticate_connection(client.0, user.id.clone())
+                .await
+                .unwrap();
+            self.connection_manager
+                .set_connection_user(client.0, Some(user))
+                .await;
+            client
+        }
+
+        async fn connect(&self, user_id: &str) -> TestClient {
+            self.connect_as(test_user(user_id)).await
+        }
+
+        /// Sign in one connection per name, in order
+        async fn connect_all(
+            &self,
+            names: &[&str],
+        ) -> (
+            Vec<ConnectionId>,
+            Vec<MessageHandler>,
+            Vec<mpsc::UnboundedReceiver<ServerMessage>>,
+        ) {
+            let mut connection_ids = Vec::new();
+            let mut handlers = Vec::new();
+            let mut receivers = Vec::new();
+            for name in names {
+                let (connection_id, handler, receiver) = self.connect(name).await;
+                connection_ids.push(connection_id);
+                handlers.push(handler);
+                receivers.push(receiver);
+            }
+            (connection_ids, handlers, receivers)
+        }
+
+        /// Start a game between `players` and seat their connections in it
+        async fn seat_in_game(&self, players: &[ConnectionId]) -> String {
+            let game_id = self
+                .game_manager
+                .create_game(players.to_vec())
+                .await
+                .unwrap();
+            for connection_id in players {
+                self.connection_manager
+                    .set_connection_game(*connection_id, Some(game_id.clone()))
+                    .await;
+            }
+            game_id
+        }
+    }
+
+    /// Unsigned token the dev-mode auth service accepts for `user_id`
+    fn dev_token(user_id: &str, jti: &str) -> String {
+        use base64::Engine;
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let claims = serde_json::json!({
+            "aud": "dev",
+            "iss": "dev",
+            "iat": now,
+            "exp": now + 3600,
+            "oid": user_id,
+            "name": user_id,
+            "jti": jti,
+        });
+        let payload = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(claims.to_string());
+        format!("header.{}.signature", payload)
+    }
+
+    /// Default fixture with one signed-in connection per name
+    async fn handler_fixture(
+        names: &[&str],
+    ) -> (
+        HandlerFixture,
+        Vec<ConnectionId>,
+        Vec<MessageHandler>,
+        Vec<mpsc::UnboundedReceiver<ServerMessage>>,
+    ) {
+        let fixture = HandlerFixture::new().await;
+        let (connection_ids, handlers, receivers) = fixture.connect_all(names).await;
+        (fixture, connection_ids, handlers, receivers)
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_client_gets_close_code() {
+        let fixture = HandlerFixture::new().await;
+        let (connection_id, message_handler, message_receiver) = fixture.connect_anonymous().await;
+
+        // Far more heartbeats than the rate limiter's burst allows
+        let heartbeat = serde_json::to_string(&ClientMessage::Heartbeat).unwrap();
+        let flood =
+            futures_util::stream::iter((0..100).map(move |_| Ok(Message::text(heartbeat.clone()))))
+                .chain(futures_util::stream::pending());
+
+        let sink = RecordingSink::default();
+        let run = run_connection(
+            connection_id,
+            sink.clone(),
+            flood,
+            message_receiver,
+            message_handler,
+            fixture.connection_manager.clone(),
+            ConnectOptions::default(),
+            ClientInfo::default(),
+        );
+        tokio::time::timeout(Duration::from_secs(5), run)
+            .await
+            .expect("Rate limiting should end the connection");
+
+        let frames = sink.0.lock().unwrap();
+        let close = frames.last().expect("Expected a close frame");
+        assert!(close.is_close());
+        assert_eq!(
+            close.close_frame(),
+            Some((4001, CloseReason::RateLimited.reason()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_repeated_rate_limiting_cools_down_next_connection() {
+        let fixture = HandlerFixture::new().await;
+        let policy = AbusePolicy {
+            window: Duration::from_secs(3600),
+            cooldown_after: 2,
+            ban_after: None,
+        };
+        let tracked_queue = || {
+            Arc::new(
+                MatchmakingQueue::new().with_abuse_tracker(AbuseTracker::new(
+                    Arc::new(AbuseRepository::new(fixture.db.clone())),
+                    policy,
+                )),
+            )
+        };
+        let fixture = fixture.clone().with_queue(tracked_queue());
+
+        // Each reconnect starts with a fresh rate limiter
+        for _ in 0..policy.cooldown_after {
+            let (connection_id, handler, receiver) = fixture.connect("flooder").await;
+            let heartbeat = serde_json::to_string(&ClientMessage::Heartbeat).unwrap();
+            let flood = futures_util::stream::iter(
+                (0..100).map(move |_| Ok(Message::text(heartbeat.clone()))),
+            )
+            .chain(futures_util::stream::pending());
+            let run = run_connection(
+                connection_id,
+                RecordingSink::default(),
+                flood,
+                receiver,
+                handler,
+                fixture.connection_manager.clone(),
+                ConnectOptions::default(),
+                ClientInfo::default(),
+            );
+            tokio::time::timeout(Duration::from_secs(5), run)
+                .await
+                .expect("Rate limiting should end the connection");
+        }
+
+        // The next connection is kept out of the queue, even after a restart
+        for fixture in [fixture.clone(), fixture.clone().with_queue(tracked_queue())] {
+            let (connection_id, handler, mut receiver) = fixture.connect("flooder").await;
+            handler
+                .handle_message(ClientMessage::JoinQueue)
+                .await
+                .unwrap();
+            assert!(matches!(
+                receiver.try_recv(),
+                Ok(ServerMessage::QueueCooldown { retry_after }) if retry_after > 0
+            ));
+            assert_eq!(
+                fixture
+                    .matchmaking_queue
+                    .get_queue_position(connection_id)
+                    .await,
+                None
+            );
+            fixture
+                .connection_manager
+                .remove_connection(connection_id)
+                .await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_session_takeover_closes_old_socket() {
+        let fixture = HandlerFixture::new().await;
+        let connection_manager = fixture.connection_manager.clone();
+        let (old_connection, message_handler, message_receiver) = fixture.connect("user-1").await;
+
+        let sink = RecordingSink::default();
+        let run = tokio::spawn(run_connection(
+            old_connection,
+            sink.clone(),
+            futures_util::stream::pending(),
+            message_receiver,
+            message_handler,
+            connection_manager.clone(),
+            ConnectOptions::default(),
+            ClientInfo::default(),
+        ));
+
+        let new_connection = ConnectionId::new();
+        let _receiver = connection_manager.create_connection(new_connection).await;
+        connection_manager
+            .force_authenticate_connection(new_connection, "user-1".to_string())
+            .await
+            .unwrap();
+
+        tokio::time::timeout(Duration::from_secs(5), run)
+            .await
+            .expect("Takeover should end the old connection")
+            .unwrap();
+
+        let frames = sink.0.lock().unwrap();
+        assert!(frames[0].is_text()); // SessionDisconnected is delivered first
+        assert_eq!(frames.last().unwrap().close_frame().unwrap().0, 4002);
+    }
+
+    #[tokio::test]
+    async fn test_stalled_send_closes_connection() {
+        let fixture = HandlerFixture::with_managers(
+            ConnectionManager::new().with_send_timeout(Duration::from_millis(50)),
+            |game_manager| game_manager,
+        )
+        .await;
+        let connection_manager = fixture.connection_manager.clone();
+        let (connection_id, message_handler, message_receiver) = fixture.connect_anonymous().await;
+
+        connection_manager
+            .send_to_connection(connection_id, ServerMessage::QueueLeft)
+            .await
+            .unwrap();
+
+        // The client never sends anything, so only the send timeout can end the loop
+        let run = run_connection(
+            connection_id,
+            StalledSink,
+            futures_util::stream::pending(),
+            message_receiver,
+            message_handler,
+            connection_manager.clone(),
+            ConnectOptions::default(),
+            ClientInfo::default(),
+        );
+        tokio::time::timeout(Duration::from_secs(5), run)
+            .await
+            .expect("Connection should give up on a stalled socket");
+
+        assert!(
+            connection_manager
+                .get_connection(connection_id)
+                .await
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_after_drop_reuses_token() {
+        let fixture = HandlerFixture::new().await.with_auth_service(
+            AuthService::new_dev_mode().with_token_policy(crate::auth::TokenPolicy {
+                replay_window: Some(Duration::from_secs(60)),
+                ..Default::default()
+            }),
+        );
+        let token = dev_token("alice", "alice-session");
+
+        let (first, handler, mut receiver) = fixture.connect_anonymous().await;
+        handler
+            .handle_message(ClientMessage::Authenticate {
+                token: token.clone(),
+                auto_queue: false,
+            })
+            .await
+            .unwrap();
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(ServerMessage::AuthenticationSuccess { .. })
+        ));
+
+        // The socket drops and the client comes straight back with its token
+        handler.handle_disconnect().await;
+        fixture.connection_manager.remove_connection(first).await;
+        let (_, handler, mut receiver) = fixture.connect_anonymous().await;
+        handler
+            .handle_message(ClientMessage::Authenticate {
+                token,
+                auto_queue: false,
+            })
+            .await
+            .unwrap();
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(ServerMessage::AuthenticationSuccess { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_replayed_token_does_not_penalize_its_owner() {
+        let fixture = HandlerFixture::new().await;
+        let tracker = AbuseTracker::new(
+            Arc::new(AbuseRepository::new(fixture.db.clone())),
+            AbusePolicy {
+                window: Duration::from_secs(3600),
+                cooldown_after: 1,
+                ban_after: Some(1),
+            },
+        );
+        let fixture = fixture
+            .clone()
+            .with_queue(Arc::new(
+                MatchmakingQueue::new().with_abuse_tracker(tracker),
+            ))
+            .with_auth_service(AuthService::new_dev_mode().with_token_policy(
+                crate::auth::TokenPolicy {
+                    replay_window: Some(Duration::from_secs(60)),
+                    ..Default::default()
+                },
+            ));
+        let token = dev_token("alice", "alice-session");
+
+        let (_, owner, mut owner_receiver) = fixture.connect_anonymous().await;
+        owner
+            .handle_message(ClientMessage::Authenticate {
+                token: token.clone(),
+                auto_queue: false,
+            })
+            .await
+            .unwrap();
+        assert!(matches!(
+            owner_receiver.try_recv(),
+            Ok(ServerMessage::AuthenticationSuccess { .. })
+        ));
+
+        // Someone else presents alice's token while her connection is open
+        let (_, replayer, mut replayer_receiver) = fixture.connect_anonymous().await;
+        for _ in 0..2 {
+            replayer
+                .handle_message(ClientMessage::Authenticate {
+                    token: token.clone(),
+                    auto_queue: false,
+                })
+                .await
+                .unwrap();
+            assert!(matches!(
+                replayer_receiver.try_recv(),
+                Ok(ServerMessage::AuthenticationFailed { .. })
+            ));
+        }
+
+        let alice = "alice".to_string();
+        assert_eq!(
+            fixture.matchmaking_queue.cooldown_remaining(&alice).await,
+            None
+        );
+        assert_eq!(fixture.matchmaking_queue.ban_remaining(&alice).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_replacing_pending_guess_does_not_broadcast_state() {
+        let (fixture, connection_ids, handlers, mut receivers) =
+            handler_fixture(&["alice", "bob"]).await;
+        let game_id = fixture.seat_in_game(&connection_ids).await;
+
+        let word_length = fixture
+            .game_manager
+            .get_game_state(&game_id)
+            .await
+            .unwrap()
+            .word_length;
+
+        // Guesses must match the target's length
+        let words = match word_length {
+            5 => ["about", "above", "after"],
+            6 => ["friend", "letter", "number"],
+            _ => ["example", "nothing", "another"],
+        };
+        for word in words {
+            handlers[0]
+                .handle_message(ClientMessage::SubmitGuess {
+                    word: word.to_string(),
+                })
+                .await
+                .unwrap();
+        }
+
+        // Bob hears once that Alice has a guess in, and nothing about her changes
+        let bob_messages: Vec<ServerMessage> =
+            std::iter::from_fn(|| receivers[1].try_recv().ok()).collect();
+        assert_eq!(bob_messages.len(), 1, "Got {:?}", bob_messages);
+        assert!(matches!(
+            &bob_messages[0],
+            ServerMessage::PlayerGuessed { player_id } if player_id == "alice"
+        ));
+
+        // Alice gets an acknowledgement for every submission
+        let alice_messages: Vec<ServerMessage> =
+            std::iter::from_fn(|| receivers[0].try_recv().ok()).collect();
+        assert_eq!(alice_messages.len(), 3, "Got {:?}", alice_messages);
+        assert!(
+            alice_messages
+                .iter()
+                .all(|message| matches!(message, ServerMessage::PlayerGuessed { .. }))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_solving_the_word_announces_word_solved() {
+        let (fixture, connection_ids, handlers, mut receivers) =
+            handler_fixture(&["alice", "bob"]).await;
+        let game_id = fixture.seat_in_game(&connection_ids).await;
+
+        // Alice guesses the word while Bob misses
+        let target = fixture
+            .game_manager
+            .reportable_word(&game_id, connection_ids[0])
+            .await
+            .unwrap();
+        let miss = ["about", "friend", "example", "above", "letter", "nothing"]
+            .into_iter()
+            .find(|word| word.len() == target.len() && *word != target)
+            .unwrap();
+        for (handler, word) in handlers.iter().zip([target.as_str(), miss]) {
+            handler
+                .handle_message(ClientMessage::SubmitGuess {
+                    word: word.to_string(),
+                })
+                .await
+                .unwrap();
+        }
+
+        for receiver in &mut receivers {
+            let messages: Vec<ServerMessage> =
+                std::iter::from_fn(|| receiver.try_recv().ok()).collect();
+            let solved: Vec<_> = messages
+                .iter()
+                .filter_map(|message| match message {
+                    ServerMessage::WordSolved {
+                        word,
+                        solver,
+                        next_round,
+                    } => Some((word.clone(), solver.clone(), *next_round)),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(solved, vec![(target.clone(), "alice".to_string(), 2)]);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rejoin_under_new_name_renames_player() {
+        let fixture = HandlerFixture::with_managers(ConnectionManager::new(), |game_manager| {
+            game_manager.with_rename_on_rejoin(true)
+        })
+        .await;
+        let (connection_ids, _handlers, mut receivers) =
+            fixture.connect_all(&["alice", "bob"]).await;
+        let game_id = fixture.seat_in_game(&connection_ids).await;
+
+        // Alice drops and comes back after changing her profile name
+        fixture
+            .game_manager
+            .handle_player_disconnect(&game_id, connection_ids[0])
+            .await
+            .unwrap();
+        fixture
+            .connection_manager
+            .remove_connection(connection_ids[0])
+            .await;
+        let (_, handler, _alice_receiver) = fixture
+            .connect_as(game_types::User {
+                display_name: "Alicia".to_string(),
+                ..test_user("alice")
+            })
+            .await;
+        handler
+            .handle_message(ClientMessage::RejoinGame {
+                game_id: game_id.clone(),
+                token: None,
+            })
+            .await
+            .unwrap();
+
+        let state = fixture.game_manager.get_game_state(&game_id).await.unwrap();
+        let alice = state.players.iter().find(|p| p.user_id == "alice").unwrap();
+        assert_eq!(alice.display_name, "Alicia");
+
+        let bob_messages: Vec<ServerMessage> =
+            std::iter::from_fn(|| receivers[1].try_recv().ok()).collect();
+        assert!(bob_messages.iter().any(|message| matches!(
+            message,
+            ServerMessage::PlayerRenamed { player_id, display_name }
+                if player_id == "alice" && display_name == "Alicia"
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_spectator_watches_without_a_seat() {
+        let (fixture, connection_ids, handlers, mut receivers) =
+            handler_fixture(&["alice", "bob", "carol"]).await;
+        let game_id = fixture.seat_in_game(&connection_ids[..2]).await;
+
+        // Carol watches and gets the sanitized state straight away
+        handlers[2]
+            .handle_message(ClientMessage::SpectateGame {
+                game_id: game_id.clone(),
+            })
+            .await
+            .unwrap();
+        let carol_messages: Vec<ServerMessage> =
+            std::iter::from_fn(|| receivers[2].try_recv().ok()).collect();
+        assert!(
+            carol_messages
+                .iter()
+                .any(|message| matches!(message, ServerMessage::SpectatorStateUpdate { .. }))
+        );
+
+        // Her guesses are refused
+        handlers[2]
+            .handle_message(ClientMessage::SubmitGuess {
+                word: "about".to_string(),
+            })
+            .await
+            .unwrap();
+        let carol_messages: Vec<ServerMessage> =
+            std::iter::from_fn(|| receivers[2].try_recv().ok()).collect();
+        assert!(carol_messages.iter().any(|message| matches!(
+            message,
+            ServerMessage::Error { message, code: Some(game_types::ErrorCode::SpectatorCannotGuess), .. }
+                if message == "Spectators cannot guess"
+        )));
+
+        // The round still resolves on the two players' guesses alone
+        let target = fixture
+            .game_manager
+            .reportable_word(&game_id, connection_ids[0])
+            .await
+            .unwrap();
+        let miss = ["about", "friend", "example", "above", "letter", "nothing"]
+            .into_iter()
+            .find(|word| word.len() == target.len() && *word != target)
+            .unwrap();
+        for (handler, word) in handlers.iter().zip([target.as_str(), miss]) {
+            handler
+                .handle_message(ClientMessage::SubmitGuess {
+                    word: word.to_string(),
+                })
+                .await
+                .unwrap();
+        }
+        let state = fixture.game_manager.get_game_state(&game_id).await.unwrap();
+        assert!(state.players.iter().all(|p| p.user_id != "carol"));
+        assert!(state.players.iter().any(|p| p.points > 0));
+        let carol_messages: Vec<ServerMessage> =
+            std::iter::from_fn(|| receivers[2].try_recv().ok()).collect();
+        assert!(
+            carol_messages
+                .iter()
+                .any(|message| matches!(message, ServerMessage::SpectatorStateUpdate { .. }))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_spectators_share_one_payload_up_to_the_cap() {
+        let fixture = HandlerFixture::with_managers(
+            ConnectionManager::new().with_max_spectators(Some(2)),
+            |game_manager| game_manager,
+        )
+        .await;
+        let (connection_ids, handlers, mut receivers) = fixture
+            .connect_all(&["alice", "bob", "carol", "dave", "erin"])
+            .await;
+        let game_id = fixture.seat_in_game(&connection_ids[..2]).await;
+
+        for handler in &handlers[2..] {
+            handler
+                .handle_message(ClientMessage::SpectateGame {
+                    game_id: game_id.clone(),
+                })
+                .await
+                .unwrap();
+        }
+        assert!(matches!(
+            receivers[4].try_recv().unwrap(),
+            ServerMessage::SpectatorsFull { max_spectators: 2 }
+        ));
+        assert!(
+            !fixture
+                .connection_manager
+                .is_spectating(connection_ids[4], &game_id)
+                .await
+        );
+        for receiver in &mut receivers[2..4] {
+            while receiver.try_recv().is_ok() {}
+        }
+
+        // One update reaches both spectators as the same rendered JSON
+        let state = fixture.game_manager.get_game_state(&game_id).await.unwrap();
+        fixture
+            .connection_manager
+            .send_personalized_game_state(&game_id, &state)
+            .await;
+        let mut payloads = Vec::new();
+        for receiver in &mut receivers[2..4] {
+            match receiver.try_recv().unwrap() {
+                ServerMessage::SpectatorStateUpdate { state } => payloads.push(state),
+                other => panic!("Expected SpectatorStateUpdate, got {:?}", other),
+            }
+        }
+        assert!(std::ptr::eq(payloads[0].render(), payloads[1].render()));
+        assert_eq!(
+            serde_json::to_value(&payloads[0]).unwrap(),
+            serde_json::to_value(connection::spectator_view(&state)).unwrap()
+        );
+        // The socket writer embeds the rendered state as is
+        let frame = serde_json::to_string(&ServerMessage::SpectatorStateUpdate {
+            state: payloads[0].clone(),
+        })
+        .unwrap();
+        assert!(frame.contains(payloads[0].render().get()));
+        assert!(receivers[4].try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_spectators_are_released_when_game_ends_or_is_removed() {
+        let fixture = HandlerFixture::with_managers(ConnectionManager::new(), |game_manager| {
+            game_manager.with_max_game_duration(Some(Duration::from_millis(1)))
+        })
+        .await;
+        let (connection_ids, handlers, mut receivers) =
+            fixture.connect_all(&["alice", "bob", "carol"]).await;
+        let connection_manager = fixture.connection_manager.clone();
+
+        // A finished game is announced to its spectator, who stops watching
+        let game_id = fixture.seat_in_game(&connection_ids[..2]).await;
+        handlers[2]
+            .handle_message(ClientMessage::SpectateGame {
+                game_id: game_id.clone(),
+            })
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        handlers::end_overlong_games(
+            &connection_manager,
+            &fixture.game_manager,
+            &UserRepository::new(fixture.db.clone()),
+        )
+        .await;
+        let carol_messages: Vec<ServerMessage> =
+            std::iter::from_fn(|| receivers[2].try_recv().ok()).collect();
+        assert!(
+            carol_messages
+                .iter()
+                .any(|message| matches!(message, ServerMessage::GameOver { .. }))
+        );
+        assert!(
+            !connection_manager
+                .is_spectating(connection_ids[2], &game_id)
+                .await
+        );
+
+        // So does the spectator of a game cleaned up unfinished
+        let game_id = fixture.seat_in_game(&connection_ids[..2]).await;
+        handlers[2]
+            .handle_message(ClientMessage::SpectateGame {
+                game_id: game_id.clone(),
+            })
+            .await
+            .unwrap();
+        fixture
+            .game_manager
+            .cleanup_abandoned_games(Duration::ZERO)
+            .await;
+        let carol = connection_manager
+            .get_connection(connection_ids[2])
+            .await
+            .unwrap();
+        assert_eq!(carol.spectating, None);
+    }
+
+    #[tokio::test]
+    async fn test_lobby_host_starts_game_with_members() {
+        let (fixture, connection_ids, handlers, mut receivers) =
+            handler_fixture(&["alice", "bob", "carol"]).await;
+        let connection_manager = fixture.connection_manager.clone();
+        let matchmaking_queue = fixture.matchmaking_queue.clone();
+
+        handlers[0]
+            .handle_message(ClientMessage::CreateLobby)
+            .await
+            .unwrap();
+        let code = match receivers[0].try_recv().unwrap() {
+            ServerMessage::LobbyUpdate { code, host, .. } => {
+                assert_eq!(host, "alice");
+                code
+            }
+            other => panic!("Expected LobbyUpdate, got {:?}", other),
+        };
+
+        // A mistyped code is refused with the code in the message
+        handlers[1]
+            .handle_message(ClientMessage::JoinLobby {
+                code: "WRONG1".to_string(),
+            })
+            .await
+            .unwrap();
+        assert!(matches!(
+            receivers[1].try_recv().unwrap(),
+            ServerMessage::Error { message, .. }
+                if message == "Failed to join lobby: No lobby found with code WRONG1"
+        ));
+
+        handlers[1]
+            .handle_message(ClientMessage::JoinLobby { code: code.clone() })
+            .await
+            .unwrap();
+        assert!(matches!(
+            receivers[0].try_recv().unwrap(),
+            ServerMessage::LobbyUpdate { players, .. } if players.len() == 2
+        ));
+
+        // Only the host can start it
+        handlers[1]
+            .handle_message(ClientMessage::StartLobby)
+            .await
+            .unwrap();
+        let bob_messages: Vec<ServerMessage> =
+            std::iter::from_fn(|| receivers[1].try_recv().ok()).collect();
+        assert!(bob_messages.iter().any(|message| matches!(
+            message,
+            ServerMessage::Error { message, .. }
+                if message == "Failed to start lobby: Only the lobby host can do that"
+        )));
+
+        handlers[0]
+            .handle_message(ClientMessage::VoteStartGame)
+            .await
+            .unwrap();
+        for receiver in &mut receivers[..2] {
+            let messages: Vec<ServerMessage> =
+                std::iter::from_fn(|| receiver.try_recv().ok()).collect();
+            assert!(
+                messages
+                    .iter()
+                    .any(|message| matches!(message, ServerMessage::MatchFound { players, .. } if players.len() == 2))
+            );
+        }
+        for connection_id in &connection_ids[..2] {
+            let connection = connection_manager
+                .get_connection(*connection_id)
+                .await
+                .unwrap();
+            assert!(connection.game_id.is_some());
+        }
+        assert_eq!(matchmaking_queue.lobbies().lobby_count().await, 0);
+
+        // The code is gone once its game starts
+        handlers[2]
+            .handle_message(ClientMessage::JoinLobby { code })
+            .await
+            .unwrap();
+        assert!(matches!(
+            receivers[2].try_recv().unwrap(),
+            ServerMessage::Error { .. }
+        ));
+
+        // Nor can the private game be spectated
+        let game_id = connection_manager
+            .get_connection(connection_ids[0])
+            .await
+            .unwrap()
+            .game_id
+            .unwrap();
+        handlers[2]
+            .handle_message(ClientMessage::SpectateGame { game_id })
+            .await
+            .unwrap();
+        assert!(matches!(
+            receivers[2].try_recv().unwrap(),
+            ServerMessage::Error { message, .. } if message == "This game can't be spectated"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_lobby_starts_only_one_game() {
+        let (fixture, _, handlers, mut receivers) = handler_fixture(&["alice", "bob"]).await;
+
+        handlers[0]
+            .handle_message(ClientMessage::CreateLobby)
+            .await
+            .unwrap();
+        let code = match receivers[0].try_recv().unwrap() {
+            ServerMessage::LobbyUpdate { code, .. } => code,
+            other => panic!("Expected LobbyUpdate, got {:?}", other),
+        };
+        handlers[1]
+            .handle_message(ClientMessage::JoinLobby { code })
+            .await
+            .unwrap();
+
+        // A double-clicked start races itself
+        let (first, second) = tokio::join!(
+            handlers[0].handle_message(ClientMessage::StartLobby),
+            handlers[0].handle_message(ClientMessage::VoteStartGame),
+        );
+        first.unwrap();
+        second.unwrap();
+
+        assert_eq!(fixture.game_manager.get_active_games_count().await, 1);
+        let messages: Vec<ServerMessage> =
+            std::iter::from_fn(|| receivers[1].try_recv().ok()).collect();
+        assert_eq!(
+            messages
+                .iter()
+                .filter(|message| matches!(message, ServerMessage::MatchFound { .. }))
+                .count(),
+            1
+        );
+    }
+
+    /// Alice hosts a lobby that Bob and Carol have joined, with everyone's
+    /// messages so far drained
+    async fn lobby_of_three(
+        policy: HostLeavePolicy,
+    ) -> (
+        Vec<MessageHandler>,
+        Vec<mpsc::UnboundedReceiver<ServerMessage>>,
+    ) {
+        let fixture = HandlerFixture::new().await.with_queue(Arc::new(
+            MatchmakingQueue::new().with_host_leave_policy(policy),
+        ));
+        let (_, handlers, mut receivers) = fixture.connect_all(&["alice", "bob", "carol"]).await;
+
+        handlers[0]
+            .handle_message(ClientMessage::CreateLobby)
+            .await
+            .unwrap();
+        let code = match receivers[0].try_recv().unwrap() {
+            ServerMessage::LobbyUpdate { code, .. } => code,
+            other => panic!("Expected LobbyUpdate, got {:?}", other),
+        };
+        for handler in &handlers[1..] {
+            handler
+                .handle_message(ClientMessage::JoinLobby { code: code.clone() })
+                .await
+                .unwrap();
+        }
+        for receiver in &mut receivers {
+            while receiver.try_recv().is_ok() {}
+        }
+        (handlers, receivers)
+    }
+
+    #[tokio::test]
+    async fn test_host_disconnect_hands_lobby_over() {
+        let (handlers, mut receivers) = lobby_of_three(HostLeavePolicy::TransferHost).await;
+
+        handlers[0].handle_disconnect().await;
+
+        for receiver in &mut receivers[1..] {
+            assert!(matches!(
+                receiver.try_recv().unwrap(),
+                ServerMessage::HostChanged { new_host } if new_host == "bob"
+            ));
+            assert!(matches!(
+                receiver.try_recv().unwrap(),
+                ServerMessage::LobbyUpdate { host, players, .. }
+                    if host == "bob" && players.len() == 2
+            ));
+        }
+
+        // The new host can start the game
+        handlers[1]
+            .handle_message(ClientMessage::StartLobby)
+            .await
+            .unwrap();
+        assert!(matches!(
+            receivers[2].try_recv().unwrap(),
+            ServerMessage::MatchFound { players, .. } if players.len() == 2
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_host_disconnect_dissolves_lobby_under_dissolve_policy() {
+        let (handlers, mut receivers) = lobby_of_three(HostLeavePolicy::DissolveOnHostLeave).await;
+
+        handlers[0].handle_disconnect().await;
+
+        for receiver in &mut receivers[1..] {
+            assert!(matches!(
+                receiver.try_recv().unwrap(),
+                ServerMessage::LobbyClosed
+            ));
+            assert!(receiver.try_recv().is_err());
+        }
+
+        // Nobody is left in the lobby to start it
+        handlers[1]
+            .handle_message(ClientMessage::StartLobby)
+            .await
+            .unwrap();
+        assert!(matches!(
+            receivers[1].try_recv().unwrap(),
+            ServerMessage::Error { message, .. }
+                if message == "Failed to start lobby: Not in a lobby"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_host_announcement_reaches_members_and_joiners() {
+        let (handlers, mut receivers) = lobby_of_three(HostLeavePolicy::TransferHost).await;
+        let lobby_error = |receiver: &mut mpsc::UnboundedReceiver<ServerMessage>| match receiver
+            .try_recv()
+            .unwrap()
+        {
+            ServerMessage::Error { message, .. } => message,
+            other => panic!("Expected Error, got {:?}", other),
+        };
+
+        handlers[1]
+            .handle_message(ClientMessage::SetLobbyAnnouncement {
+                text: "Bob's tournament".to_string(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            lobby_error(&mut receivers[1]),
+            "Failed to set lobby announcement: Only the lobby host can do that"
+        );
+
+        handlers[0]
+            .handle_message(ClientMessage::SetLobbyAnnouncement {
+                text: "x".repeat(201),
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            lobby_error(&mut receivers[0]),
+            "Lobby announcement must be at most 200 characters"
+        );
+
+        handlers[0]
+            .handle_message(ClientMessage::SetLobbyAnnouncement {
+                text: "Round 3 of our tournament, 7-letter words".to_string(),
+            })
+            .await
+            .unwrap();
+        for receiver in &mut receivers {
+            assert!(matches!(
+                receiver.try_recv().unwrap(),
+                ServerMessage::LobbyAnnouncement { text: Some(text) }
+                    if text == "Round 3 of our tournament, 7-letter words"
+            ));
+        }
+
+        // Someone leaving and coming back sees it after the lobby update
+        handlers[2]
+            .handle_message(ClientMessage::LeaveLobby)
+            .await
+            .unwrap();
+        while receivers[2].try_recv().is_ok() {}
+        let code = match receivers[0].try_recv().unwrap() {
+            ServerMessage::LobbyUpdate { code, .. } => code,
+            other => panic!("Expected LobbyUpdate, got {:?}", other),
+        };
+        handlers[2]
+            .handle_message(ClientMessage::JoinLobby { code })
+            .await
+            .unwrap();
+        assert!(matches!(
+            receivers[2].try_recv().unwrap(),
+            ServerMessage::LobbyUpdate { .. }
+        ));
+        assert!(matches!(
+            receivers[2].try_recv().unwrap(),
+            ServerMessage::LobbyAnnouncement { text: Some(_) }
+        ));
+
+        // An empty announcement clears it
+        handlers[0]
+            .handle_message(ClientMessage::SetLobbyAnnouncement {
+                text: "  ".to_string(),
+            })
+            .await
+            .unwrap();
+        assert!(
+            std::iter::from_fn(|| receivers[1].try_recv().ok())
+                .any(|message| matches!(message, ServerMessage::LobbyAnnouncement { text: None }))
+        );
+
+        // It goes with the lobby when the game starts
+        handlers[0]
+            .handle_message(ClientMessage::StartLobby)
+            .await
+            .unwrap();
+        handlers[0]
+            .handle_message(ClientMessage::SetLobbyAnnouncement {
+                text: "Still here?".to_string(),
+            })
+            .await
+            .unwrap();
+        assert!(
+            std::iter::from_fn(|| receivers[0].try_recv().ok()).any(|message| matches!(
+                message,
+                ServerMessage::Error { message, .. }
+                    if message == "Failed to set lobby announcement: Not in a lobby"
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_lobby_announcement_is_checked_against_blocklist() {
+        let (fixture, connection_ids, handlers, mut receivers) = handler_fixture(&["alice"]).await;
+        fixture.game_manager.set_word_validator(
+            game_core::WordValidator::new_with_test_words().with_blocklist("jerks"),
+        );
+        handlers[0]
+            .handle_message(ClientMessage::CreateLobby)
+            .await
+            .unwrap();
+        receivers[0].try_recv().unwrap();
+
+        handlers[0]
+            .handle_message(ClientMessage::SetLobbyAnnouncement {
+                text: "Play fast, JERKS!".to_string(),
+            })
+            .await
+            .unwrap();
+        assert!(matches!(
+            receivers[0].try_recv().unwrap(),
+            ServerMessage::Error { message, .. }
+                if message == "Lobby announcement contains a word that isn't allowed"
+        ));
+        assert_eq!(
+            fixture
+                .matchmaking_queue
+                .lobbies()
+                .lobby_of(connection_ids[0])
+                .await
+                .and_then(|lobby| lobby.announcement),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_match_start_is_called_off_when_a_player_cannot_be_notified() {
+        let fixture = HandlerFixture::new().await;
+        let connection_manager = fixture.connection_manager.clone();
+        let game_manager = fixture.game_manager.clone();
+        let matchmaking_queue = fixture.matchmaking_queue.clone();
+
+        let mut players = Vec::new();
+        for user_id in ["alice", "bob"] {
+            let (connection_id, handler, receiver) = fixture.connect(user_id).await;
+            handler
+                .handle_message(ClientMessage::JoinQueue)
+                .await
+                .unwrap();
+            players.push((connection_id, handler, receiver));
+        }
+        let (alice_conn, alice, mut alice_rx) = players.remove(0);
+        let (bob_conn, bob, bob_rx) = players.remove(0);
+
+        // Bob votes, then his socket dies before the match is announced
+        bob.handle_message(ClientMessage::VoteStartGame)
+            .await
+            .unwrap();
+        drop(bob_rx);
+        alice
+            .handle_message(ClientMessage::VoteStartGame)
+            .await
+            .unwrap();
+        let matchmaker = Matchmaker::new(
+            connection_manager.clone(),
+            game_manager.clone(),
+            matchmaking_queue.clone(),
+        );
+        assert_eq!(matchmaker.tick().await, 1);
+
+        // Nobody is left in a game; Alice is told and goes back in the queue
+        assert_eq!(game_manager.get_active_games_count().await, 0);
+        for connection_id in [alice_conn, bob_conn] {
+            let connection = connection_manager.get_connection(connection_id).await;
+            assert!(connection.unwrap().game_id.is_none());
+        }
+        assert_eq!(
+            matchmaking_queue.get_queue_position(alice_conn).await,
+            Some(1)
+        );
+        assert_eq!(matchmaking_queue.get_queue_position(bob_conn).await, None);
+
+        let alice_messages: Vec<ServerMessage> =
+            std::iter::from_fn(|| alice_rx.try_recv().ok()).collect();
+        let match_found = alice_messages
+            .iter()
+            .position(|m| matches!(m, ServerMessage::MatchFound { .. }))
+            .expect("Alice should have heard about the match");
+        assert!(matches!(
+            alice_messages[match_found + 1..],
+            [
+                ServerMessage::GameLeft,
+                ServerMessage::QueueJoined { position: 1 }
+            ]
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_holds_queue_place_but_leave_queue_does_not() {
+        let fixture = HandlerFixture::new().await.with_queue(Arc::new(
+            MatchmakingQueue::new().with_disconnect_hold(Some(Duration::from_secs(30))),
+        ));
+        let connection_manager = fixture.connection_manager.clone();
+        let matchmaking_queue = fixture.matchmaking_queue.clone();
+
+        let connect = |user_id: &'static str| {
+            let fixture = fixture.clone();
+            async move {
+                let (connection_id, handler, receiver) = fixture.connect(user_id).await;
+                handler
+                    .handle_message(ClientMessage::JoinQueue)
+                    .await
+                    .unwrap();
+                (connection_id, handler, receiver)
+            }
+        };
+
+        let (alice_conn, alice, _alice_rx) = connect("alice").await;
+        let _bob = connect("bob").await;
+
+        // The socket drops: Alice's place is held and restored on reconnect
+        alice.handle_disconnect().await;
+        connection_manager.remove_connection(alice_conn).await;
+        let (alice_conn, alice, _alice_rx) = connect("alice").await;
+        assert_eq!(
+            matchmaking_queue.get_queue_position(alice_conn).await,
+            Some(1)
+        );
+
+        // Leaving on purpose sends her to the back next time
+        alice
+            .handle_message(ClientMessage::LeaveQueue)
+            .await
+            .unwrap();
+        alice.handle_disconnect().await;
+        connection_manager.remove_connection(alice_conn).await;
+        let (alice_conn, _alice, _alice_rx) = connect("alice").await;
+        assert_eq!(
+            matchmaking_queue.get_queue_position(alice_conn).await,
+            Some(2)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_queue_status_subscriber_is_not_queued() {
+        let fixture = HandlerFixture::new().await;
+        let connection_manager = fixture.connection_manager.clone();
+        let game_manager = fixture.game_manager.clone();
+        let matchmaking_queue = fixture.matchmaking_queue.clone();
+
+        // A lobby display watches without signing in
+        let (watcher_conn, watcher, mut watcher_rx) = fixture.connect_anonymous().await;
+        watcher
+            .handle_message(ClientMessage::SubscribeQueueStatus)
+            .await
+            .unwrap();
+        assert!(matches!(
+            watcher_rx.try_recv(),
+            Ok(ServerMessage::QueueStatus {
+                players_in_queue: 0
+            })
+        ));
+
+        let mut players = Vec::new();
+        for user_id in ["alice", "bob"] {
+            let (_, handler, receiver) = fixture.connect(user_id).await;
+            handler
+                .handle_message(ClientMessage::JoinQueue)
+                .await
+                .unwrap();
+            players.push((handler, receiver));
+        }
+        for (handler, _) in &players {
+            handler
+                .handle_message(ClientMessage::VoteStartGame)
+                .await
+                .unwrap();
+        }
+
+        let mut watcher_messages = Vec::new();
+        while let Ok(message) = watcher_rx.try_recv() {
+            watcher_messages.push(message);
+        }
+        assert!(watcher_messages.iter().any(|message| matches!(
+            message,
+            ServerMessage::MatchmakingCountdown {
+                total_players: 2,
+                ..
+            }
+        )));
+        assert!(watcher_messages.iter().any(|message| matches!(
+            message,
+            ServerMessage::QueueStatus {
+                players_in_queue: 2
+            }
+        )));
+        assert_eq!(matchmaking_queue.get_queue_length().await, 2);
+        assert!(
+            !matchmaking_queue
+                .get_queue_players()
+                .await
+                .contains(&watcher_conn)
+        );
+
+        // The match takes only the queued players
+        let matchmaker = Matchmaker::new(
+            connection_manager.clone(),
+            game_manager.clone(),
+            matchmaking_queue.clone(),
+        );
+        assert_eq!(matchmaker.tick().await, 1);
+        assert_eq!(game_manager.get_active_games_count().await, 1);
+        for (_, receiver) in &mut players {
+            let mut messages = std::iter::from_fn(|| receiver.try_recv().ok());
+            assert!(messages.any(|message| matches!(message, ServerMessage::MatchFound { .. })));
+        }
+        assert!(matches!(
+            watcher_rx.try_recv(),
+            Ok(ServerMessage::QueueStatus {
+                players_in_queue: 0
+            })
+        ));
+        assert!(watcher_rx.try_recv().is_err());
+        let watcher_connection = connection_manager.get_connection(watcher_conn).await;
+        assert_eq!(watcher_connection.unwrap().game_id, None);
+
+        // Unsubscribing stops the updates
+        watcher
+            .handle_message(ClientMessage::UnsubscribeQueueStatus)
+            .await
+            .unwrap();
+        let (_, carol, _carol_rx) = fixture.connect("carol").await;
+        carol
+            .handle_message(ClientMessage::JoinQueue)
+            .await
+            .unwrap();
+        assert!(watcher_rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_practice_word_requires_admin_outside_dev_mode() {
+        let fixture = HandlerFixture::new()
+            .await
+            .with_auth_service(AuthService::new("tenant".to_string(), "client".to_string()));
+
+        for (user_id, roles) in [("player", vec![]), ("admin", vec![game_types::Role::Admin])] {
+            let (_, handler, mut receiver) = fixture
+                .connect_as(game_types::User {
+                    roles,
+                    ..test_user(user_id)
+                })
+                .await;
+
+            handler
+                .handle_message(ClientMessage::StartPracticeWord {
+                    word: "world".to_string(),
+                })
+                .await
+                .unwrap();
+            let reply = receiver.try_recv().unwrap();
+            if user_id == "admin" {
+                assert!(matches!(reply, ServerMessage::MatchFound { .. }));
+                assert!(matches!(
+                    receiver.try_recv(),
+                    Ok(ServerMessage::GameStateUpdate { .. })
+                ));
+            } else {
+                assert!(matches!(reply, ServerMessage::Error { .. }));
+            }
+        }
+        assert_eq!(fixture.game_manager.get_active_games_count().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_muted_player_chat_skips_only_the_muting_connection() {
+        let (fixture, connection_ids, handlers, mut receivers) =
+            handler_fixture(&["alice", "bob", "carol"]).await;
+        fixture.seat_in_game(&connection_ids).await;
+        for receiver in &mut receivers {
+            while receiver.try_recv().is_ok() {}
+        }
+        let chat_from = |receiver: &mut mpsc::UnboundedReceiver<ServerMessage>| {
+            std::iter::from_fn(|| receiver.try_recv().ok()).find_map(|message| match message {
+                ServerMessage::ChatMessage { player_id, text } => Some((player_id, text)),
+                _ => None,
+            })
+        };
+
+        handlers[0]
+            .handle_message(ClientMessage::MutePlayer {
+                player_id: "bob".to_string(),
+            })
+            .await
+            .unwrap();
+        handlers[1]
+            .handle_message(ClientMessage::SendChat {
+                text: "good luck".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(chat_from(&mut receivers[0]), None);
+        for receiver in &mut receivers[1..] {
+            assert_eq!(
+                chat_from(receiver),
+                Some(("bob".to_string(), "good luck".to_string()))
+            );
+        }
+
+        // Muting bob doesn't hide carol, and unmuting lets bob through again
+        handlers[2]
+            .handle_message(ClientMessage::SendChat {
+                text: "hi all".to_string(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            chat_from(&mut receivers[0]),
+            Some(("carol".to_string(), "hi all".to_string()))
+        );
+        handlers[0]
+            .handle_message(ClientMessage::UnmutePlayer {
+                player_id: "bob".to_string(),
+            })
+            .await
+            .unwrap();
+        handlers[1]
+            .handle_message(ClientMessage::SendChat {
+                text: "gg".to_string(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            chat_from(&mut receivers[0]),
+            Some(("bob".to_string(), "gg".to_string()))
+        );
+    }
+}
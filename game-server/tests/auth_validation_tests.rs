@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use game_core::GameError;
 use game_server::{
     auth::AuthService,
     game_manager::{GameEvent, GameManager},
@@ -26,6 +27,8 @@ async fn setup_authenticated_connections(
             total_wins: 0,
             total_games: 0,
             created_at: chrono::Utc::now().to_rfc3339(),
+            avatar: None,
+            roles: Vec::new(),
         };
 
         connection_manager
@@ -111,8 +114,7 @@ async fn test_create_game_with_unauthenticated_users() {
     let result = game_manager
         .create_game(vec![connection_id1, connection_id2])
         .await;
-    assert!(result.is_err());
-    assert!(result.unwrap_err().contains("not authenticated"));
+    assert!(matches!(result, Err(GameError::PlayerNotAuthenticated)));
 }
 
 #[tokio::test]
@@ -142,8 +144,7 @@ async fn test_create_game_with_partially_authenticated_users() {
     let result = game_manager
         .create_game(vec![authenticated_id, unauthenticated_id])
         .await;
-    assert!(result.is_err());
-    assert!(result.unwrap_err().contains("not authenticated"));
+    assert!(matches!(result, Err(GameError::PlayerNotAuthenticated)));
 }
 
 #[tokio::test]
@@ -162,6 +163,8 @@ async fn test_create_game_with_duplicate_users() {
         total_wins: 0,
         total_games: 0,
         created_at: chrono::Utc::now().to_rfc3339(),
+        avatar: None,
+        roles: Vec::new(),
     };
 
     let connection_id1 = ConnectionId::new();
@@ -181,8 +184,10 @@ async fn test_create_game_with_duplicate_users() {
     let result = game_manager
         .create_game(vec![connection_id1, connection_id2])
         .await;
-    assert!(result.is_err());
-    assert!(result.unwrap_err().contains("already in the game"));
+    assert!(matches!(
+        result,
+        Err(GameError::DuplicatePlayer { display_name }) if display_name == "Alice"
+    ));
 }
 
 #[tokio::test]
@@ -198,9 +203,7 @@ async fn test_create_game_with_nonexistent_connections() {
     let result = game_manager
         .create_game(vec![connection_id1, connection_id2])
         .await;
-    assert!(result.is_err());
-    let error_msg = result.unwrap_err();
-    assert!(error_msg.contains("Connection") && error_msg.contains("not found"));
+    assert!(matches!(result, Err(GameError::PlayerNotConnected)));
 }
 
 #[tokio::test]
@@ -223,8 +226,10 @@ async fn test_create_game_with_insufficient_players() {
 
     // Attempt to create game should fail (need at least 2 players)
     let result = game_manager.create_game(connection_ids).await;
-    assert!(result.is_err());
-    assert!(result.unwrap_err().contains("Need at least 2 players"));
+    assert!(matches!(
+        result,
+        Err(GameError::InvalidPlayerCount { count: 1 })
+    ));
 }
 
 #[tokio::test]
@@ -258,25 +263,34 @@ async fn test_submit_guess_validates_user_identity() {
         .await
         .unwrap();
 
+    // Guesses have to match the target's length
+    let word_length = game_manager
+        .get_game_state(&game_id)
+        .await
+        .unwrap()
+        .word_length;
+    let (first, second) = match word_length {
+        5 => ("ABOUT", "BEACH"),
+        6 => ("SECOND", "FRIEND"),
+        _ => ("EXAMPLE", "NOTHING"),
+    };
+
     // First player submits a guess - should work
     let result1 = game_manager
-        .submit_guess(&game_id, connection_ids[0], "ABOUT".to_string())
+        .submit_guess(&game_id, connection_ids[0], first.to_string())
         .await;
 
     // Verify the guess was associated with the correct user
-    if let Ok(GameEvent::StateUpdate { state }) = result1 {
-        // Check that Alice's guess is recorded
-        let alice = state
-            .players
-            .iter()
-            .find(|p| p.display_name == "Alice")
-            .unwrap();
-        assert_eq!(alice.user_id, "550e8400-e29b-41d4-a716-446655440001");
+    match result1 {
+        Ok(GameEvent::GuessPending { player_id, .. }) => {
+            assert_eq!(player_id, "550e8400-e29b-41d4-a716-446655440001");
+        }
+        other => panic!("Expected GuessPending, got {:?}", other),
     }
 
     // Second player submits a guess - should also work
     let result2 = game_manager
-        .submit_guess(&game_id, connection_ids[1], "BEACH".to_string())
+        .submit_guess(&game_id, connection_ids[1], second.to_string())
         .await;
     assert!(result2.is_ok());
 }
@@ -388,7 +402,7 @@ async fn test_security_user_cannot_access_other_game() {
         .submit_guess(&game_id1, charlie_connection, "ABOUT".to_string())
         .await;
     assert!(result.is_err());
-    assert!(result.unwrap_err().contains("Player not in game"));
+    assert!(matches!(result.unwrap_err(), GameError::PlayerNotInGame));
 }
 
 #[tokio::test]
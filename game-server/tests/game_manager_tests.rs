@@ -1,7 +1,9 @@
 mod test_helpers;
 
-use game_server::game_manager::GameEvent;
-use game_types::{GamePhase, GameStatus};
+use game_core::GameError;
+use game_server::game_manager::{GameEvent, RoundTimer, error_code};
+use game_server::websocket::connection::ConnectionId;
+use game_types::{ErrorCode, GameOverReason, GamePhase, GameStatus, PriorGuess};
 use test_helpers::*;
 
 #[tokio::test]
@@ -28,8 +30,74 @@ async fn test_game_creation_insufficient_players() {
     let connection_ids: Vec<_> = connections.iter().map(|(id, _)| *id).collect();
 
     let result = setup.create_test_game(connection_ids).await;
-    assert!(result.is_err());
-    assert!(result.unwrap_err().contains("Need at least 2 players"));
+    assert!(matches!(
+        result,
+        Err(GameError::InvalidPlayerCount { count: 1 })
+    ));
+}
+
+#[tokio::test]
+async fn test_game_created_with_requested_point_threshold() {
+    let setup = TestGameServerSetup::new();
+
+    let connections = setup.create_multiple_connections(&["Alice", "Bob"]).await;
+    let connection_ids: Vec<_> = connections.iter().map(|(id, _)| *id).collect();
+
+    let game_id = setup
+        .game_manager
+        .create_game_with_threshold(connection_ids.clone(), Some(15))
+        .await
+        .unwrap();
+    let state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+    assert_eq!(state.point_threshold, 15);
+
+    for point_threshold in [4, 201] {
+        let error = setup
+            .game_manager
+            .create_game_with_threshold(connection_ids.clone(), Some(point_threshold))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error,
+            GameError::PointThresholdOutOfRange {
+                threshold: point_threshold,
+                min: 5,
+                max: 200,
+            }
+        );
+        assert_eq!(error_code(&error), ErrorCode::InvalidPointThreshold);
+    }
+}
+
+#[tokio::test]
+async fn test_requested_point_threshold_beats_length_scaling() {
+    let setup = TestGameServerSetup::with_manager(|game_manager| {
+        game_manager.with_rules(game_core::GameRules {
+            points_per_letter: Some(10),
+            ..Default::default()
+        })
+    });
+
+    let connections = setup.create_multiple_connections(&["Alice", "Bob"]).await;
+    let connection_ids: Vec<_> = connections.iter().map(|(id, _)| *id).collect();
+    let game_id = setup
+        .game_manager
+        .create_game_with_threshold(connection_ids, Some(15))
+        .await
+        .unwrap();
+    let state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+    assert_eq!(state.point_threshold, 15);
+
+    // Without a requested threshold the word length decides
+    let connections = setup.create_multiple_connections(&["Carol", "Dave"]).await;
+    let connection_ids: Vec<_> = connections.iter().map(|(id, _)| *id).collect();
+    let game_id = setup
+        .game_manager
+        .create_game_with_threshold(connection_ids, None)
+        .await
+        .unwrap();
+    let state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+    assert_eq!(state.point_threshold, 10 * state.word_length);
 }
 
 #[tokio::test]
@@ -38,15 +106,16 @@ async fn test_single_guess_submission_waits_for_others() {
     let (game_id, connections) = setup_ready_game(&setup, &["Alice", "Bob"]).await.unwrap();
 
     let (alice_conn, _) = &connections[0];
+    let words = words_for_target(&setup, &game_id).await;
 
     // Alice submits a guess
     let event = setup
-        .submit_guess(&game_id, *alice_conn, "ABOUT")
+        .submit_guess(&game_id, *alice_conn, words[0])
         .await
         .unwrap();
 
-    // Should get StateUpdate, not RoundResult (waiting for Bob)
-    assert_state_update(&event);
+    // Should get GuessPending, not RoundResult (waiting for Bob)
+    assert_guess_pending(&event);
 }
 
 #[tokio::test]
@@ -56,17 +125,18 @@ async fn test_both_players_guess_triggers_round_processing() {
 
     let (alice_conn, _) = &connections[0];
     let (bob_conn, _) = &connections[1];
+    let words = words_for_target(&setup, &game_id).await;
 
     // Alice submits first guess - should wait
     let event1 = setup
-        .submit_guess(&game_id, *alice_conn, "ABOUT")
+        .submit_guess(&game_id, *alice_conn, words[0])
         .await
         .unwrap();
-    assert_state_update(&event1);
+    assert_guess_pending(&event1);
 
     // Bob submits second guess - should trigger round processing
     let event2 = setup
-        .submit_guess(&game_id, *bob_conn, "AFTER")
+        .submit_guess(&game_id, *bob_conn, words[2])
         .await
         .unwrap();
 
@@ -92,23 +162,24 @@ async fn test_multiplayer_guess_coordination() {
     let (alice_conn, _) = &connections[0];
     let (bob_conn, _) = &connections[1];
     let (charlie_conn, _) = &connections[2];
+    let words = words_for_target(&setup, &game_id).await;
 
-    // First two guesses should return StateUpdate
+    // First two guesses should return GuessPending
     let event1 = setup
-        .submit_guess(&game_id, *alice_conn, "ABOUT")
+        .submit_guess(&game_id, *alice_conn, words[0])
         .await
         .unwrap();
-    assert_state_update(&event1);
+    assert_guess_pending(&event1);
 
     let event2 = setup
-        .submit_guess(&game_id, *bob_conn, "ABOVE")
+        .submit_guess(&game_id, *bob_conn, words[1])
         .await
         .unwrap();
-    assert_state_update(&event2);
+    assert_guess_pending(&event2);
 
     // Third guess should trigger processing
     let event3 = setup
-        .submit_guess(&game_id, *charlie_conn, "AFTER")
+        .submit_guess(&game_id, *charlie_conn, words[2])
         .await
         .unwrap();
 
@@ -118,6 +189,7 @@ async fn test_multiplayer_guess_coordination() {
             winning_guess,
             player_guesses,
             is_word_completed: _,
+            ..
         } => {
             assert!(!winning_guess.word.is_empty());
             assert_eq!(player_guesses.len(), 3); // All players should have personal guesses
@@ -136,10 +208,14 @@ async fn test_invalid_word_rejection() {
 
     let (alice_conn, _) = &connections[0];
 
-    // Try to submit invalid word
-    let result = setup.submit_guess(&game_id, *alice_conn, "XYZABC").await;
+    let words = words_for_target(&setup, &game_id).await;
+
+    // Try to submit invalid word of the right length
+    let result = setup
+        .submit_guess(&game_id, *alice_conn, &"XYZABCD"[..words[0].len()])
+        .await;
     assert!(result.is_err());
-    assert!(result.unwrap_err().contains("Invalid word"));
+    assert!(matches!(result.unwrap_err(), GameError::InvalidWord { .. }));
 }
 
 #[tokio::test]
@@ -150,16 +226,9 @@ async fn test_duplicate_word_rejection() {
     let (alice_conn, _) = &connections[0];
     let (bob_conn, _) = &connections[1];
 
-    // Get the target word length and select appropriate words
-    let initial_state = setup.game_manager.get_game_state(&game_id).await.unwrap();
-    let target_length = initial_state.word_length as usize;
-
-    let (word1, word2) = match target_length {
-        5 => ("ABOUT", "AFTER"),
-        6 => ("SECOND", "FOURTH"),
-        7 => ("EXAMPLE", "NOTHING"),
-        _ => ("ABOUT", "AFTER"), // fallback
-    };
+    // Valid guesses of the target's length that don't solve it
+    let words = words_for_target(&setup, &game_id).await;
+    let (word1, word2) = (words[0], words[2]);
 
     // Play one complete round to get a word onto the official board
     let _round_event = play_round(
@@ -208,11 +277,11 @@ async fn test_duplicate_word_rejection() {
         result.is_err(),
         "Expected error when submitting word that's already on the official board"
     );
-    let error_msg = result.unwrap_err().to_string();
+    let error = result.unwrap_err();
     assert!(
-        error_msg.contains("already guessed") || error_msg.contains("Word already guessed"),
-        "Expected 'already guessed' error, got: '{}'",
-        error_msg
+        matches!(error, GameError::WordAlreadyGuessed { .. }),
+        "Expected WordAlreadyGuessed error, got: {:?}",
+        error
     );
 }
 
@@ -226,7 +295,7 @@ async fn test_player_not_in_game() {
 
     let result = setup.submit_guess(&game_id, outsider_conn, "ABOUT").await;
     assert!(result.is_err());
-    assert!(result.unwrap_err().contains("Player not in game"));
+    assert!(matches!(result.unwrap_err(), GameError::PlayerNotInGame));
 }
 
 #[tokio::test]
@@ -238,7 +307,90 @@ async fn test_game_not_found() {
         .submit_guess("nonexistent-game", alice_conn, "ABOUT")
         .await;
     assert!(result.is_err());
-    assert!(result.unwrap_err().contains("Game not found"));
+    assert!(matches!(result.unwrap_err(), GameError::GameNotFound));
+}
+
+#[tokio::test]
+async fn test_guess_errors_map_to_error_codes() {
+    let setup = TestGameServerSetup::new();
+    let (game_id, connections) = setup_ready_game(&setup, &["Alice", "Bob"]).await.unwrap();
+    let (alice_conn, _) = &connections[0];
+    let word_length = setup
+        .game_manager
+        .get_game_state(&game_id)
+        .await
+        .unwrap()
+        .word_length as usize;
+
+    let invalid = setup
+        .submit_guess(&game_id, *alice_conn, &"XYZABCD"[..word_length])
+        .await
+        .unwrap_err();
+    assert_eq!(error_code(&invalid), ErrorCode::InvalidWord);
+
+    let too_long = setup
+        .submit_guess(&game_id, *alice_conn, "ABOUTABOUT")
+        .await
+        .unwrap_err();
+    assert_eq!(error_code(&too_long), ErrorCode::WrongLength);
+
+    let missing = setup
+        .submit_guess("nonexistent-game", *alice_conn, "ABOUT")
+        .await
+        .unwrap_err();
+    assert_eq!(error_code(&missing), ErrorCode::GameNotFound);
+}
+
+#[tokio::test]
+async fn test_board_duplicate_reports_prior_guess() {
+    let setup = TestGameServerSetup::new();
+    let connections = setup.create_multiple_connections(&["Alice", "Bob"]).await;
+    let (alice_conn, _) = connections[0].clone();
+    let (bob_conn, _) = connections[1].clone();
+    let game_id = setup
+        .game_manager
+        .create_game_with_seed(vec![alice_conn, bob_conn], 7)
+        .await
+        .unwrap();
+    let target = setup.game_manager.replay_target_word(7, 1).unwrap();
+    let mut words = ["about", "beach", "chair"]
+        .into_iter()
+        .filter(|word| *word != target);
+    play_round(
+        &setup,
+        &game_id,
+        vec![
+            (alice_conn, words.next().unwrap()),
+            (bob_conn, words.next().unwrap()),
+        ],
+    )
+    .await
+    .unwrap();
+
+    // The round winner tries the word that is already on the board
+    let state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+    let on_board = state.official_board[0].clone();
+    let winner_conn = if state.current_winner.as_ref() == Some(&connections[0].1.id) {
+        alice_conn
+    } else {
+        bob_conn
+    };
+    let duplicate = setup
+        .submit_guess(&game_id, winner_conn, &on_board.word)
+        .await
+        .unwrap_err();
+
+    assert_eq!(error_code(&duplicate), ErrorCode::WordAlreadyGuessed);
+    assert_eq!(
+        duplicate,
+        GameError::WordAlreadyGuessed {
+            word: on_board.word.clone(),
+            prior: Some(PriorGuess {
+                round: 1,
+                player_id: on_board.player_id,
+            }),
+        }
+    );
 }
 
 #[tokio::test]
@@ -260,14 +412,15 @@ async fn test_disconnected_player_handling() {
         .unwrap();
 
     // Now only Alice and Bob need to guess for round to process
+    let words = words_for_target(&setup, &game_id).await;
     let event1 = setup
-        .submit_guess(&game_id, *alice_conn, "ABOUT")
+        .submit_guess(&game_id, *alice_conn, words[0])
         .await
         .unwrap();
-    assert_state_update(&event1); // Still waiting for Bob
+    assert_guess_pending(&event1); // Still waiting for Bob
 
     let event2 = setup
-        .submit_guess(&game_id, *bob_conn, "AFTER")
+        .submit_guess(&game_id, *bob_conn, words[2])
         .await
         .unwrap();
     // Should process now since Charlie is disconnected
@@ -295,12 +448,13 @@ async fn test_game_state_progression() {
 
     let (alice_conn, _) = &connections[0];
     let (bob_conn, _) = &connections[1];
+    let words = words_for_target(&setup, &game_id).await;
 
     // Play a complete round
     let round_event = play_round(
         &setup,
         &game_id,
-        vec![(*alice_conn, "ABOUT"), (*bob_conn, "AFTER")],
+        vec![(*alice_conn, words[0]), (*bob_conn, words[2])],
     )
     .await
     .unwrap();
@@ -341,7 +495,7 @@ async fn test_game_state_progression() {
             // The round should have progressed in some way
             let round_progressed = after_state.current_round >= 2;
             let in_individual_phase = after_state.current_phase == GamePhase::IndividualGuess;
-            let has_official_board_entries = after_state.official_board.len() >= 1;
+            let has_official_board_entries = !after_state.official_board.is_empty();
 
             assert!(
                 round_progressed || in_individual_phase,
@@ -371,11 +525,9 @@ async fn test_game_continues_with_valid_words() {
     let (alice_conn, _) = &connections[0];
     let (bob_conn, _) = &connections[1];
 
-    // Get the target word length and select appropriate words
-    let initial_state = setup.game_manager.get_game_state(&game_id).await.unwrap();
-    let target_length = initial_state.word_length as usize;
-
-    let valid_words = match target_length {
+    // Words of each target length; a solved word brings a new target, possibly
+    // of another length
+    let valid_words = |target_length: i32| match target_length {
         5 => vec![
             "ABOUT", "ABOVE", "AFTER", "AGAIN", "BEACH", "BLACK", "BROWN", "CHAIR",
         ],
@@ -389,16 +541,16 @@ async fn test_game_continues_with_valid_words() {
     };
     let mut round_count = 0;
 
-    for i in (0..valid_words.len()).step_by(2) {
-        if i + 1 >= valid_words.len() {
+    for i in (0..8).step_by(2) {
+        // Check current game phase first
+        let current_state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+        let words = valid_words(current_state.word_length);
+        if i + 1 >= words.len() {
             break;
         }
 
-        let word1 = valid_words[i];
-        let word2 = valid_words[i + 1];
-
-        // Check current game phase first
-        let current_state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+        let word1 = words[i];
+        let word2 = words[i + 1];
 
         let event = if current_state.current_phase == GamePhase::IndividualGuess {
             // Individual guess phase - only winner can guess
@@ -429,6 +581,7 @@ async fn test_game_continues_with_valid_words() {
             GameEvent::GameOver {
                 winner,
                 final_scores,
+                ..
             } => {
                 // Game ended (either word was guessed or points threshold reached)
                 assert_eq!(final_scores.len(), 2);
@@ -443,11 +596,12 @@ async fn test_game_continues_with_valid_words() {
                 winning_guess,
                 player_guesses,
                 is_word_completed: _,
+                ..
             } => {
                 // Game continues - verify round was processed correctly
                 assert!(!winning_guess.word.is_empty());
                 // In collaborative phase we expect 2 guesses, in individual phase we expect 1
-                assert!(player_guesses.len() >= 1 && player_guesses.len() <= 2);
+                assert!(!player_guesses.is_empty() && player_guesses.len() <= 2);
                 assert!(winning_guess.points_earned >= 0);
             }
             _ => panic!("Unexpected event: {:?}", event),
@@ -487,29 +641,30 @@ async fn test_concurrent_guess_overwrite() {
 
     let (alice_conn, _) = &connections[0];
     let (bob_conn, _) = &connections[1];
+    let words = words_for_target(&setup, &game_id).await;
 
     // Alice submits first guess
     let _event1 = setup
-        .submit_guess(&game_id, *alice_conn, "ABOUT")
+        .submit_guess(&game_id, *alice_conn, words[0])
         .await
         .unwrap();
 
     // Alice submits second guess (should overwrite first)
     let _event2 = setup
-        .submit_guess(&game_id, *alice_conn, "AFTER")
+        .submit_guess(&game_id, *alice_conn, words[2])
         .await
         .unwrap();
 
     // Bob submits final guess to trigger processing
     let event3 = setup
-        .submit_guess(&game_id, *bob_conn, "AGAIN")
+        .submit_guess(&game_id, *bob_conn, words[3])
         .await
         .unwrap();
 
     // Check that processing occurred
     match event3 {
         GameEvent::RoundResult { player_guesses, .. } => {
-            // Alice's guess should be "AFTER" (the latest one)
+            // Alice's guess should be her second one
             let alice_guess = player_guesses
                 .iter()
                 .find(|(conn_id, _)| *conn_id == *alice_conn);
@@ -530,12 +685,13 @@ async fn test_points_accumulation() {
 
     let (alice_conn, _) = &connections[0];
     let (bob_conn, _) = &connections[1];
+    let words = words_for_target(&setup, &game_id).await;
 
     // Play one complete round
     let event = play_round(
         &setup,
         &game_id,
-        vec![(*alice_conn, "ABOUT"), (*bob_conn, "AFTER")],
+        vec![(*alice_conn, words[0]), (*bob_conn, words[2])],
     )
     .await
     .unwrap();
@@ -617,6 +773,7 @@ async fn test_round_completion_starts_new_round() {
                 .game_manager
                 .submit_guess(&game_id, winner_conn, target_word.to_string())
                 .await
+                .map_err(|e| e.to_string())
         } else {
             // Skip other phases
             continue;
@@ -624,9 +781,7 @@ async fn test_round_completion_starts_new_round() {
 
         match event {
             Ok(GameEvent::RoundResult {
-                winning_guess,
-                is_word_completed,
-                ..
+                is_word_completed, ..
             }) => {
                 // Check if this was a word completion (should trigger round restart)
                 if is_word_completed {
@@ -700,17 +855,17 @@ async fn test_round_completion_starts_new_round() {
                     .submit_guess(&game_id, winner_conn, target_word.to_string())
                     .await;
 
-                if let Ok(GameEvent::RoundResult { winning_guess, .. }) = result {
-                    if winning_guess.word.to_lowercase() == target_word.to_lowercase() {
-                        let post_completion_state =
-                            setup.game_manager.get_game_state(&game_id).await.unwrap();
-                        assert!(
-                            post_completion_state.current_round > initial_round,
-                            "Round should have incremented after individual word completion"
-                        );
-                        round_completed = true;
-                        break;
-                    }
+                if let Ok(GameEvent::RoundResult { winning_guess, .. }) = result
+                    && winning_guess.word.to_lowercase() == target_word.to_lowercase()
+                {
+                    let post_completion_state =
+                        setup.game_manager.get_game_state(&game_id).await.unwrap();
+                    assert!(
+                        post_completion_state.current_round > initial_round,
+                        "Round should have incremented after individual word completion"
+                    );
+                    round_completed = true;
+                    break;
                 }
             }
         }
@@ -722,3 +877,2007 @@ async fn test_round_completion_starts_new_round() {
     );
     // Note: Even if we didn't complete a round, the test validates the structure is in place
 }
+
+#[tokio::test]
+async fn test_pinned_seed_reproduces_target_words() {
+    let setup = TestGameServerSetup::new();
+    let connections = setup.create_multiple_connections(&["Alice", "Bob"]).await;
+    let connection_ids: Vec<_> = connections.iter().map(|(id, _)| *id).collect();
+    let (alice_conn, bob_conn) = (connection_ids[0], connection_ids[1]);
+
+    let game_id = setup
+        .game_manager
+        .create_game_with_seed(connection_ids, 42)
+        .await
+        .unwrap();
+    assert_eq!(setup.game_manager.get_game_seed(&game_id).await, Some(42));
+
+    // Replaying the seed yields the word the game is actually using
+    let target = setup.game_manager.replay_target_word(42, 1).unwrap();
+    let state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+    assert_eq!(state.word_length as usize, target.len());
+
+    let other = if target == "about" { "beach" } else { "about" };
+    setup
+        .submit_guess(&game_id, alice_conn, &target)
+        .await
+        .unwrap();
+    let event = setup.submit_guess(&game_id, bob_conn, other).await.unwrap();
+    assert!(matches!(
+        event,
+        GameEvent::RoundResult {
+            is_word_completed: true,
+            ..
+        }
+    ));
+
+    // The next round's word is derived from the same seed
+    let next_target = setup.game_manager.replay_target_word(42, 2).unwrap();
+    let state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+    assert_eq!(state.current_round, 2);
+    assert_eq!(state.word_length as usize, next_target.len());
+}
+
+#[tokio::test]
+async fn test_ready_check_holds_game_until_all_ready() {
+    let setup = TestGameServerSetup::with_manager(|game_manager| {
+        game_manager.with_ready_timeout(Some(std::time::Duration::from_secs(30)))
+    });
+    let (game_id, connections) = setup_ready_game(&setup, &["Alice", "Bob"]).await.unwrap();
+    let (alice_conn, _) = &connections[0];
+    let (bob_conn, _) = &connections[1];
+
+    let state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+    assert_eq!(state.current_phase, GamePhase::Waiting);
+
+    // Guessing is rejected until the round starts
+    let words = words_for_target(&setup, &game_id).await;
+    let result = setup.submit_guess(&game_id, *alice_conn, words[0]).await;
+    assert!(matches!(result, Err(GameError::WrongPhase { .. })));
+
+    let update = setup
+        .game_manager
+        .mark_player_ready(&game_id, *alice_conn)
+        .await
+        .unwrap();
+    assert!(!update.game_started);
+    assert_eq!(update.state.current_phase, GamePhase::Waiting);
+
+    let update = setup
+        .game_manager
+        .mark_player_ready(&game_id, *bob_conn)
+        .await
+        .unwrap();
+    assert!(update.game_started);
+    assert_eq!(update.state.current_phase, GamePhase::Guessing);
+    assert_eq!(update.state.status, GameStatus::Active);
+}
+
+#[tokio::test]
+async fn test_ready_check_timeout_starts_game() {
+    let setup = TestGameServerSetup::with_manager(|game_manager| {
+        game_manager.with_ready_timeout(Some(std::time::Duration::from_secs(30)))
+    });
+    let (game_id, connections) = setup_ready_game(&setup, &["Alice", "Bob"]).await.unwrap();
+    let (alice_conn, _) = &connections[0];
+
+    setup
+        .game_manager
+        .mark_player_ready(&game_id, *alice_conn)
+        .await
+        .unwrap();
+
+    // Bob never readies up; the timeout starts the game anyway
+    let state = setup
+        .game_manager
+        .start_after_ready_timeout(&game_id)
+        .await
+        .unwrap();
+    assert_eq!(state.current_phase, GamePhase::Guessing);
+
+    // Once started, the timeout is a no-op
+    assert!(
+        setup
+            .game_manager
+            .start_after_ready_timeout(&game_id)
+            .await
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_game_history_reveals_words_after_game_over() {
+    let setup = TestGameServerSetup::new();
+    let connections = setup
+        .create_multiple_connections(&["Alice", "Bob", "Outsider"])
+        .await;
+    let (alice_conn, alice) = connections[0].clone();
+    let (bob_conn, bob) = connections[1].clone();
+    let (_, outsider) = connections[2].clone();
+
+    let game_id = setup
+        .game_manager
+        .create_game_with_seed(vec![alice_conn, bob_conn], 7)
+        .await
+        .unwrap();
+    let first_word = setup.game_manager.replay_target_word(7, 1).unwrap();
+    let second_word = setup.game_manager.replay_target_word(7, 2).unwrap();
+
+    // Alice solves the first word
+    let other = if first_word == "about" {
+        "beach"
+    } else {
+        "about"
+    };
+    play_round(
+        &setup,
+        &game_id,
+        vec![(alice_conn, &first_word), (bob_conn, other)],
+    )
+    .await
+    .unwrap();
+
+    // Mid-game: solved rounds are revealed, the current word and Bob's guesses are not
+    let history = setup
+        .game_manager
+        .get_game_history(&game_id, &alice.id)
+        .await
+        .unwrap();
+    assert_eq!(history.rounds.len(), 2);
+    assert_eq!(history.rounds[0].target_word.as_ref(), Some(&first_word));
+    assert!(history.rounds[1].target_word.is_none());
+    let bob_view = history.players.iter().find(|p| p.user_id == bob.id);
+    assert!(bob_view.unwrap().guess_history.is_empty());
+
+    // Solving the second word pushes Alice over the threshold
+    let other = if second_word == "about" {
+        "beach"
+    } else {
+        "about"
+    };
+    let event = play_round(
+        &setup,
+        &game_id,
+        vec![(alice_conn, &second_word), (bob_conn, other)],
+    )
+    .await
+    .unwrap();
+    assert!(matches!(event, GameEvent::GameOver { .. }));
+
+    let history = setup
+        .game_manager
+        .get_game_history(&game_id, &bob.id)
+        .await
+        .unwrap();
+    assert_eq!(history.status, GameStatus::Completed);
+    assert_eq!(history.rounds[1].target_word.as_ref(), Some(&second_word));
+    let bob_view = history.players.iter().find(|p| p.user_id == bob.id);
+    assert_eq!(bob_view.unwrap().guess_history.len(), 2);
+
+    let result = setup
+        .game_manager
+        .get_game_history(&game_id, &outsider.id)
+        .await;
+    assert!(matches!(result, Err(GameError::PlayerNotInGame)));
+}
+
+#[tokio::test]
+async fn test_game_creation_refused_past_capacity() {
+    let setup = TestGameServerSetup::with_manager(|game_manager| {
+        game_manager.with_max_active_games(Some(1))
+    });
+    let connections = setup
+        .create_multiple_connections(&["Alice", "Bob", "Carol", "Dave"])
+        .await;
+    let (alice_conn, _) = connections[0];
+    let (bob_conn, _) = connections[1];
+    let (carol_conn, _) = connections[2];
+    let (dave_conn, _) = connections[3];
+
+    let game_id = setup
+        .game_manager
+        .create_game_with_seed(vec![alice_conn, bob_conn], 7)
+        .await
+        .unwrap();
+    assert!(setup.game_manager.at_capacity().await);
+
+    let result = setup
+        .game_manager
+        .create_game(vec![carol_conn, dave_conn])
+        .await;
+    assert!(result.is_err());
+
+    // Alice solves two words to finish the game and free the slot
+    for round in 1..=2 {
+        let word = setup.game_manager.replay_target_word(7, round).unwrap();
+        let other = if word == "about" { "beach" } else { "about" };
+        play_round(
+            &setup,
+            &game_id,
+            vec![(alice_conn, &word), (bob_conn, other)],
+        )
+        .await
+        .unwrap();
+    }
+    let state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+    assert_eq!(state.status, GameStatus::Completed);
+    assert!(!setup.game_manager.at_capacity().await);
+
+    let result = setup
+        .game_manager
+        .create_game(vec![carol_conn, dave_conn])
+        .await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_game_over_recaps_every_round() {
+    let setup = TestGameServerSetup::new();
+    let connections = setup.create_multiple_connections(&["Alice", "Bob"]).await;
+    let (alice_conn, alice) = connections[0].clone();
+    let (bob_conn, bob) = connections[1].clone();
+
+    let game_id = setup
+        .game_manager
+        .create_game_with_seed(vec![alice_conn, bob_conn], 7)
+        .await
+        .unwrap();
+
+    // Alice and Bob take turns solving words until someone reaches the threshold
+    let mut solved = Vec::new();
+    let recap = loop {
+        assert!(solved.len() < 10, "Game should have ended by now");
+        let word = setup
+            .game_manager
+            .reportable_word(&game_id, alice_conn)
+            .await
+            .unwrap();
+        let (solver_conn, solver, other_conn) = if solved.len() % 2 == 0 {
+            (bob_conn, &bob, alice_conn)
+        } else {
+            (alice_conn, &alice, bob_conn)
+        };
+        let other_word = words_for_target(&setup, &game_id)
+            .await
+            .into_iter()
+            .find(|w| *w != word)
+            .unwrap();
+        solved.push((word.clone(), solver.id.clone()));
+
+        let event = play_round(
+            &setup,
+            &game_id,
+            vec![(other_conn, other_word), (solver_conn, &word)],
+        )
+        .await
+        .unwrap();
+        if let GameEvent::GameOver { recap, .. } = event {
+            break recap;
+        }
+    };
+
+    assert!(recap.len() >= 2);
+    assert_eq!(recap.len(), solved.len());
+    for (index, (entry, (word, solver_id))) in recap.iter().zip(&solved).enumerate() {
+        assert_eq!(entry.round, index as i32 + 1);
+        assert_eq!(&entry.word, word);
+        assert_eq!(entry.solved_by.as_ref(), Some(solver_id));
+        let solver_points = entry
+            .points_awarded
+            .iter()
+            .find(|points| &points.player_id == solver_id)
+            .unwrap();
+        assert!(solver_points.points > 0);
+    }
+}
+
+#[tokio::test]
+async fn test_idle_game_expires_on_mock_clock() {
+    let clock = std::sync::Arc::new(game_core::MockClock::new());
+    let setup =
+        TestGameServerSetup::with_manager(|game_manager| game_manager.with_clock(clock.clone()))
+            .with_queue(|queue| queue.with_clock(clock.clone()));
+    let connections = setup.create_multiple_connections(&["Alice", "Bob"]).await;
+    let (alice_conn, _) = connections[0];
+    let (bob_conn, _) = connections[1];
+
+    let game_id = setup
+        .create_test_game(vec![alice_conn, bob_conn])
+        .await
+        .unwrap();
+    let timeout = std::time::Duration::from_secs(300);
+
+    clock.advance(std::time::Duration::from_secs(299));
+    setup.game_manager.cleanup_abandoned_games(timeout).await;
+    assert!(setup.game_manager.get_game_state(&game_id).await.is_some());
+
+    clock.advance(std::time::Duration::from_secs(2));
+    setup.game_manager.cleanup_abandoned_games(timeout).await;
+    assert!(setup.game_manager.get_game_state(&game_id).await.is_none());
+}
+
+#[tokio::test]
+async fn test_empty_game_survives_reconnect_grace() {
+    let clock = std::sync::Arc::new(game_core::MockClock::new());
+    let setup = TestGameServerSetup::with_manager(|game_manager| {
+        game_manager
+            .with_clock(clock.clone())
+            .with_empty_game_grace(Some(std::time::Duration::from_secs(30)))
+    });
+    let (game_id, connections) = setup_ready_game(&setup, &["Alice", "Bob"]).await.unwrap();
+    let timeout = std::time::Duration::from_secs(3600);
+
+    // Both players drop at once, as with a shared network outage
+    for (connection_id, _) in &connections {
+        setup
+            .game_manager
+            .handle_player_disconnect(&game_id, *connection_id)
+            .await
+            .unwrap();
+    }
+    setup.game_manager.cleanup_abandoned_games(timeout).await;
+    assert!(setup.game_manager.get_game_state(&game_id).await.is_some());
+
+    // A reconnect within the grace keeps the game; emptying it again restarts the grace
+    clock.advance(std::time::Duration::from_secs(20));
+    let alice_return = ConnectionId::new();
+    setup
+        .game_manager
+        .rejoin_player(&game_id, alice_return, None)
+        .await
+        .unwrap();
+    clock.advance(std::time::Duration::from_secs(20));
+    setup
+        .game_manager
+        .handle_player_disconnect(&game_id, alice_return)
+        .await
+        .unwrap();
+    clock.advance(std::time::Duration::from_secs(20));
+    setup.game_manager.cleanup_abandoned_games(timeout).await;
+    assert!(setup.game_manager.get_game_state(&game_id).await.is_some());
+
+    // Nobody comes back before the grace runs out
+    clock.advance(std::time::Duration::from_secs(11));
+    setup.game_manager.cleanup_abandoned_games(timeout).await;
+    assert!(setup.game_manager.get_game_state(&game_id).await.is_none());
+}
+
+#[tokio::test]
+async fn test_game_pauses_with_guesses_when_everyone_drops() {
+    let setup = TestGameServerSetup::new();
+    let (game_id, connections) = setup_ready_game(&setup, &["Alice", "Bob"]).await.unwrap();
+    let words = words_for_target(&setup, &game_id).await;
+
+    let event = setup
+        .submit_guess(&game_id, connections[0].0, words[0])
+        .await
+        .unwrap();
+    assert_guess_pending(&event);
+
+    for (connection_id, _) in &connections {
+        setup
+            .game_manager
+            .handle_player_disconnect(&game_id, *connection_id)
+            .await
+            .unwrap();
+    }
+    let state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+    assert_eq!(state.status, GameStatus::Paused);
+
+    // Both players come back and the round carries on with Alice's guess
+    let (alice_return, bob_return) = (ConnectionId::new(), ConnectionId::new());
+    for connection_id in [alice_return, bob_return] {
+        setup
+            .game_manager
+            .rejoin_player(&game_id, connection_id, None)
+            .await
+            .unwrap();
+    }
+    let state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+    assert_eq!(state.status, GameStatus::Active);
+
+    let event = setup
+        .submit_guess(&game_id, bob_return, words[1])
+        .await
+        .unwrap();
+    assert_round_result(&event);
+}
+
+#[tokio::test]
+async fn test_game_past_max_duration_ends_with_leader_winning() {
+    let clock = std::sync::Arc::new(game_core::MockClock::new());
+    let setup = TestGameServerSetup::with_manager(|game_manager| {
+        game_manager
+            .with_clock(clock.clone())
+            .with_max_game_duration(Some(std::time::Duration::from_secs(600)))
+    });
+    let (game_id, connections) = setup_ready_game(&setup, &["Alice", "Bob"]).await.unwrap();
+    let words = words_for_target(&setup, &game_id).await;
+
+    setup
+        .submit_guess(&game_id, connections[0].0, words[0])
+        .await
+        .unwrap();
+    let event = setup
+        .submit_guess(&game_id, connections[1].0, words[1])
+        .await
+        .unwrap();
+    assert_round_result(&event);
+    let state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+    let leader = state
+        .players
+        .iter()
+        .max_by_key(|p| p.points)
+        .unwrap()
+        .clone();
+    assert!(leader.points > 0);
+
+    // Play never reached the threshold, but time has run out
+    clock.advance(std::time::Duration::from_secs(601));
+    let event = setup
+        .submit_guess(&game_id, connections[0].0, words[2])
+        .await
+        .unwrap();
+    match event {
+        GameEvent::GameOver { winner, reason, .. } => {
+            assert_eq!(winner.user_id, leader.user_id);
+            assert_eq!(reason, GameOverReason::TimeLimit);
+        }
+        other => panic!("Expected GameOver, got {:?}", other),
+    }
+    let state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+    assert_eq!(state.status, GameStatus::Completed);
+}
+
+#[tokio::test]
+async fn test_overlong_game_ends_without_a_guess() {
+    let clock = std::sync::Arc::new(game_core::MockClock::new());
+    let setup = TestGameServerSetup::with_manager(|game_manager| {
+        game_manager
+            .with_clock(clock.clone())
+            .with_max_game_duration(Some(std::time::Duration::from_secs(600)))
+    });
+    let (game_id, _) = setup_ready_game(&setup, &["Alice", "Bob"]).await.unwrap();
+
+    assert!(setup.game_manager.end_overlong_games().await.is_empty());
+
+    // Nobody guesses after the limit, so the sweep has to end the game
+    clock.advance(std::time::Duration::from_secs(601));
+    let ended = setup.game_manager.end_overlong_games().await;
+    match ended.as_slice() {
+        [(id, GameEvent::GameOver { reason, .. })] => {
+            assert_eq!(*id, game_id);
+            assert_eq!(*reason, GameOverReason::TimeLimit);
+        }
+        other => panic!("Expected one timed out game, got {:?}", other),
+    }
+    let state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+    assert_eq!(state.status, GameStatus::Completed);
+    assert!(setup.game_manager.end_overlong_games().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_skip_round_vote_starts_new_round_at_threshold() {
+    let setup = TestGameServerSetup::with_manager(|game_manager| {
+        game_manager.with_rules(game_core::GameRules {
+            skip_round_vote_fraction: Some(0.6),
+            ..Default::default()
+        })
+    });
+    let (game_id, connections) = setup_ready_game(&setup, &["Alice", "Bob", "Carol"])
+        .await
+        .unwrap();
+    let word = setup
+        .game_manager
+        .reportable_word(&game_id, connections[0].0)
+        .await
+        .unwrap();
+
+    // Voting twice still counts once; two of three players are needed
+    for _ in 0..2 {
+        let update = setup
+            .game_manager
+            .vote_skip_round(&game_id, connections[0].0)
+            .await
+            .unwrap();
+        assert_eq!((update.votes, update.needed), (1, 2));
+        assert_eq!(update.skipped_word, None);
+        assert_eq!(update.state.current_round, 1);
+    }
+
+    let update = setup
+        .game_manager
+        .vote_skip_round(&game_id, connections[1].0)
+        .await
+        .unwrap();
+    assert_eq!((update.votes, update.needed), (2, 2));
+    assert_eq!(update.skipped_word, Some(word));
+    assert_eq!(update.state.current_round, 2);
+    assert_eq!(update.state.current_phase, GamePhase::Guessing);
+    assert!(update.state.players.iter().all(|p| p.points == 0));
+
+    // Votes start over for the new round
+    let update = setup
+        .game_manager
+        .vote_skip_round(&game_id, connections[2].0)
+        .await
+        .unwrap();
+    assert_eq!((update.votes, update.needed), (1, 2));
+}
+
+#[tokio::test]
+async fn test_skip_round_vote_disabled_by_default() {
+    let setup = TestGameServerSetup::new();
+    let (game_id, connections) = setup_ready_game(&setup, &["Alice", "Bob"]).await.unwrap();
+
+    let error = setup
+        .game_manager
+        .vote_skip_round(&game_id, connections[0].0)
+        .await
+        .unwrap_err();
+    assert_eq!(error_code(&error), ErrorCode::RoundSkipNotAllowed);
+}
+
+#[tokio::test]
+async fn test_rejoin_keeps_in_game_name_by_default() {
+    let setup = TestGameServerSetup::new();
+    let (game_id, connections) = setup_ready_game(&setup, &["Alice", "Bob"]).await.unwrap();
+    let (alice_conn, alice) = &connections[0];
+
+    setup
+        .game_manager
+        .handle_player_disconnect(&game_id, *alice_conn)
+        .await
+        .unwrap();
+    let alice_return = ConnectionId::new();
+    let _receiver = setup
+        .connection_manager
+        .create_connection(alice_return)
+        .await;
+    let renamed_user = game_types::User {
+        display_name: "Alicia".to_string(),
+        ..alice.clone()
+    };
+    setup
+        .connection_manager
+        .set_connection_user(alice_return, Some(renamed_user))
+        .await;
+
+    let rejoin = setup
+        .game_manager
+        .rejoin_player(&game_id, alice_return, None)
+        .await
+        .unwrap();
+    assert_eq!(rejoin.renamed, None);
+    let player = rejoin
+        .state
+        .players
+        .iter()
+        .find(|p| p.user_id == alice.id)
+        .unwrap();
+    assert_eq!(player.display_name, alice.display_name);
+}
+
+#[tokio::test]
+async fn test_reportable_word_limited_to_players() {
+    let setup = TestGameServerSetup::new();
+    let connections = setup
+        .create_multiple_connections(&["Alice", "Bob", "Carol"])
+        .await;
+    let (alice_conn, _) = connections[0];
+    let (bob_conn, _) = connections[1];
+    let (carol_conn, _) = connections[2];
+
+    let game_id = setup
+        .game_manager
+        .create_game_with_seed(vec![alice_conn, bob_conn], 7)
+        .await
+        .unwrap();
+
+    let word = setup
+        .game_manager
+        .reportable_word(&game_id, alice_conn)
+        .await
+        .unwrap();
+    assert_eq!(word, setup.game_manager.replay_target_word(7, 1).unwrap());
+
+    assert_eq!(
+        setup
+            .game_manager
+            .reportable_word(&game_id, carol_conn)
+            .await,
+        Err(GameError::PlayerNotInGame)
+    );
+    assert_eq!(
+        setup
+            .game_manager
+            .reportable_word("missing-game", alice_conn)
+            .await,
+        Err(GameError::GameNotFound)
+    );
+}
+
+#[tokio::test]
+async fn test_team_mode_assigns_balanced_teams() {
+    let setup = TestGameServerSetup::with_manager(|game_manager| {
+        game_manager.with_rules(game_core::GameRules {
+            team_mode: true,
+            ..Default::default()
+        })
+    });
+    let connections = setup
+        .create_multiple_connections(&["Alice", "Bob", "Carol", "Dave"])
+        .await;
+    let connection_ids: Vec<_> = connections.iter().map(|(id, _)| *id).collect();
+
+    let game_id = setup
+        .game_manager
+        .create_game_with_seed(connection_ids, 7)
+        .await
+        .unwrap();
+    let state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+
+    assert_eq!(state.teams.len(), 2);
+    assert!(state.teams.iter().all(|team| team.player_ids.len() == 2));
+}
+
+#[tokio::test]
+async fn test_abandoning_live_game_imposes_queue_cooldown() {
+    let setup = TestGameServerSetup::new()
+        .with_queue(|queue| queue.with_abandon_cooldown(Some(std::time::Duration::from_secs(60))));
+    let connections = setup
+        .create_multiple_connections(&["Alice", "Bob", "Carol", "Dave"])
+        .await;
+    let (alice_conn, alice) = connections[0].clone();
+    let (bob_conn, bob) = connections[1].clone();
+    let (carol_conn, _) = connections[2].clone();
+    let (dave_conn, _) = connections[3].clone();
+
+    // Bob walks out of a game that is still being played
+    let live_game = setup
+        .game_manager
+        .create_game_with_seed(vec![carol_conn, bob_conn], 3)
+        .await
+        .unwrap();
+    let abandoned = setup
+        .game_manager
+        .leave_game(&live_game, bob_conn)
+        .await
+        .unwrap();
+    assert!(abandoned);
+    setup.matchmaking_queue.record_abandonment(&bob.id).await;
+    assert!(
+        setup
+            .matchmaking_queue
+            .cooldown_remaining(&bob.id)
+            .await
+            .is_some()
+    );
+
+    // Alice finishes her game before leaving, so she is not penalized
+    let game_id = setup
+        .game_manager
+        .create_game_with_seed(vec![alice_conn, dave_conn], 7)
+        .await
+        .unwrap();
+    for round in 1..=2 {
+        let word = setup.game_manager.replay_target_word(7, round).unwrap();
+        let other = if word == "about" { "beach" } else { "about" };
+        play_round(
+            &setup,
+            &game_id,
+            vec![(alice_conn, &word), (dave_conn, other)],
+        )
+        .await
+        .unwrap();
+    }
+    let abandoned = setup
+        .game_manager
+        .leave_game(&game_id, alice_conn)
+        .await
+        .unwrap();
+    assert!(!abandoned);
+    assert!(
+        setup
+            .matchmaking_queue
+            .cooldown_remaining(&alice.id)
+            .await
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_disconnect_past_grace_counts_as_abandonment() {
+    let clock = std::sync::Arc::new(game_core::MockClock::new());
+    let setup =
+        TestGameServerSetup::with_manager(|game_manager| game_manager.with_clock(clock.clone()))
+            .with_queue(|queue| queue.with_clock(clock.clone()));
+    let connections = setup.create_multiple_connections(&["Alice", "Bob"]).await;
+    let (alice_conn, _) = connections[0];
+    let (bob_conn, bob) = connections[1].clone();
+    let grace = std::time::Duration::from_secs(60);
+
+    let game_id = setup
+        .create_test_game(vec![alice_conn, bob_conn])
+        .await
+        .unwrap();
+    setup
+        .game_manager
+        .handle_player_disconnect(&game_id, bob_conn)
+        .await
+        .unwrap();
+
+    clock.advance(std::time::Duration::from_secs(30));
+    assert!(
+        setup
+            .game_manager
+            .take_abandoned_players(grace)
+            .await
+            .is_empty()
+    );
+
+    clock.advance(std::time::Duration::from_secs(31));
+    assert_eq!(
+        setup.game_manager.take_abandoned_players(grace).await,
+        vec![bob.id]
+    );
+
+    // Reported only once per disconnect
+    assert!(
+        setup
+            .game_manager
+            .take_abandoned_players(grace)
+            .await
+            .is_empty()
+    );
+}
+
+#[tokio::test]
+async fn test_replay_matches_game_avoiding_recent_targets() {
+    const WORDS: [&str; 4] = ["crane", "flame", "grape", "shine"];
+    let setup = TestGameServerSetup::with_manager(|game_manager| {
+        game_manager
+            .with_recent_word_window(3)
+            .with_rules(game_core::GameRules {
+                word_categories: vec!["small".to_string()],
+                ..Default::default()
+            })
+    });
+    setup.game_manager.set_word_validator(
+        game_core::WordValidator::from_word_list(&WORDS.join("\n"))
+            .with_category("small", &WORDS.join("\n")),
+    );
+    let connections = setup.create_multiple_connections(&["Alice", "Bob"]).await;
+    let (alice_conn, _) = connections[0];
+    let (bob_conn, _) = connections[1];
+    let game_id = setup
+        .game_manager
+        .create_game_with_threshold(vec![alice_conn, bob_conn], Some(200))
+        .await
+        .unwrap();
+
+    // Solve each word in turn; with four words and a window of three, every
+    // word comes up once before any repeats, and replay follows along
+    let mut targets = Vec::new();
+    for round in 1..=WORDS.len() as i32 + 1 {
+        let target = setup
+            .game_manager
+            .reportable_word(&game_id, alice_conn)
+            .await
+            .unwrap();
+        let seed = setup.game_manager.get_game_seed(&game_id).await.unwrap();
+        assert_eq!(
+            setup.game_manager.replay_target_word(seed, round).unwrap(),
+            target
+        );
+        if targets.len() < WORDS.len() {
+            assert!(!targets.contains(&target), "Target word repeated");
+        }
+        targets.push(target.clone());
+
+        let other = WORDS.iter().find(|word| **word != target).unwrap();
+        setup
+            .submit_guess(&game_id, alice_conn, &target)
+            .await
+            .unwrap();
+        setup.submit_guess(&game_id, bob_conn, other).await.unwrap();
+        let state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+        assert_eq!(state.current_round, round + 1);
+    }
+}
+
+#[tokio::test]
+async fn test_rejoin_replays_rounds_missed_while_disconnected() {
+    let setup = TestGameServerSetup::new();
+    let connections = setup.create_multiple_connections(&["Alice", "Bob"]).await;
+    let (alice_conn, _) = connections[0];
+    let (bob_conn, bob) = connections[1].clone();
+
+    let game_id = setup
+        .game_manager
+        .create_game_with_seed(vec![alice_conn, bob_conn], 42)
+        .await
+        .unwrap();
+
+    // Guesses that match the target's length without solving it
+    let target = setup.game_manager.replay_target_word(42, 1).unwrap();
+    let words: Vec<&str> = [
+        "about", "beach", "black", "chair", "house", "place", "world", "friend", "letter",
+        "number", "people", "school", "change", "example", "nothing", "another", "between",
+        "company", "problem",
+    ]
+    .into_iter()
+    .filter(|w| w.len() == target.len() && *w != target)
+    .collect();
+
+    // A round Bob saw himself is not replayed. Its winner then takes the
+    // individual guess before collaborative guessing resumes.
+    setup
+        .submit_guess(&game_id, alice_conn, words[0])
+        .await
+        .unwrap();
+    let event = setup
+        .submit_guess(&game_id, bob_conn, words[1])
+        .await
+        .unwrap();
+    assert_round_result(&event);
+    let state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+    let winner_conn = if state.current_winner.as_deref() == Some(bob.id.as_str()) {
+        bob_conn
+    } else {
+        alice_conn
+    };
+    let event = setup
+        .submit_guess(&game_id, winner_conn, words[2])
+        .await
+        .unwrap();
+    assert_round_result(&event);
+
+    setup
+        .game_manager
+        .handle_player_disconnect(&game_id, bob_conn)
+        .await
+        .unwrap();
+
+    // Alice plays on alone: a collaborative round, then her individual guess
+    for word in &words[3..5] {
+        let event = setup
+            .submit_guess(&game_id, alice_conn, word)
+            .await
+            .unwrap();
+        assert_round_result(&event);
+    }
+
+    let (new_bob_conn, _) = setup
+        .create_authenticated_connection(&bob.display_name)
+        .await;
+    let rejoin = setup
+        .game_manager
+        .rejoin_player(&game_id, new_bob_conn, None)
+        .await
+        .unwrap();
+
+    let missed: Vec<&str> = rejoin
+        .missed_rounds
+        .iter()
+        .map(|summary| summary.winning_guess.word.as_str())
+        .collect();
+    assert_eq!(missed, words[3..5].to_vec());
+    assert!(
+        rejoin
+            .missed_rounds
+            .iter()
+            .all(|summary| summary.round == 1)
+    );
+    assert!(!rejoin.missed_rounds[0].is_word_completed);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_concurrent_guesses_resolve_each_round_once() {
+    const GAMES: usize = 8;
+    const PLAYERS: usize = 16;
+
+    let setup = TestGameServerSetup::with_manager(|game_manager| {
+        game_manager.with_max_concurrent_guesses(Some(4))
+    });
+    let game_manager = setup.game_manager.clone();
+
+    let names: Vec<String> = (0..PLAYERS).map(|i| format!("Player{}", i)).collect();
+    let names: Vec<&str> = names.iter().map(String::as_str).collect();
+    let mut games = Vec::new();
+    for seed in 0..GAMES as u64 {
+        let connections = setup.create_multiple_connections(&names).await;
+        let connection_ids: Vec<_> = connections.iter().map(|(id, _)| *id).collect();
+        let game_id = game_manager
+            .create_game_with_seed(connection_ids.clone(), seed)
+            .await
+            .unwrap();
+        games.push((game_id, connection_ids));
+    }
+
+    let words_of_length = |length: i32| -> Vec<&'static str> {
+        let words: &[&'static str] = match length {
+            5 => &[
+                "about", "above", "after", "again", "beach", "black", "brown", "chair", "close",
+                "early", "house", "place", "right", "round", "today", "which",
+            ],
+            6 => &[
+                "second", "fourth", "before", "friend", "letter", "number", "people", "should",
+                "around", "change", "better", "little", "myself", "family", "school", "mother",
+            ],
+            _ => &[
+                "example", "nothing", "another", "without", "between", "through", "because",
+                "against", "thought", "problem", "company", "service", "program", "already",
+                "believe", "produce",
+            ],
+        };
+        words.to_vec()
+    };
+
+    let started = std::time::Instant::now();
+    let mut submissions = Vec::new();
+    for (game_id, connection_ids) in &games {
+        let state = game_manager.get_game_state(game_id).await.unwrap();
+        let words = words_of_length(state.word_length);
+        for (connection_id, word) in connection_ids.iter().zip(words) {
+            let game_manager = game_manager.clone();
+            let game_id = game_id.clone();
+            let connection_id = *connection_id;
+            submissions.push(tokio::spawn(async move {
+                let event = game_manager
+                    .submit_guess(&game_id, connection_id, word.to_string())
+                    .await
+                    .unwrap();
+                (game_id, event)
+            }));
+        }
+    }
+
+    let mut round_results = std::collections::HashMap::new();
+    let mut pending = 0;
+    for submission in submissions {
+        match submission.await.unwrap() {
+            (game_id, GameEvent::RoundResult { .. }) => {
+                *round_results.entry(game_id).or_insert(0) += 1;
+            }
+            (_, GameEvent::GuessPending { .. }) => pending += 1,
+            (_, event) => panic!("Unexpected event {:?}", event),
+        }
+    }
+    println!(
+        "{} concurrent guesses across {} games resolved in {:?}",
+        GAMES * PLAYERS,
+        GAMES,
+        started.elapsed()
+    );
+
+    // Every game resolved exactly one round, counting every player's guess
+    assert_eq!(round_results.len(), GAMES);
+    assert!(round_results.values().all(|count| *count == 1));
+    assert_eq!(pending, GAMES * (PLAYERS - 1));
+    for (game_id, _) in &games {
+        let state = game_manager.get_game_state(game_id).await.unwrap();
+        assert!(state.players.iter().all(|p| p.guess_history.len() == 1));
+    }
+}
+
+#[tokio::test]
+async fn test_abandoned_game_is_recorded_as_abandoned() {
+    use game_persistence::repositories::{GameRepository, game_repository::GameRecordStatus};
+    use migration::MigratorTrait;
+
+    let db = game_persistence::connection::connect_to_memory_database()
+        .await
+        .unwrap();
+    migration::Migrator::up(&db, None).await.unwrap();
+    let game_repository = std::sync::Arc::new(GameRepository::new(db));
+
+    let setup = TestGameServerSetup::with_manager(|game_manager| {
+        game_manager.with_game_repository(game_repository.clone())
+    });
+    let game_manager = setup.game_manager.clone();
+
+    let connections = setup.create_multiple_connections(&["Alice", "Bob"]).await;
+    let connection_ids: Vec<_> = connections.iter().map(|(id, _)| *id).collect();
+    let game_id = game_manager
+        .create_game(connection_ids.clone())
+        .await
+        .unwrap();
+
+    let record = game_repository.find_by_id(&game_id).await.unwrap().unwrap();
+    assert_eq!(record.status, GameRecordStatus::InProgress);
+    assert_eq!(
+        record.player_ids,
+        connections
+            .iter()
+            .map(|(_, user)| user.id.clone())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(record.mode, "classic");
+    let state = game_manager.get_game_state(&game_id).await.unwrap();
+    assert_eq!(record.word_length, state.word_length);
+    // The seed is kept so a reported game can be replayed after it ends
+    assert_eq!(record.seed, game_manager.get_game_seed(&game_id).await);
+
+    // Everyone leaves, so cleanup drops the unfinished game
+    for connection_id in connection_ids {
+        game_manager
+            .handle_player_disconnect(&game_id, connection_id)
+            .await
+            .unwrap();
+    }
+    game_manager
+        .cleanup_abandoned_games(std::time::Duration::from_secs(3600))
+        .await;
+    assert!(game_manager.get_game_state(&game_id).await.is_none());
+
+    let record = game_repository.find_by_id(&game_id).await.unwrap().unwrap();
+    assert_eq!(record.status, GameRecordStatus::Abandoned);
+    assert!(record.ended_at.is_some());
+    assert_eq!(record.winner_id, None);
+}
+
+#[tokio::test]
+async fn test_finished_game_is_recorded_as_completed() {
+    use game_persistence::repositories::{GameRepository, game_repository::GameRecordStatus};
+    use migration::MigratorTrait;
+
+    let db = game_persistence::connection::connect_to_memory_database()
+        .await
+        .unwrap();
+    migration::Migrator::up(&db, None).await.unwrap();
+    let game_repository = std::sync::Arc::new(GameRepository::new(db));
+
+    let setup = TestGameServerSetup::with_manager(|game_manager| {
+        game_manager
+            .with_game_repository(game_repository.clone())
+            .with_rules(game_core::GameRules {
+                word_categories: vec!["greetings".to_string()],
+                ..Default::default()
+            })
+    });
+    // Every target is "hello"
+    setup.game_manager.set_word_validator(
+        game_core::WordValidator::from_word_list("hello\nhelps")
+            .with_category("greetings", "hello"),
+    );
+    let connections = setup.create_multiple_connections(&["Alice", "Bob"]).await;
+    let game_id = setup
+        .game_manager
+        .create_game_with_threshold(connections.iter().map(|(id, _)| *id).collect(), Some(5))
+        .await
+        .unwrap();
+
+    setup
+        .submit_guess(&game_id, connections[0].0, "hello")
+        .await
+        .unwrap();
+    let event = setup
+        .submit_guess(&game_id, connections[1].0, "helps")
+        .await
+        .unwrap();
+    assert_game_over(&event);
+
+    // The record, with the round's word and final scores, is written once
+    // the game lock is released
+    let record = game_repository.find_by_id(&game_id).await.unwrap().unwrap();
+    assert_eq!(record.status, GameRecordStatus::Completed);
+    assert_eq!(record.winner_id, Some(connections[0].1.id.clone()));
+    assert_eq!(record.words, vec!["hello".to_string()]);
+    assert_eq!(record.final_scores.len(), 2);
+}
+
+#[tokio::test]
+async fn test_round_resolves_once_quorum_has_guessed() {
+    let setup = TestGameServerSetup::with_manager(|game_manager| {
+        game_manager.with_rules(game_core::GameRules {
+            round_quorum: Some(2),
+            ..Default::default()
+        })
+    });
+    let connections = setup
+        .create_multiple_connections(&["Alice", "Bob", "Carol"])
+        .await;
+    let connection_ids: Vec<_> = connections.iter().map(|(id, _)| *id).collect();
+    let game_id = setup
+        .game_manager
+        .create_game_with_seed(connection_ids.clone(), 7)
+        .await
+        .unwrap();
+    let target = setup.game_manager.replay_target_word(7, 1).unwrap();
+    let mut words = ["about", "beach", "chair"]
+        .into_iter()
+        .filter(|word| *word != target);
+
+    let event = setup
+        .submit_guess(&game_id, connection_ids[0], words.next().unwrap())
+        .await
+        .unwrap();
+    assert_guess_pending(&event);
+
+    // Two of three players is a majority, so Carol is not waited for
+    let event = setup
+        .submit_guess(&game_id, connection_ids[1], words.next().unwrap())
+        .await
+        .unwrap();
+    match event {
+        GameEvent::RoundResult { player_guesses, .. } => {
+            assert_eq!(player_guesses.len(), 2);
+            assert!(
+                player_guesses
+                    .iter()
+                    .all(|(id, _)| connection_ids[..2].contains(id))
+            );
+        }
+        _ => panic!("Expected RoundResult, got {:?}", event),
+    }
+
+    let state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+    assert_eq!(state.official_board.len(), 1);
+    let carol = state
+        .players
+        .iter()
+        .find(|p| p.user_id == connections[2].1.id)
+        .unwrap();
+    assert!(carol.guess_history.is_empty());
+}
+
+#[tokio::test]
+async fn test_guesses_rejected_during_intermission() {
+    let clock = std::sync::Arc::new(game_core::MockClock::new());
+    let setup = TestGameServerSetup::with_manager(|game_manager| {
+        game_manager
+            .with_rules(game_core::GameRules {
+                intermission: Some(std::time::Duration::from_secs(5)),
+                ..Default::default()
+            })
+            .with_clock(clock.clone())
+    });
+
+    let connections = setup.create_multiple_connections(&["Alice", "Bob"]).await;
+    let (alice_conn, _) = connections[0].clone();
+    let (bob_conn, _) = connections[1].clone();
+    let game_id = setup
+        .game_manager
+        .create_game_with_seed(vec![alice_conn, bob_conn], 7)
+        .await
+        .unwrap();
+    let target = setup.game_manager.replay_target_word(7, 1).unwrap();
+    let mut words = ["about", "beach", "chair", "house"]
+        .into_iter()
+        .filter(|word| *word != target);
+    play_round(
+        &setup,
+        &game_id,
+        vec![
+            (alice_conn, words.next().unwrap()),
+            (bob_conn, words.next().unwrap()),
+        ],
+    )
+    .await
+    .unwrap();
+
+    let state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+    assert_eq!(state.current_phase, GamePhase::Intermission);
+    let winner_conn = if state.current_winner.as_ref() == Some(&connections[0].1.id) {
+        alice_conn
+    } else {
+        bob_conn
+    };
+    let bonus_word = words.next().unwrap();
+
+    clock.advance(std::time::Duration::from_secs(4));
+    let early = setup
+        .submit_guess(&game_id, winner_conn, bonus_word)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        early,
+        GameError::WrongPhase {
+            phase: GamePhase::Intermission
+        }
+    );
+    assert!(
+        setup
+            .game_manager
+            .end_intermission(&game_id)
+            .await
+            .is_none()
+    );
+
+    // Once the pause is over the winner's individual guess goes through
+    clock.advance(std::time::Duration::from_secs(1));
+    assert!(
+        setup
+            .submit_guess(&game_id, winner_conn, bonus_word)
+            .await
+            .is_ok()
+    );
+}
+
+#[tokio::test]
+async fn test_strict_games_refuse_blocked_guesses() {
+    for strict_guesses in [false, true] {
+        let setup = TestGameServerSetup::with_manager(|game_manager| {
+            game_manager.with_rules(game_core::GameRules {
+                strict_guesses,
+                ..Default::default()
+            })
+        });
+        setup.game_manager.set_word_validator(
+            game_core::WordValidator::new_with_test_words().with_blocklist("beach"),
+        );
+        // Seed 42 draws a five-letter word, the length of the blocked guess
+        let connections = setup.create_multiple_connections(&["Alice", "Bob"]).await;
+        let game_id = setup
+            .game_manager
+            .create_game_with_seed(connections.iter().map(|(id, _)| *id).collect(), 42)
+            .await
+            .unwrap();
+
+        let result = setup
+            .submit_guess(&game_id, connections[0].0, "BEACH")
+            .await;
+        if strict_guesses {
+            let error = result.unwrap_err();
+            assert_eq!(error_code(&error), ErrorCode::WordNotAllowed);
+        } else {
+            assert_guess_pending(&result.unwrap());
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_guesses_are_normalized_before_validation() {
+    let setup = TestGameServerSetup::new();
+    let connections = setup.create_multiple_connections(&["Alice", "Bob"]).await;
+    let (alice_conn, _) = connections[0];
+    let (bob_conn, _) = connections[1];
+
+    let game_id = setup
+        .game_manager
+        .create_game_with_seed(vec![alice_conn, bob_conn], 42)
+        .await
+        .unwrap();
+
+    // Mobile keyboards add stray spaces, capitals and hyphens
+    setup
+        .submit_guess(&game_id, alice_conn, " ABOUT ")
+        .await
+        .unwrap();
+    let event = setup
+        .submit_guess(&game_id, bob_conn, "ab-out")
+        .await
+        .unwrap();
+    let GameEvent::RoundResult { winning_guess, .. } = event else {
+        panic!("Expected RoundResult, got {:?}", event);
+    };
+    assert_eq!(winning_guess.word, "about");
+
+    // Spaces inside a word are not repaired
+    assert!(matches!(
+        setup.submit_guess(&game_id, alice_conn, "be ach").await,
+        Err(GameError::InvalidWord { .. } | GameError::WrongLength { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_guesses_must_match_target_length() {
+    let setup = TestGameServerSetup::new();
+    let connections = setup.create_multiple_connections(&["Alice", "Bob"]).await;
+    let (alice_conn, _) = connections[0];
+    let (bob_conn, _) = connections[1];
+
+    // Seed 42 draws a five-letter word
+    let game_id = setup
+        .game_manager
+        .create_game_with_seed(vec![alice_conn, bob_conn], 42)
+        .await
+        .unwrap();
+    assert_eq!(
+        setup.game_manager.replay_target_word(42, 1).unwrap().len(),
+        5
+    );
+
+    // Real dictionary words, just the wrong size
+    assert!(matches!(
+        setup.submit_guess(&game_id, alice_conn, "example").await,
+        Err(GameError::WrongLength {
+            expected: 5,
+            actual: 7
+        })
+    ));
+    assert!(matches!(
+        setup.submit_guess(&game_id, alice_conn, "cat").await,
+        Err(GameError::WrongLength {
+            expected: 5,
+            actual: 3
+        })
+    ));
+
+    // Neither counted as Alice's guess for the round
+    let event = setup
+        .submit_guess(&game_id, alice_conn, "about")
+        .await
+        .unwrap();
+    assert!(matches!(
+        event,
+        GameEvent::GuessPending {
+            replaced: false,
+            ..
+        }
+    ));
+
+    // Games that opt in score them as before
+    let lenient = TestGameServerSetup::with_manager(|game_manager| {
+        game_manager.with_rules(game_core::GameRules {
+            allow_mismatched_length: true,
+            ..Default::default()
+        })
+    });
+    let connections = lenient.create_multiple_connections(&["Alice", "Bob"]).await;
+    let (alice_conn, _) = connections[0];
+    let (bob_conn, _) = connections[1];
+    let game_id = lenient
+        .game_manager
+        .create_game_with_seed(vec![alice_conn, bob_conn], 42)
+        .await
+        .unwrap();
+    let event = lenient
+        .submit_guess(&game_id, alice_conn, "example")
+        .await
+        .unwrap();
+    assert_guess_pending(&event);
+}
+
+fn themed_setup(categories: &[&str], category_guesses_only: bool) -> TestGameServerSetup {
+    let setup = TestGameServerSetup::with_manager(|game_manager| {
+        game_manager.with_rules(game_core::GameRules {
+            word_categories: categories.iter().map(|name| name.to_string()).collect(),
+            category_guesses_only,
+            ..Default::default()
+        })
+    });
+    setup.game_manager.set_word_validator(
+        game_core::WordValidator::new_with_test_words()
+            .with_category("animals", "tiger\nzebra\nparrot\nbadger\nmonkey"),
+    );
+    setup
+}
+
+#[tokio::test]
+async fn test_category_games_only_draw_category_words() {
+    let animals = ["tiger", "zebra", "parrot", "badger", "monkey"];
+    let setup = themed_setup(&["animals"], false);
+
+    for _ in 0..5 {
+        let (game_id, connections) = setup_ready_game(&setup, &["Alice", "Bob"]).await.unwrap();
+        let word = setup
+            .game_manager
+            .reportable_word(&game_id, connections[0].0)
+            .await
+            .unwrap();
+        assert!(
+            animals.contains(&word.as_str()),
+            "{} is not an animal",
+            word
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_category_guesses_only_refuses_other_words() {
+    for category_guesses_only in [false, true] {
+        let setup = themed_setup(&["animals"], category_guesses_only);
+        let (game_id, connections) = setup_ready_game(&setup, &["Alice", "Bob"]).await.unwrap();
+        let words = words_for_target(&setup, &game_id).await;
+
+        let result = setup
+            .submit_guess(&game_id, connections[0].0, words[0])
+            .await;
+        if category_guesses_only {
+            assert_eq!(error_code(&result.unwrap_err()), ErrorCode::InvalidWord);
+        } else {
+            assert_guess_pending(&result.unwrap());
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_themed_game_keeps_its_category_across_rounds() {
+    let themes = [
+        (
+            "animals",
+            vec!["tiger", "zebra", "parrot", "badger", "monkey"],
+        ),
+        (
+            "tech",
+            vec!["laptop", "router", "server", "keyboard", "modem"],
+        ),
+    ];
+    let mut validator = game_core::WordValidator::new_with_test_words();
+    for (name, words) in &themes {
+        validator = validator.with_category(name, &words.join("\n"));
+    }
+    let setup = TestGameServerSetup::with_manager(|game_manager| {
+        game_manager
+            .with_rules(game_core::GameRules {
+                points_per_letter: Some(1000),
+                ..Default::default()
+            })
+            .with_themed_games(true)
+    });
+    setup.game_manager.set_word_validator(validator);
+
+    let (game_id, connections) = setup_ready_game(&setup, &["Alice", "Bob"]).await.unwrap();
+    let categories = setup.game_manager.game_categories(&game_id).await;
+    assert_eq!(categories.len(), 1);
+    let (_, theme_words) = themes
+        .iter()
+        .find(|(name, _)| *name == categories[0])
+        .expect("Theme should be a loaded category");
+
+    for round in 1..=4 {
+        let word = setup
+            .game_manager
+            .reportable_word(&game_id, connections[0].0)
+            .await
+            .unwrap();
+        assert!(
+            theme_words.contains(&word.as_str()),
+            "Round {} word {} is not in {}",
+            round,
+            word,
+            categories[0]
+        );
+
+        // Solve the word so the next round draws a new one
+        play_round(
+            &setup,
+            &game_id,
+            vec![(connections[0].0, &word), (connections[1].0, &word)],
+        )
+        .await
+        .unwrap();
+        let state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+        assert_eq!(state.current_round, round + 1);
+    }
+}
+
+#[tokio::test]
+async fn test_unknown_category_fails_game_creation() {
+    let setup = themed_setup(&["countries"], false);
+
+    let error = setup_ready_game(&setup, &["Alice", "Bob"])
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            &error,
+            GameError::WordSelection { reason }
+                if reason.contains("Unknown word category 'countries'")
+        ),
+        "Got {}",
+        error
+    );
+}
+
+#[tokio::test]
+async fn test_practice_game_plays_the_requested_word() {
+    let setup = TestGameServerSetup::new();
+    let (connection_id, user) = setup.create_authenticated_connection("Alice").await;
+
+    let game_id = setup
+        .game_manager
+        .create_practice_game(connection_id, " World ")
+        .await
+        .unwrap();
+    assert_eq!(
+        setup
+            .game_manager
+            .reportable_word(&game_id, connection_id)
+            .await
+            .unwrap(),
+        "world"
+    );
+
+    // "wrong" against "world": a green w plus orange r and o
+    let event = setup
+        .submit_guess(&game_id, connection_id, "wrong")
+        .await
+        .unwrap();
+    match event {
+        GameEvent::RoundResult { winning_guess, .. } => {
+            assert_eq!(winning_guess.points_earned, 4);
+        }
+        other => panic!("Expected RoundResult, got {:?}", other),
+    }
+
+    // The lone player wins the round and solves the word on their own guess
+    let event = setup
+        .submit_guess(&game_id, connection_id, "world")
+        .await
+        .unwrap();
+    assert!(matches!(
+        event,
+        GameEvent::RoundResult {
+            is_word_completed: true,
+            ..
+        }
+    ));
+    let state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+    let player = state.players.iter().find(|p| p.user_id == user.id).unwrap();
+    assert_eq!(player.points, 17);
+    assert_eq!(state.current_round, 2);
+}
+
+#[tokio::test]
+async fn test_practice_game_rejects_invalid_words() {
+    let setup = TestGameServerSetup::new();
+    let (connection_id, _) = setup.create_authenticated_connection("Alice").await;
+
+    for word in ["zzzzz", "abc", "excellence"] {
+        assert!(
+            setup
+                .game_manager
+                .create_practice_game(connection_id, word)
+                .await
+                .is_err(),
+            "{} should be refused",
+            word
+        );
+    }
+    assert_eq!(setup.game_manager.get_active_games_count().await, 0);
+}
+
+#[tokio::test]
+async fn test_practice_game_over_is_unranked() {
+    let setup = TestGameServerSetup::with_manager(|game_manager| {
+        game_manager.with_rules(game_core::GameRules {
+            points_per_letter: Some(1),
+            ..Default::default()
+        })
+    });
+    let (connection_id, _) = setup.create_authenticated_connection("Alice").await;
+    let game_id = setup
+        .game_manager
+        .create_practice_game(connection_id, "world")
+        .await
+        .unwrap();
+
+    let event = setup
+        .submit_guess(&game_id, connection_id, "world")
+        .await
+        .unwrap();
+    assert!(matches!(event, GameEvent::GameOver { ranked: false, .. }));
+}
+
+#[tokio::test]
+async fn test_empty_guess_gets_dedicated_error() {
+    let setup = TestGameServerSetup::new();
+    let (game_id, connections) = setup_ready_game(&setup, &["Alice", "Bob"]).await.unwrap();
+    let before = setup.game_manager.get_game_state(&game_id).await.unwrap();
+
+    for word in ["", "   "] {
+        let error = setup
+            .submit_guess(&game_id, connections[0].0, word)
+            .await
+            .unwrap_err();
+        assert_eq!(error_code(&error), ErrorCode::EmptyGuess);
+    }
+
+    // Nothing was recorded: Alice can still make her guess for the round
+    let after = setup.game_manager.get_game_state(&game_id).await.unwrap();
+    assert_eq!(after.current_round, before.current_round);
+    assert!(after.official_board.is_empty());
+    assert!(after.players.iter().all(|p| p.guess_history.is_empty()));
+    let words = words_for_target(&setup, &game_id).await;
+    let event = setup
+        .submit_guess(&game_id, connections[0].0, words[0])
+        .await
+        .unwrap();
+    assert!(matches!(
+        event,
+        GameEvent::GuessPending {
+            replaced: false,
+            ..
+        }
+    ));
+}
+
+/// Two-player game requiring rejoin tokens that live for `ttl`, with Bob
+/// already disconnected
+async fn token_game_with_bob_away(
+    clock: game_core::SharedClock,
+    ttl: std::time::Duration,
+) -> (TestGameServerSetup, String, game_types::User, String) {
+    let setup = TestGameServerSetup::with_manager(|game_manager| {
+        game_manager
+            .with_clock(clock)
+            .with_rejoin_token_ttl(Some(ttl))
+    });
+    let connections = setup.create_multiple_connections(&["Alice", "Bob"]).await;
+    let (alice_conn, _) = connections[0];
+    let (bob_conn, bob) = connections[1].clone();
+
+    let game_id = setup
+        .create_test_game(vec![alice_conn, bob_conn])
+        .await
+        .unwrap();
+    let token = setup
+        .game_manager
+        .issue_rejoin_token(&game_id, &bob.id)
+        .await
+        .unwrap();
+    setup
+        .game_manager
+        .handle_player_disconnect(&game_id, bob_conn)
+        .await
+        .unwrap();
+    (setup, game_id, bob, token)
+}
+
+#[tokio::test]
+async fn test_rejoin_token_is_single_use() {
+    let clock = std::sync::Arc::new(game_core::MockClock::new());
+    let (setup, game_id, bob, token) =
+        token_game_with_bob_away(clock, std::time::Duration::from_secs(60)).await;
+
+    // Rejoining without the token is refused
+    let (bob_return, _) = setup
+        .create_authenticated_connection(&bob.display_name)
+        .await;
+    let err = setup
+        .game_manager
+        .rejoin_player(&game_id, bob_return, None)
+        .await
+        .unwrap_err();
+    assert_eq!(err, GameError::RejoinTokenRequired);
+    assert_eq!(error_code(&err), ErrorCode::RejoinTokenRequired);
+
+    let rejoin = setup
+        .game_manager
+        .rejoin_player(&game_id, bob_return, Some(&token))
+        .await
+        .unwrap();
+    let seat = rejoin
+        .state
+        .players
+        .iter()
+        .find(|p| p.user_id == bob.id)
+        .unwrap();
+    assert!(seat.is_connected);
+
+    // Replaying the same token after another drop fails
+    setup
+        .game_manager
+        .handle_player_disconnect(&game_id, bob_return)
+        .await
+        .unwrap();
+    let (bob_again, _) = setup
+        .create_authenticated_connection(&bob.display_name)
+        .await;
+    let err = setup
+        .game_manager
+        .rejoin_player(&game_id, bob_again, Some(&token))
+        .await
+        .unwrap_err();
+    assert_eq!(err, GameError::RejoinTokenUsed);
+
+    // A freshly issued token works again
+    let next = setup
+        .game_manager
+        .issue_rejoin_token(&game_id, &bob.id)
+        .await
+        .unwrap();
+    assert_ne!(next, token);
+    assert!(
+        setup
+            .game_manager
+            .rejoin_player(&game_id, bob_again, Some(&next))
+            .await
+            .is_ok()
+    );
+}
+
+#[tokio::test]
+async fn test_rejoin_token_expires() {
+    let clock = std::sync::Arc::new(game_core::MockClock::new());
+    let (setup, game_id, bob, token) =
+        token_game_with_bob_away(clock.clone(), std::time::Duration::from_secs(60)).await;
+
+    clock.advance(std::time::Duration::from_secs(61));
+    let (bob_return, _) = setup
+        .create_authenticated_connection(&bob.display_name)
+        .await;
+    let err = setup
+        .game_manager
+        .rejoin_player(&game_id, bob_return, Some(&token))
+        .await
+        .unwrap_err();
+    assert_eq!(err, GameError::RejoinTokenExpired);
+}
+
+#[tokio::test]
+async fn test_rejoin_token_only_works_for_its_owner() {
+    let clock = std::sync::Arc::new(game_core::MockClock::new());
+    let (setup, game_id, _bob, token) =
+        token_game_with_bob_away(clock, std::time::Duration::from_secs(60)).await;
+
+    let (mallory_conn, _) = setup.create_authenticated_connection("Mallory").await;
+    let err = setup
+        .game_manager
+        .rejoin_player(&game_id, mallory_conn, Some(&token))
+        .await
+        .unwrap_err();
+    assert_eq!(err, GameError::InvalidRejoinToken);
+}
+
+#[tokio::test]
+async fn test_round_timer_resolves_round_without_idle_players() {
+    let clock = std::sync::Arc::new(game_core::MockClock::new());
+    let setup = TestGameServerSetup::with_manager(|game_manager| {
+        game_manager
+            .with_clock(clock.clone())
+            .with_round_time_limit(Some(std::time::Duration::from_secs(60)))
+    });
+    let (game_id, connections) = setup_ready_game(&setup, &["Alice", "Bob"]).await.unwrap();
+    let words = words_for_target(&setup, &game_id).await;
+
+    // The round's timer starts once and is announced with the full limit
+    let timers = setup.game_manager.tick_round_timers().await;
+    assert!(matches!(
+        timers.as_slice(),
+        [(id, RoundTimer::Started { seconds_remaining: 60 })] if *id == game_id
+    ));
+    assert!(setup.game_manager.tick_round_timers().await.is_empty());
+
+    // Nobody guessed in time, so there is nothing to score and the timer restarts
+    clock.advance(std::time::Duration::from_secs(61));
+    let timers = setup.game_manager.tick_round_timers().await;
+    assert!(matches!(
+        timers.as_slice(),
+        [(_, RoundTimer::Started { .. })]
+    ));
+
+    // Alice guesses, Bob never does; the round resolves when time runs out
+    let event = setup
+        .submit_guess(&game_id, connections[0].0, words[0])
+        .await
+        .unwrap();
+    assert_guess_pending(&event);
+    clock.advance(std::time::Duration::from_secs(59));
+    assert!(setup.game_manager.tick_round_timers().await.is_empty());
+    clock.advance(std::time::Duration::from_secs(2));
+    let timers = setup.game_manager.tick_round_timers().await;
+    match timers.as_slice() {
+        [(id, RoundTimer::Expired(event))] => {
+            assert_eq!(*id, game_id);
+            assert_round_result(event);
+        }
+        other => panic!("Expected an expired round, got {:?}", other),
+    }
+    let state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+    assert_eq!(state.current_round, 1);
+    assert!(state.players.iter().any(|p| p.points > 0));
+}
+
+#[tokio::test]
+async fn test_round_timer_forfeits_idle_individual_guess() {
+    let clock = std::sync::Arc::new(game_core::MockClock::new());
+    let setup = TestGameServerSetup::with_manager(|game_manager| {
+        game_manager
+            .with_clock(clock.clone())
+            .with_round_time_limit(Some(std::time::Duration::from_secs(60)))
+    });
+    let (game_id, connections) = setup_ready_game(&setup, &["Alice", "Bob"]).await.unwrap();
+    let words = words_for_target(&setup, &game_id).await;
+
+    setup.game_manager.tick_round_timers().await;
+    setup
+        .submit_guess(&game_id, connections[0].0, words[0])
+        .await
+        .unwrap();
+    clock.advance(std::time::Duration::from_secs(61));
+    setup.game_manager.tick_round_timers().await;
+    let state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+    assert_eq!(state.current_phase, GamePhase::IndividualGuess);
+
+    // The round winner gets their own timer and loses the guess when it runs out
+    let timers = setup.game_manager.tick_round_timers().await;
+    assert!(matches!(
+        timers.as_slice(),
+        [(
+            _,
+            RoundTimer::Started {
+                seconds_remaining: 60
+            }
+        )]
+    ));
+    clock.advance(std::time::Duration::from_secs(61));
+    let timers = setup.game_manager.tick_round_timers().await;
+    match timers.as_slice() {
+        [(id, RoundTimer::Expired(event))] => {
+            assert_eq!(*id, game_id);
+            assert_eq!(extract_game_state(event).current_phase, GamePhase::Guessing);
+        }
+        other => panic!("Expected a forfeited turn, got {:?}", other),
+    }
+    let state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+    assert_eq!(state.current_phase, GamePhase::Guessing);
+    assert_eq!(state.current_winner, None);
+}
+
+#[tokio::test]
+async fn test_round_timer_stops_while_paused() {
+    let clock = std::sync::Arc::new(game_core::MockClock::new());
+    let setup = TestGameServerSetup::with_manager(|game_manager| {
+        game_manager
+            .with_clock(clock.clone())
+            .with_round_time_limit(Some(std::time::Duration::from_secs(60)))
+    });
+    let (game_id, connections) = setup_ready_game(&setup, &["Alice", "Bob"]).await.unwrap();
+    let words = words_for_target(&setup, &game_id).await;
+
+    setup.game_manager.tick_round_timers().await;
+    setup
+        .submit_guess(&game_id, connections[0].0, words[0])
+        .await
+        .unwrap();
+    for (connection_id, _) in &connections {
+        setup
+            .game_manager
+            .handle_player_disconnect(&game_id, *connection_id)
+            .await
+            .unwrap();
+    }
+
+    // Nobody is there to see the round resolve, so its time doesn't run
+    clock.advance(std::time::Duration::from_secs(61));
+    assert!(setup.game_manager.tick_round_timers().await.is_empty());
+    let state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+    assert_eq!(state.current_round, 1);
+    assert_eq!(state.current_phase, GamePhase::Guessing);
+
+    // A returning player gets the full limit again
+    let (alice_conn, _) = setup.create_authenticated_connection("Alice").await;
+    setup
+        .game_manager
+        .rejoin_player(&game_id, alice_conn, None)
+        .await
+        .unwrap();
+    let timers = setup.game_manager.tick_round_timers().await;
+    assert!(matches!(
+        timers.as_slice(),
+        [(
+            _,
+            RoundTimer::Started {
+                seconds_remaining: 60
+            }
+        )]
+    ));
+}
+
+#[tokio::test]
+async fn test_sudden_death_turn_passes_when_player_disconnects() {
+    let setup = TestGameServerSetup::with_manager(|game_manager| {
+        game_manager.with_rules(game_core::GameRules {
+            scoring: game_core::ScoringConfig {
+                assist_fraction: 1.0,
+                ..Default::default()
+            },
+            word_categories: vec!["greetings".to_string()],
+            ..Default::default()
+        })
+    });
+    // Every target is "hello"
+    setup.game_manager.set_word_validator(
+        game_core::WordValidator::from_word_list("hello\nhelps")
+            .with_category("greetings", "hello"),
+    );
+    let connections = setup.create_multiple_connections(&["Alice", "Bob"]).await;
+    let game_id = setup
+        .game_manager
+        .create_game_with_threshold(connections.iter().map(|(id, _)| *id).collect(), Some(5))
+        .await
+        .unwrap();
+
+    // The same guess scores the same for both, tying them at the threshold
+    for (connection_id, _) in &connections {
+        setup
+            .submit_guess(&game_id, *connection_id, "helps")
+            .await
+            .unwrap();
+    }
+    let state = setup.game_manager.get_game_state(&game_id).await.unwrap();
+    assert_eq!(state.current_phase, GamePhase::IndividualGuess);
+    assert_eq!(state.players[0].points, state.players[1].points);
+    let holder = state.current_winner.clone().unwrap();
+    let (holder_conn, other_conn, other) = if holder == connections[0].1.id {
+        (connections[0].0, connections[1].0, &connections[1].1)
+    } else {
+        (connections[1].0, connections[0].0, &connections[0].1)
+    };
+
+    // Dropping out on their turn hands it to the other tied player
+    let event = setup
+        .game_manager
+        .handle_player_disconnect(&game_id, holder_conn)
+        .await
+        .unwrap()
+        .expect("The turn should pass");
+    assert_eq!(
+        extract_game_state(&event).current_winner,
+        Some(other.id.clone())
+    );
+
+    let event = setup
+        .submit_guess(&game_id, other_conn, "hello")
+        .await
+        .unwrap();
+    assert_game_over(&event);
+
+    // A disconnect during collaborative guessing has nothing to pass on
+    let (game_id, connections) = setup_ready_game(&setup, &["Carol", "Dave"]).await.unwrap();
+    assert!(
+        setup
+            .game_manager
+            .handle_player_disconnect(&game_id, connections[0].0)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_round_timer_off_without_limit() {
+    let setup = TestGameServerSetup::new();
+    setup_ready_game(&setup, &["Alice", "Bob"]).await.unwrap();
+
+    assert!(setup.game_manager.tick_round_timers().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_hint_costs_requesting_player_only() {
+    let setup = TestGameServerSetup::with_manager(|game_manager| {
+        game_manager.with_rules(game_core::GameRules {
+            max_hints_per_round: Some(1),
+            hint_penalty: 3,
+            ..Default::default()
+        })
+    });
+    let (game_id, connections) = setup_ready_game(&setup, &["Alice", "Bob"]).await.unwrap();
+    let target = setup
+        .game_manager
+        .reportable_word(&game_id, connections[0].0)
+        .await
+        .unwrap();
+
+    // A player with no points pays nothing, and sees the first letter
+    let update = setup
+        .game_manager
+        .request_hint(&game_id, connections[0].0)
+        .await
+        .unwrap();
+    assert_eq!(update.letter.position, 0);
+    assert_eq!(update.letter.letter, target[..1]);
+    assert_eq!(update.points_deducted, 0);
+    assert!(update.state.players.iter().all(|p| p.points == 0));
+
+    let error = setup
+        .game_manager
+        .request_hint(&game_id, connections[0].0)
+        .await
+        .unwrap_err();
+    assert_eq!(error, GameError::HintLimitReached { limit: 1 });
+    assert_eq!(error_code(&error), ErrorCode::HintLimitReached);
+
+    // Bob's allowance is his own
+    assert!(
+        setup
+            .game_manager
+            .request_hint(&game_id, connections[1].0)
+            .await
+            .is_ok()
+    );
+}
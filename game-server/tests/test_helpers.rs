@@ -1,4 +1,6 @@
-use chrono;
+#![allow(dead_code)]
+
+use game_core::GameResult;
 use game_core::word_validation::WordValidator;
 use game_server::auth::AuthService;
 use game_server::game_manager::GameManager;
@@ -16,6 +18,8 @@ pub fn create_test_user(name: &str) -> User {
         total_wins: 0,
         total_games: 0,
         created_at: chrono::Utc::now().to_rfc3339(),
+        avatar: None,
+        roles: Vec::new(),
     }
 }
 
@@ -27,6 +31,7 @@ pub fn user_to_player(user: &User) -> Player {
         points: 0,
         guess_history: Vec::new(),
         is_connected: true,
+        avatar: None,
     }
 }
 
@@ -38,6 +43,12 @@ pub struct TestGameServerSetup {
     pub auth_service: Arc<AuthService>,
 }
 
+impl Default for TestGameServerSetup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TestGameServerSetup {
     pub fn new() -> Self {
         let connection_manager = Arc::new(ConnectionManager::new());
@@ -56,6 +67,31 @@ impl TestGameServerSetup {
         }
     }
 
+    /// Setup whose game manager is configured by `configure`, e.g.
+    /// `TestGameServerSetup::with_manager(|game_manager| game_manager.with_rules(rules))`
+    pub fn with_manager(configure: impl FnOnce(GameManager) -> GameManager) -> Self {
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let game_manager = GameManager::new_with_validator(
+            connection_manager.clone(),
+            WordValidator::new_with_test_words(),
+        );
+
+        Self {
+            connection_manager,
+            game_manager: Arc::new(configure(game_manager)),
+            ..Self::new()
+        }
+    }
+
+    /// Swap in a matchmaking queue configured by `configure`
+    pub fn with_queue(
+        mut self,
+        configure: impl FnOnce(MatchmakingQueue) -> MatchmakingQueue,
+    ) -> Self {
+        self.matchmaking_queue = Arc::new(configure(MatchmakingQueue::new()));
+        self
+    }
+
     /// Creates a connection and authenticates it with a test user
     pub async fn create_authenticated_connection(&self, name: &str) -> (ConnectionId, User) {
         let connection_id = ConnectionId::new();
@@ -83,10 +119,7 @@ impl TestGameServerSetup {
     }
 
     /// Helper to create a game with specific players
-    pub async fn create_test_game(
-        &self,
-        connection_ids: Vec<ConnectionId>,
-    ) -> Result<String, String> {
+    pub async fn create_test_game(&self, connection_ids: Vec<ConnectionId>) -> GameResult<String> {
         self.game_manager.create_game(connection_ids).await
     }
 
@@ -96,7 +129,7 @@ impl TestGameServerSetup {
         game_id: &str,
         connection_id: ConnectionId,
         word: &str,
-    ) -> Result<game_server::game_manager::GameEvent, String> {
+    ) -> game_core::GameResult<game_server::game_manager::GameEvent> {
         self.game_manager
             .submit_guess(game_id, connection_id, word.to_string())
             .await
@@ -122,6 +155,15 @@ pub fn assert_state_update(
     }
 }
 
+pub fn assert_guess_pending(
+    event: &game_server::game_manager::GameEvent,
+) -> &game_server::game_manager::GameEvent {
+    match event {
+        game_server::game_manager::GameEvent::GuessPending { .. } => event,
+        _ => panic!("Expected GuessPending event, got {:?}", event),
+    }
+}
+
 pub fn assert_game_over(
     event: &game_server::game_manager::GameEvent,
 ) -> &game_server::game_manager::GameEvent {
@@ -144,7 +186,7 @@ pub async fn all_players_guessed(setup: &TestGameServerSetup, game_id: &str) ->
     if let Some(state) = setup.game_manager.get_game_state(game_id).await {
         // This is a simplified check - in real implementation we'd need access to current_guesses
         // For testing, we'll rely on the game logic
-        return state.current_round > 1 || state.official_board.len() > 0;
+        return state.current_round > 1 || !state.official_board.is_empty();
     }
     false
 }
@@ -153,7 +195,7 @@ pub async fn all_players_guessed(setup: &TestGameServerSetup, game_id: &str) ->
 pub async fn setup_ready_game(
     setup: &TestGameServerSetup,
     player_names: &[&str],
-) -> Result<(String, Vec<(ConnectionId, User)>), String> {
+) -> GameResult<(String, Vec<(ConnectionId, User)>)> {
     let connections = setup.create_multiple_connections(player_names).await;
     let connection_ids: Vec<_> = connections.iter().map(|(id, _)| *id).collect();
 
@@ -162,6 +204,39 @@ pub async fn setup_ready_game(
     Ok((game_id, connections))
 }
 
+/// Dictionary words with as many letters as the game's current target, for
+/// tests that need valid guesses but don't care which. The target itself is
+/// left out (as replayed from the game's seed) so the guesses never solve it.
+pub async fn words_for_target(setup: &TestGameServerSetup, game_id: &str) -> Vec<&'static str> {
+    let state = setup
+        .game_manager
+        .get_game_state(game_id)
+        .await
+        .expect("Game should exist");
+    let target = match setup.game_manager.get_game_seed(game_id).await {
+        Some(seed) => setup
+            .game_manager
+            .replay_target_word(seed, state.current_round)
+            .ok(),
+        None => None,
+    };
+    let words = match state.word_length {
+        5 => vec![
+            "about", "above", "after", "again", "beach", "black", "brown",
+        ],
+        6 => vec![
+            "second", "fourth", "before", "friend", "letter", "number", "people",
+        ],
+        _ => vec![
+            "example", "nothing", "another", "without", "between", "through", "because",
+        ],
+    };
+    words
+        .into_iter()
+        .filter(|word| target.as_deref() != Some(*word))
+        .collect()
+}
+
 /// Simulate a complete round with all players guessing
 pub async fn play_round(
     setup: &TestGameServerSetup,
@@ -171,7 +246,10 @@ pub async fn play_round(
     let mut last_event = None;
 
     for (connection_id, word) in guesses {
-        let event = setup.submit_guess(game_id, connection_id, word).await?;
+        let event = setup
+            .submit_guess(game_id, connection_id, word)
+            .await
+            .map_err(|e| e.to_string())?;
         last_event = Some(event);
     }
 
@@ -1,7 +1,8 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export)]
 pub enum GameError {
     InvalidWord { word: String },
@@ -15,7 +16,7 @@ pub enum GameError {
     InvalidGameState { current_state: String },
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export)]
 pub enum ConnectionError {
     InvalidToken,
@@ -24,3 +25,33 @@ pub enum ConnectionError {
     ServerOverloaded,
     InternalError { message: String },
 }
+
+/// Machine-readable code attached to `ServerMessage::Error` so clients can
+/// react to specific failures without parsing the message text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export)]
+pub enum ErrorCode {
+    GameNotFound,
+    PlayerNotInGame,
+    EmptyGuess,
+    WrongLength,
+    InvalidWord,
+    WordNotAllowed,
+    WordAlreadyGuessed,
+    WrongPhase,
+    NotYourTurn,
+    TurnSkipNotAllowed,
+    GuessTooFast,
+    RoundSkipNotAllowed,
+    SpectatorCannotGuess,
+    HintsNotAllowed,
+    HintLimitReached,
+    NoHintAvailable,
+    InvalidPointThreshold,
+    RejoinTokenRequired,
+    InvalidRejoinToken,
+    RejoinTokenUsed,
+    RejoinTokenExpired,
+    RejoinUnavailable, // The seat is still occupied or nobody is away
+    Internal,
+}
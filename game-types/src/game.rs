@@ -1,10 +1,14 @@
 use crate::{GameId, PlayerId};
-use serde::{Deserialize, Serialize};
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::value::RawValue;
+use std::ops::Deref;
+use std::sync::{Arc, OnceLock};
 use ts_rs::TS;
 
 use crate::user::Player;
 
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export)]
 pub struct RoundCompletion {
     pub word: String,
@@ -19,7 +23,7 @@ pub enum RoundResult {
     GameOver(GuessResult),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export)]
 pub struct GameState {
     pub id: GameId,
@@ -33,11 +37,38 @@ pub struct GameState {
     pub current_winner: Option<PlayerId>,
     pub created_at: String,   // ISO 8601 string
     pub point_threshold: i32, // Configurable win condition
+    #[serde(default)]
+    pub teams: Vec<Team>, // Empty unless the game is played in team mode
+    #[serde(default)]
+    pub campaign: Option<CampaignProgress>, // Set when progress carries across words
+    #[serde(default)]
+    pub hide_scores_until_end: bool, // Opponents' points are withheld from each player until the game ends
+}
+
+/// Running totals kept from word to word when a game carries progress over.
+/// The board still resets for every new word.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export)]
+pub struct CampaignProgress {
+    pub words_solved: u32,
+    pub combo: u32,                     // Consecutive words solved by the same player
+    pub combo_player: Option<PlayerId>, // Who is on the current combo
+    pub best_combo: u32,
+}
+
+/// A side in team mode. Team points are the sum of the members' points.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export)]
+pub struct Team {
+    pub id: u32,
+    pub name: String,
+    pub player_ids: Vec<PlayerId>,
+    pub points: i32,
 }
 
 /// Safe version of GameState that doesn't expose the target word
 /// Used for HTTP responses where we need to protect game integrity
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export)]
 pub struct SafeGameState {
     pub id: GameId,
@@ -50,6 +81,10 @@ pub struct SafeGameState {
     pub current_winner: Option<PlayerId>,
     pub created_at: String,
     pub point_threshold: i32,
+    #[serde(default)]
+    pub teams: Vec<Team>,
+    #[serde(default)]
+    pub campaign: Option<CampaignProgress>,
 }
 
 impl From<&GameState> for SafeGameState {
@@ -65,14 +100,79 @@ impl From<&GameState> for SafeGameState {
             current_winner: game_state.current_winner.clone(),
             created_at: game_state.created_at.clone(),
             point_threshold: game_state.point_threshold,
+            teams: game_state.teams.clone(),
+            campaign: game_state.campaign.clone(),
+        }
+    }
+}
+
+/// A running game as listed for would-be spectators: who is playing and how
+/// far along it is, never the word or anyone's guesses
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export)]
+pub struct LiveGameSummary {
+    pub game_id: GameId,
+    pub players: Vec<Player>, // No guess histories; scores hidden while the game hides them
+    pub current_round: i32,
+    pub word_length: i32,
+    pub point_threshold: i32,
+}
+
+/// A `SafeGameState` broadcast to many connections at once. Clones share
+/// the state and the JSON it renders to, so a game's spectators cost one
+/// serialization per update however many of them are watching.
+#[derive(Debug, Clone)]
+pub struct SharedGameState {
+    state: Arc<SafeGameState>,
+    json: Arc<OnceLock<Box<RawValue>>>,
+}
+
+impl SharedGameState {
+    pub fn new(state: SafeGameState) -> Self {
+        Self {
+            state: Arc::new(state),
+            json: Arc::new(OnceLock::new()),
         }
     }
+
+    /// The state's JSON, serialized the first time any clone needs it
+    pub fn render(&self) -> &RawValue {
+        self.json.get_or_init(|| {
+            serde_json::value::to_raw_value(&*self.state).expect("game state serializes to JSON")
+        })
+    }
+}
+
+impl Deref for SharedGameState {
+    type Target = SafeGameState;
+
+    fn deref(&self) -> &SafeGameState {
+        &self.state
+    }
+}
+
+impl Serialize for SharedGameState {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.render().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SharedGameState {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        SafeGameState::deserialize(deserializer).map(Self::new)
+    }
 }
 
 impl GameState {
     /// Create a personalized version of the game state for a specific player
-    /// Only includes that player's guess history, while other players' histories are cleared
+    /// Only includes that player's guess history, while other players' histories are cleared,
+    /// and the masked word shows the positions already solved on the official board
     pub fn personalized_for_player(&self, player_id: PlayerId) -> Self {
+        let hide_scores = self.hide_scores_until_end
+            && self.status != GameStatus::Completed
+            && self.current_phase != GamePhase::GameOver;
+        let other_points = |points: i32| if hide_scores { 0 } else { points };
+
         let filtered_players = self
             .players
             .iter()
@@ -85,9 +185,10 @@ impl GameState {
                     Player {
                         user_id: player.user_id.clone(),
                         display_name: player.display_name.clone(),
-                        points: player.points,
+                        points: other_points(player.points),
                         guess_history: Vec::new(), // Clear other players' guess histories
                         is_connected: player.is_connected,
+                        avatar: player.avatar.clone(),
                     }
                 }
             })
@@ -95,21 +196,185 @@ impl GameState {
 
         GameState {
             id: self.id.clone(),
-            word: self.word.clone(),
+            word: self.revealed_mask(),
             word_length: self.word_length,
             current_round: self.current_round,
             status: self.status.clone(),
             current_phase: self.current_phase.clone(),
             players: filtered_players,
-            official_board: self.official_board.clone(),
+            official_board: self
+                .official_board
+                .iter()
+                .map(|guess| {
+                    if guess.player_id == player_id || !hide_scores {
+                        guess.clone()
+                    } else {
+                        // What each guess earned would give the scores away
+                        GuessResult {
+                            points_earned: 0,
+                            breakdown: ScoreBreakdown::default(),
+                            ..guess.clone()
+                        }
+                    }
+                })
+                .collect(),
             current_winner: self.current_winner.clone(),
             created_at: self.created_at.clone(),
             point_threshold: self.point_threshold,
+            teams: self
+                .teams
+                .iter()
+                .map(|team| Team {
+                    points: if team.player_ids.contains(&player_id) {
+                        team.points
+                    } else {
+                        other_points(team.points)
+                    },
+                    ..team.clone()
+                })
+                .collect(),
+            campaign: self.campaign.clone(),
+            hide_scores_until_end: self.hide_scores_until_end,
+        }
+    }
+
+    /// The masked word with every position the official board has shown as
+    /// correct filled in, e.g. `h**lo`. Positions nobody has solved stay
+    /// masked, so only letters the viewer can already see are exposed.
+    fn revealed_mask(&self) -> String {
+        let mut mask: Vec<char> = self.word.chars().collect();
+
+        for letter in self
+            .official_board
+            .iter()
+            .flat_map(|guess| &guess.letters)
+            .filter(|letter| matches!(letter.status, LetterStatus::Correct))
+        {
+            let Some(slot) = usize::try_from(letter.position)
+                .ok()
+                .and_then(|position| mask.get_mut(position))
+            else {
+                continue;
+            };
+            if *slot == '*'
+                && let Some(c) = letter.letter.chars().next()
+            {
+                *slot = c;
+            }
         }
+
+        mask.into_iter().collect()
+    }
+}
+
+/// The board of a single round, for post-game review
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export)]
+pub struct RoundHistory {
+    pub round: i32,
+    pub target_word: Option<String>, // Hidden while the round is still being played
+    pub board: Vec<GuessResult>,
+}
+
+/// A resolved round as announced in `RoundResult`, kept so players who were
+/// disconnected can catch up on what they missed
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export)]
+pub struct RoundSummary {
+    pub round: i32,
+    pub winning_guess: GuessResult,
+    pub is_word_completed: bool,
+}
+
+/// One word of a finished game, as recapped in `GameOver`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export)]
+pub struct RoundRecap {
+    pub round: i32,
+    pub word: String,
+    pub solved_by: Option<PlayerId>, // None when the game ended before the word was solved
+    pub points_awarded: Vec<RoundPoints>, // Players who made the board, in order of their first guess
+}
+
+/// Points a player earned on one round's board
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export)]
+pub struct RoundPoints {
+    pub player_id: PlayerId,
+    pub points: i32,
+}
+
+/// Where a word was first played in this game, so a client told the word
+/// was already guessed can point at it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export)]
+pub struct PriorGuess {
+    pub round: i32,
+    pub player_id: PlayerId,
+}
+
+/// Full record of a game's rounds and each player's guesses
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export)]
+pub struct GameHistory {
+    pub game_id: GameId,
+    pub status: GameStatus,
+    pub rounds: Vec<RoundHistory>,
+    pub players: Vec<Player>,
+}
+
+impl GameHistory {
+    /// Per-round recap of who solved each word and the points its board
+    /// awarded. Rounds whose word is still hidden are left out, so only a
+    /// finished game recaps every round.
+    pub fn recap(&self) -> Vec<RoundRecap> {
+        self.rounds
+            .iter()
+            .filter_map(|round| {
+                let word = round.target_word.clone()?;
+                let solved_by = round
+                    .board
+                    .iter()
+                    .find(|guess| guess.word == word)
+                    .map(|guess| guess.player_id.clone());
+
+                let mut points_awarded: Vec<RoundPoints> = Vec::new();
+                for guess in &round.board {
+                    match points_awarded
+                        .iter_mut()
+                        .find(|entry| entry.player_id == guess.player_id)
+                    {
+                        Some(entry) => {
+                            entry.points = entry.points.saturating_add(guess.points_earned)
+                        }
+                        None => points_awarded.push(RoundPoints {
+                            player_id: guess.player_id.clone(),
+                            points: guess.points_earned,
+                        }),
+                    }
+                }
+
+                Some(RoundRecap {
+                    round: round.round,
+                    word,
+                    solved_by,
+                    points_awarded,
+                })
+            })
+            .collect()
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+/// Why a game ended
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export)]
+pub enum GameOverReason {
+    #[default]
+    PointThreshold, // A player or team reached the point threshold
+    TimeLimit, // The game ran past the server's maximum duration; the leader wins
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export)]
 pub enum GameStatus {
     Queuing,   // Players in matchmaking
@@ -121,17 +386,18 @@ pub enum GameStatus {
     TimedOut,  // Game exceeded maximum duration
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export)]
 pub enum GamePhase {
     Waiting,
     Countdown,
     Guessing,
     IndividualGuess,
+    Intermission, // Brief pause after a round result before play resumes
     GameOver,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export)]
 pub struct GuessResult {
     pub word: String,
@@ -139,9 +405,26 @@ pub struct GuessResult {
     pub letters: Vec<LetterResult>,
     pub points_earned: i32,
     pub timestamp: String, // ISO 8601 string
+    #[serde(default)]
+    pub breakdown: ScoreBreakdown,
+}
+
+/// How a guess's `points_earned` was made up
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export)]
+pub struct ScoreBreakdown {
+    pub base_points: i32,    // Letters revealed plus the solve bonus
+    pub catch_up_bonus: i32, // Extra points for a winner trailing the leader
+}
+
+impl ScoreBreakdown {
+    /// The points the guess earned in total, saturating rather than overflowing
+    pub fn total(&self) -> i32 {
+        self.base_points.saturating_add(self.catch_up_bonus)
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export)]
 pub struct LetterResult {
     pub letter: String,
@@ -149,7 +432,7 @@ pub struct LetterResult {
     pub position: i32,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export)]
 pub enum LetterStatus {
     Correct, // Blue - correct letter in correct position
@@ -1,12 +1,14 @@
 pub mod errors;
 pub mod game;
 pub mod messages;
+pub mod schema;
 pub mod user;
 
 // Re-export all types
 pub use errors::*;
 pub use game::*;
 pub use messages::*;
+pub use schema::*;
 pub use user::*;
 
 // Shared type aliases for cross-tenant support
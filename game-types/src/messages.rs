@@ -1,32 +1,101 @@
 use crate::PlayerId;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
-use crate::{GamePhase, GameState, GuessResult, PersonalGuess, Player};
+use crate::{
+    Avatar, AvatarColor, ErrorCode, GameOverReason, GamePhase, GameState, GuessResult,
+    LetterResult, PersonalGuess, Player, PriorGuess, RoundRecap, RoundSummary, SafeGameState,
+    SharedGameState,
+};
 
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export)]
 pub enum ClientMessage {
-    Authenticate { token: String },
-    ForceAuthenticate { token: String }, // Force disconnect existing session
+    Hello {
+        protocol_version: u32,
+    }, // Optional; clients that skip it are assumed to speak version 1
+    Authenticate {
+        token: String,
+        #[serde(default)]
+        auto_queue: bool, // Join the queue as soon as authentication succeeds
+    },
+    ForceAuthenticate {
+        token: String,
+    }, // Force disconnect existing session
     JoinQueue,
     LeaveQueue,
     VoteStartGame,
-    SubmitGuess { word: String },
+    SubmitGuess {
+        word: String,
+    },
+    SkipTurn,      // Round winner passes on their individual guess
+    VoteSkipRound, // Vote to reveal the word and move on from a stuck round
+    RequestHint,   // Reveal one letter of the word to this player, for a point penalty
     LeaveGame,
-    RejoinGame { game_id: String },
+    RejoinGame {
+        game_id: String,
+        #[serde(default)]
+        token: Option<String>, // From `RejoinToken`; required when the server enforces tokens
+    },
+    Ready,
+    SetAvatar {
+        color: AvatarColor,
+        emoji: String,
+    },
+    ReportWord {
+        reason: String,
+    }, // Flags the current target word for review
+    StartPracticeWord {
+        word: String,
+    }, // Admins and dev mode only: solo game on a chosen target word
+    SubscribeQueueStatus, // Follow the countdown and queue size without joining the queue
+    UnsubscribeQueueStatus,
+    SpectateGame {
+        game_id: String,
+    }, // Watch a game without a seat in it
+    CreateLobby, // Open a private lobby; others join it with the code in `LobbyUpdate`
+    JoinLobby {
+        code: String,
+    },
+    LeaveLobby,
+    StartLobby, // Host only; `VoteStartGame` from the host does the same
+    SetLobbyAnnouncement {
+        text: String, // Host only; empty clears the announcement
+    },
+    SendChat {
+        text: String,
+    }, // To everyone seated in the sender's game
+    MutePlayer {
+        player_id: PlayerId,
+    }, // Stop receiving this player's chat on this connection
+    UnmutePlayer {
+        player_id: PlayerId,
+    },
     Heartbeat,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export)]
 pub enum ServerMessage {
+    Welcome {
+        server_version: String,
+        protocol_version: u32,
+    },
+    IncompatibleVersion {
+        min: u32, // Oldest protocol version the server accepts
+        max: u32, // Newest protocol version the server accepts
+    },
     AuthenticationSuccess {
         user: crate::User,
     },
     AuthenticationFailed {
         reason: String,
     },
+    AvatarUpdated {
+        avatar: Avatar,
+    },
+    WordReported,
     SessionConflict {
         existing_connection: String, // Description of existing session
     },
@@ -34,30 +103,129 @@ pub enum ServerMessage {
         position: u32,
     },
     QueueLeft,
+    QueueStatus {
+        players_in_queue: u32,
+    }, // Sent to queue status subscribers whenever the queue grows or shrinks
+    RequeueOnReconnect, // Server is restarting; rejoin the queue after reconnecting
     MatchmakingCountdown {
         seconds_remaining: u32,
         players_ready: u32,
         total_players: u32,
+        rating_window: Option<u32>, // Searching for opponents within this many rating points, if matching by skill
+    },
+    ServerBusy {
+        retry_after: u32, // Seconds until matchmaking is worth retrying
+    },
+    QueueCooldown {
+        retry_after: u32, // Seconds until a player who abandoned a game may queue again
+    },
+    QueueFull {
+        retry_after: u32, // Seconds until the next match is expected to free up places
+    },
+    MatchFailed {
+        reason: String,
+        requeued: bool, // Put back in the queue; a QueueJoined with the new position follows
+    }, // A match was formed but its game could not be created
+    LobbyUpdate {
+        code: String,   // Shareable code others join the lobby with
+        host: PlayerId, // The only member who can start the game
+        players: Vec<Player>,
+        max_players: u32,
+    }, // Sent to every member whenever someone joins or leaves
+    LobbyLeft,
+    LobbyAnnouncement {
+        text: Option<String>, // None once the host clears it
+    }, // Sent to every member when the host changes it, and to each new joiner
+    LobbyClosed, // The host left and the lobby was dissolved; members are out of it
+    HostChanged {
+        new_host: PlayerId, // Took over after the host left; a LobbyUpdate follows
+    },
+    NoMatchAvailable {
+        suggest_practice: bool, // Nobody else is queueing; a practice game is the way to play now
+    }, // Sent once to a player left waiting alone past the solo wait limit
+
+    GuessCooldown {
+        retry_after_ms: u32, // Milliseconds until the next guess will be accepted
     },
     MatchFound {
         game_id: String,
         players: Vec<Player>,
+        #[serde(default)]
+        categories: Vec<String>, // Theme the game's words come from; empty for the full dictionary
+        point_threshold: i32, // Points needed to win the game
     },
     GameStateUpdate {
         state: GameState,
     },
+    SpectatorStateUpdate {
+        #[ts(as = "SafeGameState")]
+        #[schemars(with = "SafeGameState")]
+        state: SharedGameState, // No target word, guess histories or hidden scores
+    }, // Sent to spectators whenever the players get a GameStateUpdate
+    SpectatorsFull {
+        max_spectators: u32, // The game already has this many spectators
+    },
     CountdownStart {
         seconds: u32,
     },
+    PlayerReady {
+        player_id: PlayerId,
+    },
+    PlayerGuessed {
+        player_id: PlayerId, // Has a guess in for this round; the word stays hidden
+    },
     RoundResult {
         winning_guess: GuessResult,
         your_guess: Option<PersonalGuess>,
         next_phase: GamePhase,
         is_word_completed: bool, // Explicit flag for word completion
     },
+    WordSolved {
+        word: String,     // The word that was just solved
+        solver: PlayerId, // Who solved it
+        next_round: u32,  // Round the new word is played in
+    }, // Follows the RoundResult that completed a word
+    SkipRoundVote {
+        votes: u32,  // Connected players who voted to skip this round
+        needed: u32, // Votes that will skip it
+    },
+    HintRevealed {
+        letter: LetterResult, // A letter of the word in its correct position
+        points_deducted: i32, // Less than the penalty when the score hit zero
+    }, // Sent to the requesting player only
+    RoundSkipped {
+        word: String,    // The word nobody found
+        next_round: u32, // Round the new word is played in
+    }, // Sent when a skip vote passes, before the new round's state
+
+    LettersRevealed {
+        positions: Vec<LetterResult>, // Letters that improved on what the board already showed
+    },
+    Intermission {
+        seconds: u32, // Pause before play resumes; a GameStateUpdate follows when it ends
+    },
+    RoundTimerUpdate {
+        seconds_remaining: u32, // Until the round is resolved with whatever guesses are in
+    }, // Sent when a collaborative round's timer starts
+    MissedRounds {
+        rounds: Vec<RoundSummary>, // Oldest first; sent after rejoining a game
+    },
+    RejoinToken {
+        game_id: String,
+        token: String, // Single-use; a new one is sent after each successful rejoin
+    },
     GameOver {
         winner: Player,
         final_scores: Vec<Player>,
+        recap: Vec<RoundRecap>, // One entry per word played, with the words revealed
+        #[serde(default)]
+        categories: Vec<String>, // Theme the game's words came from; empty for the full dictionary
+        #[serde(default)]
+        reason: GameOverReason,
+    },
+    RankChanged {
+        old_rank: Option<u32>, // None before the player's first recorded game
+        new_rank: u32,
     },
     GameLeft,
     PlayerDisconnected {
@@ -66,15 +234,26 @@ pub enum ServerMessage {
     PlayerReconnected {
         player_id: PlayerId,
     },
+    PlayerRenamed {
+        player_id: PlayerId,
+        display_name: String, // Name the player rejoined under
+    },
+    ChatMessage {
+        player_id: PlayerId,
+        text: String,
+    }, // Not sent to connections that muted the sender
     SessionDisconnected {
         reason: String,
     },
     Error {
         message: String,
+        code: Option<ErrorCode>,
+        #[serde(default)]
+        prior_guess: Option<PriorGuess>, // Set for WordAlreadyGuessed when the original is known
     },
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export)]
 pub struct ConnectionInfo {
     pub session_token: String,
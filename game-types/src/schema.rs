@@ -0,0 +1,29 @@
+use schemars::schema::RootSchema;
+use schemars::schema_for;
+use serde::Serialize;
+
+use crate::{ClientMessage, ServerMessage};
+
+/// Version of the WebSocket protocol this server speaks. Bump it whenever a
+/// message changes in a way older clients cannot handle.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Oldest protocol version the server still accepts in `Hello`
+pub const MIN_PROTOCOL_VERSION: u32 = 1;
+
+/// JSON Schema of every message a client may send or receive, for
+/// third-party client authors
+#[derive(Debug, Clone, Serialize)]
+pub struct ProtocolSchema {
+    pub protocol_version: u32,
+    pub client_message: RootSchema,
+    pub server_message: RootSchema,
+}
+
+pub fn protocol_schema() -> ProtocolSchema {
+    ProtocolSchema {
+        protocol_version: PROTOCOL_VERSION,
+        client_message: schema_for!(ClientMessage),
+        server_message: schema_for!(ServerMessage),
+    }
+}
@@ -1,8 +1,9 @@
 use crate::PlayerId;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export)]
 pub struct User {
     pub id: PlayerId,
@@ -12,9 +13,106 @@ pub struct User {
     pub total_wins: i32,
     pub total_games: i32,
     pub created_at: String, // ISO 8601 string for simplicity
+    #[serde(default)]
+    pub avatar: Option<Avatar>,
+    /// Roles granted by the identity provider or the server's admin allowlist.
+    /// Never persisted; filled in each time a token is validated.
+    #[serde(default)]
+    pub roles: Vec<Role>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+impl User {
+    pub fn has_role(&self, role: Role) -> bool {
+        self.roles.contains(&role)
+    }
+}
+
+/// Authorization roles beyond "the user themself"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    Admin,
+}
+
+impl Role {
+    /// Map a role claim from a token, e.g. an Azure AD app role, to a `Role`
+    pub fn from_claim(claim: &str) -> Option<Self> {
+        match claim.to_ascii_lowercase().as_str() {
+            "admin" => Some(Role::Admin),
+            _ => None,
+        }
+    }
+}
+
+/// Colors a player may pick for their avatar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "lowercase")]
+pub enum AvatarColor {
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    Purple,
+    Pink,
+    Gray,
+}
+
+impl AvatarColor {
+    pub const ALL: [AvatarColor; 8] = [
+        AvatarColor::Red,
+        AvatarColor::Orange,
+        AvatarColor::Yellow,
+        AvatarColor::Green,
+        AvatarColor::Blue,
+        AvatarColor::Purple,
+        AvatarColor::Pink,
+        AvatarColor::Gray,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AvatarColor::Red => "red",
+            AvatarColor::Orange => "orange",
+            AvatarColor::Yellow => "yellow",
+            AvatarColor::Green => "green",
+            AvatarColor::Blue => "blue",
+            AvatarColor::Purple => "purple",
+            AvatarColor::Pink => "pink",
+            AvatarColor::Gray => "gray",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|color| color.as_str() == value)
+    }
+}
+
+/// Emoji a player may pick for their avatar
+pub const AVATAR_EMOJIS: &[&str] = &[
+    "🦊", "🐼", "🐸", "🐙", "🦉", "🐢", "🦄", "🐝", "🐧", "🦁", "🐳", "🌵", "🍄", "⭐", "🔥", "🎲",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
+#[ts(export)]
+pub struct Avatar {
+    pub color: AvatarColor,
+    pub emoji: String,
+}
+
+impl Avatar {
+    /// Build an avatar, accepting only emoji from `AVATAR_EMOJIS`
+    pub fn new(color: AvatarColor, emoji: &str) -> Option<Self> {
+        AVATAR_EMOJIS.contains(&emoji).then(|| Self {
+            color,
+            emoji: emoji.to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export)]
 pub struct Player {
     pub user_id: PlayerId,
@@ -22,13 +120,17 @@ pub struct Player {
     pub points: i32,
     pub guess_history: Vec<PersonalGuess>,
     pub is_connected: bool,
+    #[serde(default)]
+    pub avatar: Option<Avatar>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, JsonSchema)]
 #[ts(export)]
 pub struct PersonalGuess {
     pub word: String,
     pub points_earned: i32,
     pub was_winning_guess: bool,
     pub timestamp: String, // ISO 8601 string
+    #[serde(default)]
+    pub assist_points: i32, // Part of `points_earned` for revealing letters in a round someone else won
 }
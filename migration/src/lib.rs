@@ -1,12 +1,30 @@
 pub use sea_orm_migration::prelude::*;
 
 mod m20240101_000001_create_users_table;
+mod m20240102_000001_add_avatar_to_users;
+mod m20240103_000001_create_word_reports_table;
+mod m20240104_000001_create_games_table;
+mod m20240105_000001_add_last_played_at_to_users;
+mod m20240106_000001_create_abuse_events_table;
+mod m20240107_000001_add_rating_to_users;
+mod m20240108_000001_add_results_to_games;
+mod m20240109_000001_add_seed_to_games;
 
 pub struct Migrator;
 
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20240101_000001_create_users_table::Migration)]
+        vec![
+            Box::new(m20240101_000001_create_users_table::Migration),
+            Box::new(m20240102_000001_add_avatar_to_users::Migration),
+            Box::new(m20240103_000001_create_word_reports_table::Migration),
+            Box::new(m20240104_000001_create_games_table::Migration),
+            Box::new(m20240105_000001_add_last_played_at_to_users::Migration),
+            Box::new(m20240106_000001_create_abuse_events_table::Migration),
+            Box::new(m20240107_000001_add_rating_to_users::Migration),
+            Box::new(m20240108_000001_add_results_to_games::Migration),
+            Box::new(m20240109_000001_add_seed_to_games::Migration),
+        ]
     }
 }
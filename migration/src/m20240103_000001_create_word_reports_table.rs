@@ -0,0 +1,65 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(WordReports::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(WordReports::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(WordReports::GameId).string().not_null())
+                    .col(ColumnDef::new(WordReports::Word).string().not_null())
+                    .col(ColumnDef::new(WordReports::ReporterId).string().not_null())
+                    .col(ColumnDef::new(WordReports::Reason).string().not_null())
+                    .col(
+                        ColumnDef::new(WordReports::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create index on reporter_id for per-user rate limiting
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_word_reports_reporter_id")
+                    .table(WordReports::Table)
+                    .col(WordReports::ReporterId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(WordReports::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum WordReports {
+    Table,
+    Id,
+    GameId,
+    Word,
+    ReporterId,
+    Reason,
+    CreatedAt,
+}
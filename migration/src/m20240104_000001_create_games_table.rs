@@ -0,0 +1,114 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Games::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Games::Id).string().not_null().primary_key())
+                    .col(ColumnDef::new(Games::PlayerCount).integer().not_null())
+                    .col(ColumnDef::new(Games::WordLength).integer().not_null())
+                    .col(ColumnDef::new(Games::Mode).string().not_null())
+                    .col(ColumnDef::new(Games::Status).string().not_null())
+                    .col(ColumnDef::new(Games::WinnerId).string().null())
+                    .col(
+                        ColumnDef::new(Games::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(Games::EndedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create index on status for created/completed/abandoned counts
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_games_status")
+                    .table(Games::Table)
+                    .col(Games::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(GamePlayers::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(GamePlayers::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(GamePlayers::GameId).string().not_null())
+                    .col(ColumnDef::new(GamePlayers::PlayerId).string().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_game_players_game_id")
+                            .from(GamePlayers::Table, GamePlayers::GameId)
+                            .to(Games::Table, Games::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create index on player_id for looking up a player's games
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_game_players_player_id")
+                    .table(GamePlayers::Table)
+                    .col(GamePlayers::PlayerId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(GamePlayers::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Games::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Games {
+    Table,
+    Id,
+    PlayerCount,
+    WordLength,
+    Mode,
+    Status,
+    WinnerId,
+    CreatedAt,
+    EndedAt,
+}
+
+#[derive(DeriveIden)]
+enum GamePlayers {
+    Table,
+    Id,
+    GameId,
+    PlayerId,
+}
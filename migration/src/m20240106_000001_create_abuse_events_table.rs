@@ -0,0 +1,62 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AbuseEvents::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AbuseEvents::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(AbuseEvents::UserId).string().not_null())
+                    .col(ColumnDef::new(AbuseEvents::Kind).string().not_null())
+                    .col(
+                        ColumnDef::new(AbuseEvents::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Penalties look up a user's recent events
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_abuse_events_user_id_created_at")
+                    .table(AbuseEvents::Table)
+                    .col(AbuseEvents::UserId)
+                    .col(AbuseEvents::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AbuseEvents::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum AbuseEvents {
+    Table,
+    Id,
+    UserId,
+    Kind,
+    CreatedAt,
+}